@@ -0,0 +1,110 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use mdmodels::datamodel::DataModel;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    env, fs,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Allocator tracking the peak of the allocated memory.
+struct PeakAllocator {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl PeakAllocator {
+    /// Resets the peak to the currently allocated memory.
+    fn reset(&self) {
+        self.peak
+            .store(self.current.load(Ordering::SeqCst), Ordering::SeqCst);
+    }
+
+    /// Returns the peak of the allocated memory since the last reset.
+    fn peak(&self) -> usize {
+        self.peak.load(Ordering::SeqCst)
+    }
+}
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let current = self.current.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            self.peak.fetch_max(current, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.current.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: PeakAllocator = PeakAllocator {
+    current: AtomicUsize::new(0),
+    peak: AtomicUsize::new(0),
+};
+
+/// Number of objects and enumerations of the generated model (about 5MB).
+const SIZE: usize = 12_000;
+
+/// Generates a large model with the given number of objects and enumerations.
+///
+/// Each object references the next one and an enumeration, such that the
+/// model stays valid.
+fn generate_model(size: usize) -> String {
+    let mut content = String::from(
+        "---\nid-field: true\nprefixes:\n  schema: http://schema.org/\n---\n\n# Large Model\n\n",
+    );
+
+    for i in 0..size {
+        let next = (i + 1) % size;
+        content.push_str(&format!(
+            "### Object{i} (schema:Thing)\n\nThe object number {i} of the generated model.\n\n\
+             - __id__\n  - Type: Identifier\n  - Term: schema:identifier\n  - Description: The identifier of the object.\n\
+             - name\n  - Type: string\n  - Term: schema:name\n  - Description: The name of the object.\n\
+             - values\n  - Type: float[]\n  - Description: Measured values of the object.\n\
+             - status\n  - Type: Status{i}\n  - Description: The status of the object.\n\
+             - next\n  - Type: Object{next}\n  - Description: The next object.\n\n\
+             ### Status{i}\n\n```python\nACTIVE = \"active\"\nINACTIVE = \"inactive\"\n```\n\n"
+        ));
+    }
+
+    content
+}
+
+/// Benchmarks parsing a large model, excluding its validation.
+///
+/// The peak memory of a single parse is reported beforehand, since criterion
+/// only measures the time.
+fn bench_parse_markdown(c: &mut Criterion) {
+    let path = env::temp_dir().join("mdmodels-bench-model.md");
+    let content = generate_model(SIZE);
+    fs::write(&path, &content).expect("Could not write model");
+    let size = content.len();
+    drop(content);
+
+    let baseline = ALLOCATOR.current.load(Ordering::SeqCst);
+    ALLOCATOR.reset();
+    let model = DataModel::from_markdown_unvalidated(&path).expect("Could not parse model");
+    println!(
+        "parser/parse_markdown_5mb: peak memory {:.1} MiB for a {:.1} MiB model",
+        (ALLOCATOR.peak() - baseline) as f64 / 1024.0 / 1024.0,
+        size as f64 / 1024.0 / 1024.0
+    );
+    drop(model);
+
+    let mut group = c.benchmark_group("parser");
+    group.sample_size(10);
+    group.bench_function("parse_markdown_5mb", |b| {
+        b.iter(|| DataModel::from_markdown_unvalidated(&path).expect("Could not parse model"))
+    });
+    group.finish();
+
+    fs::remove_file(&path).expect("Could not remove model");
+}
+
+criterion_group!(benches, bench_parse_markdown);
+criterion_main!(benches);
@@ -1,7 +1,22 @@
-use crate::xmltype::XMLType;
+use crate::{primitives::PrimitiveTypes, xmltype::XMLType};
+use lazy_static::lazy_static;
+use regex::Regex;
 use serde::{de::Visitor, Deserialize, Serialize};
 use std::{error::Error, fmt, str::FromStr};
 
+lazy_static! {
+    /// Matches the `identifier` type, which is turned into a string.
+    static ref IDENTIFIER_PATTERN: Regex = Regex::new(r"[I|i]dentifier").unwrap();
+
+    /// Matches fixed-size arrays, given as `T[n]`.
+    static ref FIXED_ARRAY_PATTERN: Regex = Regex::new(r"^(.+)\[\s*(\d+)\s*\]$").unwrap();
+
+    /// Matches map types, given as `map<K, V>` or `dict[K, V]`.
+    static ref MAP_PATTERN: Regex =
+        Regex::new(r"^(?:map<\s*([^,<>\s]+)\s*,\s*(.+)>|dict\[\s*([^,\[\]\s]+)\s*,\s*(.+)\])$")
+            .unwrap();
+}
+
 /// Represents an attribute with various properties and options.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Attribute {
@@ -10,10 +25,19 @@ pub struct Attribute {
     /// Indicates if the attribute is an array.
     #[serde(rename = "multiple")]
     pub is_array: bool,
+    /// Fixed number of items of an array attribute, e.g. `3` for `float[3]`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<usize>,
+    /// Indicates if the data types are the items of a tuple, in order, rather than a union.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub tuple: bool,
     /// Is an identifier or not
     pub is_id: bool,
     /// Data types associated with the attribute.
     pub dtypes: Vec<String>,
+    /// Key type of a map attribute, whose value types are given by `dtypes`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub map_key: Option<String>,
     /// Documentation string for the attribute.
     pub docstring: String,
     /// List of additional options for the attribute.
@@ -28,6 +52,62 @@ pub struct Attribute {
     /// XML type information for the attribute.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub xml: Option<XMLType>,
+    /// Name of the field that selects the member type of a union.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discriminator: Option<String>,
+    /// Unit of a quantitative attribute (e.g. `mmol/L`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// Alternative names accepted for the attribute during deserialization.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// Fixed value the attribute must hold.
+    #[serde(rename = "const", skip_serializing_if = "Option::is_none")]
+    pub constant: Option<DataType>,
+    /// Indicates if the attributes of the referenced object are flattened into the parent.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub inline: bool,
+    /// Indicates if generated deserializers accept values given as strings, e.g. `"123"` for an integer.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub coerce: bool,
+    /// Name of the group the attribute belongs to, e.g. for documentation sections.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Name of the database column, overriding the attribute name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    /// One-line summary of the attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// Access modifier of the generated field, public if not given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+    /// Targets the attribute is generated for, or excluded from if prefixed by `!`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<String>,
+    /// Kinds of the definitions the data types refer to, in the order of `dtypes`.
+    ///
+    /// The kinds are derived from the model and hence not serialized.
+    #[serde(skip)]
+    pub kinds: Vec<TypeKind>,
+}
+
+/// Kind of definition a data type of an attribute refers to.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TypeKind {
+    /// A primitive type, such as `string` or `float`.
+    Primitive,
+    /// An object of the model, including instances of generic objects.
+    Object,
+    /// An enumeration of the model.
+    Enum,
+    /// A schema referenced by URL.
+    External,
+    /// The type parameter of the enclosing generic object.
+    Parameter,
+    /// A type that is not defined, which is reported by the validator.
+    Undefined,
 }
 
 impl Attribute {
@@ -41,14 +121,29 @@ impl Attribute {
         Attribute {
             name: name.clone(),
             dtypes: Vec::new(),
+            map_key: None,
             docstring: String::new(),
             options: Vec::new(),
             is_array: false,
+            size: None,
+            tuple: false,
             is_id: false,
             term: None,
             required,
-            xml: Some(XMLType::from_str(name.as_str()).unwrap()),
+            xml: None,
             default: None,
+            discriminator: None,
+            unit: None,
+            aliases: Vec::new(),
+            constant: None,
+            inline: false,
+            coerce: false,
+            group: None,
+            column: None,
+            visibility: None,
+            summary: None,
+            targets: Vec::new(),
+            kinds: Vec::new(),
         }
     }
 
@@ -71,8 +166,20 @@ impl Attribute {
             "type" => self.set_dtype(option.value),
             "term" => self.term = Some(option.value),
             "description" => self.docstring = option.value,
+            "summary" => self.summary = Some(option.value),
             "xml" => self.set_xml(XMLType::from_str(&option.value).expect("Invalid XML type")),
             "default" => self.default = Some(DataType::from_str(&option.value)?),
+            "discriminator" => self.discriminator = Some(option.value),
+            "unit" => self.unit = Some(option.value),
+            "alias" => self.set_aliases(option.value),
+            "const" => self.set_constant(&option.value)?,
+            "inline" => self.inline = option.value.to_lowercase().parse::<bool>()?,
+            "coerce" => self.coerce = option.value.to_lowercase().parse::<bool>()?,
+            "optional" => self.required = !option.value.to_lowercase().parse::<bool>()?,
+            "group" => self.group = Some(option.value),
+            "column" => self.column = Some(option.value),
+            "visibility" => self.visibility = Some(option.value.to_lowercase()),
+            "targets" => self.targets = split_targets(&option.value),
             _ => self.options.push(option),
         }
 
@@ -81,25 +188,179 @@ impl Attribute {
 
     /// Sets the data type for the attribute.
     ///
+    /// Multiple types can be given as a list separated by commas or `|`,
+    /// which results in a union of the given types. An attribute is either
+    /// an array or not, so `[]` applies to the whole union, no matter which
+    /// member it is attached to. A union can be grouped in parentheses to
+    /// mark it as an array explicitly, e.g. `(Quantity | Range)[]`.
+    ///
+    /// Arrays of a fixed size are given as `float[3]`, tuples as a
+    /// parenthesized list of comma-separated types, e.g. `(float, string)`.
+    ///
     /// # Arguments
     ///
     /// * `dtype` - The data type to set.
     fn set_dtype(&mut self, dtype: String) {
-        let mut dtype = dtype;
-        // Handle special case for identifiers
-        if dtype.to_lowercase().starts_with("identifier") {
-            self.is_id = true;
-            // Regex replace identifier or Identifier with string
-            let pattern = regex::Regex::new(r"[I|i]dentifier").unwrap();
-            dtype = pattern.replace_all(&dtype, "string").to_string();
+        // Arrays of maps are kept as they are and rejected by the validation
+        if dtype.trim().strip_suffix("[]").is_some_and(is_map_type) {
+            self.dtypes.push(dtype.trim().to_string());
+            return;
+        }
+
+        // Handle special case for maps, whose value type is parsed as usual
+        if let Some(captures) = MAP_PATTERN.captures(dtype.trim()) {
+            let key = captures.get(1).or(captures.get(3)).unwrap().as_str();
+            let value = captures.get(2).or(captures.get(4)).unwrap().as_str();
+
+            // Nested maps are kept as they are and rejected by the validation
+            if is_map_type(value.trim().trim_end_matches("[]")) {
+                self.dtypes.push(dtype.trim().to_string());
+                return;
+            }
+
+            self.map_key = Some(map_type_name(key).to_string());
+            return self.set_dtype(
+                value
+                    .split(',')
+                    .map(|v| map_type_name(v.trim()))
+                    .collect::<Vec<&str>>()
+                    .join(", "),
+            );
         }
 
-        // Handle special case for arrays
-        if dtype.ends_with("[]") {
+        // Handle special case for grouped unions, whose array suffix applies to all members
+        if let Some(members) = dtype
+            .trim()
+            .strip_suffix("[]")
+            .and_then(|d| d.trim_end().strip_suffix(')'))
+            .and_then(|d| d.strip_prefix('('))
+        {
             self.is_array = true;
+            return self.set_dtype(members.to_string());
         }
 
-        self.dtypes.push(dtype.trim_end_matches("[]").to_string());
+        // Handle special case for tuples, whose items are typed by position
+        if let Some(items) = dtype
+            .trim()
+            .strip_prefix('(')
+            .and_then(|d| d.strip_suffix(')'))
+        {
+            // Parentheses around a union merely group it
+            if items.contains('|') {
+                return self.set_dtype(items.to_string());
+            }
+
+            let items = items.split(',').map(|d| d.trim()).collect::<Vec<&str>>();
+            self.is_array = true;
+            self.tuple = true;
+            self.size = Some(items.len());
+            self.dtypes.extend(items.iter().map(|d| d.to_string()));
+            return;
+        }
+
+        // Handle special case for arrays of a fixed size
+        if let Some(captures) = FIXED_ARRAY_PATTERN.captures(dtype.trim()) {
+            self.is_array = true;
+            self.size = captures[2].parse().ok();
+            return self.set_dtype(captures[1].to_string());
+        }
+
+        for dtype in dtype
+            .split([',', '|'])
+            .map(|d| d.trim())
+            .filter(|d| !d.is_empty())
+        {
+            let mut dtype = dtype.to_string();
+
+            // Handle special case for identifiers
+            if dtype.to_lowercase().starts_with("identifier") {
+                self.is_id = true;
+                // Regex replace identifier or Identifier with string
+                dtype = IDENTIFIER_PATTERN.replace_all(&dtype, "string").to_string();
+            }
+
+            // Handle special case for arrays
+            if dtype.ends_with("[]") {
+                self.is_array = true;
+            }
+
+            self.dtypes.push(dtype.trim_end_matches("[]").to_string());
+        }
+    }
+
+    /// Sets the aliases of the attribute.
+    ///
+    /// # Arguments
+    ///
+    /// * `aliases` - A comma-separated list of aliases.
+    fn set_aliases(&mut self, aliases: String) {
+        self.aliases.extend(
+            aliases
+                .split(',')
+                .map(|a| a.trim())
+                .filter(|a| !a.is_empty())
+                .map(|a| a.to_string()),
+        );
+    }
+
+    /// Sets the constant value of the attribute.
+    ///
+    /// An attribute with a constant value is implicitly required.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The constant value to set.
+    fn set_constant(&mut self, value: &str) -> Result<(), Box<dyn Error>> {
+        self.constant = Some(DataType::from_str(value)?);
+        self.required = true;
+
+        Ok(())
+    }
+
+    /// Classifies each data type by the kind of definition it refers to.
+    ///
+    /// Objects take precedence over enumerations of the same name, which
+    /// are reported as duplicates by the validator.
+    ///
+    /// # Arguments
+    ///
+    /// * `objects` - The names of the objects of the model.
+    /// * `enums` - The names of the enumerations of the model.
+    /// * `type_param` - The type parameter of the enclosing object, if generic.
+    pub fn resolve_kinds(&mut self, objects: &[&str], enums: &[&str], type_param: Option<&str>) {
+        let primitives = PrimitiveTypes::new();
+
+        self.kinds = self
+            .dtypes
+            .iter()
+            .map(|dtype| {
+                let name = generic_type(dtype).map_or(dtype.as_str(), |(name, _)| name);
+
+                if objects.contains(&name) {
+                    TypeKind::Object
+                } else if enums.contains(&name) {
+                    TypeKind::Enum
+                } else if is_external_type(dtype) {
+                    TypeKind::External
+                } else if type_param == Some(name) {
+                    TypeKind::Parameter
+                } else if primitives.is_primitive(dtype) {
+                    TypeKind::Primitive
+                } else {
+                    TypeKind::Undefined
+                }
+            })
+            .collect();
+    }
+
+    /// Checks if the attribute is a union of multiple types.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the attribute has more than one data type and is not a
+    /// tuple, `false` otherwise.
+    pub fn is_union(&self) -> bool {
+        self.dtypes.len() > 1 && !self.tuple
     }
 
     /// Converts the attribute to a JSON schema.
@@ -130,6 +391,168 @@ impl Attribute {
     }
 }
 
+/// Name of the built-in composite type for a numeric value with a unit.
+pub const QUANTITY_TYPE: &str = "quantity";
+
+/// Attribute option keys that are processed by the parser or the generators.
+///
+/// Other keys are kept as options, but flagged by the validator as likely
+/// typos, unless they are one of the `SCHEMA_KEYWORDS`.
+pub const KNOWN_OPTIONS: [&str; 17] = [
+    "Type",
+    "Term",
+    "Description",
+    "Summary",
+    "XML",
+    "Default",
+    "Discriminator",
+    "Unit",
+    "Alias",
+    "Const",
+    "Inline",
+    "Coerce",
+    "Optional",
+    "Group",
+    "Column",
+    "Visibility",
+    "Targets",
+];
+
+/// JSON Schema keywords that are passed through as options to the properties of schemas.
+///
+/// The keywords are written in the case given here, with a lowercase first letter.
+pub const SCHEMA_KEYWORDS: [&str; 22] = [
+    "Minimum",
+    "Maximum",
+    "ExclusiveMinimum",
+    "ExclusiveMaximum",
+    "MultipleOf",
+    "MinLength",
+    "MaxLength",
+    "Pattern",
+    "Format",
+    "MinItems",
+    "MaxItems",
+    "UniqueItems",
+    "MinContains",
+    "MaxContains",
+    "MinProperties",
+    "MaxProperties",
+    "Title",
+    "Examples",
+    "ReadOnly",
+    "WriteOnly",
+    "Deprecated",
+    "ContentMediaType",
+];
+
+/// Name of the pseudo-type accepting values of any type.
+pub const ANY_TYPE: &str = "any";
+
+/// Name of the object the built-in `quantity` type expands into.
+pub const QUANTITY_OBJECT: &str = "Quantity";
+
+/// Checks whether a data type refers to an external schema by URL.
+///
+/// # Arguments
+///
+/// * `dtype` - The data type to check.
+///
+/// # Returns
+///
+/// `true` if the data type is an absolute `http(s)` URL, `false` otherwise.
+pub fn is_external_type(dtype: &str) -> bool {
+    dtype.starts_with("http://") || dtype.starts_with("https://")
+}
+
+/// Splits a generic type, given as `Name<Arg>`, into its name and type argument.
+///
+/// # Arguments
+///
+/// * `dtype` - The data type to split.
+///
+/// # Returns
+///
+/// The name and type argument, or `None` if the type is not generic.
+pub fn generic_type(dtype: &str) -> Option<(&str, &str)> {
+    let (name, arg) = dtype.strip_suffix('>')?.split_once('<')?;
+    let is_identifier = |s: &str| {
+        !s.is_empty()
+            && s.chars()
+                .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+    };
+
+    match is_identifier(name) && is_identifier(arg) {
+        true => Some((name, arg)),
+        false => None,
+    }
+}
+
+/// Splits a comma-separated list of targets, e.g. `sql, !json-schema`.
+///
+/// # Arguments
+///
+/// * `targets` - The list of targets.
+pub(crate) fn split_targets(targets: &str) -> Vec<String> {
+    targets
+        .split(',')
+        .map(|target| target.trim().replace("! ", "!"))
+        .filter(|target| !target.is_empty())
+        .collect()
+}
+
+/// Splits the items of a list default, e.g. `"a, b", c`, at commas outside of quotes.
+///
+/// # Arguments
+///
+/// * `items` - The items of the list, without the square brackets.
+fn split_list_items(items: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut quoted = false;
+    let mut start = 0;
+
+    for (index, c) in items.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                parts.push(&items[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    parts.push(&items[start..]);
+    parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Checks whether a type is a map, given as `map<K, V>` or `dict[K, V]`.
+///
+/// # Arguments
+///
+/// * `dtype` - The type to check.
+pub(crate) fn is_map_type(dtype: &str) -> bool {
+    MAP_PATTERN.is_match(dtype.trim())
+}
+
+/// Returns the MD-Models name of a type given in a map, accepting Python names.
+///
+/// # Arguments
+///
+/// * `dtype` - The type name to convert.
+fn map_type_name(dtype: &str) -> &str {
+    match dtype {
+        "str" => "string",
+        "int" => "integer",
+        "bool" => "boolean",
+        dtype => dtype,
+    }
+}
+
 /// Represents an option for an attribute.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AttrOption {
@@ -178,6 +601,7 @@ pub enum DataType {
     Integer(i64),
     Float(f64),
     String(String),
+    List(Vec<DataType>),
 }
 
 impl PartialEq for DataType {
@@ -187,6 +611,7 @@ impl PartialEq for DataType {
             (DataType::Integer(a), DataType::Integer(b)) => a == b,
             (DataType::Float(a), DataType::Float(b)) => a == b,
             (DataType::String(a), DataType::String(b)) => a == b,
+            (DataType::List(a), DataType::List(b)) => a == b,
             _ => false,
         }
     }
@@ -195,20 +620,58 @@ impl PartialEq for DataType {
 impl FromStr for DataType {
     type Err = String;
 
-    /// Converts a string to a DataType (Boolean, Integer, Float, or String).
+    /// Converts a string to a DataType (Boolean, Integer, Float, String, or List).
+    ///
+    /// Values enclosed in square brackets, such as `[a, b]`, are parsed as lists
+    /// of comma-separated items, where `[]` denotes an empty list. Commas within
+    /// quoted items, such as `["a, b"]`, do not separate items.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Ok(b) = s.to_lowercase().parse::<bool>() {
+        if let Some(items) = s.trim().strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            split_list_items(items)
+                .into_iter()
+                .map(
+                    |item| match item.len() > 1 && item.starts_with('"') && item.ends_with('"') {
+                        true => Ok(DataType::String(item.to_string())),
+                        false => DataType::from_str(item),
+                    },
+                )
+                .collect::<Result<Vec<_>, _>>()
+                .map(DataType::List)
+        } else if let Ok(b) = s.to_lowercase().parse::<bool>() {
             Ok(DataType::Boolean(b))
         } else if let Ok(i) = s.to_lowercase().parse::<i64>() {
             Ok(DataType::Integer(i))
         } else if let Ok(f) = s.to_lowercase().parse::<f64>() {
             Ok(DataType::Float(f))
-        } else if let Ok(s) = s.to_lowercase().parse::<String>() {
-            Ok(DataType::String(format!("\"{}\"", s)))
         } else {
-            Err("Invalid data type".to_string())
+            Ok(DataType::String(format!("\"{}\"", s)))
+        }
+    }
+}
+
+impl DataType {
+    /// Returns the raw value of the data type, without surrounding quotes.
+    pub fn raw_value(&self) -> String {
+        match self {
+            DataType::Boolean(b) => b.to_string(),
+            DataType::Integer(i) => i.to_string(),
+            DataType::Float(f) => f.to_string(),
+            DataType::String(s) => s.trim_matches('"').to_string(),
+            DataType::List(items) => format!(
+                "[{}]",
+                items
+                    .iter()
+                    .map(DataType::raw_value)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
         }
     }
+
+    /// Checks whether the data type is a list of values.
+    pub fn is_list(&self) -> bool {
+        matches!(self, DataType::List(_))
+    }
 }
 
 impl Serialize for DataType {
@@ -222,6 +685,7 @@ impl Serialize for DataType {
             DataType::Integer(i) => serializer.serialize_i64(*i),
             DataType::Float(f) => serializer.serialize_f64(*f),
             DataType::String(s) => serializer.serialize_str(s),
+            DataType::List(items) => items.serialize(serializer),
         }
     }
 }
@@ -237,7 +701,7 @@ impl<'de> Deserialize<'de> for DataType {
             type Value = DataType;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("a boolean, integer, float, or string")
+                formatter.write_str("a boolean, integer, float, string, or list")
             }
 
             fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
@@ -259,6 +723,17 @@ impl<'de> Deserialize<'de> for DataType {
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
                 Ok(DataType::String(v.to_string()))
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut items = Vec::new();
+                while let Some(item) = seq.next_element()? {
+                    items.push(item);
+                }
+                Ok(DataType::List(items))
+            }
         }
 
         deserializer.deserialize_any(DataTypeVisitor)
@@ -323,6 +798,21 @@ mod tests {
         assert_eq!(attr.docstring, "This is a test");
     }
 
+    #[test]
+    fn test_attribute_set_map_dtype() {
+        let mut attr = Attribute::new("name".to_string(), false);
+        attr.set_dtype("map<string, Quantity>".to_string());
+        assert_eq!(attr.map_key, Some("string".to_string()));
+        assert_eq!(attr.dtypes, vec!["Quantity"]);
+        assert!(!attr.is_array);
+
+        let mut attr = Attribute::new("name".to_string(), false);
+        attr.set_dtype("dict[str, float[]]".to_string());
+        assert_eq!(attr.map_key, Some("string".to_string()));
+        assert_eq!(attr.dtypes, vec!["float"]);
+        assert!(attr.is_array);
+    }
+
     #[test]
     fn test_attribute_set_dtype() {
         let mut attr = Attribute::new("name".to_string(), false);
@@ -341,6 +831,142 @@ mod tests {
         assert_eq!(attr.is_array, true);
     }
 
+    #[test]
+    fn test_attribute_set_union_dtype() {
+        let mut attr = Attribute::new("name".to_string(), false);
+        attr.set_dtype("Cat, Dog[]".to_string());
+        assert_eq!(attr.dtypes, vec!["Cat".to_string(), "Dog".to_string()]);
+        assert_eq!(attr.is_array, true);
+        assert_eq!(attr.is_union(), true);
+    }
+
+    #[test]
+    fn test_attribute_set_union_array_dtype() {
+        let mut attr = Attribute::new("values".to_string(), false);
+        attr.set_dtype("(Quantity | Range)[]".to_string());
+        assert_eq!(
+            attr.dtypes,
+            vec!["Quantity".to_string(), "Range".to_string()]
+        );
+        assert_eq!(attr.is_array, true);
+
+        let mut attr = Attribute::new("value".to_string(), false);
+        attr.set_dtype("Quantity | Range".to_string());
+        assert_eq!(
+            attr.dtypes,
+            vec!["Quantity".to_string(), "Range".to_string()]
+        );
+        assert_eq!(attr.is_array, false);
+    }
+
+    #[test]
+    fn test_attribute_set_fixed_size_dtype() {
+        let mut attr = Attribute::new("rgb".to_string(), false);
+        attr.set_dtype("float[3]".to_string());
+        assert_eq!(attr.dtypes, vec!["float".to_string()]);
+        assert_eq!(attr.is_array, true);
+        assert_eq!(attr.size, Some(3));
+        assert_eq!(attr.tuple, false);
+    }
+
+    #[test]
+    fn test_attribute_set_tuple_dtype() {
+        let mut attr = Attribute::new("point".to_string(), false);
+        attr.set_dtype("(float, string)".to_string());
+        assert_eq!(attr.dtypes, vec!["float".to_string(), "string".to_string()]);
+        assert_eq!(attr.is_array, true);
+        assert_eq!(attr.size, Some(2));
+        assert_eq!(attr.tuple, true);
+        assert_eq!(attr.is_union(), false);
+    }
+
+    #[test]
+    fn test_is_external_type() {
+        assert!(is_external_type("https://example.org/schemas/sample.json"));
+        assert!(is_external_type(
+            "http://example.org/sample.json#/$defs/Sample"
+        ));
+        assert!(!is_external_type("Sample"));
+        assert!(!is_external_type("string"));
+    }
+
+    #[test]
+    fn test_attribute_add_const_option() {
+        let mut attr = Attribute::new("kind".to_string(), false);
+        let option = AttrOption::new("Const".to_string(), "liquid".to_string());
+        attr.add_option(option).expect("Failed to add option");
+        assert_eq!(
+            attr.constant,
+            Some(DataType::String("\"liquid\"".to_string()))
+        );
+        assert_eq!(attr.required, true);
+        assert_eq!(attr.options.len(), 0);
+    }
+
+    #[test]
+    fn test_attribute_add_coerce_option() {
+        let mut attr = Attribute::new("count".to_string(), false);
+        assert!(!attr.coerce);
+        let option = AttrOption::new("Coerce".to_string(), "true".to_string());
+        attr.add_option(option).expect("Failed to add option");
+        assert!(attr.coerce);
+        assert_eq!(attr.options.len(), 0);
+    }
+
+    #[test]
+    fn test_attribute_add_inline_option() {
+        let mut attr = Attribute::new("address".to_string(), false);
+        let option = AttrOption::new("Inline".to_string(), "True".to_string());
+        attr.add_option(option).expect("Failed to add option");
+        assert!(attr.inline);
+        assert_eq!(attr.options.len(), 0);
+    }
+
+    #[test]
+    fn test_attribute_add_group_option() {
+        let mut attr = Attribute::new("name".to_string(), false);
+        let option = AttrOption::new("Group".to_string(), "Identity".to_string());
+        attr.add_option(option).expect("Failed to add option");
+        assert_eq!(attr.group, Some("Identity".to_string()));
+        assert_eq!(attr.options.len(), 0);
+    }
+
+    #[test]
+    fn test_attribute_add_visibility_option() {
+        let mut attr = Attribute::new("secret".to_string(), false);
+        let option = AttrOption::new("Visibility".to_string(), "Private".to_string());
+        attr.add_option(option).expect("Failed to add option");
+        assert_eq!(attr.visibility, Some("private".to_string()));
+        assert_eq!(attr.options.len(), 0);
+    }
+
+    #[test]
+    fn test_attribute_add_discriminator_option() {
+        let mut attr = Attribute::new("pet".to_string(), false);
+        let option = AttrOption::new("Discriminator".to_string(), "kind".to_string());
+        attr.add_option(option).expect("Failed to add option");
+        assert_eq!(attr.discriminator, Some("kind".to_string()));
+        assert_eq!(attr.options.len(), 0);
+    }
+
+    #[test]
+    fn test_attribute_add_unit_option() {
+        let mut attr = Attribute::new("concentration".to_string(), false);
+        let option = AttrOption::new("Unit".to_string(), "mmol/L".to_string());
+        attr.add_option(option).expect("Failed to add option");
+        assert_eq!(attr.unit, Some("mmol/L".to_string()));
+        assert_eq!(attr.options.len(), 0);
+    }
+
+    #[test]
+    fn test_attribute_add_alias_option() {
+        let mut attr = Attribute::new("name".to_string(), false);
+        let option = AttrOption::new("Alias".to_string(), "title, label".to_string());
+        attr.add_option(option).expect("Failed to add option");
+        assert_eq!(attr.aliases, vec!["title".to_string(), "label".to_string()]);
+        assert_eq!(attr.options.len(), 0);
+    }
+
     #[test]
     fn test_attribute_set_xml_attr() {
         let mut attr = Attribute::new("name".to_string(), false);
@@ -373,14 +999,9 @@ mod tests {
 
     #[test]
     fn test_default_xml_type() {
+        // The XML type follows the `xml-default` policy and is set by the parser
         let attr = Attribute::new("name".to_string(), false);
-        assert_eq!(
-            attr.xml.unwrap(),
-            XMLType::Element {
-                is_attr: false,
-                name: "name".to_string(),
-            }
-        );
+        assert_eq!(attr.xml, None);
     }
 
     #[test]
@@ -428,4 +1049,42 @@ mod tests {
             serde_json::from_str("true").expect("Failed to deserialize bool DataType");
         assert_eq!(deserialized, DataType::Boolean(true));
     }
+
+    #[test]
+    fn test_list_data_type() {
+        // Test list of values
+        let dt = DataType::from_str("[1, two, \"3\"]").expect("Failed to parse list");
+        assert_eq!(
+            dt,
+            DataType::List(vec![
+                DataType::Integer(1),
+                DataType::String("\"two\"".to_string()),
+                DataType::String("\"3\"".to_string()),
+            ])
+        );
+        assert_eq!(dt.raw_value(), "[1, two, 3]");
+
+        // Test quoted items containing commas
+        let dt = DataType::from_str("[\"a, b\", c]").expect("Failed to parse list");
+        assert_eq!(
+            dt,
+            DataType::List(vec![
+                DataType::String("\"a, b\"".to_string()),
+                DataType::String("\"c\"".to_string()),
+            ])
+        );
+
+        // Test empty list
+        let dt = DataType::from_str("[]").expect("Failed to parse empty list");
+        assert_eq!(dt, DataType::List(vec![]));
+        assert_eq!(serde_json::to_string(&dt).unwrap(), "[]");
+
+        // Test round trip
+        let deserialized: DataType =
+            serde_json::from_str("[1, true]").expect("Failed to deserialize list DataType");
+        assert_eq!(
+            deserialized,
+            DataType::List(vec![DataType::Integer(1), DataType::Boolean(true)])
+        );
+    }
 }
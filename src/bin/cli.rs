@@ -1,12 +1,25 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use colored::Colorize;
+use log::{debug, info, warn, LevelFilter, Log, Metadata, Record};
 use mdmodels::{
     datamodel::DataModel,
-    exporters::{render_jinja_template, Templates},
-    pipeline::process_pipeline,
+    error::MdModelsError,
+    exporters::{render_jinja_template, render_jinja_units, Templates},
+    migration::rust_migration,
+    pipeline::process_pipeline_with,
+    validation::Validator,
 };
 use serde::{Deserialize, Serialize};
-use std::{error::Error, fmt::Display, fs, io::Write, path::PathBuf, str::FromStr};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{self, ExitCode, Stdio},
+    str::FromStr,
+};
 
 /// Command-line interface for MD-Models CLI.
 #[derive(Parser)]
@@ -16,6 +29,25 @@ struct Cli {
     /// Subcommands for the CLI.
     #[command(subcommand)]
     cmd: Commands,
+
+    /// Suppress warnings and status messages, errors are still printed.
+    #[arg(
+        short,
+        long,
+        global = true,
+        conflicts_with = "verbose",
+        help = "Suppress warnings and status messages, errors are still printed"
+    )]
+    quiet: bool,
+
+    /// Print warnings and the generation progress of each object.
+    #[arg(
+        short,
+        long,
+        global = true,
+        help = "Print warnings and the generation progress of each object"
+    )]
+    verbose: bool,
 }
 
 /// Enum representing the subcommands.
@@ -27,21 +59,45 @@ enum Commands {
     Validate(ValidateArgs),
     /// Pipeline for generating multiple files.
     Pipeline(PipelineArgs),
+    /// Compare two versions of a markdown model.
+    Diff(DiffArgs),
+    /// Check that a markdown model is backward-compatible to a previous version.
+    Compat(CompatArgs),
+    /// Generate Rust conversion stubs between two versions of a markdown model.
+    Migrate(MigrateArgs),
+    /// Print metrics summarizing the size and complexity of a markdown model.
+    Stats(StatsArgs),
+    /// Write a starter markdown model to begin a new data model with.
+    Init(InitArgs),
+    /// List all available templates.
+    Targets,
 }
 
 /// Arguments for the validate subcommand.
 #[derive(Parser, Debug)]
 struct ValidateArgs {
     /// Path or URL to the markdown file.
-    #[arg(short, long, help = "Path or URL to the markdown file")]
+    #[arg(
+        short,
+        long,
+        help = "Path or URL to the markdown file (URLs require the `remote` feature)"
+    )]
     input: InputType,
+
+    /// Treat violations of the naming conventions as errors.
+    #[arg(long, help = "Treat violations of the naming conventions as errors")]
+    strict: bool,
 }
 
 /// Arguments for the convert subcommand.
 #[derive(Parser, Debug)]
 struct ConvertArgs {
     /// Path or URL to the markdown file.
-    #[arg(short, long, help = "Path or URL to the markdown file")]
+    #[arg(
+        short,
+        long,
+        help = "Path or URL to the markdown file (URLs require the `remote` feature)"
+    )]
     input: InputType,
 
     /// Path to the output file.
@@ -49,16 +105,111 @@ struct ConvertArgs {
     output: Option<PathBuf>,
 
     /// Template to use for rendering.
-    #[arg(short, long, help = "Template to use for rendering")]
+    #[arg(short, long, alias = "target", help = "Template to use for rendering")]
     template: Templates,
 
-    /// Root object to start rendering from (required for JSON Schema).
+    /// Root object to start rendering from (required for JSON Schema and Table Schema, highlighted in DOT and PlantUML).
     #[arg(
         short,
         long,
-        help = "Root object to start rendering from (required for JSON Schema)"
+        help = "Root object to start rendering from (required for JSON Schema and Table Schema, highlighted in DOT and PlantUML)"
     )]
     root: Option<String>,
+
+    /// Repository URL overriding the base IRI of the frontmatter.
+    #[arg(
+        long,
+        help = "Repository URL overriding the base IRI of the frontmatter"
+    )]
+    repo: Option<String>,
+
+    /// Prefix overriding the base IRI prefix of the frontmatter.
+    #[arg(
+        long,
+        help = "Prefix overriding the base IRI prefix of the frontmatter"
+    )]
+    prefix: Option<String>,
+
+    /// Pretty-print JSON outputs, also after post-processing.
+    #[arg(
+        long,
+        conflicts_with = "minify",
+        help = "Pretty-print JSON outputs, also after post-processing"
+    )]
+    pretty: bool,
+
+    /// Minify JSON outputs, also after post-processing.
+    #[arg(long, help = "Minify JSON outputs, also after post-processing")]
+    minify: bool,
+
+    /// Embed a `$id` and anchors of all definitions into the JSON Schema.
+    #[arg(
+        long,
+        help = "Embed a $id based on the repository URL and anchors of all definitions into the JSON Schema"
+    )]
+    embed_ids: bool,
+
+    /// Note the source file, object and model version of each JSON Schema definition.
+    #[arg(
+        long,
+        help = "Add a $comment with the source file, object and model version to each JSON Schema definition"
+    )]
+    provenance: bool,
+
+    /// Flatten nested objects and arrays of the Table Schema.
+    #[arg(
+        long,
+        help = "Flatten nested objects into dotted fields and arrays into array fields of the Table Schema"
+    )]
+    flatten: bool,
+
+    /// Number of instances generated by the mock-json template.
+    #[arg(
+        long,
+        default_value_t = 1,
+        help = "Number of instances generated by the mock-json template"
+    )]
+    count: usize,
+
+    /// Seed of the mock-json template, equal seeds yield equal instances.
+    #[arg(
+        long,
+        default_value_t = 0,
+        help = "Seed of the mock-json template, equal seeds yield equal instances"
+    )]
+    seed: u64,
+
+    /// Write each object and enumeration to its own file within the output directory.
+    #[arg(
+        long,
+        requires = "output",
+        help = "Write each object and enumeration to its own file within the output directory"
+    )]
+    output_per_object: bool,
+
+    /// Convert each model of a file with several models separated by `---`.
+    #[arg(
+        long,
+        requires = "output",
+        conflicts_with_all = ["output_per_object", "no_validate"],
+        help = "Convert each model of a file with several models separated by ---, written to numbered output files"
+    )]
+    multi: bool,
+
+    /// Skip validation and generate output even from an invalid model.
+    #[arg(
+        long,
+        help = "Skip validation and generate output even from an invalid model. Unsafe, intended for debugging only"
+    )]
+    no_validate: bool,
+
+    /// Shell command each generated file is piped through before it is written.
+    #[arg(
+        long,
+        value_name = "COMMAND",
+        help = "Shell command each generated file is piped through before it is written, e.g. rustfmt. Reads from stdin and writes to stdout"
+    )]
+    post_process: Option<String>,
 }
 
 /// Arguments for the pipeline subcommand.
@@ -69,6 +220,132 @@ struct PipelineArgs {
     input: PathBuf,
 }
 
+/// Arguments for the diff subcommand.
+#[derive(Parser, Debug)]
+struct DiffArgs {
+    /// Path or URL to the old version of the markdown file.
+    #[arg(
+        short,
+        long,
+        help = "Path or URL to the old version of the markdown file (URLs require the `remote` feature)"
+    )]
+    old: InputType,
+
+    /// Path or URL to the new version of the markdown file.
+    #[arg(
+        short,
+        long,
+        help = "Path or URL to the new version of the markdown file (URLs require the `remote` feature)"
+    )]
+    new: InputType,
+}
+
+/// Arguments for the compat subcommand.
+#[derive(Parser, Debug)]
+struct CompatArgs {
+    /// Path or URL to the base version of the markdown file.
+    #[arg(
+        short,
+        long,
+        help = "Path or URL to the base version of the markdown file (URLs require the `remote` feature)"
+    )]
+    base: InputType,
+
+    /// Path or URL to the head version of the markdown file.
+    #[arg(
+        long,
+        help = "Path or URL to the head version of the markdown file (URLs require the `remote` feature)"
+    )]
+    head: InputType,
+
+    /// Breaking changes that are accepted, e.g. `Object.attribute`.
+    #[arg(
+        short,
+        long,
+        num_args = 1..,
+        help = "Breaking changes that are accepted, e.g. Object.attribute"
+    )]
+    allow: Vec<String>,
+}
+
+/// Arguments for the migrate subcommand.
+#[derive(Parser, Debug)]
+struct MigrateArgs {
+    /// Path or URL to the old version of the markdown file.
+    #[arg(
+        short,
+        long,
+        help = "Path or URL to the old version of the markdown file (URLs require the `remote` feature)"
+    )]
+    old: InputType,
+
+    /// Path or URL to the new version of the markdown file.
+    #[arg(
+        short,
+        long,
+        help = "Path or URL to the new version of the markdown file (URLs require the `remote` feature)"
+    )]
+    new: InputType,
+
+    /// Path to the output file.
+    #[arg(long, help = "Path to the output file")]
+    output: Option<PathBuf>,
+}
+
+/// Arguments for the stats subcommand.
+#[derive(Parser, Debug)]
+struct StatsArgs {
+    /// Path or URL to the markdown file.
+    #[arg(
+        short,
+        long,
+        help = "Path or URL to the markdown file (URLs require the `remote` feature)"
+    )]
+    input: InputType,
+
+    /// Format of the printed metrics.
+    #[arg(
+        short,
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        help = "Format of the printed metrics"
+    )]
+    format: OutputFormat,
+}
+
+/// Arguments for the init subcommand.
+#[derive(Parser, Debug)]
+struct InitArgs {
+    /// Name of the data model, which is also the name of the written file.
+    #[arg(
+        default_value = "model",
+        help = "Name of the data model, which is also the name of the written file"
+    )]
+    name: String,
+
+    /// Path to the output file, defaults to the name with an `.md` extension.
+    #[arg(
+        short,
+        long,
+        help = "Path to the output file, defaults to the name with an .md extension"
+    )]
+    output: Option<PathBuf>,
+
+    /// Overwrite the output file if it already exists.
+    #[arg(long, help = "Overwrite the output file if it already exists")]
+    force: bool,
+}
+
+/// Output formats of reports printed by the CLI.
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum OutputFormat {
+    /// Human-readable text.
+    Text,
+    /// Pretty-printed JSON.
+    Json,
+}
+
 /// Represents the input type, either remote URL or local file path.
 #[derive(Deserialize, Serialize, Clone, Debug)]
 enum InputType {
@@ -101,40 +378,178 @@ impl Display for InputType {
     }
 }
 
-/// Main entry point of the application.
-fn main() -> Result<(), Box<dyn Error>> {
-    // Initialize the logger.
-    pretty_env_logger::init();
+/// Logger of the command line interface.
+///
+/// Status messages of MD-Models are printed as they are, while warnings, errors
+/// and messages of other crates are passed to `pretty_env_logger`. Progress
+/// messages are printed to stderr to not interfere with rendered outputs.
+struct CliLogger {
+    /// Maximum level of the status messages to print.
+    status: LevelFilter,
+    /// Logger for diagnostics.
+    diagnostics: Box<dyn Log>,
+}
+
+impl CliLogger {
+    /// Initializes the logger based on the verbosity flags.
+    ///
+    /// Without flags, status messages are printed and diagnostics are
+    /// configured via the `RUST_LOG` environment variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `quiet` - Whether to print errors only.
+    /// * `verbose` - Whether to print warnings and the progress of each object.
+    fn init(quiet: bool, verbose: bool) -> Result<(), log::SetLoggerError> {
+        let mut builder = pretty_env_logger::formatted_builder();
+        builder.parse_default_env();
+
+        let status = if quiet {
+            builder.filter_level(LevelFilter::Error);
+            LevelFilter::Off
+        } else if verbose {
+            builder.filter_level(LevelFilter::Warn);
+            LevelFilter::Debug
+        } else {
+            LevelFilter::Info
+        };
+
+        log::set_boxed_logger(Box::new(CliLogger {
+            status,
+            diagnostics: Box::new(builder.build()),
+        }))?;
+        log::set_max_level(LevelFilter::Trace);
+
+        Ok(())
+    }
+
+    /// Checks whether a message is a status message of MD-Models.
+    fn is_status(metadata: &Metadata) -> bool {
+        metadata.level() > log::Level::Warn
+            && (metadata.target().starts_with("mdmodels")
+                || metadata.target().starts_with("md_models"))
+    }
+}
+
+impl Log for CliLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        match Self::is_status(metadata) {
+            true => metadata.level() <= self.status,
+            false => self.diagnostics.enabled(metadata),
+        }
+    }
+
+    fn log(&self, record: &Record) {
+        if !Self::is_status(record.metadata()) {
+            return self.diagnostics.log(record);
+        }
+
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        match record.level() {
+            log::Level::Info => println!("{}", record.args()),
+            // Keep stdout clean for rendered outputs
+            _ => eprintln!("{}", record.args()),
+        }
+    }
+
+    fn flush(&self) {
+        self.diagnostics.flush();
+    }
+}
 
+/// Main entry point of the application.
+fn main() -> ExitCode {
     // Parse the command line arguments.
     let args = Cli::parse();
 
-    match args.cmd {
+    // Initialize the logger and run the command.
+    let result = CliLogger::init(args.quiet, args.verbose)
+        .map_err(Box::<dyn Error>::from)
+        .and_then(|_| run(args.cmd, args.quiet));
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", format_error(err.as_ref()));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs the given subcommand.
+///
+/// # Arguments
+///
+/// * `cmd` - The subcommand to run.
+/// * `quiet` - Whether to suppress status messages.
+fn run(cmd: Commands, quiet: bool) -> Result<(), Box<dyn Error>> {
+    match cmd {
         Commands::Validate(args) => validate(args),
         Commands::Convert(args) => convert(args),
-        Commands::Pipeline(args) => process_pipeline(&args.input),
+        Commands::Pipeline(args) => Ok(process_pipeline_with(&args.input, quiet)?),
+        Commands::Diff(args) => diff(args),
+        Commands::Compat(args) => compat(args),
+        Commands::Migrate(args) => migrate(args),
+        Commands::Stats(args) => stats(args),
+        Commands::Init(args) => init(args),
+        Commands::Targets => {
+            print!("{}", list_targets());
+            Ok(())
+        }
     }
 }
 
+/// Formats an error for humans.
+///
+/// Errors of the library are labelled by their kind, any other error is
+/// printed as is.
+///
+/// # Arguments
+///
+/// * `err` - The error to format.
+fn format_error(err: &(dyn Error + 'static)) -> String {
+    let label = match err.downcast_ref::<MdModelsError>() {
+        Some(MdModelsError::Io { .. }) => "IOError",
+        Some(MdModelsError::Fetch { .. }) => "FetchError",
+        Some(MdModelsError::Frontmatter { .. }) => "FrontmatterError",
+        Some(MdModelsError::Parse { .. }) => "ParseError",
+        Some(MdModelsError::Validation { .. }) => "ValidationError",
+        Some(MdModelsError::Generation { .. }) => "GenerationError",
+        Some(MdModelsError::UnknownTemplate { .. }) => "TemplateError",
+        None => "Error",
+    };
+
+    format!(" {}: {}", label.red().bold(), err)
+}
+
 /// Validates the markdown model specified in the arguments.
 ///
 /// # Arguments
 ///
 /// * `args` - Arguments for the validate subcommand.
 fn validate(args: ValidateArgs) -> Result<(), Box<dyn Error>> {
-    println!("\n Validating model {} ...", args.input.to_string().bold());
+    info!("\n Validating model {} ...", args.input.to_string().bold());
 
-    let path = resolve_input_path(&args.input);
-    let model = DataModel::from_markdown(&path);
+    let result = match args.strict {
+        true => load_model(&args.input, false).and_then(|model| {
+            let mut validator = Validator::new();
+            validator.set_strict(true);
+            validator.validate(&model)
+        }),
+        false => load_model(&args.input, true).map(|_| ()),
+    };
 
-    match model {
+    match result {
         Ok(_) => {
             print_validation_result(true);
             Ok(())
         }
-        Err(_) => {
+        Err(err) => {
             print_validation_result(false);
-            Err("Model is invalid".into())
+            Err(err.into())
         }
     }
 }
@@ -150,7 +565,7 @@ fn print_validation_result(result: bool) {
         "Model is invalid".red().bold().to_string()
     };
 
-    println!(" └── {}\n", message);
+    info!(" └── {}\n", message);
 }
 
 /// Converts the markdown model specified in the arguments to another format.
@@ -159,21 +574,51 @@ fn print_validation_result(result: bool) {
 ///
 /// * `args` - Arguments for the convert subcommand.
 fn convert(args: ConvertArgs) -> Result<(), Box<dyn Error>> {
+    // Special case several models in one file
+    if args.multi {
+        return convert_multi(&args); // Early return
+    }
+
     // Parse the markdown model.
-    let path = resolve_input_path(&args.input);
-    let mut model = DataModel::from_markdown(&path)?;
+    let mut model = match args.no_validate {
+        true => {
+            let model = load_model(&args.input, false)?;
+            if let Err(err) = Validator::new().validate(&model) {
+                warn!("{} Generating anyway due to --no-validate.", err);
+            }
+            model
+        }
+        false => load_model(&args.input, true)?,
+    };
+
+    // Override the base IRI, if given.
+    override_base_iri(&args, &mut model);
 
     // Special case JSON Schema all
     if let Templates::JsonSchemaAll = args.template {
-        render_all_json_schemes(&model, &args.output)?;
+        render_all_json_schemes(
+            &model,
+            &args.output,
+            args.embed_ids,
+            JsonLayout::from_args(&args),
+            args.post_process.as_deref(),
+        )?;
+        return Ok(()); // Early return
+    }
+
+    // Special case one file per object
+    if args.output_per_object {
+        render_per_object(
+            &args.template,
+            &mut model,
+            &args.output,
+            args.post_process.as_deref(),
+        )?;
         return Ok(()); // Early return
     }
 
     // Render the template.
-    let rendered = match args.template {
-        Templates::JsonSchema => model.json_schema(args.root),
-        _ => render_jinja_template(&args.template, &mut model, None)?,
-    };
+    let rendered = render(&args, &mut model)?;
 
     // Output the rendered content.
     match args.output {
@@ -188,78 +633,516 @@ fn convert(args: ConvertArgs) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-/// Resolves the input path based on the InputType.
+/// Converts each model of a file containing several models.
 ///
-/// If the input is a remote URL, it fetches the content and saves it to a temporary file.
-/// If the input is a local path, it returns the corresponding PathBuf.
+/// The models are written to the output path, numbered by their position
+/// in the file, e.g. `model_1.py` and `model_2.py` for `model.py`.
 ///
 /// # Arguments
 ///
-/// * `input` - The input type (Remote or Local).
-///
-/// # Returns
-///
-/// PathBuf representing the local path to the input file.
-fn resolve_input_path(input: &InputType) -> PathBuf {
-    match input {
-        InputType::Remote(url) => {
-            let mut path = std::env::temp_dir();
-            path.push("markdown.md");
-            let mut file = std::fs::File::create(&path).expect("Failed to create file");
-            let content = reqwest::blocking::get(url)
-                .expect("Failed to fetch URL")
-                .text()
-                .expect("Failed to read response");
-            file.write_all(content.as_bytes())
-                .expect("Failed to write to file");
-            path
+/// * `args` - Arguments for the convert subcommand.
+fn convert_multi(args: &ConvertArgs) -> Result<(), Box<dyn Error>> {
+    let path = match &args.input {
+        InputType::Local(path) => path,
+        InputType::Remote(_) => {
+            return Err("Several models per file are only supported for local files".into())
         }
-        InputType::Local(path) => PathBuf::from(path),
+    };
+
+    let output = args.output.as_ref().expect("Output path is required");
+    let models = DataModel::from_markdown_multi(Path::new(path))?;
+
+    for (index, mut model) in models.into_iter().enumerate() {
+        override_base_iri(args, &mut model);
+        let rendered = render(args, &mut model)?;
+        fs::write(numbered_path(output, index + 1), rendered.trim())?;
     }
+
+    Ok(())
 }
 
-/// Renders all JSON Schemas for the model.
-fn render_all_json_schemes(
-    model: &DataModel,
-    outdir: &Option<PathBuf>,
-) -> Result<(), Box<dyn Error>> {
-    if outdir.is_none() {
-        panic!("Output directory is required for JSON Schema all");
+/// Overrides the base IRI of the model, if given in the arguments.
+///
+/// # Arguments
+///
+/// * `args` - Arguments for the convert subcommand.
+/// * `model` - The model to update.
+fn override_base_iri(args: &ConvertArgs, model: &mut DataModel) {
+    if let Some(repo) = &args.repo {
+        model.set_repo(repo.clone());
     }
 
-    let outdir = outdir.as_ref().unwrap();
+    if let Some(prefix) = &args.prefix {
+        model.set_prefix(prefix.clone());
+    }
+}
 
-    // Check if the output is a directory
-    if !outdir.is_dir() && outdir.exists() {
-        panic!("Output must be a directory");
+/// Renders the template specified in the arguments for a model.
+///
+/// # Arguments
+///
+/// * `args` - Arguments for the convert subcommand.
+/// * `model` - The model to render.
+fn render(args: &ConvertArgs, model: &mut DataModel) -> Result<String, Box<dyn Error>> {
+    let root = args.root.clone();
+    let rendered = match args.template {
+        Templates::JsonSchema if args.provenance => {
+            model.json_schema_with_provenance(root, args.embed_ids)?
+        }
+        Templates::JsonSchema if args.embed_ids => model.json_schema_with_ids(root)?,
+        Templates::JsonSchema => model.json_schema(root)?,
+        Templates::JsonSchemaBundle => model.json_schema_bundle()?,
+        Templates::Internal => model.sdrdm_schema(),
+        Templates::TableSchema => model.table_schema(root, args.flatten)?,
+        Templates::MockJson => model.mock_json(root, args.count, args.seed)?,
+        Templates::Dot | Templates::PlantUml => {
+            let config = root.map(|root| HashMap::from([("root".to_string(), root)]));
+            render_jinja_template(&args.template, model, config.as_ref())?
+        }
+        _ => render_jinja_template(&args.template, model, None)?,
+    };
+
+    let processed = post_process(args.post_process.as_deref(), rendered)?;
+    match args.template.extension() {
+        "json" => JsonLayout::from_args(args).apply(processed),
+        _ => Ok(processed),
     }
+}
 
-    // If the output directory does not exist, create it
-    fs::create_dir_all(outdir)?;
+/// Pipes generated content through an external command, if given.
+///
+/// The command is run by the shell, receives the content on stdin and
+/// has to write the processed content to stdout.
+///
+/// # Arguments
+///
+/// * `command` - The shell command to run, if any.
+/// * `content` - The generated content.
+///
+/// # Errors
+///
+/// Returns an error if the command cannot be run or exits with a non-zero status.
+fn post_process(command: Option<&str>, content: String) -> Result<String, Box<dyn Error>> {
+    let Some(command) = command else {
+        return Ok(content);
+    };
 
-    // Render the JSON Schema for each entity
-    model.json_schema_all(outdir.to_str().unwrap().to_string());
+    let (shell, flag) = match cfg!(windows) {
+        true => ("cmd", "/C"),
+        false => ("sh", "-c"),
+    };
 
-    Ok(())
-}
+    let mut child = process::Command::new(shell)
+        .arg(flag)
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Could not run post-processing command '{}': {}", command, e))?;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use assert_cmd::Command;
-    use pretty_assertions::assert_eq;
+    // Write from a separate thread, such that a full stdout pipe cannot block the command
+    let mut stdin = child.stdin.take().expect("Failed to open stdin");
+    let writer = std::thread::spawn(move || stdin.write_all(content.as_bytes()));
 
-    /// Test for resolving local input paths.
-    #[test]
-    fn test_resolve_input_path() {
-        let path = resolve_input_path(&InputType::Local("tests/data/markdown.md".to_string()));
-        assert_eq!(path.to_str().unwrap(), "tests/data/markdown.md");
+    let output = child.wait_with_output()?;
+    writer.join().expect("Failed to write to stdin")?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Post-processing command '{}' failed with {}: {}",
+            command,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )
+        .into());
     }
 
-    /// Test Display for InputType
-    #[test]
-    fn test_display_input_type() {
-        let remote = InputType::Remote("https://example.com".to_string());
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// Returns the output path of a model, numbered by its position in the file.
+///
+/// # Arguments
+///
+/// * `output` - The output path given in the arguments.
+/// * `number` - The position of the model, starting at one.
+fn numbered_path(output: &Path, number: usize) -> PathBuf {
+    let stem = output.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match output.extension() {
+        Some(ext) => format!("{}_{}.{}", stem, number, ext.to_string_lossy()),
+        None => format!("{}_{}", stem, number),
+    };
+
+    output.with_file_name(name)
+}
+
+/// Prints the differences between two versions of a markdown model.
+///
+/// The differences are status messages, which are suppressed by `--quiet`.
+///
+/// # Arguments
+///
+/// * `args` - Arguments for the diff subcommand.
+fn diff(args: DiffArgs) -> Result<(), Box<dyn Error>> {
+    let old = load_model(&args.old, true)?;
+    let new = load_model(&args.new, true)?;
+    let diff = old.diff(&new);
+
+    info!(
+        "\n Comparing {} to {} ...",
+        args.old.to_string().bold(),
+        args.new.to_string().bold()
+    );
+
+    if diff.is_empty() {
+        info!(" └── {}\n", "No changes".green().bold());
+        return Ok(());
+    }
+
+    for change in &diff.changes {
+        let label = if change.is_breaking() {
+            "breaking".red().bold()
+        } else {
+            "compatible".green().bold()
+        };
+
+        info!(" ├── [{}] {}", label, change);
+    }
+
+    info!(
+        " └── {} changes, {} breaking\n",
+        diff.changes.len(),
+        diff.breaking_changes().len()
+    );
+
+    Ok(())
+}
+
+/// Checks that the head model does not introduce breaking changes.
+///
+/// # Arguments
+///
+/// * `args` - Arguments for the compat subcommand.
+fn compat(args: CompatArgs) -> Result<(), Box<dyn Error>> {
+    let base = load_model(&args.base, true)?;
+    let head = load_model(&args.head, true)?;
+    let diff = base.diff(&head);
+    let violations = diff.violations(&args.allow);
+
+    info!(
+        "\n Checking compatibility of {} to {} ...",
+        args.head.to_string().bold(),
+        args.base.to_string().bold()
+    );
+
+    for violation in &violations {
+        info!(" ├── [{}] {}", "breaking".red().bold(), violation);
+    }
+
+    if violations.is_empty() {
+        info!(" └── {}\n", "Model is backward-compatible".green().bold());
+        Ok(())
+    } else {
+        info!(
+            " └── {}\n",
+            format!("{} breaking changes", violations.len())
+                .red()
+                .bold()
+        );
+        Err("Model is not backward-compatible".into())
+    }
+}
+
+/// Generates Rust conversion stubs from the old to the new model.
+///
+/// # Arguments
+///
+/// * `args` - Arguments for the migrate subcommand.
+fn migrate(args: MigrateArgs) -> Result<(), Box<dyn Error>> {
+    let old = load_model(&args.old, true)?;
+    let new = load_model(&args.new, true)?;
+    let rendered = rust_migration(&old, &new);
+
+    match args.output {
+        Some(ref output) => {
+            std::fs::write(output, rendered.trim()).expect("Failed to write output");
+        }
+        None => {
+            println!("{}", rendered.trim());
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the metrics of the markdown model specified in the arguments.
+///
+/// # Arguments
+///
+/// * `args` - Arguments for the stats subcommand.
+fn stats(args: StatsArgs) -> Result<(), Box<dyn Error>> {
+    let model = load_model(&args.input, true)?;
+    let stats = model.stats();
+
+    match args.format {
+        OutputFormat::Text => println!("{}", stats),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&stats)?),
+    }
+
+    Ok(())
+}
+
+/// Writes a starter markdown model to the path specified in the arguments.
+///
+/// # Arguments
+///
+/// * `args` - Arguments for the init subcommand.
+fn init(args: InitArgs) -> Result<(), Box<dyn Error>> {
+    let output = args
+        .output
+        .unwrap_or_else(|| PathBuf::from(&args.name).with_extension("md"));
+
+    if output.exists() && !args.force {
+        return Err(format!(
+            "File {} already exists, use --force to overwrite it",
+            output.display()
+        )
+        .into());
+    }
+
+    fs::write(&output, starter_model(&args.name))?;
+    info!("Wrote starter model to {}", output.display());
+
+    Ok(())
+}
+
+/// Returns a starter markdown model, which parses and validates as is.
+///
+/// The model consists of a frontmatter with a prefix and an ontology, one
+/// object with a few typed attributes and one enumeration.
+///
+/// # Arguments
+///
+/// * `name` - Name of the data model, used as its title.
+fn starter_model(name: &str) -> String {
+    format!(
+        r#"---
+id-field: true
+repo: "https://example.org/{name}/"
+prefix: "ex"
+prefixes:
+  schema: http://schema.org/
+---
+
+# {name}
+
+Describe the purpose of the data model here.
+
+### Sample
+
+A sample that is measured in the laboratory.
+
+- __name__
+  - Type: Identifier
+  - Term: schema:name
+  - Description: Unique name of the sample.
+- volume
+  - Type: float
+  - Term: schema:size
+  - Description: Volume of the sample in liters.
+  - Minimum: 0
+- state
+  - Type: State
+  - Description: Physical state of the sample.
+- tags
+  - Type: string[]
+  - Term: schema:keywords
+  - Description: Keywords describing the sample.
+
+## Enumerations
+
+### State
+
+Physical state of a sample.
+
+```
+SOLID = solid
+LIQUID = liquid
+GAS = gas
+```
+"#
+    )
+}
+
+/// Lists all available templates, one per line.
+///
+/// Each line contains the name, file extension and description of
+/// a template, separated by tabs.
+fn list_targets() -> String {
+    Templates::value_variants()
+        .iter()
+        .map(|t| format!("{}\t{}\t{}\n", t, t.extension(), t.description()))
+        .collect()
+}
+
+/// Loads the data model from a local file or a remote URL.
+///
+/// # Arguments
+///
+/// * `input` - The input type (Remote or Local).
+/// * `validate` - Whether to validate the model.
+///
+/// # Returns
+///
+/// The parsed data model, or an error if it could not be read or is invalid.
+fn load_model(input: &InputType, validate: bool) -> Result<DataModel, MdModelsError> {
+    match (input, validate) {
+        #[cfg(feature = "remote")]
+        (InputType::Remote(url), true) => DataModel::from_url(url),
+        #[cfg(feature = "remote")]
+        (InputType::Remote(url), false) => DataModel::from_url_unvalidated(url),
+        #[cfg(not(feature = "remote"))]
+        (InputType::Remote(url), _) => Err(MdModelsError::Fetch {
+            url: url.clone(),
+            message: "Remote models require the 'remote' feature".to_string(),
+        }),
+        (InputType::Local(path), true) => DataModel::from_markdown(Path::new(path)),
+        (InputType::Local(path), false) => DataModel::from_markdown_unvalidated(Path::new(path)),
+    }
+}
+
+/// Layout of JSON outputs, selected by the `--pretty` and `--minify` flags.
+///
+/// The layout is applied after post-processing, such that the final
+/// output has the requested layout regardless of the post-processing command.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum JsonLayout {
+    /// Keeps the JSON as generated, or as returned by the post-processing command.
+    AsIs,
+    /// Pretty-prints the JSON.
+    Pretty,
+    /// Minifies the JSON.
+    Minified,
+}
+
+impl JsonLayout {
+    /// Returns the layout selected in the arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Arguments for the convert subcommand.
+    fn from_args(args: &ConvertArgs) -> Self {
+        match (args.pretty, args.minify) {
+            (_, true) => JsonLayout::Minified,
+            (true, false) => JsonLayout::Pretty,
+            (false, false) => JsonLayout::AsIs,
+        }
+    }
+
+    /// Formats a JSON string in this layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The JSON string to format.
+    fn apply(self, json: String) -> Result<String, Box<dyn Error>> {
+        if self == JsonLayout::AsIs {
+            return Ok(json);
+        }
+
+        let value: serde_json::Value = serde_json::from_str(&json)?;
+        match self {
+            JsonLayout::Minified => Ok(serde_json::to_string(&value)?),
+            _ => Ok(serde_json::to_string_pretty(&value)?),
+        }
+    }
+}
+
+/// Renders each object and enumeration of the model into its own file.
+fn render_per_object(
+    template: &Templates,
+    model: &mut DataModel,
+    outdir: &Option<PathBuf>,
+    command: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let outdir = outdir.as_ref().expect("Output directory is required");
+
+    // Check if the output is a directory
+    if !outdir.is_dir() && outdir.exists() {
+        panic!("Output must be a directory");
+    }
+
+    fs::create_dir_all(outdir)?;
+
+    for (file_name, content) in render_jinja_units(template, model, None)? {
+        debug!(
+            " [{}] Writing '{}'",
+            template.to_string().green().bold(),
+            file_name
+        );
+        fs::write(outdir.join(file_name), post_process(command, content)?)?;
+    }
+
+    Ok(())
+}
+
+/// Renders all JSON Schemas for the model.
+fn render_all_json_schemes(
+    model: &DataModel,
+    outdir: &Option<PathBuf>,
+    embed_ids: bool,
+    layout: JsonLayout,
+    command: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    if outdir.is_none() {
+        panic!("Output directory is required for JSON Schema all");
+    }
+
+    let outdir = outdir.as_ref().unwrap();
+
+    // Check if the output is a directory
+    if !outdir.is_dir() && outdir.exists() {
+        panic!("Output must be a directory");
+    }
+
+    // If the output directory does not exist, create it
+    fs::create_dir_all(outdir)?;
+
+    // Render the JSON Schema for each entity
+    for (name, schema) in model.json_schemas(embed_ids) {
+        debug!(
+            " [{}] Rendering object '{}'",
+            "json-schema".green().bold(),
+            name
+        );
+        let schema = layout.apply(post_process(command, schema)?)?;
+        fs::write(outdir.join(format!("{}.json", name)), schema)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_cmd::Command;
+    use pretty_assertions::assert_eq;
+
+    /// Test for loading models from local input paths.
+    #[test]
+    fn test_load_model_local() {
+        let model = load_model(&InputType::Local("tests/data/model.md".to_string()), true)
+            .expect("Could not load model");
+        assert_eq!(model.objects.len(), 2);
+
+        let missing = load_model(&InputType::Local("tests/data/missing.md".to_string()), true);
+        assert!(matches!(missing, Err(MdModelsError::Io { .. })));
+    }
+
+    /// Test Display for InputType
+    #[test]
+    fn test_display_input_type() {
+        let remote = InputType::Remote("https://example.com".to_string());
         let local = InputType::Local("tests/data/markdown.md".to_string());
         assert_eq!(remote.to_string(), "https://example.com");
         assert_eq!(local.to_string(), "tests/data/markdown.md");
@@ -287,6 +1170,40 @@ mod tests {
         assert.failure();
     }
 
+    #[test]
+    fn test_strict_naming_validation() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        cmd.arg("validate")
+            .arg("-i")
+            .arg("tests/data/model_naming.md")
+            .assert()
+            .success();
+
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        cmd.arg("validate")
+            .arg("-i")
+            .arg("tests/data/model_naming.md")
+            .arg("--strict")
+            .assert()
+            .failure();
+    }
+
+    #[test]
+    fn test_failed_validation_error_message() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let output = cmd
+            .arg("validate")
+            .arg("-i")
+            .arg("tests/data/model_missing_types.md")
+            .output()
+            .unwrap();
+
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(!output.status.success());
+        assert!(stderr.contains("ValidationError"));
+        assert!(stderr.contains("Affected definitions: Test"));
+    }
+
     #[test]
     fn test_successful_conversion() {
         let mut cmd = Command::cargo_bin("md-models").unwrap();
@@ -300,6 +1217,338 @@ mod tests {
         assert.success();
     }
 
+    #[test]
+    fn test_plantuml_conversion() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let output = cmd
+            .arg("convert")
+            .arg("-i")
+            .arg("tests/data/model.md")
+            .arg("-t")
+            .arg("plantuml")
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let expected = std::fs::read_to_string("tests/data/expected_plantuml.puml")
+            .expect("Could not read expected file");
+        assert_eq!(stdout.trim_end(), expected.trim_end());
+    }
+
+    #[test]
+    fn test_override_base_iri() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let output = cmd
+            .arg("convert")
+            .arg("-i")
+            .arg("tests/data/model.md")
+            .arg("-t")
+            .arg("python-dataclass")
+            .arg("--repo")
+            .arg("https://prod.example.org/")
+            .arg("--prefix")
+            .arg("prod")
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.contains(r#""prod": "https://prod.example.org/""#));
+        assert!(stdout.contains(r#""prod:Test/""#));
+        assert!(!stdout.contains("https://www.github.com/my/repo/"));
+    }
+
+    #[test]
+    fn test_json_minify() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let output = cmd
+            .arg("convert")
+            .arg("-i")
+            .arg("tests/data/model.md")
+            .arg("-t")
+            .arg("json-schema")
+            .arg("-r")
+            .arg("Test")
+            .arg("--minify")
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(!stdout.trim().contains('\n'));
+        assert!(stdout.starts_with(r#"{"$schema":"#));
+    }
+
+    #[test]
+    fn test_json_pretty() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let output = cmd
+            .arg("convert")
+            .arg("-i")
+            .arg("tests/data/model.md")
+            .arg("-t")
+            .arg("json-schema")
+            .arg("-r")
+            .arg("Test")
+            .arg("--pretty")
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.trim().contains('\n'));
+    }
+
+    #[test]
+    fn test_json_pretty_after_post_process() {
+        let convert = |pretty: bool| {
+            let mut cmd = Command::cargo_bin("md-models").unwrap();
+            cmd.arg("convert")
+                .arg("-i")
+                .arg("tests/data/model.md")
+                .arg("-t")
+                .arg("json-schema")
+                .arg("-r")
+                .arg("Test")
+                .arg("--post-process")
+                .arg("tr -d '\\n'");
+
+            if pretty {
+                cmd.arg("--pretty");
+            }
+
+            cmd.output().unwrap()
+        };
+
+        let joined = convert(false);
+        let pretty = convert(true);
+
+        assert!(joined.status.success());
+        assert!(pretty.status.success());
+        assert!(!String::from_utf8(joined.stdout)
+            .unwrap()
+            .trim()
+            .contains('\n'));
+        assert!(String::from_utf8(pretty.stdout)
+            .unwrap()
+            .trim()
+            .contains('\n'));
+    }
+
+    #[test]
+    fn test_post_process() {
+        let convert = |post_process: Option<&str>| {
+            let mut cmd = Command::cargo_bin("md-models").unwrap();
+            cmd.arg("convert")
+                .arg("-i")
+                .arg("tests/data/model.md")
+                .arg("-t")
+                .arg("python-dataclass");
+
+            if let Some(command) = post_process {
+                cmd.arg("--post-process").arg(command);
+            }
+
+            cmd.output().unwrap()
+        };
+
+        let plain = convert(None);
+        let piped = convert(Some("cat"));
+
+        assert!(piped.status.success());
+        assert!(!piped.stdout.is_empty());
+        assert_eq!(
+            String::from_utf8(piped.stdout).unwrap(),
+            String::from_utf8(plain.stdout).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_post_process_failure() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let output = cmd
+            .arg("convert")
+            .arg("-i")
+            .arg("tests/data/model.md")
+            .arg("-t")
+            .arg("python-dataclass")
+            .arg("--post-process")
+            .arg("cat > /dev/null; echo 'bad style' >&2; exit 3")
+            .output()
+            .unwrap();
+
+        assert!(!output.status.success());
+
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.contains("Post-processing command"));
+        assert!(stderr.contains("bad style"));
+    }
+
+    #[test]
+    fn test_json_schema_provenance() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let output = cmd
+            .arg("convert")
+            .arg("-i")
+            .arg("tests/data/model.md")
+            .arg("-t")
+            .arg("json-schema")
+            .arg("-r")
+            .arg("Test")
+            .arg("--provenance")
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+
+        let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(
+            schema["$comment"],
+            "source: tests/data/model.md; object: Test"
+        );
+        assert_eq!(
+            schema["definitions"]["Test2"]["$comment"],
+            "source: tests/data/model.md; object: Test2"
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let assert = cmd
+            .arg("diff")
+            .arg("-o")
+            .arg("tests/data/model.md")
+            .arg("-n")
+            .arg("tests/data/model_inheritance.md")
+            .assert();
+        assert.success();
+    }
+
+    #[test]
+    fn test_compat_additive_change() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let assert = cmd
+            .arg("compat")
+            .arg("--base")
+            .arg("tests/data/model_compat_base.md")
+            .arg("--head")
+            .arg("tests/data/model_compat_additive.md")
+            .assert();
+        assert.success();
+    }
+
+    #[test]
+    fn test_compat_breaking_change() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let assert = cmd
+            .arg("compat")
+            .arg("--base")
+            .arg("tests/data/model_compat_base.md")
+            .arg("--head")
+            .arg("tests/data/model_compat_breaking.md")
+            .assert();
+        assert.failure();
+    }
+
+    #[test]
+    fn test_compat_allowed_breaking_change() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let assert = cmd
+            .arg("compat")
+            .arg("--base")
+            .arg("tests/data/model_compat_base.md")
+            .arg("--head")
+            .arg("tests/data/model_compat_breaking.md")
+            .arg("--allow")
+            .arg("Sample.value")
+            .arg("Unit.GRAM")
+            .assert();
+        assert.success();
+    }
+
+    #[test]
+    fn test_quiet_compat() {
+        let compat = |quiet: bool| {
+            let mut cmd = Command::cargo_bin("md-models").unwrap();
+            cmd.arg("compat")
+                .arg("--base")
+                .arg("tests/data/model_compat_base.md")
+                .arg("--head")
+                .arg("tests/data/model_compat_breaking.md");
+
+            if quiet {
+                cmd.arg("--quiet");
+            }
+
+            cmd.output().unwrap()
+        };
+
+        // Without the flag, each breaking change is listed
+        let loud = compat(false);
+        assert!(!loud.status.success());
+        assert!(String::from_utf8(loud.stdout)
+            .unwrap()
+            .contains("[breaking]"));
+
+        // With the flag, only the error is printed
+        let quiet = compat(true);
+        assert!(!quiet.status.success());
+        assert!(quiet.stdout.is_empty());
+        assert!(String::from_utf8(quiet.stderr)
+            .unwrap()
+            .contains("Model is not backward-compatible"));
+    }
+
+    #[test]
+    fn test_list_targets() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let output = cmd.arg("targets").output().unwrap();
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let names = stdout
+            .lines()
+            .map(|line| line.split('\t').next().unwrap())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(names.len(), Templates::value_variants().len());
+        for template in Templates::value_variants() {
+            assert!(names.contains(&template.to_string().as_str()));
+        }
+
+        // Each listed name is accepted by the `--template` argument
+        for name in names {
+            let parsed = <Templates as ValueEnum>::from_str(name, false)
+                .unwrap_or_else(|e| panic!("Target '{}' is not accepted: {}", name, e));
+            assert_eq!(parsed.to_string(), name);
+        }
+    }
+
+    #[test]
+    fn test_internal_conversion() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let output = cmd
+            .arg("convert")
+            .arg("-i")
+            .arg("tests/data/model.md")
+            .arg("-t")
+            .arg("internal")
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let model: DataModel = serde_json::from_str(&stdout).expect("Could not parse the model");
+        assert_eq!(model.objects.len(), 2);
+    }
+
     #[test]
     fn test_json_schema_no_root() {
         let mut cmd = Command::cargo_bin("md-models").unwrap();
@@ -313,6 +1562,98 @@ mod tests {
         assert.success();
     }
 
+    #[test]
+    fn test_table_schema_flatten() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let output = cmd
+            .arg("convert")
+            .arg("-i")
+            .arg("tests/data/model.md")
+            .arg("-t")
+            .arg("table-schema")
+            .arg("--flatten")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.contains("\"name\": \"test2\""));
+        assert!(stdout.contains("\"type\": \"array\""));
+    }
+
+    #[test]
+    fn test_convert_multi() {
+        let outdir = std::env::temp_dir().join("md-models-multi");
+        fs::create_dir_all(&outdir).unwrap();
+
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let assert = cmd
+            .arg("convert")
+            .arg("-i")
+            .arg("tests/data/model_multi.md")
+            .arg("-t")
+            .arg("python-dataclass")
+            .arg("-o")
+            .arg(outdir.join("model.py"))
+            .arg("--multi")
+            .assert();
+        assert.success();
+
+        let first = fs::read_to_string(outdir.join("model_1.py")).unwrap();
+        let second = fs::read_to_string(outdir.join("model_2.py")).unwrap();
+        assert!(first.contains("class Sample:"));
+        assert!(!first.contains("class Chemical:"));
+        assert!(second.contains("class Chemical:"));
+        assert!(second.contains("class Hazard(Enum):"));
+    }
+
+    #[test]
+    fn test_stats_json() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let output = cmd
+            .arg("stats")
+            .arg("-i")
+            .arg("tests/data/model.md")
+            .arg("--format")
+            .arg("json")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+
+        let stats: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(
+            stats,
+            serde_json::json!({
+                "objects": 2,
+                "attributes": 6,
+                "enums": 1,
+                "required": 1,
+                "optional": 5,
+                "arrays": 2,
+                "references": 1,
+                "max_depth": 1,
+                "warnings": [],
+            })
+        );
+    }
+
+    #[test]
+    fn test_stats_text_warnings() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let output = cmd
+            .arg("stats")
+            .arg("-i")
+            .arg("tests/data/model_unit_warning.md")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.contains("Objects:    1"));
+        assert!(stdout.contains("Warnings:   1"));
+        assert!(stdout.contains("UnitWarning: Property name has unit mL but is not numeric."));
+    }
+
     #[test]
     fn test_pipeline_single_model() {
         let mut cmd = Command::cargo_bin("md-models").unwrap();
@@ -345,4 +1686,153 @@ mod tests {
             .assert();
         assert.failure();
     }
+
+    #[test]
+    fn test_quiet_warning_only_model() {
+        let validate = |quiet: bool| {
+            let mut cmd = Command::cargo_bin("md-models").unwrap();
+            cmd.env("RUST_LOG", "warn")
+                .arg("validate")
+                .arg("-i")
+                .arg("tests/data/model_unit_warning.md");
+
+            if quiet {
+                cmd.arg("--quiet");
+            }
+
+            cmd.output().unwrap()
+        };
+
+        // Without the flag, the status message and the warning are printed
+        let loud = validate(false);
+        assert!(loud.status.success());
+        assert!(String::from_utf8(loud.stdout)
+            .unwrap()
+            .contains("Model is valid"));
+        assert!(String::from_utf8(loud.stderr)
+            .unwrap()
+            .contains("UnitWarning"));
+
+        let quiet = validate(true);
+        assert!(quiet.status.success());
+        assert!(quiet.stdout.is_empty());
+        assert!(quiet.stderr.is_empty());
+    }
+
+    #[test]
+    fn test_quiet_pipeline() {
+        let pipeline = |quiet: bool| {
+            let mut cmd = Command::cargo_bin("md-models").unwrap();
+            cmd.arg("pipeline")
+                .arg("-i")
+                .arg("tests/test_pipeline.toml");
+
+            if quiet {
+                cmd.arg("--quiet");
+            }
+
+            cmd.output().unwrap()
+        };
+
+        let loud = pipeline(false);
+        assert!(loud.status.success());
+        assert!(String::from_utf8(loud.stdout)
+            .unwrap()
+            .contains("Writing to"));
+
+        let quiet = pipeline(true);
+        assert!(quiet.status.success());
+        assert!(quiet.stdout.is_empty());
+        assert!(quiet.stderr.is_empty());
+    }
+
+    #[test]
+    fn test_convert_no_validate() {
+        for template in ["json-schema", "rust"] {
+            let mut cmd = Command::cargo_bin("md-models").unwrap();
+            let output = cmd
+                .arg("convert")
+                .arg("-i")
+                .arg("tests/data/model_missing_types.md")
+                .arg("-t")
+                .arg(template)
+                .arg("-r")
+                .arg("Test")
+                .arg("--no-validate")
+                .output()
+                .unwrap();
+
+            assert!(output.status.success());
+
+            let stdout = String::from_utf8(output.stdout).unwrap();
+            assert!(stdout.contains("ontology"));
+        }
+    }
+
+    #[test]
+    fn test_convert_invalid_without_no_validate() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let assert = cmd
+            .arg("convert")
+            .arg("-i")
+            .arg("tests/data/model_missing_types.md")
+            .arg("-t")
+            .arg("rust")
+            .assert();
+        assert.failure();
+    }
+
+    #[test]
+    fn test_verbose_progress() {
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        let output = cmd
+            .arg("convert")
+            .arg("-i")
+            .arg("tests/data/model.md")
+            .arg("-t")
+            .arg("rust")
+            .arg("--verbose")
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stdout.starts_with("//!"));
+        assert!(stderr.contains("Rendering object 'Test'"));
+        assert!(stderr.contains("Rendering object 'Test2'"));
+    }
+
+    #[test]
+    fn test_init() {
+        let outdir = std::env::temp_dir().join("md-models-init");
+        fs::create_dir_all(&outdir).unwrap();
+        let output = outdir.join("starter.md");
+
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        cmd.arg("init")
+            .arg("Starter")
+            .arg("-o")
+            .arg(&output)
+            .arg("--force")
+            .assert()
+            .success();
+
+        let model = DataModel::from_markdown(&output).expect("Could not parse starter model");
+        assert_eq!(model.name, Some("Starter".to_string()));
+        assert_eq!(model.objects.len(), 1);
+        assert_eq!(model.enums.len(), 1);
+        assert!(Validator::new().validate(&model).is_ok());
+        assert!(mdmodels::validation::collect_warnings(&model).is_empty());
+
+        // An existing file is only overwritten with --force
+        let mut cmd = Command::cargo_bin("md-models").unwrap();
+        cmd.arg("init")
+            .arg("Starter")
+            .arg("-o")
+            .arg(&output)
+            .assert()
+            .failure();
+    }
 }
@@ -0,0 +1,206 @@
+use std::{collections::BTreeMap, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::datamodel::DataModel;
+
+/// Ordinals of the fields and enumerants of a Cap'n Proto schema.
+///
+/// Cap'n Proto identifies fields and enumerants by their ordinals, hence an
+/// ordinal must not change once the schema is in use. Members are numbered
+/// in the order of the model, fields by declaration and enumerants by name.
+/// Members without an ordinal are appended, wherever they are declared, so
+/// persisting the ordinals in a lock file keeps them stable across inserts.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub(crate) struct Ordinals {
+    /// ID of the schema file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Ordinals of the fields of every struct, by name.
+    #[serde(default)]
+    pub structs: BTreeMap<String, BTreeMap<String, usize>>,
+    /// Ordinals of the enumerants of every enum, by name.
+    #[serde(default)]
+    pub enums: BTreeMap<String, BTreeMap<String, usize>>,
+}
+
+impl Ordinals {
+    /// Loads the ordinals of a lock file, which may not exist yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the lock file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Could not read lock file '{}': {}", path.display(), e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| format!("Invalid lock file '{}': {}", path.display(), e))
+    }
+
+    /// Writes the ordinals to a lock file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the lock file.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Could not serialize lock file '{}': {}", path.display(), e))?;
+        std::fs::write(path, content + "\n")
+            .map_err(|e| format!("Could not write lock file '{}': {}", path.display(), e))
+    }
+
+    /// Assigns ordinals to the members of the model that have none yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The data model to number.
+    /// * `id` - ID of the schema file, unless one is already assigned.
+    ///
+    /// # Errors
+    ///
+    /// If a numbered member was removed, since Cap'n Proto requires the
+    /// ordinals of a definition to be consecutive.
+    pub fn assign(&mut self, model: &DataModel, id: String) -> Result<(), String> {
+        self.id.get_or_insert(id);
+
+        for object in &model.objects {
+            let fields = object.attributes.iter().map(|a| &a.name).collect();
+            number(&mut self.structs, &object.name, fields)?;
+        }
+
+        for enumeration in &model.enums {
+            let enumerants = enumeration.mappings.keys().collect();
+            number(&mut self.enums, &enumeration.name, enumerants)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Assigns ordinals to the members of a definition that have none yet.
+///
+/// # Arguments
+///
+/// * `definitions` - The ordinals of all structs or all enums.
+/// * `name` - Name of the definition.
+/// * `members` - Names of the members, in the order of the model.
+///
+/// # Errors
+///
+/// If a numbered member was removed.
+fn number(
+    definitions: &mut BTreeMap<String, BTreeMap<String, usize>>,
+    name: &str,
+    members: Vec<&String>,
+) -> Result<(), String> {
+    let ordinals = definitions.entry(name.to_string()).or_default();
+
+    if let Some(member) = ordinals.keys().find(|m| !members.contains(m)) {
+        return Err(format!(
+            "Member '{}.{}' has the ordinal @{} and cannot be removed from the schema",
+            name, member, ordinals[member]
+        ));
+    }
+
+    for member in members {
+        let next = ordinals.len();
+        ordinals.entry(member.clone()).or_insert(next);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Helper function to parse an inline markdown model.
+    fn model(content: &str) -> DataModel {
+        DataModel::from_markdown_string(content).expect("Could not parse markdown")
+    }
+
+    #[test]
+    fn test_assign_appends_inserted_members() {
+        // Arrange
+        let before = model(
+            "### Sample\n\n- name\n  - Type: string\n- value\n  - Type: float\n\n## Enumerations\n\n### State\n\n```\nSOLID = solid\nLIQUID = liquid\n```\n",
+        );
+        let after = model(
+            "### Sample\n\n- name\n  - Type: string\n- unit\n  - Type: string\n- value\n  - Type: float\n\n## Enumerations\n\n### State\n\n```\nAAA = aaa\nSOLID = solid\nLIQUID = liquid\n```\n",
+        );
+
+        // Act
+        let mut ordinals = Ordinals::default();
+        ordinals.assign(&before, "0x1".to_string()).unwrap();
+        ordinals.assign(&after, "0x2".to_string()).unwrap();
+
+        // Assert
+        assert_eq!(ordinals.id, Some("0x1".to_string()));
+        assert_eq!(
+            ordinals.structs["Sample"],
+            BTreeMap::from([
+                ("name".to_string(), 0),
+                ("value".to_string(), 1),
+                ("unit".to_string(), 2),
+            ])
+        );
+        assert_eq!(
+            ordinals.enums["State"],
+            BTreeMap::from([
+                ("LIQUID".to_string(), 0),
+                ("SOLID".to_string(), 1),
+                ("AAA".to_string(), 2),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        // Arrange
+        let path = std::env::temp_dir().join("md-models-ordinals.lock");
+        let mut ordinals = Ordinals::default();
+        ordinals
+            .assign(
+                &model("### Sample\n\n- name\n  - Type: string\n\n## Enumerations\n\n### State\n\n```\nSOLID = solid\n```\n"),
+                "0x1".to_string(),
+            )
+            .unwrap();
+
+        // Act
+        ordinals.save(&path).unwrap();
+        let loaded = Ordinals::load(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        // Assert
+        assert_eq!(loaded, ordinals);
+        assert!(content.contains("\"structs\": {\n    \"Sample\": {\n      \"name\": 0\n"));
+        assert!(content.contains("\"enums\": {\n    \"State\": {\n      \"SOLID\": 0\n"));
+    }
+
+    #[test]
+    fn test_assign_rejects_removed_members() {
+        // Arrange
+        let before = model("### Sample\n\n- name\n  - Type: string\n- value\n  - Type: float\n");
+        let after = model("### Sample\n\n- name\n  - Type: string\n");
+        let mut ordinals = Ordinals::default();
+        ordinals.assign(&before, "0x1".to_string()).unwrap();
+
+        // Act
+        let result = ordinals.assign(&after, "0x1".to_string());
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(
+                "Member 'Sample.value' has the ordinal @1 and cannot be removed from the schema"
+                    .to_string()
+            )
+        );
+    }
+}
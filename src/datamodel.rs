@@ -1,16 +1,31 @@
-use std::collections::HashMap;
-use std::{error::Error, fs, path::Path};
+use std::collections::{BTreeMap, HashMap};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 use log::error;
 use serde::{Deserialize, Serialize};
 
-use crate::exporters::{render_jinja_template, Templates};
+use crate::attribute::{generic_type, Attribute};
+use crate::diff::ModelDiff;
+use crate::error::MdModelsError;
+use crate::exporters::{filter_targets, render_jinja_template, supports_generics, Templates};
 use crate::json::parser::parse_json_schema;
-use crate::markdown::frontmatter::FrontMatter;
-use crate::markdown::parser::parse_markdown;
-use crate::object::{Enumeration, Object};
-use crate::{markdown, schema};
+use crate::markdown::parser::{
+    load_enum_sources, parse_markdown, parse_markdown_unvalidated, parse_markdown_with_diagnostics,
+    split_documents,
+};
+use crate::object::{expand_iri, Enumeration, Object};
+use crate::stats::ModelStats;
+use crate::validation::{collect_diagnostics, Diagnostic, Validator};
+use crate::{instance, markdown, mock, schema, tableschema};
+
+pub use crate::instance::InstanceError;
+pub use crate::markdown::frontmatter::FrontMatter;
+pub use crate::markdown::style::{MarkdownStyle, RequiredMarker};
 use colored::Colorize;
+use convert_case::{Case, Casing};
 
 // Data model
 //
@@ -33,6 +48,7 @@ use colored::Colorize;
 // * `parse` - Parse a markdown file and create a data model
 // * `json_schema` - Generate a JSON schema from the data model
 // * `json_schema_all` - Generate JSON schemas for all objects in the data model
+// * `json_schema_bundle` - Generate a single JSON schema holding all objects
 // * `sdrdm_schema` - Generate a SDRDM schema from the data model
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DataModel {
@@ -58,7 +74,7 @@ impl DataModel {
     //
     // * `obj_name` - Name of the object
     //
-    // # Panics
+    // # Errors
     //
     // If no objects are found in the markdown file
     // If the object is not found in the markdown file
@@ -74,20 +90,203 @@ impl DataModel {
     // # Returns
     //
     // A JSON schema string
-    pub fn json_schema(&self, obj_name: Option<String>) -> String {
-        if self.objects.is_empty() {
-            panic!("No objects found in the markdown file");
-        }
+    pub fn json_schema(&self, obj_name: Option<String>) -> Result<String, MdModelsError> {
+        self.render_json_schema(obj_name, false, false)
+    }
+
+    // Get the JSON schema for an object with a `$id` and anchored definitions
+    //
+    // The `$id` is built from the repository URL of the frontmatter and the
+    // model name. Each definition is anchored by its name, e.g. `#Address`,
+    // which is also used for all references within the schema.
+    //
+    // * `obj_name` - Name of the object
+    //
+    // # Errors
+    //
+    // If no objects are found in the markdown file
+    // If the object is not found in the markdown file
+    //
+    // # Returns
+    //
+    // A JSON schema string
+    pub fn json_schema_with_ids(&self, obj_name: Option<String>) -> Result<String, MdModelsError> {
+        self.render_json_schema(obj_name, true, false)
+    }
+
+    // Get the JSON schema for an object with a `$comment` on each object
+    // definition, noting the source file, object and model version
+    //
+    // * `obj_name` - Name of the object
+    // * `embed_ids` - Whether to embed a `$id` and anchored definitions
+    //
+    // # Errors
+    //
+    // If no objects are found in the markdown file
+    // If the object is not found in the markdown file
+    //
+    // # Returns
+    //
+    // A JSON schema string
+    pub fn json_schema_with_provenance(
+        &self,
+        obj_name: Option<String>,
+        embed_ids: bool,
+    ) -> Result<String, MdModelsError> {
+        self.render_json_schema(obj_name, embed_ids, true)
+    }
 
-        match obj_name {
+    // Render the JSON schema for an object, optionally embedding ids and provenance
+    fn render_json_schema(
+        &self,
+        obj_name: Option<String>,
+        embed_ids: bool,
+        provenance: bool,
+    ) -> Result<String, MdModelsError> {
+        let model = self.for_target(&Templates::JsonSchema);
+        let name = match obj_name {
             Some(name) => {
-                if self.objects.iter().all(|o| o.name != name) {
-                    panic!("Object '{}' not found in the markdown file", name);
+                if model.objects.iter().all(|o| o.name != name) {
+                    return Err(MdModelsError::generation(
+                        Templates::JsonSchema,
+                        format!("Object '{}' not found in the model", name),
+                    ));
                 }
-                schema::to_json_schema(&name, self)
+                name
             }
-            None => schema::to_json_schema(&self.objects[0].name, self),
+            None => match model.objects.first() {
+                Some(object) => object.name.clone(),
+                None => {
+                    return Err(MdModelsError::generation(
+                        Templates::JsonSchema,
+                        "No objects found in the model",
+                    ))
+                }
+            },
+        };
+
+        // The schema is identified by the model, if named, or by its root object
+        let id_name = embed_ids.then(|| model.name.as_deref().unwrap_or(&name));
+        Ok(schema::to_json_schema(&name, &model, id_name, provenance))
+    }
+
+    // Get a copy of the data model holding only the definitions generated for a template
+    //
+    // Generic objects are replaced by concrete objects, unless the template supports generics.
+    //
+    // * `template` - The template to generate
+    fn for_target(&self, template: &Templates) -> DataModel {
+        let mut model = self.clone();
+        filter_targets(&mut model, template);
+        if !supports_generics(template) {
+            model.monomorphize_generics();
         }
+        model
+    }
+
+    // Get the Frictionless Table Schema for a flat object
+    //
+    // * `obj_name` - Name of the object, defaults to the first object
+    // * `flatten` - Whether nested objects and arrays are flattened
+    //
+    // # Errors
+    //
+    // If the object is not found or cannot be mapped to a flat table
+    //
+    // # Returns
+    //
+    // A Table Schema descriptor string
+    pub fn table_schema(
+        &self,
+        obj_name: Option<String>,
+        flatten: bool,
+    ) -> Result<String, MdModelsError> {
+        let model = self.for_target(&Templates::TableSchema);
+        let name = match obj_name {
+            Some(name) => name,
+            None => match model.objects.first() {
+                Some(object) => object.name.clone(),
+                None => {
+                    return Err(MdModelsError::generation(
+                        Templates::TableSchema,
+                        "No objects found in the model",
+                    ))
+                }
+            },
+        };
+
+        tableschema::to_table_schema(&name, &model, flatten)
+    }
+
+    // Generate reproducible mock instances of an object
+    //
+    // * `obj_name` - Name of the object, defaults to the first object
+    // * `count` - Number of instances to generate
+    // * `seed` - Seed of the pseudo-random values, equal seeds yield equal instances
+    //
+    // # Errors
+    //
+    // If the object is not found
+    //
+    // # Returns
+    //
+    // A JSON array of the instances
+    pub fn mock_json(
+        &self,
+        obj_name: Option<String>,
+        count: usize,
+        seed: u64,
+    ) -> Result<String, MdModelsError> {
+        let model = self.for_target(&Templates::MockJson);
+        let name = match obj_name {
+            Some(name) => name,
+            None => match model.objects.first() {
+                Some(object) => object.name.clone(),
+                None => {
+                    return Err(MdModelsError::generation(
+                        Templates::MockJson,
+                        "No objects found in the model",
+                    ))
+                }
+            },
+        };
+
+        mock::to_mock_json(&name, &model, count, seed)
+    }
+
+    // Validate a JSON document as an instance of an object
+    //
+    // Required properties, data types, enumeration values, arrays and maps
+    // are checked against the model directly, without a JSON schema.
+    //
+    // * `obj_name` - Name of the root object, defaults to the first object
+    // * `value` - The instance document
+    //
+    // # Errors
+    //
+    // All violations, each located by a JSON pointer into the document
+    pub fn validate_instance(
+        &self,
+        obj_name: Option<String>,
+        value: &serde_json::Value,
+    ) -> Result<(), Vec<InstanceError>> {
+        let mut model = self.clone();
+        model.monomorphize_generics();
+
+        let name = match obj_name {
+            Some(name) => name,
+            None => match model.objects.first() {
+                Some(object) => object.name.clone(),
+                None => {
+                    return Err(vec![InstanceError {
+                        pointer: String::new(),
+                        message: "No objects found in the model".to_string(),
+                    }])
+                }
+            },
+        };
+
+        instance::validate_instance(&name, &model, value)
     }
 
     // Get the JSON schema for all objects in the markdown file
@@ -95,32 +294,85 @@ impl DataModel {
     //
     // * `path` - Path to the directory where the JSON schema files will be written
     //
-    // # Panics
+    // # Errors
     //
     // If no objects are found in the markdown file
+    // If the directory or a schema file cannot be written
     //
     // # Examples
     //
     // ```
     // let model = DataModel::new();
     // model.parse("path/to/file.md".to_string());
-    // model.json_schema_all("path/to/directory".to_string());
+    // model.json_schema_all("path/to/directory".to_string())?;
     // ```
-    pub fn json_schema_all(&self, path: String) {
-        if self.objects.is_empty() {
-            panic!("No objects found in the markdown file");
+    pub fn json_schema_all(&self, path: String) -> Result<(), MdModelsError> {
+        let model = self.for_target(&Templates::JsonSchemaAll);
+        if model.objects.is_empty() {
+            return Err(MdModelsError::generation(
+                Templates::JsonSchemaAll,
+                "No objects found in the model",
+            ));
         }
 
         // Create the directory if it does not exist
         if !std::path::Path::new(&path).exists() {
-            fs::create_dir_all(&path).expect("Could not create directory");
+            fs::create_dir_all(&path).map_err(|e| MdModelsError::io(&path, e))?;
         }
 
-        for object in &self.objects {
-            let schema = schema::to_json_schema(&object.name, self);
-            let file_name = format!("{}/{}.json", path, object.name);
-            fs::write(file_name, schema).expect("Could not write file");
+        for (name, schema) in model.json_schemas(false) {
+            let file_name = format!("{}/{}.json", path, name);
+            fs::write(&file_name, schema).map_err(|e| MdModelsError::io(&file_name, e))?;
         }
+
+        Ok(())
+    }
+
+    // Get the JSON schema of each non-generic object, paired with its name
+    //
+    // * `embed_ids` - Whether to embed a `$id` and anchored definitions, where
+    //   each `$id` is built from the name of the object
+    //
+    // # Returns
+    //
+    // A list of object names and JSON schema strings
+    pub fn json_schemas(&self, embed_ids: bool) -> Vec<(String, String)> {
+        let model = self.for_target(&Templates::JsonSchemaAll);
+        model
+            .objects
+            .iter()
+            .filter(|o| o.type_param.is_none())
+            .map(|o| {
+                let id_name = embed_ids.then_some(o.name.as_str());
+                let schema = schema::to_json_schema(&o.name, &model, id_name, false);
+                (o.name.clone(), schema)
+            })
+            .collect()
+    }
+
+    // Get a single JSON schema holding all objects under `$defs`
+    //
+    // The bundle has no root object. Its `$id` is built from the title of the
+    // model like the ids of `json_schema_with_ids`, such that each object is
+    // addressable as `<id>#/$defs/<name>`. Untitled models have no `$id`.
+    //
+    // # Errors
+    //
+    // If no objects are found in the markdown file
+    //
+    // # Returns
+    //
+    // A JSON schema string
+    pub fn json_schema_bundle(&self) -> Result<String, MdModelsError> {
+        let model = self.for_target(&Templates::JsonSchemaBundle);
+        if model.objects.is_empty() {
+            return Err(MdModelsError::generation(
+                Templates::JsonSchemaBundle,
+                "No objects found in the model",
+            ));
+        }
+
+        Ok(schema::to_json_schema_bundle(&model))
     }
 
     // Get the SDRDM schema for the markdown file
@@ -163,13 +415,13 @@ impl DataModel {
     //
     // A data model
     //
-    pub fn from_sdrdm_schema(path: &Path) -> Result<Self, Box<dyn Error>> {
-        if !path.exists() {
-            return Err("File does not exist".into());
-        }
-
-        let contents = fs::read_to_string(path)?;
-        let model: DataModel = serde_json::from_str(&contents)?;
+    pub fn from_sdrdm_schema(path: &Path) -> Result<Self, MdModelsError> {
+        let contents = fs::read_to_string(path).map_err(|e| MdModelsError::io(path, e))?;
+        let model: DataModel =
+            serde_json::from_str(&contents).map_err(|e| MdModelsError::Parse {
+                object: None,
+                message: format!("Invalid sdRDM schema: {}", e),
+            })?;
 
         Ok(model)
     }
@@ -181,6 +433,166 @@ impl DataModel {
         }
     }
 
+    /// Compare this data model to another version of it
+    ///
+    /// * `other` - The newer version of the data model
+    ///
+    /// # Returns
+    ///
+    /// A `ModelDiff` listing added, removed and changed elements, each
+    /// classified as backward-compatible or breaking
+    pub fn diff(&self, other: &DataModel) -> ModelDiff {
+        ModelDiff::new(self, other)
+    }
+
+    /// Summarize the size and complexity of this data model
+    ///
+    /// # Returns
+    ///
+    /// A `ModelStats` counting objects, attributes and enumerations, the
+    /// longest chain of object references and the warnings of the model
+    pub fn stats(&self) -> ModelStats {
+        ModelStats::new(self)
+    }
+
+    /// Record the markdown file the objects were parsed from
+    ///
+    /// Objects with a known source, e.g. merged from an import, keep it.
+    ///
+    /// * `path` - Path to the markdown file
+    pub fn set_source(&mut self, path: &Path) {
+        for object in self.objects.iter_mut() {
+            object
+                .source
+                .get_or_insert_with(|| path.display().to_string());
+        }
+    }
+
+    /// Override the repository URL used as base IRI
+    ///
+    /// * `repo` - The repository URL that replaces the frontmatter value
+    pub fn set_repo(&mut self, repo: String) {
+        self.config.get_or_insert_with(FrontMatter::default).repo = repo;
+    }
+
+    /// Override the prefix used for the base IRI
+    ///
+    /// * `prefix` - The prefix that replaces the frontmatter value
+    pub fn set_prefix(&mut self, prefix: String) {
+        self.config.get_or_insert_with(FrontMatter::default).prefix = prefix;
+    }
+
+    /// Get the frontmatter the model was parsed with
+    ///
+    /// # Returns
+    ///
+    /// The parsed `FrontMatter`, or `None` if the model has no frontmatter
+    pub fn frontmatter(&self) -> Option<&FrontMatter> {
+        self.config.as_ref()
+    }
+
+    /// Get the prefixes declared in the frontmatter
+    ///
+    /// # Returns
+    ///
+    /// The prefixes mapped to their IRIs, or `None` if none are declared
+    pub fn prefixes(&self) -> Option<&HashMap<String, String>> {
+        self.frontmatter()?.prefixes.as_ref()
+    }
+
+    /// Get the repository URL used as base IRI
+    ///
+    /// # Returns
+    ///
+    /// The repository URL, or `None` if the model has no frontmatter
+    pub fn repo(&self) -> Option<&str> {
+        self.frontmatter().map(|config| config.repo.as_str())
+    }
+
+    /// Get the version of the model declared in the frontmatter
+    ///
+    /// # Returns
+    ///
+    /// The version, or `None` if no version is declared
+    pub fn version(&self) -> Option<&str> {
+        self.frontmatter()?.version.as_deref()
+    }
+
+    /// Get an object by its name
+    ///
+    /// Names are matched exactly, as types are resolved by the validator.
+    ///
+    /// * `name` - Name of the object
+    ///
+    /// # Returns
+    ///
+    /// The object, or `None` if the model has no object of this name
+    pub fn object(&self, name: &str) -> Option<&Object> {
+        self.objects.iter().find(|o| o.name == name)
+    }
+
+    /// Get a mutable reference to an object by its name
+    ///
+    /// * `name` - Name of the object
+    ///
+    /// # Returns
+    ///
+    /// The object, or `None` if the model has no object of this name
+    pub fn object_mut(&mut self, name: &str) -> Option<&mut Object> {
+        self.objects.iter_mut().find(|o| o.name == name)
+    }
+
+    /// Get an enumeration by its name
+    ///
+    /// Names are matched exactly, as types are resolved by the validator.
+    ///
+    /// * `name` - Name of the enumeration
+    ///
+    /// # Returns
+    ///
+    /// The enumeration, or `None` if the model has no enumeration of this name
+    pub fn enumeration(&self, name: &str) -> Option<&Enumeration> {
+        self.enums.iter().find(|e| e.name == name)
+    }
+
+    /// Get the concrete objects inheriting from an object
+    ///
+    /// Subtypes of subtypes are included, abstract subtypes are skipped.
+    ///
+    /// * `name` - Name of the base object
+    ///
+    /// # Returns
+    ///
+    /// The concrete subtypes in the order of the model
+    pub fn concrete_subtypes(&self, name: &str) -> Vec<&Object> {
+        let inherits = |object: &Object| {
+            let mut parent = object.parent.as_deref();
+            let mut visited = vec![object.name.as_str()];
+
+            while let Some(current) = parent {
+                if current == name {
+                    return true;
+                } else if visited.contains(&current) {
+                    return false;
+                }
+
+                visited.push(current);
+                parent = self
+                    .objects
+                    .iter()
+                    .find(|o| o.name == current)
+                    .and_then(|o| o.parent.as_deref());
+            }
+
+            false
+        };
+
+        self.objects
+            .iter()
+            .filter(|o| !o.is_abstract && inherits(o))
+            .collect()
+    }
+
     // Convert the data model to a template using Jinja
     //
     // * `template` - The Jinja template
@@ -197,11 +609,27 @@ impl DataModel {
         &mut self,
         template: &Templates,
         config: Option<&HashMap<String, String>>,
-    ) -> Result<String, minijinja::Error> {
+    ) -> Result<String, MdModelsError> {
         self.sort_attrs();
         render_jinja_template(template, self, config)
     }
 
+    // Convert the data model to markdown written in the given style
+    //
+    // * `style` - The style of the markdown, e.g. the indent width
+    //
+    // # Returns
+    //
+    // A string containing the markdown model
+    //
+    // # Errors
+    //
+    // If the markdown template cannot be rendered
+    //
+    pub fn to_markdown(&mut self, style: &MarkdownStyle) -> Result<String, MdModelsError> {
+        self.convert_to(&Templates::Markdown, Some(&style.to_config()))
+    }
+
     // Merge two data models
     //
     // * `other` - The other data model to merge
@@ -244,6 +672,93 @@ impl DataModel {
         // Merge the objects and enums
         self.objects.extend(other.objects.clone());
         self.enums.extend(other.enums.clone());
+        self.resolve_type_kinds();
+    }
+
+    /// Classifies the data types of all attributes as objects, enumerations or primitives.
+    ///
+    /// The kinds are stored in the attributes, such that generators do not
+    /// have to look up the definitions. They are resolved again whenever
+    /// definitions are added to the model.
+    pub(crate) fn resolve_type_kinds(&mut self) {
+        let objects = self
+            .objects
+            .iter()
+            .map(|o| o.name.clone())
+            .collect::<Vec<String>>();
+        let enums = self
+            .enums
+            .iter()
+            .map(|e| e.name.clone())
+            .collect::<Vec<String>>();
+        let objects = objects.iter().map(String::as_str).collect::<Vec<&str>>();
+        let enums = enums.iter().map(String::as_str).collect::<Vec<&str>>();
+
+        for object in self.objects.iter_mut() {
+            let type_param = object.type_param.clone();
+            for attribute in object.attributes.iter_mut() {
+                attribute.resolve_kinds(&objects, &enums, type_param.as_deref());
+            }
+        }
+    }
+
+    /// Replaces generic objects by concrete objects for each type argument used.
+    ///
+    /// A type `Result<Sample>` refers to a copy of the generic object `Result<T>`
+    /// named `ResultSample`, in which `T` is substituted by `Sample`. This is used
+    /// for targets without support for generics.
+    ///
+    /// Returns the generic name and type argument of every concrete object, by
+    /// the name of the concrete object.
+    pub(crate) fn monomorphize_generics(&mut self) -> BTreeMap<String, (String, String)> {
+        let generics = self
+            .objects
+            .iter()
+            .filter(|o| o.type_param.is_some())
+            .cloned()
+            .collect::<Vec<Object>>();
+
+        let mut instantiated = BTreeMap::new();
+        if generics.is_empty() {
+            return instantiated;
+        }
+
+        self.objects.retain(|o| o.type_param.is_none());
+
+        // Instantiated objects are appended and processed in turn
+        let mut index = 0;
+        while index < self.objects.len() {
+            let mut instances = vec![];
+            for dtype in self.objects[index]
+                .attributes
+                .iter_mut()
+                .flat_map(|a| a.dtypes.iter_mut())
+            {
+                if let Some((name, arg)) = generic_type(dtype) {
+                    let (name, arg) = (name.to_string(), arg.to_string());
+                    *dtype = format!("{}{}", name, arg.to_case(Case::Pascal));
+                    instances.push((name, arg, dtype.clone()));
+                }
+            }
+
+            for (name, arg, concrete) in instances {
+                if self.objects.iter().any(|o| o.name == concrete) {
+                    continue;
+                }
+
+                if let Some(generic) = generics.iter().find(|o| o.name == name) {
+                    self.objects
+                        .push(generic.instantiate(concrete.clone(), &arg));
+                    instantiated.insert(concrete, (name, arg));
+                }
+            }
+
+            index += 1;
+        }
+
+        self.resolve_type_kinds();
+
+        instantiated
     }
 
     /// Parse a markdown file and create a data model
@@ -261,9 +776,120 @@ impl DataModel {
     /// ```
     /// # Returns
     /// A data model
-    pub fn from_markdown(path: &Path) -> Result<Self, Box<dyn Error>> {
-        let content = fs::read_to_string(path)?;
-        parse_markdown(&content)
+    pub fn from_markdown(path: &Path) -> Result<Self, MdModelsError> {
+        let model = Self::from_markdown_unvalidated(path)?;
+        Validator::new().validate(&model)?;
+
+        Ok(model)
+    }
+
+    /// Parse a markdown file and create a data model along with its warnings
+    ///
+    /// Warnings, e.g. unused enumerations or naming lints, do not invalidate
+    /// the model and are returned instead of only being logged.
+    ///
+    /// * `path` - Path to the markdown file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use mdmodels::datamodel::DataModel;
+    ///
+    /// let path = Path::new("tests/data/model.md");
+    /// let (model, diagnostics) = DataModel::from_markdown_with_diagnostics(path).unwrap();
+    ///
+    /// for diagnostic in diagnostics {
+    ///     println!("{}", diagnostic);
+    /// }
+    /// ```
+    /// # Returns
+    /// A data model and its warnings
+    pub fn from_markdown_with_diagnostics(
+        path: &Path,
+    ) -> Result<(Self, Vec<Diagnostic>), MdModelsError> {
+        let model = Self::from_markdown(path)?;
+        let diagnostics = collect_diagnostics(&model);
+
+        Ok((model, diagnostics))
+    }
+
+    /// Parse a markdown file containing several models separated by `---`
+    ///
+    /// Each model may start with its own front matter, opened by another
+    /// `---` line after the separator. Like [`DataModel::from_markdown`],
+    /// imports and enumeration sources are resolved relative to the file.
+    ///
+    /// * `path` - Path to the markdown file
+    ///
+    /// # Returns
+    /// A data model for each document of the file
+    pub fn from_markdown_multi(path: &Path) -> Result<Vec<Self>, MdModelsError> {
+        let location = ModelLocation::File(path.to_path_buf());
+        let content = location.read()?;
+
+        split_documents(&content)
+            .into_iter()
+            .map(|document| {
+                let mut model = load_document(document, &location, &mut vec![])?;
+                model.set_source(path);
+                Validator::new().validate(&model)?;
+
+                Ok(model)
+            })
+            .collect()
+    }
+
+    /// Parse a markdown file and create a data model without validating it
+    ///
+    /// **Unsafe for production use:** the model may reference undefined types
+    /// or violate other validation rules, so generated output may be
+    /// incomplete. Intended as a debugging aid only.
+    ///
+    /// * `path` - Path to the markdown file
+    ///
+    /// # Returns
+    /// A data model
+    pub fn from_markdown_unvalidated(path: &Path) -> Result<Self, MdModelsError> {
+        let location = ModelLocation::File(path.to_path_buf());
+        let mut model = load_model(&location, &mut vec![])?;
+        model.set_source(path);
+
+        Ok(model)
+    }
+
+    /// Fetch a markdown file from a URL and create a data model
+    ///
+    /// Requires the `remote` feature. Imports and enumeration sources are
+    /// resolved relative to the URL.
+    ///
+    /// * `url` - URL of the markdown file
+    ///
+    /// # Returns
+    /// A data model
+    #[cfg(feature = "remote")]
+    pub fn from_url(url: &str) -> Result<Self, MdModelsError> {
+        let model = Self::from_url_unvalidated(url)?;
+        Validator::new().validate(&model)?;
+
+        Ok(model)
+    }
+
+    /// Fetch a markdown file from a URL and create a data model without validating it
+    ///
+    /// See [`DataModel::from_markdown_unvalidated`] for the caveats.
+    ///
+    /// * `url` - URL of the markdown file
+    ///
+    /// # Returns
+    /// A data model
+    #[cfg(feature = "remote")]
+    pub fn from_url_unvalidated(url: &str) -> Result<Self, MdModelsError> {
+        let url = reqwest::Url::parse(url).map_err(|e| MdModelsError::fetch(url, e))?;
+        let mut model = load_model(&ModelLocation::Url(url.clone()), &mut vec![])?;
+        model.set_source(Path::new(url.as_str()));
+
+        Ok(model)
     }
 
     /// Parse a markdown file and create a data model
@@ -283,18 +909,375 @@ impl DataModel {
     /// ```
     /// # Returns
     /// A data model
-    pub fn from_markdown_string(content: &str) -> Result<Self, Box<dyn Error>> {
+    pub fn from_markdown_string(content: &str) -> Result<Self, MdModelsError> {
         parse_markdown(content)
     }
 
+    /// Parse a markdown string and create a data model along with its warnings
+    ///
+    /// * `content` - Markdown content of the model
+    ///
+    /// # Returns
+    /// A data model and its warnings
+    pub fn from_markdown_string_with_diagnostics(
+        content: &str,
+    ) -> Result<(Self, Vec<Diagnostic>), MdModelsError> {
+        parse_markdown_with_diagnostics(content)
+    }
+
+    /// Find the attributes whose term resolves to the given IRI
+    ///
+    /// Terms given as CURIEs are expanded using the prefixes of the
+    /// frontmatter. The expanded IRI has to match exactly.
+    ///
+    /// * `iri` - The expanded IRI of the term
+    ///
+    /// # Returns
+    /// The attributes matching the IRI, paired with their objects
+    pub fn attributes_by_term(&self, iri: &str) -> Vec<(&Object, &Attribute)> {
+        let prefixes = self
+            .config
+            .as_ref()
+            .and_then(|c| c.prefixes.clone())
+            .unwrap_or_default();
+
+        self.objects
+            .iter()
+            .flat_map(|object| object.attributes.iter().map(move |attr| (object, attr)))
+            .filter(|(_, attr)| {
+                attr.term
+                    .as_ref()
+                    .and_then(|term| expand_iri(term, &prefixes))
+                    .is_some_and(|term| term == iri)
+            })
+            .collect()
+    }
+
     /// Parse a JSON schema and create a data model
     ///
     /// * `path` - Path to the JSON schema file
-    pub fn from_json_schema(path: &Path) -> Result<Self, Box<dyn Error>> {
+    pub fn from_json_schema(path: &Path) -> Result<Self, MdModelsError> {
         parse_json_schema(path)
     }
 }
 
+/// Location of a markdown model, which its imports and sources are relative to.
+enum ModelLocation {
+    /// A local markdown file.
+    File(PathBuf),
+    /// A remote markdown file.
+    #[cfg(feature = "remote")]
+    Url(reqwest::Url),
+}
+
+impl ModelLocation {
+    /// Reads the content of the file.
+    fn read(&self) -> Result<String, MdModelsError> {
+        match self {
+            Self::File(path) => read_markdown(path),
+            #[cfg(feature = "remote")]
+            Self::Url(url) => fetch_markdown(url.as_str()),
+        }
+    }
+
+    /// Resolves a path relative to the file.
+    fn join(&self, relative: &str) -> Result<Self, MdModelsError> {
+        match self {
+            Self::File(path) => Ok(Self::File(
+                path.parent().unwrap_or(Path::new("")).join(relative),
+            )),
+            #[cfg(feature = "remote")]
+            Self::Url(url) => url
+                .join(relative)
+                .map(Self::Url)
+                .map_err(|e| MdModelsError::fetch(relative, e)),
+        }
+    }
+
+    /// Identifies the file, such that cyclic imports can be detected.
+    fn key(&self) -> String {
+        match self {
+            Self::File(path) => fs::canonicalize(path)
+                .unwrap_or(path.clone())
+                .display()
+                .to_string(),
+            #[cfg(feature = "remote")]
+            Self::Url(url) => url.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for ModelLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            #[cfg(feature = "remote")]
+            Self::Url(url) => write!(f, "{}", url),
+        }
+    }
+}
+
+/// Reads a markdown model, including its enumeration sources and imports.
+///
+/// # Arguments
+///
+/// * `location` - Location of the markdown file.
+/// * `chain` - Locations of the models currently being imported.
+///
+/// # Returns
+///
+/// The unvalidated model, or an error if the model or one of the files it
+/// refers to cannot be read or parsed.
+fn load_model(
+    location: &ModelLocation,
+    chain: &mut Vec<String>,
+) -> Result<DataModel, MdModelsError> {
+    load_document(&location.read()?, location, chain)
+}
+
+/// Parses a markdown model read from a location, including its enumeration sources and imports.
+///
+/// # Arguments
+///
+/// * `content` - The markdown content of the model.
+/// * `location` - Location of the markdown file, which sources and imports are relative to.
+/// * `chain` - Locations of the models currently being imported.
+///
+/// # Returns
+///
+/// The unvalidated model, or an error if the model or one of the files it
+/// refers to cannot be read or parsed.
+fn load_document(
+    content: &str,
+    location: &ModelLocation,
+    chain: &mut Vec<String>,
+) -> Result<DataModel, MdModelsError> {
+    let mut model = parse_markdown_unvalidated(content)?;
+    load_enum_sources(&mut model, |source| location.join(source)?.read())?;
+    resolve_imports(&mut model, location, chain)?;
+
+    Ok(model)
+}
+
+/// Merges the models imported in the frontmatter into a model.
+///
+/// Imports map a namespace to the path of a markdown model, relative to the
+/// importing file or URL. Types given as `namespace:Name` are resolved to the merged
+/// object or enumeration. Only types are resolved, such that terms using the
+/// same prefix as a namespace remain CURIEs. Imported definitions whose names
+/// collide with a different definition keep their namespace, e.g. `chem:Sample`
+/// is merged as `ChemSample`.
+///
+/// # Arguments
+///
+/// * `model` - The model to merge the imported models into.
+/// * `location` - Location of the markdown file of the model.
+/// * `chain` - Locations of the models currently being imported.
+///
+/// # Errors
+///
+/// Returns a `MdModelsError` if an imported model cannot be read or parsed,
+/// if the imports are cyclic, or if a namespaced name collides again.
+fn resolve_imports(
+    model: &mut DataModel,
+    location: &ModelLocation,
+    chain: &mut Vec<String>,
+) -> Result<(), MdModelsError> {
+    let imports = match model.config.as_ref().and_then(|c| c.imports.clone()) {
+        Some(imports) => imports,
+        None => return Ok(()),
+    };
+
+    chain.push(location.key());
+
+    for (namespace, import) in imports {
+        let import_location = location.join(&import)?;
+        if chain.contains(&import_location.key()) {
+            return Err(MdModelsError::Parse {
+                object: None,
+                message: format!("Import '{}' of namespace '{}' is cyclic", import, namespace),
+            });
+        }
+
+        let mut imported = load_model(&import_location, chain)?;
+        imported.set_source(Path::new(&import_location.to_string()));
+        let renames = namespace_collisions(model, &mut imported, &namespace)?;
+
+        // Resolve the qualified types referring to the imported model
+        let names = imported
+            .objects
+            .iter()
+            .map(|o| o.name.as_str())
+            .chain(imported.enums.iter().map(|e| e.name.as_str()))
+            .collect::<Vec<&str>>();
+
+        let qualifier = format!("{}:", namespace);
+        for attribute in model
+            .objects
+            .iter_mut()
+            .flat_map(|o| o.attributes.iter_mut())
+        {
+            for dtype in attribute.dtypes.iter_mut() {
+                match dtype.strip_prefix(&qualifier) {
+                    Some(name) if renames.contains_key(name) => *dtype = renames[name].clone(),
+                    Some(name) if names.contains(&name) => *dtype = name.to_string(),
+                    _ => {}
+                }
+            }
+        }
+
+        // Merge the imported definitions, which may be shared by several imports
+        for object in imported.objects {
+            if !model.objects.contains(&object) {
+                model.objects.push(object);
+            }
+        }
+
+        for enumeration in imported.enums {
+            if !model.enums.contains(&enumeration) {
+                model.enums.push(enumeration);
+            }
+        }
+    }
+
+    chain.pop();
+    model.resolve_type_kinds();
+    Ok(())
+}
+
+/// Prefixes imported definitions colliding with a different definition by their namespace.
+///
+/// A definition collides if the importing model defines an object or
+/// enumeration of the same name that is not the very same definition. Colliding
+/// definitions are renamed to the namespace in PascalCase followed by their
+/// name, and all references within the imported model are updated.
+///
+/// # Arguments
+///
+/// * `model` - The importing model.
+/// * `imported` - The imported model whose definitions are renamed.
+/// * `namespace` - The namespace of the import.
+///
+/// # Returns
+///
+/// The new names of the renamed definitions, keyed by their original names.
+///
+/// # Errors
+///
+/// Returns a `MdModelsError` if a namespaced name is already taken.
+fn namespace_collisions(
+    model: &DataModel,
+    imported: &mut DataModel,
+    namespace: &str,
+) -> Result<HashMap<String, String>, MdModelsError> {
+    let prefix = namespace.to_case(Case::Pascal);
+    let defined = |name: &str| {
+        model.objects.iter().any(|o| o.name == name) || model.enums.iter().any(|e| e.name == name)
+    };
+
+    let mut renames = HashMap::new();
+    for object in imported.objects.iter() {
+        if defined(&object.name) && !model.objects.contains(object) {
+            renames.insert(object.name.clone(), format!("{}{}", prefix, object.name));
+        }
+    }
+
+    for enumeration in imported.enums.iter() {
+        if defined(&enumeration.name) && !model.enums.contains(enumeration) {
+            renames.insert(
+                enumeration.name.clone(),
+                format!("{}{}", prefix, enumeration.name),
+            );
+        }
+    }
+
+    for (name, renamed) in renames.iter() {
+        let taken = imported.objects.iter().any(|o| &o.name == renamed)
+            || imported.enums.iter().any(|e| &e.name == renamed);
+
+        if defined(renamed) || taken {
+            return Err(MdModelsError::Parse {
+                object: Some(name.clone()),
+                message: format!(
+                    "Cannot import '{}:{}', the namespaced name '{}' is already defined",
+                    namespace, name, renamed
+                ),
+            });
+        }
+    }
+
+    let rename = |name: &mut String| {
+        if let Some(renamed) = renames.get(name.as_str()) {
+            *name = renamed.clone();
+        }
+    };
+
+    for object in imported.objects.iter_mut() {
+        rename(&mut object.name);
+        object.parent.iter_mut().for_each(&rename);
+
+        for attribute in object.attributes.iter_mut() {
+            attribute.dtypes.iter_mut().for_each(&rename);
+            attribute.map_key.iter_mut().for_each(&rename);
+        }
+    }
+
+    for enumeration in imported.enums.iter_mut() {
+        rename(&mut enumeration.name);
+    }
+
+    Ok(renames)
+}
+
+/// Reads the content of a local markdown file.
+///
+/// # Arguments
+///
+/// * `path` - Path to the markdown file.
+///
+/// # Returns
+///
+/// The content of the file, or an I/O error naming the file if it cannot be
+/// read or is not valid UTF-8.
+fn read_markdown(path: &Path) -> Result<String, MdModelsError> {
+    let bytes = fs::read(path).map_err(|e| MdModelsError::io(path, e))?;
+
+    String::from_utf8(bytes).map_err(|e| {
+        let message = format!(
+            "File is not valid UTF-8 (invalid byte at offset {})",
+            e.utf8_error().valid_up_to()
+        );
+        MdModelsError::io(
+            path,
+            std::io::Error::new(std::io::ErrorKind::InvalidData, message),
+        )
+    })
+}
+
+/// Fetches the content of a remote markdown file.
+///
+/// # Arguments
+///
+/// * `url` - URL of the markdown file.
+///
+/// # Returns
+///
+/// The content of the file, or an error if the request fails or the server
+/// does not respond with a success status.
+#[cfg(feature = "remote")]
+fn fetch_markdown(url: &str) -> Result<String, MdModelsError> {
+    let response = reqwest::blocking::get(url).map_err(|e| MdModelsError::fetch(url, e))?;
+
+    if !response.status().is_success() {
+        return Err(MdModelsError::fetch(
+            url,
+            format!("Server responded with {}", response.status()),
+        ));
+    }
+
+    response.text().map_err(|e| MdModelsError::fetch(url, e))
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
@@ -314,6 +1297,8 @@ mod tests {
         obj1.add_attribute(crate::attribute::Attribute {
             name: "test1".to_string(),
             is_array: false,
+            size: None,
+            tuple: false,
             is_id: false,
             dtypes: vec!["string".to_string()],
             docstring: "".to_string(),
@@ -322,12 +1307,27 @@ mod tests {
             required: false,
             xml: None,
             default: None,
+            discriminator: None,
+            unit: None,
+            aliases: vec![],
+            constant: None,
+            inline: false,
+            coerce: false,
+            group: None,
+            column: None,
+            visibility: None,
+            map_key: None,
+            summary: None,
+            targets: vec![],
+            kinds: vec![],
         });
 
         let mut obj2 = Object::new("Object2".to_string(), None);
         obj2.add_attribute(crate::attribute::Attribute {
             name: "test2".to_string(),
             is_array: false,
+            size: None,
+            tuple: false,
             is_id: false,
             dtypes: vec!["string".to_string()],
             docstring: "".to_string(),
@@ -336,18 +1336,39 @@ mod tests {
             required: false,
             xml: None,
             default: None,
+            discriminator: None,
+            unit: None,
+            aliases: vec![],
+            constant: None,
+            inline: false,
+            coerce: false,
+            group: None,
+            column: None,
+            visibility: None,
+            map_key: None,
+            summary: None,
+            targets: vec![],
+            kinds: vec![],
         });
 
         let enm1 = Enumeration {
             name: "Enum1".to_string(),
             mappings: BTreeMap::from([("key1".to_string(), "value1".to_string())]),
             docstring: "".to_string(),
+            open: false,
+            iris: BTreeMap::new(),
+            extends: None,
+            source: None,
         };
 
         let enm2 = Enumeration {
             name: "Enum2".to_string(),
             mappings: BTreeMap::from([("key2".to_string(), "value2".to_string())]),
             docstring: "".to_string(),
+            open: false,
+            iris: BTreeMap::new(),
+            extends: None,
+            source: None,
         };
 
         model1.objects.push(obj1);
@@ -375,6 +1396,8 @@ mod tests {
         obj.add_attribute(crate::attribute::Attribute {
             name: "not_required".to_string(),
             is_array: false,
+            size: None,
+            tuple: false,
             is_id: false,
             dtypes: vec!["string".to_string()],
             docstring: "".to_string(),
@@ -383,11 +1406,26 @@ mod tests {
             required: false,
             xml: None,
             default: Some(DataType::String("".to_string())),
+            discriminator: None,
+            unit: None,
+            aliases: vec![],
+            constant: None,
+            inline: false,
+            coerce: false,
+            group: None,
+            column: None,
+            visibility: None,
+            map_key: None,
+            summary: None,
+            targets: vec![],
+            kinds: vec![],
         });
 
         obj.add_attribute(crate::attribute::Attribute {
             name: "required".to_string(),
             is_array: false,
+            size: None,
+            tuple: false,
             is_id: false,
             dtypes: vec!["string".to_string()],
             docstring: "".to_string(),
@@ -396,6 +1434,19 @@ mod tests {
             required: true,
             xml: None,
             default: None,
+            discriminator: None,
+            unit: None,
+            aliases: vec![],
+            constant: None,
+            inline: false,
+            coerce: false,
+            group: None,
+            column: None,
+            visibility: None,
+            map_key: None,
+            summary: None,
+            targets: vec![],
+            kinds: vec![],
         });
 
         model.objects.push(obj);
@@ -460,4 +1511,71 @@ mod tests {
         assert_eq!(model.objects.len(), 2);
         assert_eq!(model.enums.len(), 1);
     }
+
+    #[test]
+    fn test_override_repo_and_prefix() {
+        // Arrange
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model.md"))
+            .expect("Could not parse markdown");
+        let mut no_config = DataModel::new(None, None);
+
+        // Act
+        model.set_repo("https://prod.example.org/".to_string());
+        model.set_prefix("prod".to_string());
+        no_config.set_prefix("prod".to_string());
+
+        // Assert
+        let config = model.config.unwrap();
+        assert_eq!(config.repo, "https://prod.example.org/");
+        assert_eq!(config.prefix, "prod");
+
+        let config = no_config.config.unwrap();
+        assert_eq!(config.repo, "http://mdmodel.net/");
+        assert_eq!(config.prefix, "prod");
+    }
+
+    #[test]
+    fn test_attributes_by_term() {
+        // Arrange
+        let model = DataModel::from_markdown_string(
+            "---\nprefixes:\n  schema: http://schema.org/\n---\n\n### Person\n\n- name\n  - Type: string\n  - Term: schema:name\n- email\n  - Type: string\n  - Term: schema:email\n\n### Organization\n\n- title\n  - Type: string\n  - Term: http://schema.org/name\n",
+        )
+        .expect("Could not parse markdown");
+
+        // Act
+        let matches = model.attributes_by_term("http://schema.org/name");
+
+        // Assert
+        let names = matches
+            .iter()
+            .map(|(object, attr)| (object.name.as_str(), attr.name.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec![("Person", "name"), ("Organization", "title")]);
+        assert!(model.attributes_by_term("schema:name").is_empty());
+    }
+
+    #[test]
+    fn test_lookup_definitions() {
+        // Arrange
+        let mut model = DataModel::from_markdown(Path::new("tests/data/model.md"))
+            .expect("Could not parse markdown");
+
+        // Act
+        let object = model.object("Test");
+        let enumeration = model.enumeration("Ontology");
+
+        // Assert
+        assert_eq!(object.map(|o| o.name.as_str()), Some("Test"));
+        assert_eq!(enumeration.map(|e| e.name.as_str()), Some("Ontology"));
+        assert!(model.object("test").is_none());
+        assert!(model.object("Ontology").is_none());
+        assert!(model.enumeration("Test").is_none());
+
+        model
+            .object_mut("Test2")
+            .expect("Could not find object")
+            .set_docstring("Changed".to_string());
+        assert_eq!(model.object("Test2").unwrap().docstring, "Changed");
+        assert!(model.object_mut("Missing").is_none());
+    }
 }
@@ -0,0 +1,638 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    attribute::Attribute,
+    datamodel::DataModel,
+    object::{Enumeration, Object},
+};
+
+/// Families of numeric types, within which types are ranked by their width.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NumericFamily {
+    Signed,
+    Unsigned,
+    Float,
+}
+
+/// Numeric types with their family and widening rank. A type can be widened
+/// to any type of its family with at least its rank. Unsigned integers also
+/// widen to signed integers of a higher rank, and all integers to floats.
+const NUMERIC_WIDENING: [(&str, NumericFamily, u8); 7] = [
+    ("int32", NumericFamily::Signed, 32),
+    ("int64", NumericFamily::Signed, 64),
+    ("integer", NumericFamily::Signed, 64),
+    ("uint32", NumericFamily::Unsigned, 32),
+    ("uint64", NumericFamily::Unsigned, 64),
+    ("float", NumericFamily::Float, 1),
+    ("number", NumericFamily::Float, 2),
+];
+
+/// Represents the differences between two versions of a data model.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ModelDiff {
+    /// All changes from the old to the new model.
+    pub changes: Vec<Change>,
+}
+
+/// Represents a single change between two versions of a data model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Change {
+    /// An object has been added.
+    ObjectAdded { object: String },
+    /// An object has been removed.
+    ObjectRemoved { object: String },
+    /// An attribute has been added to an object.
+    AttributeAdded {
+        object: String,
+        attribute: String,
+        required: bool,
+    },
+    /// An attribute has been removed from an object.
+    AttributeRemoved { object: String, attribute: String },
+    /// The types of an attribute have changed.
+    AttributeRetyped {
+        object: String,
+        attribute: String,
+        from: Vec<String>,
+        to: Vec<String>,
+    },
+    /// An attribute has changed from single to multiple values or vice versa.
+    MultiplicityChanged {
+        object: String,
+        attribute: String,
+        multiple: bool,
+    },
+    /// The fixed number of items of an array has changed, or an array has
+    /// changed from or to a fixed size.
+    SizeChanged {
+        object: String,
+        attribute: String,
+        from: Option<usize>,
+        to: Option<usize>,
+    },
+    /// An attribute has changed from an array to a tuple or vice versa.
+    TupleChanged {
+        object: String,
+        attribute: String,
+        tuple: bool,
+    },
+    /// The key type of a map attribute has changed, or an attribute has
+    /// changed from or to a map.
+    MapKeyChanged {
+        object: String,
+        attribute: String,
+        from: Option<String>,
+        to: Option<String>,
+    },
+    /// An attribute has changed from optional to required or vice versa.
+    RequiredChanged {
+        object: String,
+        attribute: String,
+        required: bool,
+    },
+    /// An enumeration has been added.
+    EnumAdded { enumeration: String },
+    /// An enumeration has been removed.
+    EnumRemoved { enumeration: String },
+    /// A value has been added to an enumeration.
+    EnumValueAdded { enumeration: String, key: String },
+    /// A value has been removed from an enumeration.
+    EnumValueRemoved { enumeration: String, key: String },
+    /// The value of an enumeration key has changed.
+    EnumValueChanged {
+        enumeration: String,
+        key: String,
+        from: String,
+        to: String,
+    },
+}
+
+impl ModelDiff {
+    /// Computes the differences between an old and a new data model.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The previous version of the data model.
+    /// * `new` - The current version of the data model.
+    ///
+    /// # Returns
+    ///
+    /// A `ModelDiff` containing all changes.
+    pub fn new(old: &DataModel, new: &DataModel) -> Self {
+        let mut changes = Vec::new();
+
+        for old_obj in &old.objects {
+            match new.objects.iter().find(|o| o.name == old_obj.name) {
+                Some(new_obj) => diff_objects(old_obj, new_obj, &mut changes),
+                None => changes.push(Change::ObjectRemoved {
+                    object: old_obj.name.clone(),
+                }),
+            }
+        }
+
+        for new_obj in &new.objects {
+            if !old.objects.iter().any(|o| o.name == new_obj.name) {
+                changes.push(Change::ObjectAdded {
+                    object: new_obj.name.clone(),
+                });
+            }
+        }
+
+        for old_enum in &old.enums {
+            match new.enums.iter().find(|e| e.name == old_enum.name) {
+                Some(new_enum) => diff_enums(old_enum, new_enum, &mut changes),
+                None => changes.push(Change::EnumRemoved {
+                    enumeration: old_enum.name.clone(),
+                }),
+            }
+        }
+
+        for new_enum in &new.enums {
+            if !old.enums.iter().any(|e| e.name == new_enum.name) {
+                changes.push(Change::EnumAdded {
+                    enumeration: new_enum.name.clone(),
+                });
+            }
+        }
+
+        ModelDiff { changes }
+    }
+
+    /// Checks if there are no changes between the models.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Checks if any of the changes is breaking.
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(|c| c.is_breaking())
+    }
+
+    /// Returns all breaking changes.
+    pub fn breaking_changes(&self) -> Vec<&Change> {
+        self.changes.iter().filter(|c| c.is_breaking()).collect()
+    }
+
+    /// Returns all breaking changes that are not explicitly allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow` - Paths of accepted breaking changes, e.g. `Object.attribute`.
+    pub fn violations(&self, allow: &[String]) -> Vec<&Change> {
+        self.breaking_changes()
+            .into_iter()
+            .filter(|c| !allow.contains(&c.path()))
+            .collect()
+    }
+
+    /// Returns all backward-compatible changes.
+    pub fn compatible_changes(&self) -> Vec<&Change> {
+        self.changes.iter().filter(|c| !c.is_breaking()).collect()
+    }
+}
+
+impl Change {
+    /// Checks if the change breaks backward compatibility.
+    ///
+    /// Data that is valid against the old model has to remain valid against
+    /// the new model. Hence, removing elements, adding required attributes,
+    /// making attributes required and narrowing types are breaking.
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            Change::ObjectAdded { .. } => false,
+            Change::ObjectRemoved { .. } => true,
+            Change::AttributeAdded { required, .. } => *required,
+            Change::AttributeRemoved { .. } => true,
+            Change::AttributeRetyped { from, to, .. } => !is_widening(from, to),
+            Change::MultiplicityChanged { .. } => true,
+            Change::SizeChanged { .. } => true,
+            Change::TupleChanged { .. } => true,
+            Change::MapKeyChanged { .. } => true,
+            Change::RequiredChanged { required, .. } => *required,
+            Change::EnumAdded { .. } => false,
+            Change::EnumRemoved { .. } => true,
+            Change::EnumValueAdded { .. } => false,
+            Change::EnumValueRemoved { .. } => true,
+            Change::EnumValueChanged { .. } => true,
+        }
+    }
+
+    /// Returns the path of the changed element, e.g. `Object.attribute`.
+    pub fn path(&self) -> String {
+        match self {
+            Change::ObjectAdded { object } | Change::ObjectRemoved { object } => object.clone(),
+            Change::AttributeAdded {
+                object, attribute, ..
+            }
+            | Change::AttributeRemoved { object, attribute }
+            | Change::AttributeRetyped {
+                object, attribute, ..
+            }
+            | Change::MultiplicityChanged {
+                object, attribute, ..
+            }
+            | Change::SizeChanged {
+                object, attribute, ..
+            }
+            | Change::TupleChanged {
+                object, attribute, ..
+            }
+            | Change::MapKeyChanged {
+                object, attribute, ..
+            }
+            | Change::RequiredChanged {
+                object, attribute, ..
+            } => format!("{}.{}", object, attribute),
+            Change::EnumAdded { enumeration } | Change::EnumRemoved { enumeration } => {
+                enumeration.clone()
+            }
+            Change::EnumValueAdded { enumeration, key }
+            | Change::EnumValueRemoved { enumeration, key }
+            | Change::EnumValueChanged {
+                enumeration, key, ..
+            } => format!("{}.{}", enumeration, key),
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Change::ObjectAdded { .. } => "object added".to_string(),
+            Change::ObjectRemoved { .. } => "object removed".to_string(),
+            Change::AttributeAdded { required, .. } => match required {
+                true => "required attribute added".to_string(),
+                false => "optional attribute added".to_string(),
+            },
+            Change::AttributeRemoved { .. } => "attribute removed".to_string(),
+            Change::AttributeRetyped { from, to, .. } => {
+                format!("type changed from {} to {}", from.join(", "), to.join(", "))
+            }
+            Change::MultiplicityChanged { multiple, .. } => match multiple {
+                true => "attribute changed to an array".to_string(),
+                false => "attribute changed from an array".to_string(),
+            },
+            Change::SizeChanged { from, to, .. } => match (from, to) {
+                (Some(from), Some(to)) => format!("size changed from {} to {}", from, to),
+                (None, Some(to)) => format!("array changed to a fixed size of {}", to),
+                (Some(from), None) => format!("array changed from a fixed size of {}", from),
+                (None, None) => "size changed".to_string(),
+            },
+            Change::TupleChanged { tuple, .. } => match tuple {
+                true => "attribute changed to a tuple".to_string(),
+                false => "attribute changed from a tuple".to_string(),
+            },
+            Change::MapKeyChanged { from, to, .. } => match (from, to) {
+                (Some(from), Some(to)) => format!("map key changed from {} to {}", from, to),
+                (None, Some(to)) => format!("attribute changed to a map with {} keys", to),
+                (Some(from), None) => format!("attribute changed from a map with {} keys", from),
+                (None, None) => "map key changed".to_string(),
+            },
+            Change::RequiredChanged { required, .. } => match required {
+                true => "attribute is now required".to_string(),
+                false => "attribute is now optional".to_string(),
+            },
+            Change::EnumAdded { .. } => "enumeration added".to_string(),
+            Change::EnumRemoved { .. } => "enumeration removed".to_string(),
+            Change::EnumValueAdded { .. } => "enumeration value added".to_string(),
+            Change::EnumValueRemoved { .. } => "enumeration value removed".to_string(),
+            Change::EnumValueChanged { from, to, .. } => {
+                format!("enumeration value changed from {} to {}", from, to)
+            }
+        };
+
+        write!(f, "{}: {}", self.path(), description)
+    }
+}
+
+/// Collects the attribute changes between two versions of an object.
+fn diff_objects(old: &Object, new: &Object, changes: &mut Vec<Change>) {
+    for old_attr in &old.attributes {
+        match new.attributes.iter().find(|a| a.name == old_attr.name) {
+            Some(new_attr) => diff_attributes(&old.name, old_attr, new_attr, changes),
+            None => changes.push(Change::AttributeRemoved {
+                object: old.name.clone(),
+                attribute: old_attr.name.clone(),
+            }),
+        }
+    }
+
+    for new_attr in &new.attributes {
+        if !old.attributes.iter().any(|a| a.name == new_attr.name) {
+            changes.push(Change::AttributeAdded {
+                object: new.name.clone(),
+                attribute: new_attr.name.clone(),
+                required: new_attr.required,
+            });
+        }
+    }
+}
+
+/// Collects the changes between two versions of an attribute.
+fn diff_attributes(object: &str, old: &Attribute, new: &Attribute, changes: &mut Vec<Change>) {
+    if old.dtypes != new.dtypes {
+        changes.push(Change::AttributeRetyped {
+            object: object.to_string(),
+            attribute: old.name.clone(),
+            from: old.dtypes.clone(),
+            to: new.dtypes.clone(),
+        });
+    }
+
+    if old.is_array != new.is_array {
+        changes.push(Change::MultiplicityChanged {
+            object: object.to_string(),
+            attribute: old.name.clone(),
+            multiple: new.is_array,
+        });
+    }
+
+    if old.size != new.size {
+        changes.push(Change::SizeChanged {
+            object: object.to_string(),
+            attribute: old.name.clone(),
+            from: old.size,
+            to: new.size,
+        });
+    }
+
+    if old.tuple != new.tuple {
+        changes.push(Change::TupleChanged {
+            object: object.to_string(),
+            attribute: old.name.clone(),
+            tuple: new.tuple,
+        });
+    }
+
+    if old.map_key != new.map_key {
+        changes.push(Change::MapKeyChanged {
+            object: object.to_string(),
+            attribute: old.name.clone(),
+            from: old.map_key.clone(),
+            to: new.map_key.clone(),
+        });
+    }
+
+    if old.required != new.required {
+        changes.push(Change::RequiredChanged {
+            object: object.to_string(),
+            attribute: old.name.clone(),
+            required: new.required,
+        });
+    }
+}
+
+/// Collects the value changes between two versions of an enumeration.
+fn diff_enums(old: &Enumeration, new: &Enumeration, changes: &mut Vec<Change>) {
+    for (key, old_value) in &old.mappings {
+        match new.mappings.get(key) {
+            Some(new_value) if new_value != old_value => {
+                changes.push(Change::EnumValueChanged {
+                    enumeration: old.name.clone(),
+                    key: key.clone(),
+                    from: old_value.clone(),
+                    to: new_value.clone(),
+                });
+            }
+            Some(_) => {}
+            None => changes.push(Change::EnumValueRemoved {
+                enumeration: old.name.clone(),
+                key: key.clone(),
+            }),
+        }
+    }
+
+    for key in new.mappings.keys() {
+        if !old.mappings.contains_key(key) {
+            changes.push(Change::EnumValueAdded {
+                enumeration: new.name.clone(),
+                key: key.clone(),
+            });
+        }
+    }
+}
+
+/// Checks if every old type is still accepted by the new types.
+fn is_widening(from: &[String], to: &[String]) -> bool {
+    from.iter().all(|old| {
+        to.iter().any(|new| {
+            if old == new {
+                return true;
+            }
+
+            match (numeric_rank(old), numeric_rank(new)) {
+                (Some((from, old_rank)), Some((to, new_rank))) => match (from, to) {
+                    _ if from == to => old_rank <= new_rank,
+                    (NumericFamily::Unsigned, NumericFamily::Signed) => old_rank < new_rank,
+                    (_, NumericFamily::Float) => true,
+                    _ => false,
+                },
+                _ => false,
+            }
+        })
+    })
+}
+
+/// Returns the family and widening rank of a numeric type.
+fn numeric_rank(dtype: &str) -> Option<(NumericFamily, u8)> {
+    NUMERIC_WIDENING
+        .iter()
+        .find(|(name, _, _)| *name == dtype)
+        .map(|(_, family, rank)| (*family, *rank))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Helper function to parse a model from a markdown string.
+    fn model(content: &str) -> DataModel {
+        DataModel::from_markdown_string(content).expect("Could not parse markdown")
+    }
+
+    const BASE: &str = "### Sample\n\n- __id__\n  - Type: string\n- value\n  - Type: integer\n\n## Enumerations\n\n### Unit\n\n```\nLITRE = l\nGRAM = g\n```\n";
+
+    #[test]
+    fn test_additive_diff() {
+        // Arrange
+        let old = model(BASE);
+        let new = model("### Sample\n\n- __id__\n  - Type: string\n- value\n  - Type: float\n- comment\n  - Type: string\n\n### Other\n\n- name\n  - Type: string\n\n## Enumerations\n\n### Unit\n\n```\nLITRE = l\nGRAM = g\nMETRE = m\n```\n");
+
+        // Act
+        let diff = old.diff(&new);
+
+        // Assert
+        assert!(!diff.is_breaking());
+        assert_eq!(
+            diff.changes,
+            vec![
+                Change::AttributeRetyped {
+                    object: "Sample".to_string(),
+                    attribute: "value".to_string(),
+                    from: vec!["integer".to_string()],
+                    to: vec!["float".to_string()],
+                },
+                Change::AttributeAdded {
+                    object: "Sample".to_string(),
+                    attribute: "comment".to_string(),
+                    required: false,
+                },
+                Change::ObjectAdded {
+                    object: "Other".to_string(),
+                },
+                Change::EnumValueAdded {
+                    enumeration: "Unit".to_string(),
+                    key: "METRE".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_breaking_diff() {
+        // Arrange
+        let old = model(BASE);
+        let new = model("### Sample\n\n- __id__\n  - Type: string\n- __unit__\n  - Type: Unit\n\n## Enumerations\n\n### Unit\n\n```\nLITRE = L\n```\n");
+
+        // Act
+        let diff = old.diff(&new);
+
+        // Assert
+        assert!(diff.is_breaking());
+
+        let breaking = diff
+            .breaking_changes()
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<String>>();
+
+        assert_eq!(
+            breaking,
+            vec![
+                "Sample.value: attribute removed",
+                "Sample.unit: required attribute added",
+                "Unit.GRAM: enumeration value removed",
+                "Unit.LITRE: enumeration value changed from l to L",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_allowed_violations() {
+        // Arrange
+        let old = model(BASE);
+        let new = model("### Sample\n\n- __id__\n  - Type: string\n\n## Enumerations\n\n### Unit\n\n```\nLITRE = l\n```\n");
+        let diff = old.diff(&new);
+
+        // Act
+        let violations = diff.violations(&["Sample.value".to_string()]);
+
+        // Assert
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].path(), "Unit.GRAM");
+    }
+
+    #[test]
+    fn test_map_key_changes() {
+        // Arrange
+        let old = model(
+            "### Sample\n\n- values\n  - Type: float[]\n- scores\n  - Type: map<string, float>\n",
+        );
+        let new = model("### Sample\n\n- values\n  - Type: map<string, float[]>\n- scores\n  - Type: map<integer, float>\n");
+
+        // Act
+        let diff = old.diff(&new);
+
+        // Assert
+        assert!(diff.is_breaking());
+
+        let breaking = diff
+            .breaking_changes()
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<String>>();
+
+        assert_eq!(
+            breaking,
+            vec![
+                "Sample.values: attribute changed to a map with string keys",
+                "Sample.scores: map key changed from string to integer",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_size_and_tuple_changes() {
+        // Arrange
+        let old =
+            model("### Sample\n\n- position\n  - Type: float[3]\n- range\n  - Type: float[]\n");
+        let new = model(
+            "### Sample\n\n- position\n  - Type: float[4]\n- range\n  - Type: (float, float)\n",
+        );
+
+        // Act
+        let diff = old.diff(&new);
+
+        // Assert
+        assert!(diff.is_breaking());
+
+        let breaking = diff
+            .breaking_changes()
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<String>>();
+
+        assert_eq!(
+            breaking,
+            vec![
+                "Sample.position: size changed from 3 to 4",
+                "Sample.range: array changed to a fixed size of 2",
+                "Sample.range: attribute changed to a tuple",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_type_narrowing() {
+        assert!(is_widening(
+            &["integer".to_string()],
+            &["number".to_string()]
+        ));
+        assert!(is_widening(
+            &["string".to_string()],
+            &["string".to_string(), "integer".to_string()]
+        ));
+        assert!(!is_widening(
+            &["float".to_string()],
+            &["integer".to_string()]
+        ));
+        assert!(!is_widening(
+            &["string".to_string(), "integer".to_string()],
+            &["string".to_string()]
+        ));
+    }
+
+    #[test]
+    fn test_fixed_width_widening() {
+        // Arrange
+        let widening = |from: &str, to: &str| is_widening(&[from.to_string()], &[to.to_string()]);
+
+        // Assert
+        assert!(widening("int32", "int64"));
+        assert!(widening("int32", "integer"));
+        assert!(widening("int64", "integer"));
+        assert!(widening("integer", "int64"));
+        assert!(widening("uint32", "uint64"));
+        assert!(widening("uint32", "int64"));
+        assert!(widening("uint64", "number"));
+        assert!(!widening("int64", "int32"));
+        assert!(!widening("uint64", "int64"));
+        assert!(!widening("int32", "uint32"));
+        assert!(!widening("integer", "uint64"));
+        assert!(!widening("number", "float"));
+    }
+}
@@ -0,0 +1,116 @@
+use std::{error::Error, fmt, io, path::PathBuf};
+
+/// Errors returned by the public API of the library.
+///
+/// Each variant carries enough context to report the failure without
+/// inspecting the logs, e.g. the file that could not be read or the
+/// definitions that failed validation.
+#[derive(Debug)]
+pub enum MdModelsError {
+    /// A file could not be read or written.
+    Io { path: PathBuf, source: io::Error },
+    /// A remote model could not be fetched.
+    Fetch { url: String, message: String },
+    /// The frontmatter of a model could not be parsed.
+    Frontmatter { message: String },
+    /// The content of a model could not be parsed.
+    Parse {
+        /// The object in which the error occurred, if any.
+        object: Option<String>,
+        message: String,
+    },
+    /// The model violates one or more validation rules.
+    ///
+    /// The individual violations are reported through the logger.
+    Validation {
+        /// The definitions that failed validation. Model-wide violations
+        /// are listed as `Global`.
+        definitions: Vec<String>,
+    },
+    /// The output for a target could not be generated.
+    Generation { target: String, message: String },
+    /// The name of a template does not refer to any available target.
+    UnknownTemplate { name: String },
+}
+
+impl MdModelsError {
+    /// Creates an I/O error for the given path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file that could not be accessed.
+    /// * `source` - The underlying I/O error.
+    pub(crate) fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        MdModelsError::Io {
+            path: path.into(),
+            source,
+        }
+    }
+
+    /// Creates a fetch error for the given URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL that could not be fetched.
+    /// * `message` - A description of the failure.
+    #[cfg(feature = "remote")]
+    pub(crate) fn fetch(url: impl Into<String>, message: impl fmt::Display) -> Self {
+        MdModelsError::Fetch {
+            url: url.into(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Creates a generation error for the given target.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The target that could not be generated.
+    /// * `message` - A description of the failure.
+    pub(crate) fn generation(target: impl fmt::Display, message: impl fmt::Display) -> Self {
+        MdModelsError::Generation {
+            target: target.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for MdModelsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MdModelsError::Io { path, source } => {
+                write!(f, "Could not access '{}': {}", path.display(), source)
+            }
+            MdModelsError::Fetch { url, message } => {
+                write!(f, "Could not fetch '{}': {}", url, message)
+            }
+            MdModelsError::Frontmatter { message } => write!(f, "Invalid frontmatter: {}", message),
+            MdModelsError::Parse {
+                object: Some(object),
+                message,
+            } => write!(f, "[{}] {}", object, message),
+            MdModelsError::Parse {
+                object: None,
+                message,
+            } => write!(f, "{}", message),
+            MdModelsError::Validation { definitions } => write!(
+                f,
+                "Invalid Markdown Model. Affected definitions: {}",
+                definitions.join(", ")
+            ),
+            MdModelsError::Generation { target, message } => {
+                write!(f, "Could not generate '{}': {}", target, message)
+            }
+            MdModelsError::UnknownTemplate { name } => write!(f, "Unknown template '{}'", name),
+        }
+    }
+}
+
+impl Error for MdModelsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MdModelsError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
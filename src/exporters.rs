@@ -1,8 +1,24 @@
-use std::{collections::HashMap, error::Error, fmt::Display, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    path::Path,
+    str::FromStr,
+};
 
-use crate::datamodel::DataModel;
+use crate::{
+    attribute::{generic_type, DataType},
+    capnp::Ordinals,
+    datamodel::DataModel,
+    error::MdModelsError,
+    markdown::frontmatter::TimestampFormat,
+    object::{compact_iri, expand_iri},
+    orm::{sql_table_name, Relations},
+};
 use clap::ValueEnum;
+use colored::Colorize;
+use convert_case::{Boundary, Case, Casing};
 use lazy_static::lazy_static;
+use log::debug;
 use minijinja::{context, Environment};
 use textwrap::wrap;
 
@@ -12,8 +28,14 @@ lazy_static! {
         let mut m = std::collections::HashMap::new();
         m.insert("string".to_string(), "str".to_string());
         m.insert("integer".to_string(), "int".to_string());
+        m.insert("int32".to_string(), "int".to_string());
+        m.insert("int64".to_string(), "int".to_string());
+        m.insert("uint32".to_string(), "int".to_string());
+        m.insert("uint64".to_string(), "int".to_string());
         m.insert("boolean".to_string(), "bool".to_string());
         m.insert("number".to_string(), "float".to_string());
+        m.insert("duration".to_string(), "timedelta".to_string());
+        m.insert("any".to_string(), "Any".to_string());
         m
     };
 
@@ -21,6 +43,12 @@ lazy_static! {
     static ref SHACL_TYPE_MAPS: std::collections::HashMap<String, String> = {
         let mut m = std::collections::HashMap::new();
         m.insert("float".to_string(), "double".to_string());
+        m.insert("bytes".to_string(), "base64Binary".to_string());
+        m.insert("datetime".to_string(), "dateTime".to_string());
+        m.insert("int32".to_string(), "int".to_string());
+        m.insert("int64".to_string(), "long".to_string());
+        m.insert("uint32".to_string(), "unsignedInt".to_string());
+        m.insert("uint64".to_string(), "unsignedLong".to_string());
         m
     };
 
@@ -28,6 +56,12 @@ lazy_static! {
     static ref XSD_TYPE_MAPS: std::collections::HashMap<String, String> = {
         let mut m = std::collections::HashMap::new();
         m.insert("str".to_string(), "string".to_string());
+        m.insert("bytes".to_string(), "base64Binary".to_string());
+        m.insert("datetime".to_string(), "dateTime".to_string());
+        m.insert("int32".to_string(), "int".to_string());
+        m.insert("int64".to_string(), "long".to_string());
+        m.insert("uint32".to_string(), "unsignedInt".to_string());
+        m.insert("uint64".to_string(), "unsignedLong".to_string());
         m
     };
 
@@ -35,8 +69,15 @@ lazy_static! {
     static ref TYPESCRIPT_TYPE_MAPS: std::collections::HashMap<String, String> = {
         let mut m = std::collections::HashMap::new();
         m.insert("integer".to_string(), "number".to_string());
+        m.insert("int32".to_string(), "number".to_string());
+        m.insert("int64".to_string(), "number".to_string());
+        m.insert("uint32".to_string(), "number".to_string());
+        m.insert("uint64".to_string(), "number".to_string());
         m.insert("float".to_string(), "number".to_string());
         m.insert("date".to_string(), "string".to_string());
+        m.insert("datetime".to_string(), "string".to_string());
+        m.insert("duration".to_string(), "string".to_string());
+        m.insert("bytes".to_string(), "string".to_string());
         m
     };
 }
@@ -50,6 +91,7 @@ pub enum Templates {
     Shacl,
     JsonSchema,
     JsonSchemaAll,
+    JsonSchemaBundle,
     Shex,
     PythonDataclass,
     PythonSdrdm,
@@ -57,6 +99,28 @@ pub enum Templates {
     MkDocs,
     Internal,
     Typescript,
+    Dot,
+    Elixir,
+    Zod,
+    #[value(name = "plantuml")]
+    PlantUml,
+    Docs,
+    Rust,
+    Sql,
+    #[value(name = "python-sqlalchemy", alias = "sqlalchemy")]
+    PythonSqlAlchemy,
+    #[value(name = "fsharp")]
+    FSharp,
+    TableSchema,
+    Nim,
+    TypescriptClass,
+    TypescriptAjv,
+    #[value(name = "ocaml")]
+    OCaml,
+    Haskell,
+    Capnp,
+    Dart,
+    MockJson,
 }
 
 impl Display for Templates {
@@ -71,10 +135,107 @@ impl Display for Templates {
             Templates::Shacl => write!(f, "shacl"),
             Templates::JsonSchema => write!(f, "json-schema"),
             Templates::JsonSchemaAll => write!(f, "json-schema-all"),
+            Templates::JsonSchemaBundle => write!(f, "json-schema-bundle"),
             Templates::Shex => write!(f, "shex"),
             Templates::MkDocs => write!(f, "mk-docs"),
             Templates::Internal => write!(f, "internal"),
             Templates::Typescript => write!(f, "typescript"),
+            Templates::Dot => write!(f, "dot"),
+            Templates::Elixir => write!(f, "elixir"),
+            Templates::Zod => write!(f, "zod"),
+            Templates::PlantUml => write!(f, "plantuml"),
+            Templates::Docs => write!(f, "docs"),
+            Templates::Rust => write!(f, "rust"),
+            Templates::Sql => write!(f, "sql"),
+            Templates::PythonSqlAlchemy => write!(f, "python-sqlalchemy"),
+            Templates::FSharp => write!(f, "fsharp"),
+            Templates::TableSchema => write!(f, "table-schema"),
+            Templates::Nim => write!(f, "nim"),
+            Templates::TypescriptClass => write!(f, "typescript-class"),
+            Templates::TypescriptAjv => write!(f, "typescript-ajv"),
+            Templates::OCaml => write!(f, "ocaml"),
+            Templates::Haskell => write!(f, "haskell"),
+            Templates::Capnp => write!(f, "capnp"),
+            Templates::Dart => write!(f, "dart"),
+            Templates::MockJson => write!(f, "mock-json"),
+        }
+    }
+}
+
+impl Templates {
+    /// Returns the file extension of the files generated by the template.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Templates::XmlSchema => "xsd",
+            Templates::Markdown => "md",
+            Templates::CompactMarkdown => "md",
+            Templates::Shacl => "ttl",
+            Templates::JsonSchema => "json",
+            Templates::JsonSchemaAll => "json",
+            Templates::JsonSchemaBundle => "json",
+            Templates::Shex => "shex",
+            Templates::PythonDataclass => "py",
+            Templates::PythonSdrdm => "py",
+            Templates::PythonPydantic => "py",
+            Templates::MkDocs => "md",
+            Templates::Internal => "json",
+            Templates::Typescript => "ts",
+            Templates::Dot => "dot",
+            Templates::Elixir => "ex",
+            Templates::Zod => "ts",
+            Templates::PlantUml => "puml",
+            Templates::Docs => "md",
+            Templates::Rust => "rs",
+            Templates::Sql => "sql",
+            Templates::PythonSqlAlchemy => "py",
+            Templates::FSharp => "fs",
+            Templates::TableSchema => "json",
+            Templates::Nim => "nim",
+            Templates::TypescriptClass => "ts",
+            Templates::TypescriptAjv => "ts",
+            Templates::OCaml => "ml",
+            Templates::Haskell => "hs",
+            Templates::Capnp => "capnp",
+            Templates::Dart => "dart",
+            Templates::MockJson => "json",
+        }
+    }
+
+    /// Returns a one-line description of the template.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Templates::XmlSchema => "XML schema definition",
+            Templates::Markdown => "Markdown model definition",
+            Templates::CompactMarkdown => "Markdown model definition with collapsible attributes",
+            Templates::Shacl => "SHACL shapes definition",
+            Templates::JsonSchema => "JSON schema definition of a single root object",
+            Templates::JsonSchemaAll => "JSON schema definitions of all objects",
+            Templates::JsonSchemaBundle => "JSON schema document holding all objects under $defs",
+            Templates::Shex => "ShEx shapes definition",
+            Templates::PythonDataclass => "Python dataclass implementation with JSON-LD support",
+            Templates::PythonSdrdm => "Python PyDantic implementation with multiple output formats",
+            Templates::PythonPydantic => "Python PyDantic implementation with JSON-LD support",
+            Templates::MkDocs => "MkDocs documentation page",
+            Templates::Internal => "Internal JSON representation of the model",
+            Templates::Typescript => "TypeScript interfaces with io-ts decoders",
+            Templates::Dot => "GraphViz DOT graph of the object references",
+            Templates::Elixir => "Elixir structs, optionally Ecto embedded schemas",
+            Templates::Zod => "TypeScript Zod schemas with inferred types",
+            Templates::PlantUml => "PlantUML class diagram of the objects and enumerations",
+            Templates::Docs => "Markdown data dictionary with attribute tables",
+            Templates::Rust => "Rust structs and enums with serde support",
+            Templates::Sql => "SQL DDL with CREATE TABLE statements",
+            Templates::PythonSqlAlchemy => "Python SQLAlchemy models with relationships",
+            Templates::FSharp => "F# record types and discriminated unions",
+            Templates::TableSchema => "Frictionless Table Schema of a single flat object",
+            Templates::Nim => "Nim object types and enums",
+            Templates::TypescriptClass => "TypeScript classes with runtime validation",
+            Templates::TypescriptAjv => "TypeScript ajv validators of the bundled JSON schema",
+            Templates::OCaml => "OCaml record and variant types",
+            Templates::Haskell => "Haskell records and sum types with aeson instances",
+            Templates::Capnp => "Cap'n Proto schema with structs and enums",
+            Templates::Dart => "Dart classes with json_serializable annotations",
+            Templates::MockJson => "Reproducible mock instances of an object",
         }
     }
 }
@@ -82,8 +243,8 @@ impl Display for Templates {
 /// Converts string representation of a template to a `Templates` enum.
 /// and returns an error if the string is not a valid template type.
 impl FromStr for Templates {
-    type Err = Box<dyn Error>;
-    fn from_str(s: &str) -> Result<Self, Box<dyn Error>> {
+    type Err = MdModelsError;
+    fn from_str(s: &str) -> Result<Self, MdModelsError> {
         match s {
             "python-dataclass" => Ok(Templates::PythonDataclass),
             "python-sdrdm" => Ok(Templates::PythonSdrdm),
@@ -94,14 +255,32 @@ impl FromStr for Templates {
             "shacl" => Ok(Templates::Shacl),
             "json-schema" => Ok(Templates::JsonSchema),
             "json-schema-all" => Ok(Templates::JsonSchemaAll),
+            "json-schema-bundle" => Ok(Templates::JsonSchemaBundle),
             "shex" => Ok(Templates::Shex),
             "mk-docs" => Ok(Templates::MkDocs),
             "internal" => Ok(Templates::Internal),
             "typescript" => Ok(Templates::Typescript),
-            _ => {
-                let err = format!("Invalid template type: {}", s);
-                Err(err.into())
-            }
+            "dot" => Ok(Templates::Dot),
+            "elixir" => Ok(Templates::Elixir),
+            "zod" => Ok(Templates::Zod),
+            "plantuml" => Ok(Templates::PlantUml),
+            "docs" => Ok(Templates::Docs),
+            "rust" => Ok(Templates::Rust),
+            "sql" => Ok(Templates::Sql),
+            "python-sqlalchemy" | "sqlalchemy" => Ok(Templates::PythonSqlAlchemy),
+            "fsharp" => Ok(Templates::FSharp),
+            "table-schema" => Ok(Templates::TableSchema),
+            "nim" => Ok(Templates::Nim),
+            "typescript-class" => Ok(Templates::TypescriptClass),
+            "typescript-ajv" => Ok(Templates::TypescriptAjv),
+            "ocaml" => Ok(Templates::OCaml),
+            "haskell" => Ok(Templates::Haskell),
+            "capnp" => Ok(Templates::Capnp),
+            "dart" => Ok(Templates::Dart),
+            "mock-json" => Ok(Templates::MockJson),
+            _ => Err(MdModelsError::UnknownTemplate {
+                name: s.to_string(),
+            }),
         }
     }
 }
@@ -120,61 +299,578 @@ pub fn render_jinja_template(
     template: &Templates,
     model: &mut DataModel,
     config: Option<&HashMap<String, String>>,
+) -> Result<String, MdModelsError> {
+    render_jinja_template_with_types(template, model, config, &TypeMap::default())
+}
+
+/// Renders a Jinja template, mapping types to custom types of the target language.
+///
+/// The types of the given map take precedence over the built-in mappings of
+/// the template, e.g. `float` may be generated as a custom `Money` type.
+///
+/// # Arguments
+///
+/// * `template` - The type of template to render.
+/// * `model` - The data model to use for rendering the template.
+/// * `config` - Optional configuration passed to the template.
+/// * `types` - The custom types, keyed by the MD-Models types they replace.
+///
+/// # Returns
+///
+/// A Result containing the rendered template as a String or an error if rendering fails.
+pub fn render_jinja_template_with_types(
+    template: &Templates,
+    model: &mut DataModel,
+    config: Option<&HashMap<String, String>>,
+    types: &TypeMap,
+) -> Result<String, MdModelsError> {
+    // Skip the definitions that are not generated for the template
+    let mut filtered = model.clone();
+    filter_targets(&mut filtered, template);
+
+    render_unit(template, &mut filtered, config, None, &[], types)
+}
+
+/// Maps MD-Models types to custom types of target languages.
+///
+/// Mappings are given per template, since a custom type of one language
+/// is meaningless in another. Templates without support for custom types
+/// reject the mappings given for them.
+///
+/// # Examples
+///
+/// ```
+/// use mdmodels::exporters::{Templates, TypeMap};
+///
+/// let mut types = TypeMap::new();
+/// types.insert(&Templates::Rust, "float", "Money");
+/// assert_eq!(types.get(&Templates::Rust, "float"), Some("Money"));
+/// assert_eq!(types.get(&Templates::Zod, "float"), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TypeMap {
+    types: HashMap<String, HashMap<String, String>>,
+}
+
+impl TypeMap {
+    /// Creates an empty type map, using the built-in mappings only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps an MD-Models type to a type of the target language of a template.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - The template generating the target language.
+    /// * `dtype` - The MD-Models type, e.g. `float`.
+    /// * `target` - The type of the target language, e.g. `Money`.
+    pub fn insert(&mut self, template: &Templates, dtype: &str, target: &str) -> &mut Self {
+        self.types
+            .entry(template.to_string())
+            .or_default()
+            .insert(dtype.to_string(), target.to_string());
+        self
+    }
+
+    /// Returns the type an MD-Models type is mapped to for a template.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - The template generating the target language.
+    /// * `dtype` - The MD-Models type.
+    pub fn get(&self, template: &Templates, dtype: &str) -> Option<&str> {
+        self.types
+            .get(&template.to_string())
+            .and_then(|types| types.get(dtype))
+            .map(String::as_str)
+    }
+
+    /// Returns the mappings of a template, which are empty if none are given.
+    fn for_template(&self, template: &Templates) -> HashMap<String, String> {
+        self.types
+            .get(&template.to_string())
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+/// Renders a Jinja template into one file per object and enumeration.
+///
+/// Each definition is rendered into its own file, importing the types it
+/// references from their respective files. An index file (`mod.rs` or
+/// `index.ts`) re-exports all definitions.
+///
+/// # Arguments
+///
+/// * `template` - The type of template to render.
+/// * `model` - The data model to use for rendering the template.
+/// * `config` - Optional configuration passed to the template.
+///
+/// # Returns
+///
+/// A Result containing the file names and rendered contents, or an error if
+/// the template does not support per-object output or rendering fails.
+pub fn render_jinja_units(
+    template: &Templates,
+    model: &mut DataModel,
+    config: Option<&HashMap<String, String>>,
+) -> Result<Vec<(String, String)>, MdModelsError> {
+    render_jinja_units_with_types(template, model, config, &TypeMap::default())
+}
+
+/// Renders a Jinja template into one file per definition, mapping types to custom types.
+///
+/// # Arguments
+///
+/// * `template` - The type of template to render.
+/// * `model` - The data model to use for rendering the template.
+/// * `config` - Optional configuration passed to the template.
+/// * `types` - The custom types, keyed by the MD-Models types they replace.
+///
+/// # Returns
+///
+/// A Result containing the file names and rendered contents, or an error if
+/// the template does not support per-object output or rendering fails.
+pub fn render_jinja_units_with_types(
+    template: &Templates,
+    model: &mut DataModel,
+    config: Option<&HashMap<String, String>>,
+    types: &TypeMap,
+) -> Result<Vec<(String, String)>, MdModelsError> {
+    let index_name = match template {
+        Templates::Rust => "mod.rs",
+        Templates::Zod => "index.ts",
+        _ => {
+            return Err(MdModelsError::generation(
+                template,
+                "Template does not support per-object output",
+            ))
+        }
+    };
+
+    // Skip the definitions that are not generated for the template
+    let mut model = model.clone();
+    filter_targets(&mut model, template);
+
+    if !supports_generics(template) {
+        model.monomorphize_generics();
+    }
+
+    let names = model
+        .objects
+        .iter()
+        .map(|o| o.name.clone())
+        .chain(model.enums.iter().map(|e| e.name.clone()))
+        .collect::<Vec<String>>();
+
+    let mut units = vec![];
+    for name in names {
+        let imports = referenced_types(&model, &name);
+        let content = render_unit(template, &mut model, config, Some(&name), &imports, types)?;
+        units.push((unit_file_name(template, &name), content));
+    }
+
+    let index = render_unit(template, &mut model, config, Some(index_name), &[], types)?;
+    units.push((index_name.to_string(), index));
+
+    Ok(units)
+}
+
+/// Checks whether definitions tagged with the given targets are generated for a template.
+///
+/// Targets name templates, e.g. `sql`, and exclude a template if prefixed by
+/// `!`. Definitions without targets, or only excluding other templates, are
+/// generated for all templates. The JSON schemas of all objects and the
+/// bundled JSON schema share the targets of `json-schema`. The ajv validators
+/// check objects against both, so they need the targets of `typescript` and
+/// `json-schema`.
+///
+/// # Arguments
+///
+/// * `targets` - The targets of the definition.
+/// * `template` - The template to generate.
+pub(crate) fn is_targeted(targets: &[String], template: &Templates) -> bool {
+    if let Templates::TypescriptAjv = template {
+        return is_targeted(targets, &Templates::Typescript)
+            && is_targeted(targets, &Templates::JsonSchema);
+    }
+
+    let name = match template {
+        Templates::JsonSchemaAll | Templates::JsonSchemaBundle => Templates::JsonSchema.to_string(),
+        template => template.to_string(),
+    };
+
+    // Aliases of templates, e.g. `sqlalchemy`, name the same target
+    let names = |target: &str| match target.parse::<Templates>() {
+        Ok(template) => template.to_string() == name,
+        Err(_) => target == name,
+    };
+
+    let (excluded, included): (Vec<&String>, Vec<&String>) =
+        targets.iter().partition(|target| target.starts_with('!'));
+
+    if excluded.iter().any(|target| names(&target[1..])) {
+        return false;
+    }
+
+    included.is_empty() || included.iter().any(|target| names(target))
+}
+
+/// Removes the objects and attributes that are not generated for a template.
+///
+/// Attributes referring to a removed object are removed as well, such that
+/// the output contains no dangling references. Markdown templates keep all
+/// definitions, since they reproduce the model itself.
+///
+/// # Arguments
+///
+/// * `model` - The data model to filter.
+/// * `template` - The template to generate.
+pub(crate) fn filter_targets(model: &mut DataModel, template: &Templates) {
+    if matches!(
+        template,
+        Templates::Markdown | Templates::CompactMarkdown | Templates::Internal
+    ) {
+        return;
+    }
+
+    let removed = model
+        .objects
+        .iter()
+        .filter(|o| !is_targeted(&o.targets, template))
+        .map(|o| o.name.clone())
+        .collect::<Vec<String>>();
+
+    model.objects.retain(|o| !removed.contains(&o.name));
+
+    for object in model.objects.iter_mut() {
+        object.attributes.retain(|a| {
+            is_targeted(&a.targets, template) && !a.dtypes.iter().any(|d| removed.contains(d))
+        });
+    }
+}
+
+/// Checks whether a template emits generic objects as parameterized types.
+///
+/// Generic objects are replaced by concrete objects for all other templates.
+pub(crate) fn supports_generics(template: &Templates) -> bool {
+    matches!(template, Templates::Rust | Templates::Typescript)
+}
+
+/// Checks whether a template documents the types as written in the model.
+///
+/// All other templates replace `timestamp` by the type of its JSON representation.
+fn documents_types(template: &Templates) -> bool {
+    matches!(
+        template,
+        Templates::Markdown
+            | Templates::CompactMarkdown
+            | Templates::MkDocs
+            | Templates::Docs
+            | Templates::Internal
+            | Templates::PlantUml
+            | Templates::Dot
+    )
+}
+
+/// Replaces timestamps by integers or date-times, depending on the
+/// `timestamp-format` of the frontmatter.
+///
+/// # Arguments
+///
+/// * `model` - The data model whose timestamps are to be replaced.
+fn resolve_timestamps(model: &mut DataModel) {
+    let dtype = match model.config.as_ref().and_then(|c| c.timestamp_format) {
+        Some(TimestampFormat::String) => "datetime",
+        _ => "integer",
+    };
+
+    let type_map = std::collections::HashMap::from([("timestamp".to_string(), dtype.to_string())]);
+    convert_model_types(model, &type_map);
+}
+
+/// Converts the type arguments of concrete objects to Typescript types.
+///
+/// Concrete objects are typed by the generic types the `typescript` target
+/// exports, e.g. `ResultFloat` by `Result<number>`.
+///
+/// # Arguments
+///
+/// * `model` - The data model containing the concrete objects.
+/// * `instances` - The generic name and type argument of every concrete object.
+fn typescript_instances(
+    model: &DataModel,
+    instances: BTreeMap<String, (String, String)>,
+) -> BTreeMap<String, (String, String)> {
+    let timestamp = match model.config.as_ref().and_then(|c| c.timestamp_format) {
+        Some(TimestampFormat::String) => "string",
+        _ => "number",
+    };
+
+    instances
+        .into_iter()
+        .map(|(concrete, (name, arg))| {
+            let arg = match arg.as_str() {
+                "timestamp" => timestamp.to_string(),
+                _ => TYPESCRIPT_TYPE_MAPS.get(&arg).cloned().unwrap_or(arg),
+            };
+            (concrete, (name, arg))
+        })
+        .collect()
+}
+
+/// Returns the file name of a single definition rendered by a template.
+///
+/// Rust modules are named in snake case, other languages use the type name.
+fn unit_file_name(template: &Templates, name: &str) -> String {
+    match template {
+        Templates::Rust => format!("{}.rs", rust_field(name).trim_start_matches("r#")),
+        _ => format!("{}.{}", name, template.extension()),
+    }
+}
+
+/// Returns the names of all objects and enumerations referenced by an object.
+///
+/// # Arguments
+///
+/// * `model` - The data model containing the object.
+/// * `name` - The name of the object.
+fn referenced_types(model: &DataModel, name: &str) -> Vec<String> {
+    let object = match model.objects.iter().find(|o| o.name == name) {
+        Some(object) => object,
+        None => return vec![],
+    };
+
+    let mut references = object
+        .attributes
+        .iter()
+        .flat_map(|a| a.dtypes.iter())
+        .flat_map(|dtype| match generic_type(dtype) {
+            Some((generic, arg)) => vec![generic, arg],
+            None => vec![dtype.as_str()],
+        })
+        .filter(|dtype| *dtype != name)
+        .filter(|dtype| {
+            model.objects.iter().any(|o| o.name == **dtype)
+                || model.enums.iter().any(|e| e.name == **dtype)
+        })
+        .map(|dtype| dtype.to_string())
+        .collect::<Vec<String>>();
+
+    references.sort();
+    references.dedup();
+    references
+}
+
+/// Returns the file of the Jinja template rendering a target.
+///
+/// Targets with dedicated exporters, such as JSON schema, have no Jinja
+/// template and return `None`.
+fn jinja_template_file(template: &Templates) -> Option<&'static str> {
+    match template {
+        Templates::PythonDataclass => Some("python-dataclass.jinja"),
+        Templates::PythonPydantic => Some("python-pydantic.jinja"),
+        Templates::XmlSchema => Some("xml-schema.jinja"),
+        Templates::Markdown => Some("markdown.jinja"),
+        Templates::CompactMarkdown => Some("markdown-compact.jinja"),
+        Templates::Shacl => Some("shacl.jinja"),
+        Templates::Shex => Some("shex.jinja"),
+        Templates::PythonSdrdm => Some("python-sdrdm.jinja"),
+        Templates::MkDocs => Some("mkdocs.jinja"),
+        Templates::Typescript => Some("typescript.jinja"),
+        Templates::Dot => Some("dot.jinja"),
+        Templates::Elixir => Some("elixir.jinja"),
+        Templates::Zod => Some("zod.jinja"),
+        Templates::PlantUml => Some("plantuml.jinja"),
+        Templates::Docs => Some("docs.jinja"),
+        Templates::Rust => Some("rust.jinja"),
+        Templates::Sql => Some("sql.jinja"),
+        Templates::PythonSqlAlchemy => Some("python-sqlalchemy.jinja"),
+        Templates::FSharp => Some("fsharp.jinja"),
+        Templates::Nim => Some("nim.jinja"),
+        Templates::TypescriptClass => Some("typescript-class.jinja"),
+        Templates::TypescriptAjv => Some("typescript-ajv.jinja"),
+        Templates::OCaml => Some("ocaml.jinja"),
+        Templates::Haskell => Some("haskell.jinja"),
+        Templates::Capnp => Some("capnp.jinja"),
+        Templates::Dart => Some("dart.jinja"),
+        _ => None,
+    }
+}
+
+/// Renders a Jinja template, optionally restricted to a single definition.
+///
+/// # Arguments
+///
+/// * `template` - The type of template to render.
+/// * `model` - The data model to use for rendering the template.
+/// * `config` - Optional configuration passed to the template.
+/// * `unit` - The definition or index file to render, or `None` for all.
+/// * `imports` - The types the rendered definition has to import.
+/// * `types` - The custom types, keyed by the MD-Models types they replace.
+fn render_unit(
+    template: &Templates,
+    model: &mut DataModel,
+    config: Option<&HashMap<String, String>>,
+    unit: Option<&str>,
+    imports: &[String],
+    types: &TypeMap,
+) -> Result<String, MdModelsError> {
+    let file = jinja_template_file(template).ok_or_else(|| MdModelsError::UnknownTemplate {
+        name: template.to_string(),
+    })?;
+
+    render_file(template, file, model, config, unit, imports, types)
+        .map_err(|e| MdModelsError::generation(template, e))
+}
+
+/// Renders the Jinja template file of a target.
+///
+/// # Arguments
+///
+/// * `template` - The type of template to render.
+/// * `file` - The Jinja template file of the target.
+/// * `model` - The data model to use for rendering the template.
+/// * `config` - Optional configuration passed to the template.
+/// * `unit` - The definition or index file to render, or `None` for all.
+/// * `imports` - The types the rendered definition has to import.
+/// * `types` - The custom types, keyed by the MD-Models types they replace.
+fn render_file(
+    template: &Templates,
+    file: &str,
+    model: &mut DataModel,
+    config: Option<&HashMap<String, String>>,
+    unit: Option<&str>,
+    imports: &[String],
+    types: &TypeMap,
 ) -> Result<String, minijinja::Error> {
     // Load the template environment
     let mut env = Environment::new();
     minijinja_embed::load_templates!(&mut env);
 
+    // Check if the template is able to handle external references
+    check_external_types(template, model)?;
+    check_identifier_collisions(template, model)?;
+    check_primary_keys(template, model)?;
+    check_fixed_arrays(template, model)?;
+    check_list_defaults(template, model)?;
+    check_custom_types(template, types)?;
+
+    // Replace generic objects by concrete objects for targets without generics
+    let instances = match supports_generics(template) {
+        true => BTreeMap::new(),
+        false => model.monomorphize_generics(),
+    };
+    let instances = typescript_instances(model, instances);
+
+    // Custom types replace the types before the built-in conversions
+    let custom_types = types.for_template(template);
+    convert_model_types(model, &custom_types);
+
+    // Code and schemas represent timestamps by their JSON type
+    if !documents_types(template) {
+        resolve_timestamps(model);
+    }
+
     // Perform type conversions and filtering based on the template
     match template {
         Templates::XmlSchema => convert_model_types(model, &XSD_TYPE_MAPS),
         Templates::Typescript => convert_model_types(model, &TYPESCRIPT_TYPE_MAPS),
         Templates::Shacl | Templates::Shex => {
             convert_model_types(model, &SHACL_TYPE_MAPS);
-            filter_objects_wo_terms(model);
+            filter_objects_wo_terms(model)?;
+            compact_terms(model);
+        }
+        Templates::PythonDataclass => {
+            convert_model_types(model, &PYTHON_TYPE_MAPS);
+            sort_attributes_by_required(model);
+            compact_terms(model);
+        }
+        Templates::PythonSdrdm => {
+            convert_model_types(model, &PYTHON_TYPE_MAPS);
+            sort_attributes_by_required(model);
         }
-        Templates::PythonDataclass | Templates::PythonSdrdm | Templates::PythonPydantic => {
+        Templates::PythonPydantic => {
             convert_model_types(model, &PYTHON_TYPE_MAPS);
             sort_attributes_by_required(model);
+            sort_objects_by_inlined(model);
+            compact_terms(model);
         }
         _ => {}
     }
 
     // Add custom functions to the Jinja environment
     env.add_function("wrap", wrap_text);
+    env.add_function("wrap_doc", wrap_doc);
+    env.add_function("rust_variant", rust_variant);
+    env.add_function("rust_field", rust_field);
+    env.add_function("rust_casing", rust_casing);
+    env.add_function("nim_field", nim_field);
+    env.add_function("ocaml_ident", ocaml_ident);
+    env.add_function("haskell_field", haskell_field);
+    env.add_function("capnp_field", capnp_field);
+    env.add_function("dart_field", dart_field);
+    env.add_function("split_generic", split_generic);
+    env.add_function("table_name", sql_table_name);
+    env.add_filter("escape_string", escape_string);
+    env.add_filter("rdf_term", rdf_term);
 
-    // Get the appropriate template
-    let template = match template {
-        Templates::PythonDataclass => env.get_template("python-dataclass.jinja")?,
-        Templates::PythonPydantic => env.get_template("python-pydantic.jinja")?,
-        Templates::XmlSchema => env.get_template("xml-schema.jinja")?,
-        Templates::Markdown => env.get_template("markdown.jinja")?,
-        Templates::CompactMarkdown => env.get_template("markdown-compact.jinja")?,
-        Templates::Shacl => env.get_template("shacl.jinja")?,
-        Templates::Shex => env.get_template("shex.jinja")?,
-        Templates::PythonSdrdm => env.get_template("python-sdrdm.jinja")?,
-        Templates::MkDocs => env.get_template("mkdocs.jinja")?,
-        Templates::Typescript => env.get_template("typescript.jinja")?,
-        _ => {
-            panic!(
-                "The template is not available as a Jinja Template and should not be used using the jinja exporter.
-                Instead, use the dedicated exporter in the DataModel struct."
-            )
-        }
+    // Collect the verbatim code injected for this template
+    let target = template.to_string();
+    match unit {
+        Some(unit) => debug!(" [{}] Rendering '{}'", target.green().bold(), unit),
+        None => model
+            .objects
+            .iter()
+            .for_each(|o| debug!(" [{}] Rendering object '{}'", target.green().bold(), o.name)),
+    }
+
+    let injections = model
+        .objects
+        .iter()
+        .filter_map(|o| Some((o.name.clone(), o.inject.get(&target)?.clone())))
+        .collect::<BTreeMap<String, String>>();
+
+    // Derive the relational mapping for ORM templates
+    let relations = match template {
+        Templates::PythonSqlAlchemy => Some(Relations::new(model)),
+        _ => None,
+    };
+
+    // Number the fields and enumerants of Cap'n Proto schemas
+    let ordinals = match template {
+        Templates::Capnp => Some(capnp_ordinals(model, config)?),
+        _ => None,
     };
 
+    // Get the appropriate template
+    let template = env.get_template(file)?;
+
     // Render the template
     let prefixes = get_prefixes(model);
+    let frontmatter = model.config.clone().unwrap_or_default();
     let rendered = template.render(context! {
         objects => model.objects,
         object_names => model.objects.iter().map(|o| o.name.clone()).collect::<Vec<String>>(),
         enums => model.enums,
         enum_names => model.enums.iter().map(|e| e.name.clone()).collect::<Vec<String>>(),
+        open_enum_names => model.enums.iter().filter(|e| e.open).map(|e| e.name.clone()).collect::<Vec<String>>(),
         title => model.name,
         prefixes => prefixes,
-        repo => model.config.as_ref().unwrap().repo.clone(),
-        prefix => model.config.as_ref().unwrap().prefix.clone(),
+        repo => frontmatter.repo,
+        prefix => frontmatter.prefix,
         config => config,
+        unit => unit,
+        imports => imports,
+        id_field => frontmatter.id_field,
+        xml_wrap_arrays => frontmatter.xml_wrap_arrays,
+        relations => relations,
+        ordinals => ordinals,
+        instances => instances,
+        inject => frontmatter.inject.and_then(|mut i| i.remove(&target)),
+        injections => injections,
+        custom_types => custom_types.values().collect::<Vec<&String>>(),
     });
 
     match rendered {
@@ -205,63 +901,923 @@ fn wrap_text(text: &str, width: usize, initial_offset: &str, offset: &str) -> St
     wrap(remove_multiple_spaces(text).as_str(), options).join("\n")
 }
 
+/// Template function that wraps the documentation of an object or attribute.
+///
+/// The summary, if given, becomes the first paragraph of the docstring.
+/// Paragraphs are wrapped separately, whereas the lines of fenced
+/// descriptions are kept as they are.
+///
+/// # Arguments
+///
+/// * `item` - The object or attribute to document.
+/// * `width` - The maximum length of a line.
+/// * `initial_offset` - The offset of the first line.
+/// * `offset` - The offset of all other lines.
+///
+/// # Returns
+///
+/// A string with the wrapped documentation.
+fn wrap_doc(item: minijinja::Value, width: usize, initial_offset: &str, offset: &str) -> String {
+    let field = |name: &str| {
+        item.get_attr(name)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default()
+    };
+
+    let text = [field("summary"), field("docstring")]
+        .into_iter()
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<String>>()
+        .join("\n\n");
+
+    // Paragraphs are wrapped separately and divided by an empty comment line
+    text.split("\n\n")
+        .enumerate()
+        .map(|(index, paragraph)| {
+            let initial_offset = if index == 0 { initial_offset } else { offset };
+
+            // Line breaks within a paragraph stem from fenced descriptions,
+            // whose lists and code have to keep their lines
+            if paragraph.trim().contains('\n') {
+                return paragraph
+                    .trim_matches('\n')
+                    .lines()
+                    .enumerate()
+                    .map(|(line_index, line)| match line_index {
+                        0 => format!("{}{}", initial_offset, line.trim_end()),
+                        _ => format!("{}{}", offset, line.trim_end()),
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n");
+            }
+
+            wrap_text(paragraph, width, initial_offset, offset)
+        })
+        .collect::<Vec<String>>()
+        .join(&format!("\n{}\n", offset.trim_end()))
+}
+
 /// Removes leading and trailing whitespace and multiple spaces from a string.
 fn remove_multiple_spaces(input: &str) -> String {
     input.split_whitespace().collect::<Vec<&str>>().join(" ")
 }
 
-/// Converts the data types in the model according to the provided type map.
-///
-/// # Arguments
-///
-/// * `model` - The data model whose types are to be converted.
-/// * `type_map` - A map of generic type names to specific type names.
-fn convert_model_types(
-    model: &mut DataModel,
-    type_map: &std::collections::HashMap<String, String>,
-) {
-    for object in &mut model.objects {
-        for attribute in &mut object.attributes {
-            attribute.dtypes = attribute
-                .dtypes
-                .iter()
-                .map(|t| type_map.get(t).unwrap_or(t))
-                .map(|t| t.to_string())
-                .collect();
+/// Serde `rename_all` conventions in order of preference.
+const SERDE_CASINGS: [&str; 8] = [
+    "PascalCase",
+    "lowercase",
+    "UPPERCASE",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+/// Reserved keywords of Rust, which have to be used as raw identifiers.
+const RUST_KEYWORDS: [&str; 38] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "abstract", "try",
+];
+
+/// Reserved words of Nim, which have to be quoted as identifiers.
+const NIM_KEYWORDS: [&str; 66] = [
+    "addr",
+    "and",
+    "as",
+    "asm",
+    "bind",
+    "block",
+    "break",
+    "case",
+    "cast",
+    "concept",
+    "const",
+    "continue",
+    "converter",
+    "defer",
+    "discard",
+    "distinct",
+    "div",
+    "do",
+    "elif",
+    "else",
+    "end",
+    "enum",
+    "except",
+    "export",
+    "finally",
+    "for",
+    "from",
+    "func",
+    "if",
+    "import",
+    "in",
+    "include",
+    "interface",
+    "is",
+    "isnot",
+    "iterator",
+    "let",
+    "macro",
+    "method",
+    "mixin",
+    "mod",
+    "nil",
+    "not",
+    "notin",
+    "object",
+    "of",
+    "or",
+    "out",
+    "proc",
+    "ptr",
+    "raise",
+    "ref",
+    "return",
+    "shl",
+    "shr",
+    "static",
+    "template",
+    "try",
+    "tuple",
+    "type",
+    "using",
+    "var",
+    "when",
+    "while",
+    "xor",
+    "yield",
+];
+
+/// Reserved words of OCaml, which are suffixed with an underscore as identifiers.
+const OCAML_KEYWORDS: [&str; 56] = [
+    "and",
+    "as",
+    "assert",
+    "asr",
+    "begin",
+    "class",
+    "constraint",
+    "do",
+    "done",
+    "downto",
+    "else",
+    "end",
+    "exception",
+    "external",
+    "false",
+    "for",
+    "fun",
+    "function",
+    "functor",
+    "if",
+    "in",
+    "include",
+    "inherit",
+    "initializer",
+    "land",
+    "lazy",
+    "let",
+    "lor",
+    "lsl",
+    "lsr",
+    "lxor",
+    "match",
+    "method",
+    "mod",
+    "module",
+    "mutable",
+    "new",
+    "nonrec",
+    "object",
+    "of",
+    "open",
+    "or",
+    "private",
+    "rec",
+    "sig",
+    "struct",
+    "then",
+    "to",
+    "true",
+    "try",
+    "type",
+    "val",
+    "virtual",
+    "when",
+    "while",
+    "with",
+];
+
+/// Reserved words of Dart, which are suffixed with an underscore as identifiers.
+const DART_KEYWORDS: [&str; 33] = [
+    "assert", "break", "case", "catch", "class", "const", "continue", "default", "do", "else",
+    "enum", "extends", "false", "final", "finally", "for", "if", "in", "is", "new", "null",
+    "rethrow", "return", "super", "switch", "this", "throw", "true", "try", "var", "void", "while",
+    "with",
+];
+
+/// Splits a PascalCase identifier into its words.
+fn split_pascal_case(ident: &str) -> Vec<String> {
+    let mut words: Vec<String> = vec![];
+    for c in ident.chars() {
+        match words.last_mut() {
+            Some(word) if !c.is_uppercase() => word.push(c),
+            _ => words.push(c.to_string()),
         }
     }
+
+    words
 }
 
-/// Retrieves the prefixes from the model configuration.
-///
-/// # Arguments
-///
-/// * `model` - The data model from which to retrieve the prefixes.
-///
-/// # Returns
-///
-/// A vector of prefix tuples (prefix, URI).
-fn get_prefixes(model: &mut DataModel) -> Vec<(String, String)> {
-    match &model.config {
-        Some(config) => config.prefixes().unwrap_or(vec![]),
-        None => vec![],
+/// Applies a serde `rename_all` convention to a PascalCase variant name.
+fn apply_serde_casing(variant: &str, casing: &str) -> String {
+    match casing {
+        "lowercase" => variant.to_lowercase(),
+        "UPPERCASE" => variant.to_uppercase(),
+        "camelCase" => {
+            let mut chars = variant.chars();
+            chars
+                .next()
+                .map(|c| c.to_lowercase().chain(chars).collect())
+                .unwrap_or_default()
+        }
+        "snake_case" => split_pascal_case(variant).join("_").to_lowercase(),
+        "SCREAMING_SNAKE_CASE" => split_pascal_case(variant).join("_").to_uppercase(),
+        "kebab-case" => split_pascal_case(variant).join("-").to_lowercase(),
+        "SCREAMING-KEBAB-CASE" => split_pascal_case(variant).join("-").to_uppercase(),
+        _ => variant.to_string(),
     }
 }
 
-/// Filters out objects from the model that do not have any terms.
-///
-/// # Arguments
+/// Converts an enumeration key to a valid Rust variant name.
 ///
-/// * `model` - The data model to filter.
-fn filter_objects_wo_terms(model: &mut DataModel) {
-    model.objects.retain(|o| o.has_any_terms());
+/// The key is split at non-alphanumeric characters and case changes, and
+/// joined in PascalCase, e.g. `IN_PROGRESS` becomes `InProgress`. Digits stay
+/// attached to their word. Names starting with a digit are prefixed.
+pub(crate) fn rust_variant(key: &str) -> String {
+    let words = key
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ");
 
-    if model.objects.is_empty() {
-        panic!("No objects with terms found in the model. Unable to build SHACL or ShEx.");
+    let mut variant = words
+        .with_boundaries(&[Boundary::Space, Boundary::LowerUpper, Boundary::Acronym])
+        .to_case(Case::Pascal);
+
+    if variant.is_empty() || variant.starts_with(|c: char| c.is_ascii_digit()) {
+        variant.insert(0, 'V');
+    }
+
+    match variant.as_str() {
+        "Self" => "Self_".to_string(),
+        _ => variant,
     }
 }
 
-/// Sorts the attributes of each object in the model by their 'required' field.
+/// Converts an attribute name to a valid Rust field name.
+///
+/// The name is converted to snake case and keywords are turned into raw
+/// identifiers.
+pub(crate) fn rust_field(name: &str) -> String {
+    let mut field = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 && !field.ends_with('_') {
+            field.push('_');
+        }
+
+        match c.is_ascii_alphanumeric() {
+            true => field.extend(c.to_lowercase()),
+            false => field.push('_'),
+        }
+    }
+
+    match RUST_KEYWORDS.contains(&field.as_str()) {
+        true => format!("r#{}", field),
+        false => field,
+    }
+}
+
+/// Splits a generic type, given as `Name<Arg>`, into its name and type argument.
+///
+/// # Arguments
+///
+/// * `dtype` - The data type to split.
+fn split_generic(dtype: &str) -> Option<Vec<String>> {
+    generic_type(dtype).map(|(name, arg)| vec![name.to_string(), arg.to_string()])
+}
+
+/// Converts a name to a camelCase Nim field, quoting keywords in backticks.
+///
+/// # Arguments
+///
+/// * `name` - The name of the attribute.
+pub(crate) fn nim_field(name: &str) -> String {
+    let variant = rust_variant(name);
+    let mut chars = variant.trim_end_matches('_').chars();
+    let field = chars
+        .next()
+        .map(|c| c.to_lowercase().chain(chars).collect::<String>())
+        .unwrap_or_default();
+
+    match NIM_KEYWORDS.contains(&field.as_str()) {
+        true => format!("`{}`", field),
+        false => field,
+    }
+}
+
+/// Converts a name to a snake_case OCaml identifier, suffixing keywords with an underscore.
+///
+/// # Arguments
+///
+/// * `name` - The name of the type or attribute.
+pub(crate) fn ocaml_ident(name: &str) -> String {
+    let ident = rust_field(name).trim_start_matches("r#").to_string();
+
+    match OCAML_KEYWORDS.contains(&ident.as_str()) {
+        true => format!("{}_", ident),
+        false => ident,
+    }
+}
+
+/// Converts an attribute name to a Haskell record field prefixed by its type.
+///
+/// Record fields share one namespace per module, hence the field is prefixed
+/// by the camelCase name of its type, e.g. `personName` for `name` of `Person`.
+///
+/// # Arguments
+///
+/// * `object` - The name of the object holding the attribute.
+/// * `name` - The name of the attribute.
+pub(crate) fn haskell_field(object: &str, name: &str) -> String {
+    let variant = rust_variant(object);
+    let mut chars = variant.chars();
+    let prefix = chars
+        .next()
+        .map(|c| c.to_lowercase().chain(chars).collect::<String>())
+        .unwrap_or_default();
+
+    format!("{}{}", prefix, rust_variant(name).trim_end_matches('_'))
+}
+
+/// Converts a name to a camelCase Cap'n Proto field or enumerant.
+///
+/// Cap'n Proto rejects underscores in names and requires fields and
+/// enumerants to start with a lowercase letter.
+///
+/// # Arguments
+///
+/// * `name` - The name to convert.
+pub(crate) fn capnp_field(name: &str) -> String {
+    let variant = rust_variant(name);
+    let mut chars = variant.trim_end_matches('_').chars();
+    chars
+        .next()
+        .map(|c| c.to_lowercase().chain(chars).collect::<String>())
+        .unwrap_or_default()
+}
+
+/// Converts a name to a camelCase Dart field or enum value, suffixing
+/// reserved words with an underscore.
+///
+/// # Arguments
+///
+/// * `name` - The name to convert.
+pub(crate) fn dart_field(name: &str) -> String {
+    let field = capnp_field(name);
+
+    match DART_KEYWORDS.contains(&field.as_str()) {
+        true => format!("{}_", field),
+        false => field,
+    }
+}
+
+/// Derives the unique 64-bit file ID of a Cap'n Proto schema from a seed.
+///
+/// The ID is stable for the same seed, such that a regenerated schema keeps
+/// its ID. Cap'n Proto requires the most significant bit to be set.
+///
+/// # Arguments
+///
+/// * `seed` - Text identifying the data model, e.g. its repository and prefix.
+pub(crate) fn capnp_id(seed: &str) -> String {
+    // FNV-1a is used, since its hashes do not change between Rust versions
+    let hash = seed.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    format!("0x{:016x}", hash | 1 << 63)
+}
+
+/// Numbers the fields and enumerants of a Cap'n Proto schema.
+///
+/// If the `lock` option names a lock file, the ordinals and the file ID
+/// are read from it and written back with the members added since. The
+/// file ID is derived from the repository, prefix and title of the model.
+///
+/// # Arguments
+///
+/// * `model` - The data model to number.
+/// * `config` - The configuration, optionally holding the `lock` path.
+fn capnp_ordinals(
+    model: &DataModel,
+    config: Option<&HashMap<String, String>>,
+) -> Result<Ordinals, minijinja::Error> {
+    let error =
+        |message: String| minijinja::Error::new(minijinja::ErrorKind::InvalidOperation, message);
+    let lock = config.and_then(|c| c.get("lock")).map(Path::new);
+
+    let mut ordinals = match lock {
+        Some(path) => Ordinals::load(path).map_err(error)?,
+        None => Ordinals::default(),
+    };
+
+    let frontmatter = model.config.clone().unwrap_or_default();
+    let seed = format!(
+        "{}{}{}",
+        frontmatter.repo,
+        frontmatter.prefix,
+        model.name.as_deref().unwrap_or_default()
+    );
+    ordinals.assign(model, capnp_id(&seed)).map_err(error)?;
+
+    if let Some(path) = lock {
+        ordinals.save(path).map_err(error)?;
+    }
+
+    Ok(ordinals)
+}
+
+/// Escapes a value for a double-quoted string literal.
+///
+/// Backslashes, double quotes and line breaks are escaped the same way in
+/// Rust, Nim, F#, OCaml and Haskell.
+///
+/// # Arguments
+///
+/// * `value` - The value to escape.
+fn escape_string(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+/// Writes a term for Turtle and ShExC, wrapping full IRIs in angle brackets.
+///
+/// CURIEs are kept as they are, since their prefixes are declared.
+///
+/// # Arguments
+///
+/// * `term` - The compacted term.
+fn rdf_term(term: &str) -> String {
+    match term.contains("://") {
+        true => format!("<{}>", term),
+        false => term.to_string(),
+    }
+}
+
+/// Detects a serde `rename_all` convention matching all values of an enumeration.
+///
+/// # Arguments
+///
+/// * `mappings` - The key-value mappings of the enumeration.
+///
+/// # Returns
+///
+/// The name of the convention if every value equals its variant name converted
+/// by this convention, `None` if the values are arbitrary.
+fn detect_serde_casing(mappings: &[(String, String)]) -> Option<&'static str> {
+    SERDE_CASINGS.into_iter().find(|casing| {
+        mappings
+            .iter()
+            .all(|(key, value)| apply_serde_casing(&rust_variant(key), casing) == *value)
+    })
+}
+
+/// Template function returning the serde `rename_all` convention of an enumeration.
+fn rust_casing(mappings: minijinja::Value) -> Result<Option<String>, minijinja::Error> {
+    let mut pairs = vec![];
+    for key in mappings.try_iter()? {
+        let value = mappings.get_item(&key)?;
+        pairs.push((key.to_string(), value.to_string()));
+    }
+
+    Ok(detect_serde_casing(&pairs).map(|casing| casing.to_string()))
+}
+
+/// Checks whether the model can be rendered with the given template.
+///
+/// Attributes typed by an external schema URL are only supported by
+/// documentation templates, code generators cannot resolve them.
+///
+/// # Arguments
+///
+/// * `template` - The template to render.
+/// * `model` - The data model to render.
+///
+/// # Returns
+///
+/// An error naming the first attribute with an external type, if unsupported.
+fn check_external_types(template: &Templates, model: &DataModel) -> Result<(), minijinja::Error> {
+    if matches!(
+        template,
+        Templates::Markdown
+            | Templates::CompactMarkdown
+            | Templates::MkDocs
+            | Templates::Docs
+            | Templates::Dot
+            | Templates::PlantUml
+    ) {
+        return Ok(());
+    }
+
+    for object in &model.objects {
+        for attribute in &object.attributes {
+            if let Some(dtype) = attribute
+                .dtypes
+                .iter()
+                .find(|dtype| crate::attribute::is_external_type(dtype))
+            {
+                return Err(minijinja::Error::new(
+                    minijinja::ErrorKind::InvalidOperation,
+                    format!(
+                        "Template '{}' does not support external type '{}' of property '{}.{}'",
+                        template, dtype, object.name, attribute.name
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that distinct names stay distinct once converted to identifiers.
+///
+/// Templates of languages with strict naming rules convert enumeration keys
+/// and attribute names, e.g. `IN_PROGRESS` and `in_progress` both become the
+/// Rust variant `InProgress`, which would not compile.
+///
+/// # Arguments
+///
+/// * `template` - The template to render.
+/// * `model` - The data model to render.
+///
+/// # Returns
+///
+/// An error naming the first two names converted to the same identifier.
+fn check_identifier_collisions(
+    template: &Templates,
+    model: &DataModel,
+) -> Result<(), minijinja::Error> {
+    let field: fn(&str) -> String = match template {
+        Templates::Rust => rust_field,
+        Templates::Nim => nim_field,
+        Templates::OCaml => ocaml_ident,
+        Templates::Capnp => capnp_field,
+        Templates::Dart => dart_field,
+        Templates::Haskell | Templates::FSharp => rust_variant,
+        _ => return Ok(()),
+    };
+
+    let keys = model.enums.iter().map(|e| {
+        let keys = e.mappings.keys().map(|k| k.as_str()).collect::<Vec<&str>>();
+        (e.name.as_str(), keys, rust_variant as fn(&str) -> String)
+    });
+    let attributes = model.objects.iter().map(|o| {
+        let names = o.attributes.iter().map(|a| a.name.as_str()).collect();
+        (o.name.as_str(), names, field)
+    });
+
+    // Open Rust enumerations hold unknown values in the variant `Other`
+    if let Templates::Rust = template {
+        for enumeration in model.enums.iter().filter(|e| e.open) {
+            if let Some(key) = enumeration
+                .mappings
+                .keys()
+                .find(|k| rust_variant(k) == "Other")
+            {
+                return Err(minijinja::Error::new(
+                    minijinja::ErrorKind::InvalidOperation,
+                    format!(
+                        "Template '{}' reserves the identifier 'Other' for unknown values of the open enumeration '{}', rename key '{}'",
+                        template, enumeration.name, key
+                    ),
+                ));
+            }
+        }
+    }
+
+    for (owner, names, convert) in keys.chain(attributes) {
+        let mut seen = HashMap::new();
+        for name in names {
+            let ident = convert(name);
+            if let Some(other) = seen.insert(ident.clone(), name) {
+                return Err(minijinja::Error::new(
+                    minijinja::ErrorKind::InvalidOperation,
+                    format!(
+                        "Template '{}' converts '{}' and '{}' of '{}' to the same identifier '{}'",
+                        template, other, name, owner, ident
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks whether every object has a primary key for ORM targets.
+///
+/// SQLAlchemy cannot map a class without a primary key, so objects opting out
+/// of the id field need an `Identifier` attribute or a `PrimaryKey`.
+///
+/// # Arguments
+///
+/// * `template` - The template to render.
+/// * `model` - The data model to render.
+///
+/// # Returns
+///
+/// An error naming the first object without a primary key, if unsupported.
+fn check_primary_keys(template: &Templates, model: &DataModel) -> Result<(), minijinja::Error> {
+    if !matches!(template, Templates::PythonSqlAlchemy) {
+        return Ok(());
+    }
+
+    let id_field = model.config.as_ref().is_none_or(|c| c.id_field);
+    if let Some(object) = model.objects.iter().find(|o| {
+        !o.has_id_field(id_field)
+            && o.primary_key.is_empty()
+            && !o.attributes.iter().any(|a| a.is_id)
+    }) {
+        return Err(minijinja::Error::new(
+            minijinja::ErrorKind::InvalidOperation,
+            format!(
+                "Template '{}' requires a primary key for object '{}', add an Identifier attribute, a PrimaryKey or enable the id field",
+                template, object.name
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks whether tuples and arrays of a fixed size can be generated by the given template.
+///
+/// Templates without tuple or fixed-size array types would emit a list of a
+/// union instead, losing the item types and the number of items.
+///
+/// # Arguments
+///
+/// * `template` - The template to render.
+/// * `model` - The data model to render.
+///
+/// # Returns
+///
+/// An error naming the first tuple or fixed-size array, if unsupported.
+fn check_fixed_arrays(template: &Templates, model: &DataModel) -> Result<(), minijinja::Error> {
+    let supported = matches!(
+        template,
+        Templates::Rust
+            | Templates::Typescript
+            | Templates::TypescriptClass
+            | Templates::TypescriptAjv
+            | Templates::Zod
+            | Templates::PythonDataclass
+            | Templates::PythonPydantic
+            | Templates::JsonSchema
+            | Templates::JsonSchemaAll
+            | Templates::JsonSchemaBundle
+            | Templates::MockJson
+            | Templates::Sql
+            | Templates::Internal
+            | Templates::Markdown
+            | Templates::CompactMarkdown
+            | Templates::MkDocs
+            | Templates::Docs
+            | Templates::Dot
+            | Templates::PlantUml
+    );
+
+    if supported {
+        return Ok(());
+    }
+
+    for object in &model.objects {
+        if let Some(attribute) = object.attributes.iter().find(|a| a.size.is_some()) {
+            return Err(minijinja::Error::new(
+                minijinja::ErrorKind::InvalidOperation,
+                format!(
+                    "Template '{}' does not support the {} of property '{}.{}'",
+                    template,
+                    if attribute.tuple {
+                        "tuple"
+                    } else {
+                        "fixed-size array"
+                    },
+                    object.name,
+                    attribute.name
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks whether the list defaults of arrays can be generated by the given template.
+///
+/// These templates render the defaults of single values, but have no literal
+/// for the default of an array and would drop it. Empty lists are the default
+/// of every array and hence always supported.
+///
+/// # Arguments
+///
+/// * `template` - The template to render.
+/// * `model` - The data model to render.
+///
+/// # Returns
+///
+/// An error naming the first array with a list default, if unsupported.
+fn check_list_defaults(template: &Templates, model: &DataModel) -> Result<(), minijinja::Error> {
+    let unsupported = matches!(
+        template,
+        Templates::XmlSchema
+            | Templates::PythonSdrdm
+            | Templates::PythonSqlAlchemy
+            | Templates::Sql
+            | Templates::Elixir
+    );
+
+    if !unsupported {
+        return Ok(());
+    }
+
+    for object in &model.objects {
+        if let Some(attribute) = object
+            .attributes
+            .iter()
+            .find(|a| matches!(&a.default, Some(DataType::List(items)) if !items.is_empty()))
+        {
+            return Err(minijinja::Error::new(
+                minijinja::ErrorKind::InvalidOperation,
+                format!(
+                    "Template '{}' does not support the list default of property '{}.{}'",
+                    template, object.name, attribute.name
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks whether custom types can be generated by the given template.
+///
+/// Only templates passing unknown types through as written support custom
+/// types, others would fall back to a generic type or prefix the type.
+///
+/// # Arguments
+///
+/// * `template` - The template to render.
+/// * `types` - The custom types to generate.
+///
+/// # Returns
+///
+/// An error if custom types are given for a template without support for them.
+fn check_custom_types(template: &Templates, types: &TypeMap) -> Result<(), minijinja::Error> {
+    let supported = matches!(
+        template,
+        Templates::Rust
+            | Templates::TypescriptClass
+            | Templates::Zod
+            | Templates::PythonDataclass
+            | Templates::PythonPydantic
+            | Templates::Sql
+            | Templates::Nim
+            | Templates::OCaml
+            | Templates::Elixir
+            | Templates::FSharp
+            | Templates::Haskell
+            | Templates::Capnp
+            | Templates::Dart
+    );
+
+    match supported || types.for_template(template).is_empty() {
+        true => Ok(()),
+        false => Err(minijinja::Error::new(
+            minijinja::ErrorKind::InvalidOperation,
+            format!("Template '{}' does not support custom types", template),
+        )),
+    }
+}
+
+/// Converts the data types in the model according to the provided type map.
+///
+/// # Arguments
+///
+/// * `model` - The data model whose types are to be converted.
+/// * `type_map` - A map of generic type names to specific type names.
+fn convert_model_types(
+    model: &mut DataModel,
+    type_map: &std::collections::HashMap<String, String>,
+) {
+    for object in &mut model.objects {
+        for attribute in &mut object.attributes {
+            attribute.dtypes = attribute
+                .dtypes
+                .iter()
+                .map(|t| match generic_type(t) {
+                    Some((name, arg)) => {
+                        format!("{}<{}>", name, type_map.get(arg).map_or(arg, |a| a))
+                    }
+                    None => type_map.get(t).unwrap_or(t).to_string(),
+                })
+                .collect();
+
+            if let Some(key) = attribute.map_key.as_mut() {
+                *key = type_map.get(key.as_str()).unwrap_or(key).to_string();
+            }
+        }
+    }
+}
+
+/// Retrieves the prefixes from the model configuration.
+///
+/// # Arguments
+///
+/// * `model` - The data model from which to retrieve the prefixes.
+///
+/// # Returns
+///
+/// A vector of prefix tuples (prefix, URI).
+fn get_prefixes(model: &mut DataModel) -> Vec<(String, String)> {
+    match &model.config {
+        Some(config) => config.prefixes().unwrap_or(vec![]),
+        None => vec![],
+    }
+}
+
+/// Filters out objects from the model that do not have any terms.
+///
+/// # Arguments
+///
+/// * `model` - The data model to filter.
+///
+/// # Returns
+///
+/// An error if no object of the model has any terms.
+fn filter_objects_wo_terms(model: &mut DataModel) -> Result<(), minijinja::Error> {
+    model.objects.retain(|o| o.has_any_terms());
+
+    if model.objects.is_empty() {
+        return Err(minijinja::Error::new(
+            minijinja::ErrorKind::InvalidOperation,
+            "No objects with terms found in the model. Unable to build SHACL or ShEx.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Compacts the terms of objects and attributes against the prefixes of the model.
+///
+/// Terms may be given as CURIEs or full IRIs. Both are expanded to the full
+/// IRI first, such that a full IRI whose base matches a prefix becomes a CURIE
+/// in the JSON-LD context, while any other IRI remains full.
+///
+/// # Arguments
+///
+/// * `model` - The data model whose terms are compacted.
+fn compact_terms(model: &mut DataModel) {
+    let prefixes = match model.config.as_ref().and_then(|c| c.prefixes.clone()) {
+        Some(prefixes) => prefixes,
+        None => return,
+    };
+
+    let compact = |term: &mut Option<String>| {
+        if let Some(iri) = term.as_deref().and_then(|t| expand_iri(t, &prefixes)) {
+            *term = Some(compact_iri(&iri, &prefixes));
+        }
+    };
+
+    for object in &mut model.objects {
+        compact(&mut object.term);
+        for attribute in &mut object.attributes {
+            compact(&mut attribute.term);
+        }
+    }
+}
+
+/// Sorts the attributes of each object in the model by their 'required' field.
 ///
 /// # Arguments
 ///
@@ -270,142 +1826,906 @@ fn sort_attributes_by_required(model: &mut DataModel) {
     for object in &mut model.objects {
         object.sort_attrs_by_required();
     }
-}
+}
+
+/// Sorts the objects of the model such that inlined objects precede their parents.
+///
+/// Templates that inline objects through inheritance require the base classes
+/// to be defined first. The original order is kept wherever possible.
+///
+/// # Arguments
+///
+/// * `model` - The data model whose objects are to be sorted.
+fn sort_objects_by_inlined(model: &mut DataModel) {
+    let mut remaining = std::mem::take(&mut model.objects);
+
+    while !remaining.is_empty() {
+        let position = remaining
+            .iter()
+            .position(|object| {
+                object
+                    .attributes
+                    .iter()
+                    .filter(|a| a.inline)
+                    .all(|a| !remaining.iter().any(|o| a.dtypes.contains(&o.name)))
+            })
+            .unwrap_or(0);
+
+        model.objects.push(remaining.remove(position));
+    }
+}
+
+fn clean_and_trim(s: &str) -> String {
+    let splitted = s.split('\n').collect::<Vec<&str>>();
+    let mut cleaned = vec![];
+    let mut consec_empty = 0;
+
+    for line in splitted {
+        let trimmed = line.trim_end();
+        if !trimmed.is_empty() {
+            cleaned.push(trimmed);
+            consec_empty = 0;
+        } else {
+            consec_empty += 1;
+            if consec_empty < 3 {
+                cleaned.push(trimmed);
+            }
+        }
+    }
+
+    cleaned.join("\n").trim().to_string()
+    // s.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use std::{fs, path::Path};
+
+    use crate::markdown::parser::parse_markdown;
+
+    use super::*;
+
+    /// Helper function to build and convert a template.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - The template type to use for rendering.
+    ///
+    /// # Returns
+    ///
+    /// A string containing the rendered template.
+    fn build_and_convert(template: Templates) -> String {
+        let path = Path::new("tests/data/model.md");
+        let content = fs::read_to_string(path).expect("Could not read markdown file");
+        let mut model = parse_markdown(&content).expect("Failed to parse markdown file");
+        render_jinja_template(&template, &mut model, None)
+            .expect("Could not render template")
+            .to_string()
+    }
+
+    #[test]
+    fn test_convert_to_shex() {
+        // Arrange
+        let rendered = build_and_convert(Templates::Shex);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_shex.shex")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_shacl() {
+        // Arrange
+        let rendered = build_and_convert(Templates::Shacl);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_shacl.ttl")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_python_dc() {
+        // Arrange
+        let rendered = build_and_convert(Templates::PythonDataclass);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_python_dc.py")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_python_sdrdm() {
+        // Arrange
+        let rendered = build_and_convert(Templates::PythonSdrdm);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_python_sdrdm.py")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_xsd() {
+        // Arrange
+        let rendered = build_and_convert(Templates::XmlSchema);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_xml_schema.xsd")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_mkdocs() {
+        // Arrange
+        let rendered = build_and_convert(Templates::MkDocs);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_mkdocs.md")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_typescript() {
+        // Arrange
+        let rendered = build_and_convert(Templates::Typescript);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_typescript.ts")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_pydantic() {
+        // Arrange
+        let rendered = build_and_convert(Templates::PythonPydantic);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_pydantic.py")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_pydantic_conditional_imports() {
+        // Arrange
+        let content = "### Shape\n\n- __kind__\n  - Type: string\n  - Const: shape\n- label\n  - Type: string\n  - Alias: name\n";
+        let mut model = parse_markdown(content).expect("Failed to parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::PythonPydantic, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(
+            rendered.contains("from pydantic import AliasChoices, BaseModel, Field, ConfigDict\n")
+        );
+        assert!(rendered.contains("from typing import Literal, Optional, Generic, TypeVar\n"));
+    }
+
+    #[test]
+    fn test_convert_to_dot() {
+        // Arrange
+        let rendered = build_and_convert(Templates::Dot);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_dot.dot")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_plantuml() {
+        // Arrange
+        let rendered = build_and_convert(Templates::PlantUml);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_plantuml.puml")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_docs() {
+        // Arrange
+        let rendered = build_and_convert(Templates::Docs);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_docs.md")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_rust() {
+        // Arrange
+        let rendered = build_and_convert(Templates::Rust);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_rust.rs")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_sql() {
+        // Arrange
+        let rendered = build_and_convert(Templates::Sql);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_sql.sql")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_convert_to_sqlalchemy() {
+        // Arrange
+        let rendered = build_and_convert(Templates::PythonSqlAlchemy);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_sqlalchemy.py")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_rust_uniform_casing() {
+        // Arrange
+        let snake = vec![
+            ("IN_PROGRESS".to_string(), "in_progress".to_string()),
+            ("DONE".to_string(), "done".to_string()),
+        ];
+        let screaming = vec![
+            ("IN_PROGRESS".to_string(), "IN_PROGRESS".to_string()),
+            ("DONE".to_string(), "DONE".to_string()),
+        ];
+        let pascal = vec![("InProgress".to_string(), "InProgress".to_string())];
+
+        // Act & Assert
+        assert_eq!(detect_serde_casing(&snake), Some("snake_case"));
+        assert_eq!(
+            detect_serde_casing(&screaming),
+            Some("SCREAMING_SNAKE_CASE")
+        );
+        assert_eq!(detect_serde_casing(&pascal), Some("PascalCase"));
+    }
+
+    #[test]
+    fn test_rust_arbitrary_values() {
+        // Arrange
+        let mappings = vec![
+            (
+                "GO".to_string(),
+                "https://amigo.geneontology.org/".to_string(),
+            ),
+            ("SIO".to_string(), "http://semanticscience.org/".to_string()),
+        ];
+
+        // Act & Assert
+        assert_eq!(detect_serde_casing(&mappings), None);
+    }
+
+    #[test]
+    fn test_rust_identifiers() {
+        assert_eq!(rust_variant("IN_PROGRESS"), "InProgress");
+        assert_eq!(rust_variant("mmol/L"), "MmolL");
+        assert_eq!(rust_variant("1st"), "V1st");
+        assert_eq!(rust_variant("Self"), "Self_");
+        assert_eq!(rust_variant("camelCase"), "CamelCase");
+        assert_eq!(rust_variant("v2beta"), "V2beta");
+        assert_eq!(rust_field("type"), "r#type");
+        assert_eq!(rust_field("firstName"), "first_name");
+    }
 
-fn clean_and_trim(s: &str) -> String {
-    let splitted = s.split('\n').collect::<Vec<&str>>();
-    let mut cleaned = vec![];
-    let mut consec_empty = 0;
+    #[test]
+    fn test_rust_variant_collision() {
+        // Arrange
+        let content = "### Task\n\n- status\n  - Type: Status\n\n## Enumerations\n\n### Status\n\n```\nIN_PROGRESS = in progress\nin_progress = in-progress\n```\n";
+        let model = parse_markdown(content).expect("Failed to parse markdown");
 
-    for line in splitted {
-        let trimmed = line.trim_end();
-        if !trimmed.is_empty() {
-            cleaned.push(trimmed);
-            consec_empty = 0;
-        } else {
-            consec_empty += 1;
-            if consec_empty < 3 {
-                cleaned.push(trimmed);
-            }
+        for template in [Templates::Rust, Templates::OCaml] {
+            // Act
+            let result = render_jinja_template(&template, &mut model.clone(), None);
+
+            // Assert
+            let err = result.expect_err("Colliding variants should be rejected");
+            assert!(err.to_string().contains("'InProgress'"));
         }
     }
 
-    cleaned.join("\n").trim().to_string()
-    // s.to_string()
-}
+    #[test]
+    fn test_rust_open_enum_other_collision() {
+        // Arrange
+        let content = "### Task\n\n- status\n  - Type: Status\n\n## Enumerations\n\n### Status\n\nOpen: true\n\n```\nDONE = done\nOTHER = other\n```\n";
+        let mut model = parse_markdown(content).expect("Failed to parse markdown");
 
-#[cfg(test)]
-mod tests {
-    use pretty_assertions::assert_eq;
-    use std::{fs, path::Path};
+        // Act
+        let result = render_jinja_template(&Templates::Rust, &mut model, None);
 
-    use crate::markdown::parser::parse_markdown;
+        // Assert
+        let err = result.expect_err("A key named Other should be rejected");
+        assert!(err.to_string().contains("rename key 'OTHER'"));
+    }
 
-    use super::*;
+    #[test]
+    fn test_rust_escaped_rename() {
+        // Arrange
+        let content = "### Task\n\n- unit\n  - Type: Unit\n\n## Enumerations\n\n### Unit\n\n```\nBACKSLASH = a\\b\n```\n";
+        let mut model = parse_markdown(content).expect("Failed to parse markdown");
 
-    /// Helper function to build and convert a template.
-    ///
-    /// # Arguments
-    ///
-    /// * `template` - The template type to use for rendering.
-    ///
-    /// # Returns
-    ///
-    /// A string containing the rendered template.
-    fn build_and_convert(template: Templates) -> String {
-        let path = Path::new("tests/data/model.md");
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains(r#"#[serde(rename = "a\\b")]"#));
+        assert_eq!(escape_string("in\"\n"), r#"in\"\n"#);
+    }
+
+    #[test]
+    fn test_rust_integer_widths() {
+        // Arrange
+        let content = "### Counter\n\n- __small__\n  - Type: int32\n- __large__\n  - Type: int64\n- __count__\n  - Type: uint32\n- __total__\n  - Type: uint64\n";
+        let mut model = parse_markdown(content).expect("Failed to parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("pub small: i32,"));
+        assert!(rendered.contains("pub large: i64,"));
+        assert!(rendered.contains("pub count: u32,"));
+        assert!(rendered.contains("pub total: u64,"));
+    }
+
+    #[test]
+    fn test_rust_bytes() {
+        // Arrange
+        let content =
+            "### Spectrum\n\n- __data__\n  - Type: bytes\n- thumbnails\n  - Type: bytes[]\n";
+        let mut model = parse_markdown(content).expect("Failed to parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("pub data: Vec<u8>,"));
+        assert!(rendered.contains("pub thumbnails: Vec<Vec<u8>>,"));
+    }
+
+    #[test]
+    fn test_compact_terms() {
+        // Arrange
+        let content = "---\nprefixes:\n  schema: http://schema.org/\n---\n\n### Person\n\n- name\n  - Type: string\n  - Term: http://schema.org/name\n- email\n  - Type: string\n  - Term: schema:email\n- nick\n  - Type: string\n  - Term: http://xmlns.com/foaf/0.1/nick\n";
+        let mut model = parse_markdown(content).expect("Failed to parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::PythonDataclass, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("\"name\": \"schema:name\","));
+        assert!(rendered.contains("\"email\": \"schema:email\","));
+        assert!(rendered.contains("\"nick\": \"http://xmlns.com/foaf/0.1/nick\","));
+        assert_eq!(model.attributes_by_term("http://schema.org/email").len(), 1);
+    }
+
+    #[test]
+    fn test_any_type_rdf() {
+        // Arrange
+        let content = "### Event\n\n- payload\n  - Type: any\n  - Term: schema:object\n- name\n  - Type: string\n  - Term: schema:name\n";
+        let model = parse_markdown(content).expect("Failed to parse markdown");
+
+        // Act
+        let shacl = render_jinja_template(&Templates::Shacl, &mut model.clone(), None)
+            .expect("Could not render template");
+        let shex = render_jinja_template(&Templates::Shex, &mut model.clone(), None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(!shacl.contains("xsd:any"));
+        assert!(shacl.contains("sh:path schema:object ;\n        sh:minCount 0 ;"));
+        assert!(shacl.contains("sh:datatype xsd:string ;"));
+        assert!(!shex.contains("xsd:any"));
+        assert!(shex.contains("    schema:object .?"));
+    }
+
+    #[test]
+    fn test_rdf_without_terms() {
+        // Arrange
+        let content = "### Event\n\n- name\n  - Type: string\n";
+        let mut model = parse_markdown(content).expect("Failed to parse markdown");
+
+        // Act
+        let shacl = render_jinja_template(&Templates::Shacl, &mut model, None);
+
+        // Assert
+        assert!(matches!(shacl, Err(MdModelsError::Generation { .. })));
+    }
+
+    #[test]
+    fn test_render_non_jinja_template() {
+        // Arrange
+        let content = "### Event\n\n- name\n  - Type: string\n";
+        let mut model = parse_markdown(content).expect("Failed to parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::JsonSchema, &mut model, None);
+
+        // Assert
+        assert!(matches!(
+            rendered,
+            Err(MdModelsError::UnknownTemplate { name }) if name == "json-schema"
+        ));
+    }
+
+    #[test]
+    fn test_compact_terms_rdf() {
+        // Arrange
+        let content = "---\nprefixes:\n  schema: http://schema.org/\n---\n\n### Person\n\n- name\n  - Type: string\n  - Term: http://schema.org/name\n- nick\n  - Type: string\n  - Term: http://xmlns.com/foaf/0.1/nick\n";
+        let model = parse_markdown(content).expect("Failed to parse markdown");
+
+        // Act
+        let shacl = render_jinja_template(&Templates::Shacl, &mut model.clone(), None)
+            .expect("Could not render template");
+        let shex = render_jinja_template(&Templates::Shex, &mut model.clone(), None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(shacl.contains("sh:path schema:name ;"));
+        assert!(shacl.contains("sh:path <http://xmlns.com/foaf/0.1/nick> ;"));
+        assert!(shex.contains("    schema:name xsd:string"));
+        assert!(shex.contains("    <http://xmlns.com/foaf/0.1/nick> xsd:string"));
+    }
+
+    #[test]
+    fn test_rust_duration() {
+        // Arrange
+        let path = Path::new("tests/data/model_temporal.md");
         let content = fs::read_to_string(path).expect("Could not read markdown file");
         let mut model = parse_markdown(&content).expect("Failed to parse markdown file");
-        render_jinja_template(&template, &mut model, None)
-            .expect("Could not render template")
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("pub started: i64,"));
+        assert!(rendered
+            .contains("    #[serde(with = \"duration\")]\n    pub runtime: std::time::Duration,"));
+        assert!(rendered.contains(
+            "with = \"duration::option\")]\n    pub timeout: Option<std::time::Duration>,"
+        ));
+        assert!(rendered
+            .contains("with = \"duration::vec\")]\n    pub intervals: Vec<std::time::Duration>,"));
+        assert!(rendered.contains("mod duration {"));
+    }
+
+    #[test]
+    fn test_rust_bytes_base64() {
+        // Arrange
+        let content = "### Blob\n\n- __data__\n  - Type: bytes\n- checksum\n  - Type: bytes\n- chunks\n  - Type: bytes[]\n";
+        let mut model = parse_markdown(content).expect("Failed to parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("    #[serde(with = \"base64_bytes\")]\n    pub data: Vec<u8>,"));
+        assert!(rendered
+            .contains("with = \"base64_bytes::option\")]\n    pub checksum: Option<Vec<u8>>,"));
+        assert!(rendered.contains("with = \"base64_bytes::vec\")]\n    pub chunks: Vec<Vec<u8>>,"));
+        assert!(rendered.contains("mod base64_bytes {"));
+        assert!(!rendered.contains("mod duration {"));
+    }
+
+    #[test]
+    fn test_custom_type_map() {
+        // Arrange
+        let content =
+            "### Invoice\n\n- __amount__\n  - Type: float\n- __count__\n  - Type: integer\n";
+        let mut types = TypeMap::new();
+        types
+            .insert(&Templates::Rust, "float", "Money")
+            .insert(&Templates::TypescriptClass, "float", "Money")
+            .insert(&Templates::Typescript, "float", "Money");
+
+        // Act
+        let rust = render_jinja_template_with_types(
+            &Templates::Rust,
+            &mut parse_markdown(content).expect("Failed to parse markdown"),
+            None,
+            &types,
+        )
+        .expect("Could not render template");
+        let typescript = render_jinja_template_with_types(
+            &Templates::TypescriptClass,
+            &mut parse_markdown(content).expect("Failed to parse markdown"),
+            None,
+            &types,
+        )
+        .expect("Could not render template");
+
+        // Assert
+        assert!(rust.contains("pub amount: Money,"));
+        assert!(rust.contains("pub count: i64,"));
+        assert!(typescript.contains("amount: Money;"));
+        assert!(typescript.contains("count: number;"));
+    }
+
+    #[test]
+    fn test_custom_type_map_scoped() {
+        // Arrange
+        let content = "### Invoice\n\n- __amount__\n  - Type: float\n";
+        let mut types = TypeMap::new();
+        types.insert(&Templates::Rust, "float", "Money").insert(
+            &Templates::Typescript,
+            "float",
+            "Money",
+        );
+
+        // Act
+        let zod = render_jinja_template_with_types(
+            &Templates::Zod,
+            &mut parse_markdown(content).expect("Failed to parse markdown"),
+            None,
+            &types,
+        )
+        .expect("Could not render template");
+        let typescript = render_jinja_template_with_types(
+            &Templates::Typescript,
+            &mut parse_markdown(content).expect("Failed to parse markdown"),
+            None,
+            &types,
+        );
+        let units = render_jinja_units_with_types(
+            &Templates::Rust,
+            &mut parse_markdown(content).expect("Failed to parse markdown"),
+            None,
+            &types,
+        )
+        .expect("Could not render units");
+
+        // Assert
+        assert!(zod.contains("amount: z.number(),"));
+        assert!(typescript
+            .expect_err("Typescript does not support custom types")
             .to_string()
+            .contains("does not support custom types"));
+        assert!(units[0].1.contains("pub amount: Money,"));
     }
 
     #[test]
-    fn test_convert_to_shex() {
+    fn test_convert_to_elixir() {
         // Arrange
-        let rendered = build_and_convert(Templates::Shex);
+        let rendered = build_and_convert(Templates::Elixir);
 
         // Assert
-        let expected = fs::read_to_string("tests/data/expected_shex.shex")
+        let expected = fs::read_to_string("tests/data/expected_elixir.ex")
             .expect("Could not read expected file");
         assert_eq!(rendered, expected);
     }
 
     #[test]
-    fn test_convert_to_shacl() {
+    fn test_convert_to_elixir_ecto() {
         // Arrange
-        let rendered = build_and_convert(Templates::Shacl);
+        let path = Path::new("tests/data/model.md");
+        let content = fs::read_to_string(path).expect("Could not read markdown file");
+        let mut model = parse_markdown(&content).expect("Failed to parse markdown file");
+        let config = HashMap::from([
+            ("ecto".to_string(), "true".to_string()),
+            ("module".to_string(), "MyApp".to_string()),
+        ]);
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Elixir, &mut model, Some(&config))
+            .expect("Could not render template");
 
         // Assert
-        let expected = fs::read_to_string("tests/data/expected_shacl.ttl")
+        let expected = fs::read_to_string("tests/data/expected_elixir_ecto.ex")
             .expect("Could not read expected file");
         assert_eq!(rendered, expected);
     }
 
     #[test]
-    fn test_convert_to_python_dc() {
+    fn test_convert_to_fsharp() {
         // Arrange
-        let rendered = build_and_convert(Templates::PythonDataclass);
+        let rendered = build_and_convert(Templates::FSharp);
 
         // Assert
-        let expected = fs::read_to_string("tests/data/expected_python_dc.py")
+        let expected = fs::read_to_string("tests/data/expected_fsharp.fs")
             .expect("Could not read expected file");
         assert_eq!(rendered, expected);
     }
 
     #[test]
-    fn test_convert_to_python_sdrdm() {
+    fn test_convert_to_fsharp_serialization() {
         // Arrange
-        let rendered = build_and_convert(Templates::PythonSdrdm);
+        let path = Path::new("tests/data/model.md");
+        let content = fs::read_to_string(path).expect("Could not read markdown file");
+        let mut model = parse_markdown(&content).expect("Failed to parse markdown file");
+        let config = HashMap::from([
+            ("cli_mutable".to_string(), "true".to_string()),
+            ("json_names".to_string(), "true".to_string()),
+            ("module".to_string(), "MyApp.Model".to_string()),
+        ]);
+
+        // Act
+        let rendered = render_jinja_template(&Templates::FSharp, &mut model, Some(&config))
+            .expect("Could not render template");
 
         // Assert
-        let expected = fs::read_to_string("tests/data/expected_python_sdrdm.py")
+        let expected = fs::read_to_string("tests/data/expected_fsharp_serialization.fs")
             .expect("Could not read expected file");
         assert_eq!(rendered, expected);
     }
 
     #[test]
-    fn test_convert_to_xsd() {
+    fn test_convert_to_nim() {
         // Arrange
-        let rendered = build_and_convert(Templates::XmlSchema);
+        let path = Path::new("tests/data/model.md");
+        let content = fs::read_to_string(path).expect("Could not read markdown file");
+        let mut model = parse_markdown(&content).expect("Failed to parse markdown file");
+        let config = HashMap::from([("json_names".to_string(), "true".to_string())]);
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Nim, &mut model, Some(&config))
+            .expect("Could not render template");
 
         // Assert
-        let expected = fs::read_to_string("tests/data/expected_xml_schema.xsd")
+        let expected = fs::read_to_string("tests/data/expected_nim.nim")
             .expect("Could not read expected file");
         assert_eq!(rendered, expected);
     }
 
     #[test]
-    fn test_convert_to_mkdocs() {
+    fn test_convert_to_ocaml() {
         // Arrange
-        let rendered = build_and_convert(Templates::MkDocs);
+        let path = Path::new("tests/data/model.md");
+        let content = fs::read_to_string(path).expect("Could not read markdown file");
+        let mut model = parse_markdown(&content).expect("Failed to parse markdown file");
+        let config = HashMap::from([("yojson".to_string(), "true".to_string())]);
+
+        // Act
+        let rendered = render_jinja_template(&Templates::OCaml, &mut model, Some(&config))
+            .expect("Could not render template");
 
         // Assert
-        let expected = fs::read_to_string("tests/data/expected_mkdocs.md")
+        let expected = fs::read_to_string("tests/data/expected_ocaml.ml")
             .expect("Could not read expected file");
         assert_eq!(rendered, expected);
     }
 
     #[test]
-    fn test_convert_to_typescript() {
+    fn test_ocaml_ident() {
+        assert_eq!(ocaml_ident("name"), "name");
+        assert_eq!(ocaml_ident("TestObject"), "test_object");
+        assert_eq!(ocaml_ident("type"), "type_");
+        assert_eq!(ocaml_ident("Object"), "object_");
+    }
+
+    #[test]
+    fn test_convert_to_haskell() {
         // Arrange
-        let rendered = build_and_convert(Templates::Typescript);
+        let path = Path::new("tests/data/model.md");
+        let content = fs::read_to_string(path).expect("Could not read markdown file");
+        let mut model = parse_markdown(&content).expect("Failed to parse markdown file");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Haskell, &mut model, None)
+            .expect("Could not render template");
 
         // Assert
-        let expected = fs::read_to_string("tests/data/expected_typescript.ts")
+        let expected = fs::read_to_string("tests/data/expected_haskell.hs")
             .expect("Could not read expected file");
         assert_eq!(rendered, expected);
     }
 
     #[test]
-    fn test_convert_to_pydantic() {
+    fn test_haskell_optional_collections_and_backslashes() {
         // Arrange
-        let rendered = build_and_convert(Templates::PythonPydantic);
+        let content = "### Sample\n\n- __tags__\n  - Type: string[]\n- notes\n  - Type: string[]\n- counts\n  - Type: map<string, integer>\n- unit\n  - Type: Unit\n\n## Enumerations\n\n### Unit\n\n```\nPATH = a\\b\n```\n";
+        let mut model = parse_markdown(content).expect("Failed to parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Haskell, &mut model, None)
+            .expect("Could not render template");
 
         // Assert
-        let expected = fs::read_to_string("tests/data/expected_pydantic.py")
+        assert!(rendered.contains("{ sampleTags :: [Text]"));
+        assert!(rendered.contains(", sampleNotes :: Maybe [Text]"));
+        assert!(rendered.contains(", sampleCounts :: Maybe (Map Text Int)"));
+        assert!(rendered.contains(r#"toJSON UnitPath = String "a\\b""#));
+        assert!(rendered.contains(r#""a\\b" -> pure UnitPath"#));
+    }
+
+    #[test]
+    fn test_convert_to_capnp() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let content = fs::read_to_string(path).expect("Could not read markdown file");
+        let mut model = parse_markdown(&content).expect("Failed to parse markdown file");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Capnp, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_capnp.capnp")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_capnp_stable_ordinals() {
+        // Arrange
+        let lock = std::env::temp_dir().join("md-models-capnp.lock");
+        let _ = fs::remove_file(&lock);
+        let config = HashMap::from([("lock".to_string(), lock.display().to_string())]);
+        let before = "### Sample\n\n- name\n  - Type: string\n- value\n  - Type: float\n\n## Enumerations\n\n### State\n\n```\nSOLID = solid\nLIQUID = liquid\n```\n";
+        let after = "### Sample\n\n- name\n  - Type: string\n- unit\n  - Type: string\n- value\n  - Type: float\n\n### Extra\n\n- note\n  - Type: string\n\n## Enumerations\n\n### State\n\n```\nAAA = aaa\nSOLID = solid\nLIQUID = liquid\n```\n";
+        let mut before = parse_markdown(before).expect("Failed to parse markdown");
+        let mut after = parse_markdown(after).expect("Failed to parse markdown");
+
+        // Act
+        let first = render_jinja_template(&Templates::Capnp, &mut before, Some(&config))
+            .expect("Could not render template");
+        let second = render_jinja_template(&Templates::Capnp, &mut after, Some(&config))
+            .expect("Could not render template");
+        let _ = fs::remove_file(&lock);
+
+        // Assert
+        for line in first
+            .lines()
+            .filter(|l| l.contains(" @") || l.starts_with('@'))
+        {
+            assert!(second.contains(line), "Ordinal changed: {}", line);
+        }
+        assert!(second.contains("  name @0 :Text;\n  unit @2 :Text;\n  value @1 :Float64;"));
+        assert!(second.contains("  aaa @2;\n  liquid @0;\n  solid @1;"));
+    }
+
+    #[test]
+    fn test_capnp_removed_member() {
+        // Arrange
+        let lock = std::env::temp_dir().join("md-models-capnp-removed.lock");
+        let _ = fs::remove_file(&lock);
+        let config = HashMap::from([("lock".to_string(), lock.display().to_string())]);
+        let mut before =
+            parse_markdown("### Sample\n\n- name\n  - Type: string\n- value\n  - Type: float\n")
+                .expect("Failed to parse markdown");
+        let mut after = parse_markdown("### Sample\n\n- name\n  - Type: string\n")
+            .expect("Failed to parse markdown");
+
+        // Act
+        render_jinja_template(&Templates::Capnp, &mut before, Some(&config))
+            .expect("Could not render template");
+        let result = render_jinja_template(&Templates::Capnp, &mut after, Some(&config));
+        let _ = fs::remove_file(&lock);
+
+        // Assert
+        let err = result.expect_err("Removing a locked field should fail");
+        assert!(err
+            .to_string()
+            .contains("Member 'Sample.value' has the ordinal @1"));
+    }
+
+    #[test]
+    fn test_capnp_id() {
+        let id = capnp_id("http://mdmodel.net/md");
+        assert_eq!(id, capnp_id("http://mdmodel.net/md"));
+        assert_ne!(id, capnp_id("http://mdmodel.net/tst"));
+        assert_eq!(id.len(), 18);
+        assert!(u64::from_str_radix(&id[2..], 16).unwrap() >= 1 << 63);
+    }
+
+    #[test]
+    fn test_convert_to_typescript_ajv() {
+        // Arrange
+        let rendered = build_and_convert(Templates::TypescriptAjv);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_typescript_ajv.ts")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_typescript_ajv_validators() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let content = fs::read_to_string(path).expect("Could not read markdown file");
+        let mut model = parse_markdown(&content).expect("Failed to parse markdown file");
+        let names: Vec<String> = model.objects.iter().map(|o| o.name.clone()).collect();
+
+        // Act
+        let rendered = render_jinja_template(&Templates::TypescriptAjv, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        for name in names {
+            assert!(rendered.contains(&format!(
+                "export const {name}Validator: ValidateFunction<{name}> = compile<{name}>('{name}');"
+            )));
+            assert!(rendered.contains(&format!(
+                "export function validate{name}(data: unknown): data is {name} {{\n  return {name}Validator(data);"
+            )));
+        }
+    }
+
+    #[test]
+    fn test_convert_to_dart() {
+        // Arrange
+        let rendered = build_and_convert(Templates::Dart);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_dart.dart")
+            .expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_dart_field() {
+        assert_eq!(dart_field("creation_date"), "creationDate");
+        assert_eq!(dart_field("Name"), "name");
+        assert_eq!(dart_field("class"), "class_");
+        assert_eq!(dart_field("NEW"), "new_");
+    }
+
+    #[test]
+    fn test_capnp_field() {
+        assert_eq!(capnp_field("creation_date"), "creationDate");
+        assert_eq!(capnp_field("Name"), "name");
+        assert_eq!(capnp_field("SIO"), "sio");
+    }
+
+    #[test]
+    fn test_haskell_field() {
+        assert_eq!(haskell_field("Test", "name"), "testName");
+        assert_eq!(
+            haskell_field("TestObject", "creation_date"),
+            "testObjectCreationDate"
+        );
+        assert_eq!(haskell_field("Person", "type"), "personType");
+    }
+
+    #[test]
+    fn test_nim_field() {
+        assert_eq!(nim_field("name"), "name");
+        assert_eq!(nim_field("creation_date"), "creationDate");
+        assert_eq!(nim_field("type"), "`type`");
+        assert_eq!(nim_field("self"), "self");
+    }
+
+    #[test]
+    fn test_convert_to_typescript_class() {
+        // Arrange
+        let rendered = build_and_convert(Templates::TypescriptClass);
+
+        // Assert
+        let expected = fs::read_to_string("tests/data/expected_typescript_class.ts")
             .expect("Could not read expected file");
         assert_eq!(rendered, expected);
     }
+
+    #[test]
+    fn test_convert_to_zod() {
+        // Arrange
+        let rendered = build_and_convert(Templates::Zod);
+
+        // Assert
+        let expected =
+            fs::read_to_string("tests/data/expected_zod.ts").expect("Could not read expected file");
+        assert_eq!(rendered, expected);
+    }
 }
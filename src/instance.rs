@@ -0,0 +1,281 @@
+use std::fmt::Display;
+
+use serde_json::Value;
+
+use crate::{
+    attribute::Attribute,
+    datamodel::DataModel,
+    markdown::frontmatter::TimestampFormat,
+    mock::constant_value,
+    object::Object,
+    primitives::{integer_range, is_integer},
+};
+
+/// A violation of a data model by an instance document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstanceError {
+    /// JSON pointer to the offending location, e.g. `/samples/0/name`.
+    pub pointer: String,
+    /// Message describing the violation.
+    pub message: String,
+}
+
+impl Display for InstanceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.pointer.is_empty() {
+            true => write!(f, "/: {}", self.message),
+            false => write!(f, "{}: {}", self.pointer, self.message),
+        }
+    }
+}
+
+/// Validates instance documents against the objects of a data model.
+struct InstanceValidator<'a> {
+    model: &'a DataModel,
+    errors: Vec<InstanceError>,
+}
+
+/// Validates an instance document against an object of a data model.
+///
+/// Required properties, data types, enumeration values, arrays, maps and
+/// constants are checked. Instances of abstract objects have to conform to
+/// one of their concrete subtypes. Properties that are `null` count as
+/// missing, and closed objects reject properties they do not define.
+///
+/// # Arguments
+///
+/// * `name` - The name of the object the document is an instance of.
+/// * `model` - The data model containing the objects and enums.
+/// * `value` - The instance document.
+///
+/// # Returns
+///
+/// All violations, each located by a JSON pointer into the document.
+pub fn validate_instance(
+    name: &str,
+    model: &DataModel,
+    value: &Value,
+) -> Result<(), Vec<InstanceError>> {
+    let mut validator = InstanceValidator {
+        model,
+        errors: vec![],
+    };
+
+    match model.object(name) {
+        Some(object) => validator.object(object, value, ""),
+        None => validator.error("", format!("Object '{}' not found in the model", name)),
+    }
+
+    match validator.errors.is_empty() {
+        true => Ok(()),
+        false => Err(validator.errors),
+    }
+}
+
+impl InstanceValidator<'_> {
+    /// Records a violation at the given location.
+    fn error(&mut self, pointer: &str, message: impl Into<String>) {
+        self.errors.push(InstanceError {
+            pointer: pointer.to_string(),
+            message: message.into(),
+        });
+    }
+
+    /// Returns the JSON representation of timestamps set by the frontmatter.
+    fn timestamps(&self) -> TimestampFormat {
+        self.model
+            .config
+            .as_ref()
+            .and_then(|c| c.timestamp_format)
+            .unwrap_or_default()
+    }
+
+    /// Validates an instance of an object.
+    fn object(&mut self, object: &Object, value: &Value, pointer: &str) {
+        if object.is_abstract {
+            return self.abstract_object(object, value, pointer);
+        }
+
+        let Some(properties) = value.as_object() else {
+            return self.error(
+                pointer,
+                format!("Expected an object of type {}", object.name),
+            );
+        };
+
+        for attr in &object.attributes {
+            let location = format!("{}/{}", pointer, escape(&attr.name));
+            match properties.get(&attr.name) {
+                None | Some(Value::Null) if attr.required => {
+                    self.error(&location, "Missing required property")
+                }
+                None | Some(Value::Null) => {}
+                Some(value) => self.attribute(attr, value, &location),
+            }
+        }
+
+        let closed_objects = self.model.config.as_ref().and_then(|c| c.closed_objects);
+        if object.closed.or(closed_objects).unwrap_or(false) {
+            for key in properties.keys() {
+                if object.attributes.iter().all(|a| a.name != *key) {
+                    let location = format!("{}/{}", pointer, escape(key));
+                    self.error(&location, "Unexpected property");
+                }
+            }
+        }
+    }
+
+    /// Validates an instance of an abstract object against its concrete subtypes.
+    fn abstract_object(&mut self, object: &Object, value: &Value, pointer: &str) {
+        let conforms = self
+            .model
+            .concrete_subtypes(&object.name)
+            .iter()
+            .any(|subtype| {
+                let mut validator = InstanceValidator {
+                    model: self.model,
+                    errors: vec![],
+                };
+                validator.object(subtype, value, pointer);
+                validator.errors.is_empty()
+            });
+
+        if !conforms {
+            self.error(
+                pointer,
+                format!("Expected an instance of a subtype of {}", object.name),
+            );
+        }
+    }
+
+    /// Validates the value of an attribute, which may be an array or a map.
+    fn attribute(&mut self, attr: &Attribute, value: &Value, pointer: &str) {
+        if let Some(constant) = &attr.constant {
+            if *value != constant_value(constant) {
+                self.error(
+                    pointer,
+                    format!("Expected the constant {}", constant_value(constant)),
+                );
+            }
+            return;
+        }
+
+        if attr.map_key.is_some() {
+            let Some(entries) = value.as_object() else {
+                return self.error(pointer, "Expected a map");
+            };
+
+            for (key, value) in entries {
+                self.items(attr, value, &format!("{}/{}", pointer, escape(key)));
+            }
+        } else {
+            self.items(attr, value, pointer);
+        }
+    }
+
+    /// Validates a single value or, if the attribute is an array, its items.
+    ///
+    /// The items of a tuple are validated against the data type at their position.
+    fn items(&mut self, attr: &Attribute, value: &Value, pointer: &str) {
+        if !attr.is_array {
+            return self.value(attr, value, pointer);
+        }
+
+        let Some(items) = value.as_array() else {
+            return self.error(pointer, "Expected an array");
+        };
+
+        if let Some(size) = attr.size.filter(|size| *size != items.len()) {
+            self.error(pointer, format!("Expected an array of {} items", size));
+        }
+
+        for (index, item) in items.iter().enumerate() {
+            let location = format!("{}/{}", pointer, index);
+            match (attr.tuple, attr.dtypes.get(index)) {
+                (true, Some(dtype)) => self.dtype(dtype, item, &location),
+                (true, None) => {}
+                (false, _) => self.value(attr, item, &location),
+            }
+        }
+    }
+
+    /// Validates a single value against the data types of an attribute.
+    fn value(&mut self, attr: &Attribute, value: &Value, pointer: &str) {
+        if let [dtype] = attr.dtypes.as_slice() {
+            return self.dtype(dtype, value, pointer);
+        }
+
+        // A value of a union type has to conform to one of the types
+        let conforms = attr.dtypes.iter().any(|dtype| {
+            let mut validator = InstanceValidator {
+                model: self.model,
+                errors: vec![],
+            };
+            validator.dtype(dtype, value, pointer);
+            validator.errors.is_empty()
+        });
+
+        if !conforms {
+            self.error(
+                pointer,
+                format!("Expected one of the types {}", attr.dtypes.join(", ")),
+            );
+        }
+    }
+
+    /// Validates a single value against a data type.
+    fn dtype(&mut self, dtype: &str, value: &Value, pointer: &str) {
+        if let Some(object) = self.model.object(dtype) {
+            return self.object(object, value, pointer);
+        }
+
+        if let Some(enumeration) = self.model.enumeration(dtype) {
+            let valid = match value.as_str() {
+                Some(value) => {
+                    enumeration.open || enumeration.mappings.values().any(|v| v == value)
+                }
+                None => false,
+            };
+
+            if !valid {
+                self.error(
+                    pointer,
+                    format!("Expected a value of the enumeration {}", enumeration.name),
+                );
+            }
+            return;
+        }
+
+        let valid = match dtype {
+            dtype if is_integer(dtype) => match integer_range(dtype) {
+                Some((min, max)) => match (value.as_i64(), value.as_u64()) {
+                    (_, Some(v)) => v <= max,
+                    (Some(v), None) => v >= min,
+                    (None, None) => false,
+                },
+                None => value.is_i64() || value.is_u64(),
+            },
+            "float" | "number" => value.is_number(),
+            "boolean" | "bool" => value.is_boolean(),
+            "string" | "identifier" | "date" | "datetime" | "bytes" | "duration" => {
+                value.is_string()
+            }
+            "null" => value.is_null(),
+            "timestamp" => match self.timestamps() {
+                TimestampFormat::Integer => value.is_i64(),
+                TimestampFormat::String => value.is_string(),
+            },
+            // `any` and external types cannot be checked against the model
+            _ => true,
+        };
+
+        if !valid {
+            self.error(pointer, format!("Expected a value of type {}", dtype));
+        }
+    }
+}
+
+/// Escapes a key as a reference token of a JSON pointer.
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
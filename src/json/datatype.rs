@@ -56,15 +56,15 @@ impl FromStr for DataType {
 impl DataType {
     pub fn from_object(value: &serde_json::Value) -> Self {
         if let Some(reference) = value.get("$ref") {
-            return DataType::Reference {
+            DataType::Reference {
                 reference: reference
                     .as_str()
                     .unwrap()
                     .split('/')
-                    .last()
+                    .next_back()
                     .unwrap()
                     .to_string(),
-            };
+            }
         } else if let Some(values) = value.get("enum") {
             let values = values
                 .as_array()
@@ -72,18 +72,18 @@ impl DataType {
                 .iter()
                 .map(|v| v.as_str().unwrap().to_string())
                 .collect();
-            return DataType::Enum { values };
+            DataType::Enum { values }
         } else if let Some(data_type) = value.get("type") {
             let data_type = data_type.as_str().unwrap();
             if data_type == "object" {
                 let properties = value.get("properties").unwrap();
-                return DataType::Object {
+                DataType::Object {
                     properties: properties.clone(),
-                };
+                }
             } else {
-                return data_type.parse().expect(
+                data_type.parse().expect(
                     "Could not parse the data type. Make sure the data type is a valid type.",
-                );
+                )
             }
         } else {
             panic!("Could not find a data type in the JSON schema");
@@ -1,12 +1,13 @@
 use crate::{
     attribute::{AttrOption, Attribute},
     datamodel::DataModel,
+    error::MdModelsError,
     markdown::frontmatter::FrontMatter,
     object::{Enumeration, Object},
+    xmltype::XMLType,
 };
 use convert_case::{Case, Casing};
-use reqwest::Url;
-use std::{error::Error, path::Path};
+use std::{collections::BTreeMap, path::Path, str::FromStr};
 
 use super::datatype::DataType;
 
@@ -16,17 +17,17 @@ static PROP_KEYS: [&str; 10] = [
 ];
 
 /// Parse a JSON schema into an MD-Models data model
-pub fn parse_json_schema(path: &Path) -> Result<DataModel, Box<dyn Error>> {
-    let schema = read_json_schema(path).expect(
-        "Could not read the JSON schema file. Make sure the file is a valid JSON schema file.",
-    );
+pub fn parse_json_schema(path: &Path) -> Result<DataModel, MdModelsError> {
+    let schema = read_json_schema(path)?;
 
     // Create a new data model
     let name = schema
         .get("title")
-        .expect("Could not find title in the JSON schema")
-        .as_str()
-        .expect("Title is not a string")
+        .and_then(|title| title.as_str())
+        .ok_or_else(|| MdModelsError::Parse {
+            object: None,
+            message: "The JSON schema has no title".to_string(),
+        })?
         .to_string();
     let mut model = DataModel::new(Some(name), None);
     model.config = Some(FrontMatter::default());
@@ -53,13 +54,18 @@ pub fn parse_json_schema(path: &Path) -> Result<DataModel, Box<dyn Error>> {
         }
     }
 
+    model.resolve_type_kinds();
+
     Ok(model)
 }
 
 /// Read JSON schema from a file
-fn read_json_schema(path: &Path) -> Result<serde_json::Value, serde_json::Error> {
-    let content = std::fs::read_to_string(path).expect("Could not read the JSON schema file");
-    serde_json::from_str(&content)
+fn read_json_schema(path: &Path) -> Result<serde_json::Value, MdModelsError> {
+    let content = std::fs::read_to_string(path).map_err(|e| MdModelsError::io(path, e))?;
+    serde_json::from_str(&content).map_err(|e| MdModelsError::Parse {
+        object: None,
+        message: format!("Invalid JSON schema: {}", e),
+    })
 }
 
 fn create_enum(name: &str, values: &[String]) -> Enumeration {
@@ -73,13 +79,17 @@ fn create_enum(name: &str, values: &[String]) -> Enumeration {
         name: name.to_string(),
         mappings,
         docstring: "".to_string(),
+        open: false,
+        iris: BTreeMap::new(),
+        extends: None,
+        source: None,
     }
 }
 
 fn create_enum_alias(name: &str) -> String {
     // If it is a URL, get the last part of the URL and part before the .org/.com
-    let name = if let Ok(url) = Url::parse(name) {
-        url_to_enum_alias(url)
+    let name = if let Some((host, path)) = split_url(name) {
+        url_to_enum_alias(&host, path)
     } else {
         remove_special_characters(name)
     };
@@ -91,11 +101,38 @@ fn remove_special_characters(input: &str) -> String {
     input.chars().filter(|c| c.is_alphanumeric()).collect()
 }
 
-fn url_to_enum_alias(url: Url) -> String {
-    // Get the host and path
-    let host = url.host_str().unwrap_or("");
-    let path = url.path();
+/// Splits an absolute URL into its lowercase host and its path.
+///
+/// Returns `None` if the value does not start with a URL scheme.
+fn split_url(value: &str) -> Option<(String, &str)> {
+    let (scheme, rest) = value.split_once(':')?;
+    let valid_scheme = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+
+    if !valid_scheme {
+        return None;
+    }
+
+    // Drop the query and fragment
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+
+    let Some(rest) = rest.strip_prefix("//") else {
+        return Some((String::new(), rest));
+    };
+
+    let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    let host = authority.rsplit('@').next().unwrap_or_default();
+    let host = host.split(':').next().unwrap_or_default();
+
+    match path.is_empty() {
+        true => Some((host.to_lowercase(), "/")),
+        false => Some((host.to_lowercase(), path)),
+    }
+}
 
+fn url_to_enum_alias(host: &str, path: &str) -> String {
     // Remove the 'www.' prefix from the host if present
     let host = host.strip_prefix("www.").unwrap_or(host);
 
@@ -134,18 +171,24 @@ fn create_object(schema: &serde_json::Value) -> Object {
             _ => process_primitive(key, value),
         };
 
-        // Add all other keys as options
+        // Add all other keys as options, such as defaults of any JSON type
         for (key, value) in value.as_object().unwrap() {
             if !PROP_KEYS.contains(&key.as_str()) {
+                let value = match value.as_str() {
+                    Some(value) => value.to_string(),
+                    None => value.to_string(),
+                };
+
                 attribute
-                    .add_option(AttrOption::new(
-                        key.to_string(),
-                        value.as_str().unwrap().to_string(),
-                    ))
+                    .add_option(AttrOption::new(key.to_string(), value))
                     .expect("Failed to add option");
             }
         }
 
+        if attribute.xml.is_none() {
+            attribute.set_xml(XMLType::from_str(key).expect("Invalid XML type"));
+        }
+
         object.attributes.push(attribute);
     }
 
@@ -1,12 +1,23 @@
 pub mod datamodel;
+pub mod diff;
+pub mod error;
 pub mod exporters;
+pub mod migration;
 pub mod pipeline;
+pub mod stats;
 pub mod validation;
 
 pub(crate) mod attribute;
+pub(crate) mod capnp;
+pub(crate) mod instance;
+pub(crate) mod mock;
 pub(crate) mod object;
+pub(crate) mod orm;
 pub(crate) mod primitives;
 pub(crate) mod schema;
+pub(crate) mod tableschema;
+#[cfg(feature = "ucum")]
+pub(crate) mod ucum;
 pub(crate) mod xmltype;
 
 pub(crate) mod json {
@@ -17,4 +28,5 @@ pub(crate) mod json {
 pub(crate) mod markdown {
     pub(crate) mod frontmatter;
     pub(crate) mod parser;
+    pub(crate) mod style;
 }
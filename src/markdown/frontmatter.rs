@@ -1,8 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use gray_matter::{engine::YAML, Matter};
 use serde::{Deserialize, Serialize};
 
+use crate::{error::MdModelsError, object::Condition};
+
 /// Represents the front matter data of a markdown file.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct FrontMatter {
@@ -19,6 +21,126 @@ pub struct FrontMatter {
     /// A string field with a default value representing the prefix.
     #[serde(default = "default_prefix")]
     pub prefix: String,
+    /// Optional flag to disallow additional properties on all objects.
+    #[serde(rename = "closed-objects", skip_serializing_if = "Option::is_none")]
+    pub closed_objects: Option<bool>,
+    /// Optional flag to parse H4 headings as objects nested in the preceding H3 object.
+    #[serde(rename = "nested-objects", skip_serializing_if = "Option::is_none")]
+    pub nested_objects: Option<bool>,
+    /// Optional flag to make attributes required unless marked as optional.
+    #[serde(rename = "default-required", skip_serializing_if = "Option::is_none")]
+    pub default_required: Option<bool>,
+    /// Optional flag to derive missing attribute terms from the object term prefix.
+    #[serde(rename = "infer-terms", skip_serializing_if = "Option::is_none")]
+    pub infer_terms: Option<bool>,
+    /// Optional list of hosts that external type references may point to.
+    #[serde(rename = "external-refs", skip_serializing_if = "Option::is_none")]
+    pub external_refs: Option<Vec<String>>,
+    /// Optional conditional requirements per object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<HashMap<String, Vec<Condition>>>,
+    /// Optional named string patterns usable as attribute types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patterns: Option<HashMap<String, String>>,
+    /// Optional verbatim code per template, placed after the imports.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inject: Option<HashMap<String, String>>,
+    /// Optional additional fields of the built-in `quantity` type, mapped to their types.
+    #[serde(rename = "quantity-fields", skip_serializing_if = "Option::is_none")]
+    pub quantity_fields: Option<BTreeMap<String, String>>,
+    /// Optional version of the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Optional models to import, mapped from their namespace to their path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imports: Option<BTreeMap<String, String>>,
+    /// Optional naming conventions of the definitions, checked by the validator.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub naming: Option<NamingConventions>,
+    /// Optional XML representation of attributes without an `XML` option.
+    #[serde(rename = "xml-default", skip_serializing_if = "Option::is_none")]
+    pub xml_default: Option<XmlDefault>,
+    /// Optional wrapping of arrays in an XML element named after the attribute.
+    #[serde(rename = "xml-wrap-arrays", skip_serializing_if = "Option::is_none")]
+    pub xml_wrap_arrays: Option<bool>,
+    /// Optional JSON representation of the `timestamp` type.
+    #[serde(rename = "timestamp-format", skip_serializing_if = "Option::is_none")]
+    pub timestamp_format: Option<TimestampFormat>,
+    /// Optional attribute option keys accepted besides the built-in ones, e.g. for custom templates.
+    #[serde(rename = "custom-options", skip_serializing_if = "Option::is_none")]
+    pub custom_options: Option<Vec<String>>,
+}
+
+/// XML representation of attributes without an `XML` option.
+///
+/// Only single primitive values can be XML attributes. Arrays and references
+/// to objects or enumerations are always serialized as elements.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum XmlDefault {
+    /// Serialize attributes as XML elements (default).
+    #[default]
+    Element,
+    /// Serialize single primitive values as XML attributes.
+    Attribute,
+}
+
+/// JSON representation of the `timestamp` type.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TimestampFormat {
+    /// Seconds since the Unix epoch as an integer (default).
+    #[default]
+    Integer,
+    /// An ISO 8601 date-time string, e.g. `2024-05-01T12:00:00Z`.
+    String,
+}
+
+/// Naming conventions of the definitions of a model.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct NamingConventions {
+    /// Optional case of object names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_case: Option<NamingCase>,
+    /// Optional case of attribute names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribute_case: Option<NamingCase>,
+    /// Optional case of enumeration names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enum_name_case: Option<NamingCase>,
+    /// Optional case of enumeration keys.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enum_value_case: Option<NamingCase>,
+}
+
+/// Case of a name, written in its own style.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum NamingCase {
+    #[serde(rename = "PascalCase", alias = "pascal")]
+    Pascal,
+    #[serde(rename = "camelCase", alias = "camel")]
+    Camel,
+    #[serde(rename = "snake_case", alias = "snake")]
+    Snake,
+    #[serde(rename = "SCREAMING_SNAKE_CASE", alias = "screaming-snake")]
+    ScreamingSnake,
+    #[serde(rename = "kebab-case", alias = "kebab")]
+    Kebab,
+}
+
+impl std::fmt::Display for NamingCase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            NamingCase::Pascal => "PascalCase",
+            NamingCase::Camel => "camelCase",
+            NamingCase::Snake => "snake_case",
+            NamingCase::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+            NamingCase::Kebab => "kebab-case",
+        };
+
+        write!(f, "{}", name)
+    }
 }
 
 impl FrontMatter {
@@ -29,6 +151,22 @@ impl FrontMatter {
             nsmap: None,
             repo: default_repo(),
             prefix: default_prefix(),
+            closed_objects: None,
+            nested_objects: None,
+            default_required: None,
+            infer_terms: None,
+            external_refs: None,
+            conditions: None,
+            patterns: None,
+            inject: None,
+            quantity_fields: None,
+            version: None,
+            imports: None,
+            naming: None,
+            xml_default: None,
+            xml_wrap_arrays: None,
+            timestamp_format: None,
+            custom_options: None,
         }
     }
 
@@ -43,13 +181,15 @@ impl FrontMatter {
     /// Returns the prefixes as an optional vector of key-value pairs.
     ///
     /// # Returns
-    /// An optional vector of tuples containing the prefixes.
+    /// An optional vector of tuples containing the prefixes, sorted by prefix name.
     pub fn prefixes(&self) -> Option<Vec<(String, String)>> {
         self.prefixes.as_ref().map(|prefixes| {
-            prefixes
+            let mut prefixes: Vec<(String, String)> = prefixes
                 .iter()
                 .map(|(k, v)| (k.clone(), v.clone()))
-                .collect()
+                .collect();
+            prefixes.sort();
+            prefixes
         })
     }
 
@@ -102,18 +242,21 @@ fn default_repo() -> String {
 /// * `content` - A string slice that holds the content to parse.
 ///
 /// # Returns
-/// An optional `FrontMatter` if parsing is successful, otherwise `None`.
-pub fn parse_frontmatter(content: &str) -> Option<FrontMatter> {
+/// An optional `FrontMatter`, which is `None` if the content has no front matter.
+///
+/// # Errors
+/// Returns a `MdModelsError::Frontmatter` if the front matter is malformed.
+pub fn parse_frontmatter(content: &str) -> Result<Option<FrontMatter>, MdModelsError> {
     let matter = Matter::<YAML>::new();
     let result = matter.parse(content);
 
     match result.data {
-        None => None,
+        None => Ok(None),
         Some(data) => {
-            let matter = data
-                .deserialize()
-                .expect("Could not deserialize frontmatter");
-            Some(matter)
+            let matter = data.deserialize().map_err(|e| MdModelsError::Frontmatter {
+                message: e.to_string(),
+            })?;
+            Ok(Some(matter))
         }
     }
 }
@@ -134,6 +277,7 @@ mod tests {
 
         // Act
         let frontmatter = parse_frontmatter(&content)
+            .expect("Could not deserialize frontmatter")
             .expect("Could not parse frontmatter from file. Please check the file content.");
 
         // Assert
@@ -147,4 +291,24 @@ mod tests {
             "http://example.com/test/"
         );
     }
+
+    #[test]
+    fn test_prefixes_sorted() {
+        // Arrange
+        let content = "---\nprefixes:\n  schema: http://schema.org/\n  dcterms: http://purl.org/dc/terms/\n  obo: http://purl.obolibrary.org/obo/\n  bibo: http://purl.org/ontology/bibo/\n---\n";
+
+        // Act
+        let frontmatter = parse_frontmatter(content)
+            .expect("Could not deserialize frontmatter")
+            .expect("Could not parse frontmatter");
+
+        // Assert
+        let names = frontmatter
+            .prefixes()
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<String>>();
+        assert_eq!(names, vec!["bibo", "dcterms", "obo", "schema"]);
+    }
 }
@@ -1,19 +1,25 @@
 use colored::Colorize;
-use core::panic;
+use convert_case::{Boundary, Case, Casing};
 use lazy_static::lazy_static;
-use log::error;
-use std::collections::BTreeMap;
-use std::error::Error;
+use log::{error, warn};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
+use std::ops::Range;
+use std::path::Path;
+use std::str::FromStr;
 
-use pulldown_cmark::{CowStr, Event, Parser, Tag};
+use pulldown_cmark::{CodeBlockKind, CowStr, Event, OffsetIter, Parser, Tag};
 use regex::Regex;
 
-use crate::attribute;
+use crate::attribute::{self, generic_type};
 use crate::datamodel::DataModel;
+use crate::error::MdModelsError;
 use crate::object::{self, Enumeration, Object};
-use crate::validation::Validator;
+use crate::primitives::PrimitiveTypes;
+use crate::validation::{collect_diagnostics, Diagnostic, Validator};
+use crate::xmltype::XMLType;
 
-use super::frontmatter::parse_frontmatter;
+use super::frontmatter::{parse_frontmatter, XmlDefault};
 
 lazy_static! {
     static ref MD_MODEL_TYPES: BTreeMap<&'static str, &'static str> = {
@@ -28,13 +34,43 @@ lazy_static! {
         );
         m
     };
+
+    /// Matches HTML tags and comments, which are removed before parsing.
+    ///
+    /// Only tag names directly followed by whitespace, `/` or `>` are matched,
+    /// so that type expressions such as `map<string, float>` are kept.
+    static ref HTML_TAG: Regex =
+        Regex::new(r"(?s)<!--.*?-->|</?[A-Za-z][A-Za-z0-9-]*(?:\s[^>]*)?/?>").unwrap();
+
+    /// Matches a term given as CURIE or IRI at the end of an object heading.
+    static ref OBJECT_TERM: Regex =
+        Regex::new(r"\(\s*([A-Za-z][A-Za-z0-9_.+-]*:[^\s()]+)\s*\)\s*$").unwrap();
+
+    /// Matches parenthesized groups of an object heading.
+    static ref HEADING_GROUP: Regex = Regex::new(r"\(([^()]*)\)").unwrap();
+
+    /// Matches markdown links, which are replaced by their text before parsing.
+    static ref MARKDOWN_LINK: Regex = Regex::new(r"\[([^\]]+)\]\([^\)]+\)").unwrap();
 }
 
+/// Placeholder for an escaped opening parenthesis (`\(`) in a heading.
+///
+/// Placeholders have the same byte length as the escape sequence they replace.
+const ESCAPED_OPEN_PAREN: char = '\u{0091}';
+
+/// Placeholder for an escaped closing parenthesis (`\)`) in a heading.
+const ESCAPED_CLOSE_PAREN: char = '\u{0092}';
+
+/// Info string prefix of code blocks injected into generated objects, e.g. `inject:rust`.
+const INJECT_PREFIX: &str = "inject:";
+
+/// Attribute type referring to the enclosing object.
+const SELF_TYPE: &str = "Self";
+
 #[derive(Debug, PartialEq, Eq)]
 enum ParserState {
     InDefinition,
     OutsideDefinition,
-    InHeading,
 }
 
 /// Parses a Markdown file at the given path and returns a `DataModel`.
@@ -46,39 +82,172 @@ enum ParserState {
 /// # Returns
 ///
 /// A `Result` containing a `DataModel` on success or an error on failure.
-pub fn parse_markdown(content: &str) -> Result<DataModel, Box<dyn Error>> {
+pub fn parse_markdown(content: &str) -> Result<DataModel, MdModelsError> {
+    parse_markdown_with_diagnostics(content).map(|(model, _)| model)
+}
+
+/// Parses a Markdown file and returns a `DataModel` along with its warnings.
+///
+/// Warnings, e.g. unused enumerations or naming lints, do not invalidate
+/// the model. Hence, they are returned instead of an error.
+///
+/// # Arguments
+///
+/// * `content` - The content of the Markdown file.
+///
+/// # Returns
+///
+/// A `Result` containing the validated `DataModel` and its warnings on
+/// success or an error on failure.
+pub fn parse_markdown_with_diagnostics(
+    content: &str,
+) -> Result<(DataModel, Vec<Diagnostic>), MdModelsError> {
+    let model = parse_markdown_unvalidated(content)?;
+
+    // Validate the model
+    let mut validator = Validator::new();
+    validator.validate(&model)?;
+
+    let diagnostics = collect_diagnostics(&model);
+    Ok((model, diagnostics))
+}
+
+/// Splits the content of a Markdown file into its documents.
+///
+/// Separators within front matter and fenced code blocks are ignored, as well
+/// as `---` lines directly following text, which underline a heading.
+///
+/// # Arguments
+///
+/// * `content` - The content of the Markdown file.
+///
+/// # Returns
+///
+/// The non-empty documents of the file.
+pub(crate) fn split_documents(content: &str) -> Vec<&str> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+
+    let mut documents = Vec::new();
+    let mut start = 0;
+    let mut offset = 0;
+    let mut at_start = true;
+    let mut in_frontmatter = false;
+    let mut in_fence = false;
+    let mut previous_blank = true;
+
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim();
+        let line_start = offset;
+        offset += line.len();
+
+        if at_start {
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            at_start = false;
+            if trimmed == "---" {
+                in_frontmatter = true;
+                continue;
+            }
+        }
+
+        if in_frontmatter {
+            in_frontmatter = trimmed != "---";
+            continue;
+        }
+
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+
+        if !in_fence && previous_blank && trimmed == "---" {
+            documents.push(&content[start..line_start]);
+            start = offset;
+            at_start = true;
+            continue;
+        }
+
+        previous_blank = trimmed.is_empty();
+    }
+
+    documents.push(&content[start..]);
+    documents
+        .into_iter()
+        .map(|document| document.trim_start())
+        .filter(|document| !document.is_empty())
+        .collect()
+}
+
+/// Parses a Markdown model without validating it.
+///
+/// The resulting model may reference undefined types or violate other
+/// validation rules and should only be used for debugging purposes.
+///
+/// Objects and enumerations are extracted in a single pass over the
+/// Markdown events, such that large models are not parsed twice.
+///
+/// # Arguments
+///
+/// * `content` - The content of the Markdown file.
+///
+/// # Returns
+///
+/// A `Result` containing a `DataModel` on success or an error on failure.
+pub fn parse_markdown_unvalidated(content: &str) -> Result<DataModel, MdModelsError> {
+    // Remove the byte-order mark added by some editors
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+
     // Remove HTML and links
-    let content = clean_content(content);
+    let cleaned = clean_content(content);
 
     // Parse the frontmatter
-    let config = parse_frontmatter(&content);
+    let config = parse_frontmatter(&cleaned.text)?;
 
     // Parse the markdown content
-    let parser = Parser::new(&content);
-    let mut iterator = parser.into_iter();
+    let mut iterator = Events::new(&cleaned.text);
 
     let mut objects = Vec::new();
     let mut enums = Vec::new();
 
     let mut model = DataModel::new(None, config);
 
-    // Extract objects from the markdown file
+    // Extract objects and enums from the markdown file
     let mut state = ParserState::OutsideDefinition;
     while let Some(event) = iterator.next() {
-        process_object_event(&mut iterator, &mut objects, event, &mut model, &mut state);
+        process_event(
+            &mut iterator,
+            &mut objects,
+            &mut enums,
+            event,
+            &mut model,
+            &mut state,
+        )
+        .map_err(|e| cleaned.locate(e, iterator.offset))?;
     }
 
-    // Reset the iterator
-    let parser = Parser::new(&content);
-    let mut iterator = parser.into_iter();
+    // Filter empty objects and enums. Headings with attributes are objects,
+    // whose code blocks are examples rather than enumeration mappings.
+    let definitions = objects.iter().filter(|o| o.nested_in.is_none());
+    model.enums = enums
+        .into_iter()
+        .zip(definitions)
+        .filter(|(e, o)| e.has_values() && !o.has_attributes())
+        .map(|(e, _)| e)
+        .collect();
+    model.objects = objects.into_iter().filter(|o| o.has_attributes()).collect();
+
+    // Replace the `Self` type by the enclosing object
+    resolve_self_types(&mut model);
 
-    while let Some(event) = iterator.next() {
-        process_enum_event(&mut iterator, &mut enums, event);
-    }
+    // Resolve named patterns used as types
+    resolve_pattern_types(&mut model)?;
 
-    // Filter empty objects and enums
-    model.enums = enums.into_iter().filter(|e| e.has_values()).collect();
-    model.objects = objects.into_iter().filter(|o| o.has_attributes()).collect();
+    // Reference nested objects from their parent objects
+    add_nested_references(&mut model);
+
+    // Expand the built-in quantity type, if used
+    add_quantity_type(&mut model);
 
     // Add internal types, if used
     add_internal_types(&mut model);
@@ -86,158 +255,553 @@ pub fn parse_markdown(content: &str) -> Result<DataModel, Box<dyn Error>> {
     // Apply inheritance
     add_parent_types(&mut model)?;
 
-    // Validate the model
-    let mut validator = Validator::new();
-    validator.validate(&model)?;
+    // Set the XML representation of attributes without an `XML` option
+    add_default_xml_types(&mut model);
+
+    // Derive missing attribute terms from the object terms, if enabled
+    infer_attribute_terms(&mut model);
+
+    // Merge the mappings of extended enumerations
+    resolve_enum_extensions(&mut model);
+
+    // Resolve enumeration keys given as defaults
+    resolve_enum_defaults(&mut model);
+
+    // Expand enumeration values given as CURIEs or IRIs
+    resolve_enum_iris(&mut model);
+
+    // Turn constants of string attributes into strings
+    resolve_string_constants(&mut model);
+
+    // Add conditional requirements from the frontmatter
+    add_conditions(&mut model);
+
+    // Classify the types of the attributes
+    model.resolve_type_kinds();
 
     Ok(model)
 }
 
-fn clean_content(content: &str) -> String {
-    // Remove all html tags
-    let re = Regex::new(r"<[^>]*>").unwrap();
-    let content = re.replace_all(content, "").to_string();
+/// Markdown content cleaned for parsing, whose byte offsets map back to the original content.
+struct CleanedContent<'a> {
+    /// The content before cleaning.
+    original: &'a str,
+    /// The cleaned content.
+    text: Cow<'a, str>,
+    /// The offsets at which the content of each replacement pass is aligned
+    /// with its input again, as pairs of output and input offsets.
+    anchors: Vec<Vec<(usize, usize)>>,
+}
+
+impl CleanedContent<'_> {
+    /// Maps a byte offset of the cleaned content to the original content.
+    ///
+    /// Offsets within removed markup map to the end of the markup.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - A byte offset of the cleaned content.
+    fn original_offset(&self, offset: usize) -> usize {
+        self.anchors.iter().rev().fold(offset, |offset, anchors| {
+            let index = anchors.partition_point(|(output, _)| *output <= offset);
+            match index {
+                0 => offset,
+                _ => {
+                    let (output, input) = anchors[index - 1];
+                    input + offset - output
+                }
+            }
+        })
+    }
+
+    /// Returns the line and column of a byte offset of the cleaned content in
+    /// the original content, both starting at one.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - A byte offset of the cleaned content.
+    fn position(&self, offset: usize) -> (usize, usize) {
+        let offset = self.original_offset(offset).min(self.original.len());
+        let before = &self.original[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+
+    /// Adds the position of a byte offset of the cleaned content to a parse error.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error to locate.
+    /// * `offset` - The byte offset of the cleaned content at which the error occurred.
+    fn locate(&self, error: MdModelsError, offset: usize) -> MdModelsError {
+        match error {
+            MdModelsError::Parse { object, message } => {
+                let (line, column) = self.position(offset);
+                MdModelsError::Parse {
+                    object,
+                    message: format!("{} (line {}, column {})", message, line, column),
+                }
+            }
+            error => error,
+        }
+    }
+}
+
+/// Markdown events that keep track of the byte offset of the current event.
+struct Events<'a> {
+    inner: OffsetIter<'a>,
+    /// The byte offset at which the last returned event starts.
+    offset: usize,
+}
+
+impl<'a> Events<'a> {
+    /// Creates the events of markdown content.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The markdown content to parse.
+    fn new(content: &'a str) -> Self {
+        Events {
+            inner: Parser::new(content).into_offset_iter(),
+            offset: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for Events<'a> {
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Event<'a>> {
+        let (event, range) = self.inner.next()?;
+        self.offset = range.start;
+        Some(event)
+    }
+}
+
+/// Removes HTML tags and markdown links and escapes parentheses in headings.
+///
+/// The content is only copied if it contains anything to remove or escape,
+/// which avoids duplicating large models written in plain markdown. The
+/// offsets of the removed markup are recorded, such that the offsets of the
+/// parsed events map back to the original content.
+///
+/// # Arguments
+///
+/// * `content` - A string slice containing the markdown content.
+fn clean_content(content: &str) -> CleanedContent<'_> {
+    let (cleaned, html_anchors) = replace_all(Cow::Borrowed(content), &HTML_TAG);
+    let (cleaned, link_anchors) = replace_all(cleaned, &MARKDOWN_LINK);
+
+    CleanedContent {
+        original: content,
+        text: escape_heading_parens(cleaned),
+        anchors: vec![html_anchors, link_anchors],
+    }
+}
+
+/// Replaces all matches of a regular expression by their first capture group,
+/// copying the content only on a match.
+///
+/// # Arguments
+///
+/// * `content` - The content to replace the matches in.
+/// * `re` - The regular expression to match.
+///
+/// # Returns
+///
+/// The replaced content and the offsets at which it is aligned with the
+/// given content again, as pairs of replaced and given offsets.
+fn replace_all<'a>(content: Cow<'a, str>, re: &Regex) -> (Cow<'a, str>, Vec<(usize, usize)>) {
+    if !re.is_match(&content) {
+        return (content, vec![]);
+    }
+
+    let fences = fenced_ranges(&content);
+    let mut replaced = String::with_capacity(content.len());
+    let mut anchors = Vec::new();
+    let mut last = 0;
+
+    for caps in re.captures_iter(&content) {
+        let matched = caps.get(0).unwrap();
+        if is_type_argument(&content, matched.start(), matched.as_str())
+            || is_fenced(&fences, matched.start())
+        {
+            continue;
+        }
+
+        replaced.push_str(&content[last..matched.start()]);
+        if let Some(kept) = caps.get(1) {
+            anchors.push((replaced.len(), kept.start()));
+            replaced.push_str(kept.as_str());
+        }
+
+        anchors.push((replaced.len(), matched.end()));
+        last = matched.end();
+    }
+
+    replaced.push_str(&content[last..]);
+    (Cow::Owned(replaced), anchors)
+}
+
+/// Checks whether a match is the type argument of a generic type, e.g. `<Sample>` in `Result<Sample>`.
+///
+/// Type arguments directly follow the type name, whereas HTML tags are
+/// separated from preceding text.
+///
+/// # Arguments
+///
+/// * `content` - The content containing the match.
+/// * `start` - The byte offset of the match.
+/// * `matched` - The matched text.
+fn is_type_argument(content: &str, start: usize, matched: &str) -> bool {
+    let follows_name = content[..start]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_');
+
+    follows_name && matched.starts_with('<') && !matched.starts_with("</")
+}
+
+/// Collects the byte ranges of all fenced code blocks, including their fence lines.
+///
+/// An unclosed block extends to the end of the content.
+///
+/// # Arguments
+///
+/// * `content` - The markdown content.
+///
+/// # Returns
+///
+/// The ranges in ascending order.
+fn fenced_ranges(content: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut opened = None;
+    let mut offset = 0;
+
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            match opened.take() {
+                Some(start) => ranges.push(start..offset + line.len()),
+                None => opened = Some(offset),
+            }
+        }
+        offset += line.len();
+    }
+
+    if let Some(start) = opened {
+        ranges.push(start..content.len());
+    }
+
+    ranges
+}
+
+/// Checks whether an offset lies within a fenced code block, whose content is kept verbatim.
+///
+/// # Arguments
+///
+/// * `fences` - The ranges of the fenced code blocks, as collected by [`fenced_ranges`].
+/// * `start` - The byte offset to check.
+fn is_fenced(fences: &[Range<usize>], start: usize) -> bool {
+    let index = fences.partition_point(|range| range.end <= start);
+    fences
+        .get(index)
+        .is_some_and(|range| range.contains(&start))
+}
+
+/// Replaces escaped parentheses (`\(` and `\)`) in headings with placeholders.
+///
+/// Escaped parentheses are part of the display name and must not be
+/// treated as term delimiters. The placeholders are turned back into
+/// literal parentheses once the term has been extracted. Lines within
+/// fenced code blocks are kept as they are.
+///
+/// # Arguments
+///
+/// * `content` - The markdown content.
+///
+/// # Returns
+///
+/// The content with escaped parentheses in headings replaced.
+fn escape_heading_parens(content: Cow<'_, str>) -> Cow<'_, str> {
+    if !content.contains("\\(") && !content.contains("\\)") {
+        return content;
+    }
+
+    let mut fenced = false;
+    let escaped = content
+        .split('\n')
+        .map(|line| {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                fenced = !fenced;
+            }
+
+            if !fenced && trimmed.starts_with('#') {
+                line.replace("\\(", &ESCAPED_OPEN_PAREN.to_string())
+                    .replace("\\)", &ESCAPED_CLOSE_PAREN.to_string())
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
 
-    // Remove all markdown links
-    let re = Regex::new(r"\[([^\]]+)\]\([^\)]+\)").unwrap();
-    let content = re.replace_all(content.as_str(), "$1").to_string();
+    Cow::Owned(escaped)
+}
 
-    content
+/// Turns escaped parenthesis placeholders back into literal parentheses.
+fn unescape_heading_parens(text: &str) -> String {
+    text.replace(ESCAPED_OPEN_PAREN, "(")
+        .replace(ESCAPED_CLOSE_PAREN, ")")
 }
 
-/// Processes a single Markdown event for object extraction.
+/// Processes a single Markdown event for object and enumeration extraction.
+///
+/// Every third-level heading starts both an object and an enumeration
+/// candidate. Candidates without attributes or mappings are filtered once
+/// all events have been processed, as are enumeration candidates of
+/// headings with attributes.
 ///
 /// # Arguments
 ///
 /// * `iterator` - A mutable reference to the parser iterator.
 /// * `objects` - A mutable reference to the vector of objects.
+/// * `enums` - A mutable reference to the vector of enumerations.
 /// * `event` - The current Markdown event.
 /// * `model` - A mutable reference to the data model.
-fn process_object_event(
-    iterator: &mut Parser,
+/// * `state` - Whether the parser is within an object definition.
+///
+/// # Errors
+///
+/// Returns a `MdModelsError::Parse` if an attribute option is invalid.
+fn process_event(
+    iterator: &mut Events,
     objects: &mut Vec<object::Object>,
+    enums: &mut Vec<Enumeration>,
     event: Event,
     model: &mut DataModel,
     state: &mut ParserState,
-) {
+) -> Result<(), MdModelsError> {
     match event {
         Event::Start(Tag::Heading(1)) => {
-            model.name = Some(extract_name(iterator));
+            model.name = Some(extract_name(iterator, None)?);
         }
         Event::Start(Tag::Heading(2)) => {
             *state = ParserState::OutsideDefinition;
         }
         Event::Start(Tag::Heading(3)) => {
-            *state = ParserState::InHeading;
-            let object = process_object_heading(iterator);
-            objects.push(object);
-        }
-        Event::End(Tag::Heading(3)) => {
+            let heading = extract_heading_events(iterator);
+            enums.push(process_enum_heading(&mut heading.clone().into_iter())?);
+            objects.push(process_object_heading(&mut heading.into_iter())?);
             *state = ParserState::InDefinition;
         }
-        Event::Text(CowStr::Borrowed("[")) => {
-            if *state == ParserState::InHeading {
-                // Extract parent from the next text event
-                let last_object = objects.last_mut().unwrap();
-                let parent = iterator.next();
-
-                match parent {
-                    Some(Event::Text(text)) if text.to_string() != "]" => {
-                        last_object.parent = Some(text.to_string());
-                    }
-                    _ => {
-                        error!(
-                            "[{}] {}: Opening bracket but no parent name. Inheritance wont be applied",
-                            last_object.name.bold(),
-                            "SyntaxError".bold(),
-                        );
+        Event::Start(Tag::Heading(4))
+            if *state == ParserState::InDefinition && has_nested_objects(model) =>
+        {
+            let parent = objects
+                .iter()
+                .rev()
+                .find(|o| o.nested_in.is_none())
+                .map(|o| o.name.clone())
+                .unwrap();
 
-                        panic!(
-                            "Inheritance syntax error. Expected parent name after opening bracket."
-                        );
-                    }
-                }
-            }
+            let mut object = process_object_heading(iterator)?;
+            object.set_name(format!("{}{}", parent, object.name));
+            object.display_name = None;
+            object.nested_in = Some(parent);
+            objects.push(object);
         }
         Event::Start(Tag::List(None)) => {
             if *state == ParserState::OutsideDefinition {
-                return;
+                return Ok(());
             }
 
             let last_object = objects.last_mut().unwrap();
             if !last_object.has_attributes() {
                 iterator.next();
-                let (required, attr_name) = extract_attr_name_required(iterator);
-                let attribute = attribute::Attribute::new(attr_name, required);
+                let attribute = new_attribute(iterator, model, &last_object.name)?;
                 objects.last_mut().unwrap().add_attribute(attribute);
             } else {
-                let attr_strings = extract_attribute_options(iterator);
-                for attr_string in attr_strings {
-                    distribute_attribute_options(objects, attr_string);
+                let attr_strings = extract_attribute_options(iterator, &last_object.name)?;
+                for (attr_string, offset) in attr_strings {
+                    if let Err(e) = distribute_attribute_options(objects, attr_string) {
+                        // Locate the error at the invalid option
+                        iterator.offset = offset;
+                        return Err(e);
+                    }
                 }
             }
         }
         Event::Start(Tag::Item) => {
             if *state == ParserState::OutsideDefinition {
-                return;
+                return Ok(());
             }
 
-            let (required, attr_string) = extract_attr_name_required(iterator);
-            let attribute = attribute::Attribute::new(attr_string, required);
-            objects.last_mut().unwrap().add_attribute(attribute);
+            let last_object = objects.last_mut().unwrap();
+            let attribute = new_attribute(iterator, model, &last_object.name)?;
+            last_object.add_attribute(attribute);
+        }
+        Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info)))
+            if info.starts_with(INJECT_PREFIX) && *state == ParserState::InDefinition =>
+        {
+            let target = info[INJECT_PREFIX.len()..].trim().to_string();
+            let content = extract_code_block(iterator);
+            let last_object = objects.last_mut().unwrap();
+            last_object.inject.insert(target, content);
+        }
+        Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info)))
+            if !info.starts_with(INJECT_PREFIX) =>
+        {
+            if let Some(Event::Text(text)) = iterator.next() {
+                // Code blocks before the first definition belong to no enumeration
+                if let Some(enum_obj) = enums.last_mut() {
+                    process_enum_mappings(enum_obj, text.to_string());
+                }
+                process_object_text(objects, text.as_ref(), state);
+            }
+        }
+        Event::Start(Tag::Paragraph) if *state == ParserState::InDefinition => {
+            let lines = extract_paragraph_lines(iterator);
+            process_object_paragraph(objects.last_mut().unwrap(), &lines);
+            if let Some(enum_obj) = enums.last_mut() {
+                for line in &lines {
+                    process_enum_annotation(enum_obj, &line.text);
+                }
+            }
         }
         Event::Text(text) => {
-            if *state == ParserState::InDefinition {
-                let last_object = objects.last_mut().unwrap();
-                last_object.docstring.push_str(text.as_ref());
+            process_object_text(objects, text.as_ref(), state);
+            if let Some(enum_obj) = enums.last_mut() {
+                process_enum_annotation(enum_obj, text.as_ref());
             }
         }
         _ => {}
     }
+
+    Ok(())
 }
 
-/// Processes the heading of an object.
+/// A line of a paragraph, split at soft and hard line breaks.
+struct ParagraphLine {
+    /// The text of the line, without inline markup.
+    text: String,
+    /// The text events of the line, as they are added to docstrings.
+    fragments: Vec<String>,
+    /// Whether the line consists of text only, without code or emphasis.
+    plain: bool,
+}
+
+/// Collects the lines of a paragraph, including its end.
 ///
 /// # Arguments
 ///
 /// * `iterator` - A mutable reference to the parser iterator.
+fn extract_paragraph_lines(iterator: &mut Events) -> Vec<ParagraphLine> {
+    let mut lines = vec![];
+    let mut line = ParagraphLine {
+        text: String::new(),
+        fragments: vec![],
+        plain: true,
+    };
+
+    for event in iterator.by_ref() {
+        match event {
+            Event::End(Tag::Paragraph) => break,
+            Event::SoftBreak | Event::HardBreak => {
+                let next = ParagraphLine {
+                    text: String::new(),
+                    fragments: vec![],
+                    plain: true,
+                };
+                lines.push(std::mem::replace(&mut line, next));
+            }
+            Event::Text(text) => {
+                line.text.push_str(text.as_ref());
+                line.fragments.push(text.to_string());
+            }
+            _ => line.plain = false,
+        }
+    }
+
+    lines.push(line);
+    lines
+}
+
+/// Adds a paragraph to the description of an object.
 ///
-/// # Returns
+/// A paragraph is only treated as annotations (`Key: value`) if each of
+/// its lines is a plain annotation, such that prose starting with a key,
+/// e.g. `Summary: ...` followed by further sentences, remains part of the
+/// docstring.
 ///
-/// An `Object` created from the heading.
-fn process_object_heading(iterator: &mut Parser) -> object::Object {
-    let heading = extract_name(iterator);
-    let term = extract_object_term(&heading);
-    let name = heading.split_whitespace().next().unwrap().to_string();
+/// # Arguments
+///
+/// * `object` - A mutable reference to the object being described.
+/// * `lines` - The lines of the paragraph.
+fn process_object_paragraph(object: &mut object::Object, lines: &[ParagraphLine]) {
+    let mut annotated = object.clone();
+    if lines
+        .iter()
+        .all(|line| line.plain && process_object_annotation(&mut annotated, &line.text))
+    {
+        *object = annotated;
+        return;
+    }
 
-    object::Object::new(name, term)
+    for fragment in lines.iter().flat_map(|line| &line.fragments) {
+        object.docstring.push_str(fragment);
+    }
 }
 
-/// Extracts the name from the next text event in the iterator.
+/// Adds a text line to the description of the current object.
+///
+/// Annotations (`Key: value`) are applied to the object instead. Text
+/// outside of an object definition is ignored.
 ///
 /// # Arguments
 ///
-/// * `iterator` - A mutable reference to the parser iterator.
+/// * `objects` - A mutable reference to the vector of objects.
+/// * `text` - The text of the description line.
+/// * `state` - Whether the parser is within an object definition.
+fn process_object_text(objects: &mut [object::Object], text: &str, state: &ParserState) {
+    if *state != ParserState::InDefinition {
+        return;
+    }
+
+    let last_object = objects.last_mut().unwrap();
+    if !process_object_annotation(last_object, text) {
+        last_object.docstring.push_str(text);
+    }
+}
+
+/// Collects the events of a heading, including its end.
 ///
-/// # Returns
+/// The events are shared by the object and enumeration candidates
+/// starting at the heading.
 ///
-/// A string containing the extracted name.
-fn extract_name(iterator: &mut Parser) -> String {
-    if let Some(Event::Text(text)) = iterator.next() {
-        return text.to_string();
-    }
+/// # Arguments
+///
+/// * `iterator` - A mutable reference to the parser iterator.
+fn extract_heading_events<'a>(iterator: &mut Events<'a>) -> Vec<Event<'a>> {
+    let mut events = vec![];
+    for event in iterator.by_ref() {
+        let is_end = matches!(event, Event::End(Tag::Heading(_)));
+        events.push(event);
 
-    // Try for two text events
-    for _ in 0..2 {
-        if let Some(Event::Text(text)) = iterator.next() {
-            return text.to_string();
+        if is_end {
+            break;
         }
     }
 
-    panic!("Could not extract name: Got {:?}", iterator.next().unwrap());
+    events
 }
 
-/// Extracts the attribute name and its required status from the iterator.
+/// Extracts the verbatim content of a code block.
 ///
 /// # Arguments
 ///
@@ -245,67 +809,466 @@ fn extract_name(iterator: &mut Parser) -> String {
 ///
 /// # Returns
 ///
-/// A tuple containing a boolean indicating if the attribute is required and the attribute name.
-fn extract_attr_name_required(iterator: &mut Parser) -> (bool, String) {
-    if let Some(Event::Text(text)) = iterator.next() {
-        return (false, text.to_string());
-    }
-
-    // Try for two text events
-    for _ in 0..2 {
-        if let Some(Event::Text(text)) = iterator.next() {
-            return (true, text.to_string());
+/// The content of the code block without the trailing newline.
+fn extract_code_block(iterator: &mut Events) -> String {
+    let mut content = String::new();
+    for event in iterator.by_ref() {
+        match event {
+            Event::End(Tag::CodeBlock(_)) => break,
+            Event::Text(text) => content.push_str(text.as_ref()),
+            _ => {}
         }
     }
 
-    panic!("Could not extract name. Plesae check the markdown file.");
+    content.trim_end().to_string()
 }
 
-/// Extracts the term from an object heading.
+/// Checks whether H4 headings are parsed as nested objects.
 ///
 /// # Arguments
 ///
-/// * `heading` - A string slice containing the heading.
-///
+/// * `model` - A reference to the data model.
+fn has_nested_objects(model: &DataModel) -> bool {
+    model
+        .config
+        .as_ref()
+        .is_some_and(|c| c.nested_objects == Some(true))
+}
+
+/// Processes an annotation line (`Key: value`) within an object description.
+///
+/// Only known keys are treated as annotations, any other text remains
+/// part of the docstring. The bare annotations `NoId` and `Abstract` are
+/// short for `Id: false` and `Abstract: true`.
+///
+/// # Arguments
+///
+/// * `object` - A mutable reference to the object being described.
+/// * `text` - The text of the description line.
+///
+/// # Returns
+///
+/// `true` if the text was an annotation, `false` otherwise.
+fn process_object_annotation(object: &mut object::Object, text: &str) -> bool {
+    if text.trim().eq_ignore_ascii_case("noid") {
+        object.id_field = Some(false);
+        return true;
+    }
+
+    if text.trim().eq_ignore_ascii_case("abstract") {
+        object.is_abstract = true;
+        return true;
+    }
+
+    let (key, value) = match text.split_once(':') {
+        Some((key, value)) => (key.trim().to_lowercase(), value.trim()),
+        None => return false,
+    };
+
+    match (key.as_str(), value.to_lowercase().as_str()) {
+        ("closed", "true") => object.closed = Some(true),
+        ("closed", "false") => object.closed = Some(false),
+        ("id", "true") => object.id_field = Some(true),
+        ("id", "false") => object.id_field = Some(false),
+        ("abstract", "true") => object.is_abstract = true,
+        ("abstract", "false") => object.is_abstract = false,
+        ("primarykey", _) => {
+            object.primary_key = value
+                .split(',')
+                .map(|field| field.trim().to_string())
+                .filter(|field| !field.is_empty())
+                .collect()
+        }
+        ("table" | "collection", _) if !value.is_empty() => object.table = Some(value.to_string()),
+        ("index", _) => match parse_index(value) {
+            Some(index) => object.indexes.push(index),
+            None => return false,
+        },
+        ("summary", _) if !value.is_empty() => object.summary = Some(value.to_string()),
+        ("targets", _) => object.targets = attribute::split_targets(value),
+        ("subclassof", _) => {
+            object.subclass_of = value
+                .split(',')
+                .map(|class| class.trim().to_string())
+                .filter(|class| !class.is_empty())
+                .collect()
+        }
+        _ => return false,
+    }
+
+    true
+}
+
+/// Parses the value of an `Index` annotation, e.g. `email, name (unique)`.
+///
+/// # Arguments
+///
+/// * `value` - The comma-separated fields, optionally followed by `(unique)`.
+///
+/// # Returns
+///
+/// The index, or `None` if no fields are given.
+fn parse_index(value: &str) -> Option<object::Index> {
+    let (fields, unique) = match value.get(value.len().saturating_sub(8)..) {
+        Some(suffix) if suffix.eq_ignore_ascii_case("(unique)") => {
+            (&value[..value.len() - 8], true)
+        }
+        _ => (value, false),
+    };
+
+    let fields = fields
+        .split(',')
+        .map(|field| field.trim().to_string())
+        .filter(|field| !field.is_empty())
+        .collect::<Vec<String>>();
+
+    match fields.is_empty() {
+        true => None,
+        false => Some(object::Index { fields, unique }),
+    }
+}
+
+/// Processes the heading of an object.
+///
+/// # Arguments
+///
+/// * `iterator` - An iterator over the events of the heading.
+///
+/// # Returns
+///
+/// An `Object` created from the heading.
+///
+/// # Errors
+///
+/// Returns a `MdModelsError::Parse` if an opening bracket is not followed by a parent name.
+fn process_object_heading<'a>(
+    iterator: &mut impl Iterator<Item = Event<'a>>,
+) -> Result<object::Object, MdModelsError> {
+    let mut heading = String::new();
+    let mut parent = None;
+
+    while let Some(event) = iterator.next() {
+        match event {
+            Event::End(Tag::Heading(_)) => break,
+            Event::Text(CowStr::Borrowed("[")) => {
+                // Extract parent from the next text event
+                match iterator.next() {
+                    Some(Event::Text(text)) if text.to_string() != "]" => {
+                        parent = Some(text.to_string());
+                    }
+                    _ => {
+                        error!(
+                            "[{}] {}: Opening bracket but no parent name. Inheritance wont be applied",
+                            heading.trim().bold(),
+                            "SyntaxError".bold(),
+                        );
+
+                        return Err(MdModelsError::Parse {
+                            object: Some(heading.trim().to_string()),
+                            message: "Expected parent name after opening bracket".to_string(),
+                        });
+                    }
+                }
+            }
+            Event::Text(CowStr::Borrowed("]")) => {}
+            Event::Text(text) | Event::Code(text) | Event::Html(text) => {
+                heading.push_str(text.as_ref())
+            }
+            _ => {}
+        }
+    }
+
+    let term = extract_object_term(&heading);
+    for group in ignored_heading_groups(&heading, term.as_deref()) {
+        warn!(
+            "[{}] {}: Group ({}) of the heading is neither a CURIE nor an IRI and is ignored. Escape the parentheses to keep them in the name.",
+            heading.split_whitespace().next().unwrap_or_default().bold(),
+            "TermWarning".bold(),
+            group.red().bold(),
+        );
+    }
+
+    let display_name = heading
+        .split_whitespace()
+        .take_while(|word| !word.starts_with('('))
+        .map(unescape_heading_parens)
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    if display_name.is_empty() {
+        return Err(MdModelsError::Parse {
+            object: None,
+            message: format!("Could not extract object name from heading '{}'", heading),
+        });
+    }
+
+    // Split the type parameter of generic objects, e.g. `Result<T>`
+    let (display_name, type_param) = match generic_type(&display_name) {
+        Some((name, param)) => (name.to_string(), Some(param.to_string())),
+        None => (display_name, None),
+    };
+
+    let name = type_identifier(&display_name);
+    let mut object = object::Object::new(name, term);
+    object.parent = parent;
+    object.type_param = type_param;
+
+    if object.name != display_name {
+        object.display_name = Some(display_name);
+    }
+
+    Ok(object)
+}
+
+/// Derives a type identifier from the display name of an object.
+///
+/// Words separated by whitespace, hyphens or any other character that is not
+/// part of an identifier are joined in PascalCase, e.g. `Plate Reader` becomes
+/// `PlateReader` and `Sample(v2)` becomes `SampleV2`. Single words are kept as
+/// they are.
+///
+/// # Arguments
+///
+/// * `display_name` - The name of the object as given in the heading.
+///
+/// # Returns
+///
+/// A string containing the type identifier.
+fn type_identifier(display_name: &str) -> String {
+    let words = display_name
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>();
+
+    if words.len() == 1 {
+        return words[0].to_string();
+    }
+
+    words
+        .into_iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Extracts the name from the next text event in the iterator.
+///
+/// # Arguments
+///
+/// * `iterator` - A mutable reference to the event iterator.
+/// * `object` - The object the name belongs to, if any.
+///
+/// # Returns
+///
+/// A string containing the extracted name.
+///
+/// # Errors
+///
+/// Returns a `MdModelsError::Parse` if no name follows, e.g. for an empty heading.
+fn extract_name<'a>(
+    iterator: &mut impl Iterator<Item = Event<'a>>,
+    object: Option<&str>,
+) -> Result<String, MdModelsError> {
+    if let Some(Event::Text(text)) = iterator.next() {
+        return Ok(text.to_string());
+    }
+
+    // Try for two text events
+    for _ in 0..2 {
+        if let Some(Event::Text(text)) = iterator.next() {
+            return Ok(text.to_string());
+        }
+    }
+
+    Err(MdModelsError::Parse {
+        object: object.map(str::to_string),
+        message: "Could not extract name. Please check the markdown file.".to_string(),
+    })
+}
+
+/// Creates an attribute from its name, which is the next event of the iterator.
+///
+/// By default, attributes are optional unless their name is bold. If the
+/// frontmatter sets `default-required`, attributes are required unless their
+/// name is bold. Either way, a trailing `?` marks an attribute as optional.
+///
+/// # Arguments
+///
+/// * `iterator` - A mutable reference to the parser iterator.
+/// * `model` - The data model holding the frontmatter.
+/// * `object` - The name of the object the attribute belongs to.
+///
+/// # Errors
+///
+/// Returns a `MdModelsError::Parse` if the list item has no name.
+fn new_attribute(
+    iterator: &mut Events,
+    model: &DataModel,
+    object: &str,
+) -> Result<attribute::Attribute, MdModelsError> {
+    let (marked, name) = extract_attr_name_required(iterator, object)?;
+    let default_required = model
+        .config
+        .as_ref()
+        .is_some_and(|c| c.default_required == Some(true));
+
+    let required = match name.trim_end().strip_suffix('?') {
+        Some(_) => false,
+        None => marked != default_required,
+    };
+
+    let name = name.trim_end().trim_end_matches('?').to_string();
+    Ok(attribute::Attribute::new(name, required))
+}
+
+/// Extracts the attribute name and its required status from the iterator.
+///
+/// # Arguments
+///
+/// * `iterator` - A mutable reference to the parser iterator.
+/// * `object` - The name of the object the attribute belongs to.
+///
+/// # Returns
+///
+/// A tuple containing a boolean indicating if the attribute is required and the attribute name.
+///
+/// # Errors
+///
+/// Returns a `MdModelsError::Parse` if the list item has no name.
+fn extract_attr_name_required(
+    iterator: &mut Events,
+    object: &str,
+) -> Result<(bool, String), MdModelsError> {
+    if let Some(Event::Text(text)) = iterator.next() {
+        return Ok((false, text.to_string()));
+    }
+
+    // Try for two text events
+    for _ in 0..2 {
+        if let Some(Event::Text(text)) = iterator.next() {
+            return Ok((true, text.to_string()));
+        }
+    }
+
+    Err(MdModelsError::Parse {
+        object: Some(object.to_string()),
+        message: "Could not extract the name of a property. Please check the markdown file."
+            .to_string(),
+    })
+}
+
+/// Extracts the term from an object heading.
+///
+/// The term is the last parenthesized group of the heading and must be
+/// placed at its very end, e.g. `### Sample (schema:Thing)`. Only CURIEs
+/// (`prefix:reference`) and IRIs are recognized as terms, any other group
+/// is considered part of the display name. If a heading contains multiple
+/// groups, only the last one is taken into account. Literal parentheses
+/// can be escaped (`\(` and `\)`) to never be treated as term delimiters.
+///
+/// # Arguments
+///
+/// * `heading` - A string slice containing the heading.
+///
 /// # Returns
 ///
 /// An optional string containing the extracted term.
 fn extract_object_term(heading: &str) -> Option<String> {
-    let re = Regex::new(r"\(([^)]+)\)").unwrap();
-
-    re.captures(heading)
+    OBJECT_TERM
+        .captures(heading)
         .map(|cap| cap.get(1).map_or("", |m| m.as_str()).to_string())
 }
 
+/// Returns the parenthesized groups of an object heading that are not its term.
+///
+/// These groups are neither part of the name nor a term, hence they are
+/// dropped from the object.
+///
+/// # Arguments
+///
+/// * `heading` - A string slice containing the heading.
+/// * `term` - The term extracted from the heading, if any.
+fn ignored_heading_groups(heading: &str, term: Option<&str>) -> Vec<String> {
+    HEADING_GROUP
+        .captures_iter(heading)
+        .map(|cap| cap[1].to_string())
+        .filter(|group| Some(group.trim()) != term)
+        .collect()
+}
+
 /// Extracts attribute options from the iterator.
 ///
+/// Text events directly following the start of an option are joined, since
+/// the markdown parser splits text at brackets such as in `float[]` or
+/// `map<string, float>`.
+///
 /// # Arguments
 ///
 /// * `iterator` - A mutable reference to the parser iterator.
+/// * `object` - The name of the object the options belong to.
 ///
 /// # Returns
 ///
-/// A vector of strings containing the extracted attribute options.
-fn extract_attribute_options(iterator: &mut Parser) -> Vec<String> {
-    let mut options = Vec::new();
+/// A vector of the extracted attribute options and the byte offsets of their items.
+///
+/// # Errors
+///
+/// Returns a `MdModelsError::Parse` if a list item has no name.
+fn extract_attribute_options(
+    iterator: &mut Events,
+    object: &str,
+) -> Result<Vec<(String, usize)>, MdModelsError> {
+    let mut options: Vec<(String, usize)> = Vec::new();
+    let mut joining = false;
     while let Some(next) = iterator.next() {
         match next {
             Event::Start(Tag::Item) => {
-                let name = extract_name(iterator);
-                options.push(name);
+                let offset = iterator.offset;
+                let name = extract_name(iterator, Some(object))?;
+                options.push((name, offset));
+                joining = true;
             }
             Event::End(Tag::List(None)) => {
                 break;
             }
-            Event::Text(text) if text.to_string() == "[" => {
-                let last_option = options.last_mut().unwrap();
-                *last_option = format!("{}[]", last_option);
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(_)))
+                if options
+                    .last()
+                    .is_some_and(|(o, _)| is_fenced_description(o)) =>
+            {
+                let content = extract_code_block(iterator);
+                options.last_mut().unwrap().0.push_str(&content);
+                joining = false;
             }
-            _ => {}
+            Event::Text(text) | Event::Html(text) if joining => {
+                options.last_mut().unwrap().0.push_str(text.as_ref());
+            }
+            _ => joining = false,
         }
     }
 
-    options
+    Ok(options)
+}
+
+/// Checks whether an option is a description without value, which is given
+/// by the fenced code block that follows it.
+///
+/// The content of the block is kept verbatim, such that the description may
+/// contain markdown spanning multiple lines. The language tag is ignored.
+///
+/// # Arguments
+///
+/// * `option` - The attribute option extracted so far.
+fn is_fenced_description(option: &str) -> bool {
+    option
+        .trim()
+        .strip_suffix(':')
+        .is_some_and(|key| key.trim().eq_ignore_ascii_case("description"))
 }
 
 /// Adds an option to the last attribute of the last object in the list.
@@ -315,16 +1278,29 @@ fn extract_attribute_options(iterator: &mut Parser) -> Vec<String> {
 /// * `objects` - A mutable reference to the list of objects.
 /// * `key` - The key of the attribute option.
 /// * `value` - The value of the attribute option.
+///
+/// # Errors
+///
+/// Returns a `MdModelsError::Parse` if the value of the option is invalid.
 fn add_option_to_last_attribute(
     objects: &mut [object::Object],
     key: String,
     value: String,
-) -> Result<(), Box<dyn Error>> {
-    let last_attr = objects.last_mut().unwrap().get_last_attribute();
-    let option = attribute::AttrOption::new(key, value);
-    last_attr.add_option(option)?;
+) -> Result<(), MdModelsError> {
+    let last_object = objects.last_mut().unwrap();
+    let object_name = last_object.name.clone();
+    let last_attr = last_object.get_last_attribute();
+    let option = attribute::AttrOption::new(key.clone(), value.clone());
 
-    Ok(())
+    last_attr
+        .add_option(option)
+        .map_err(|e| MdModelsError::Parse {
+            object: Some(object_name),
+            message: format!(
+                "Option '{}' of property '{}' has an invalid value '{}': {}",
+                key, last_attr.name, value, e
+            ),
+        })
 }
 
 /// Distributes attribute options among the objects.
@@ -334,14 +1310,17 @@ fn add_option_to_last_attribute(
 /// * `objects` - A mutable reference to the list of objects.
 /// * `attr_string` - A string containing the attribute or option.
 ///
-/// # Returns
+/// # Errors
 ///
-/// An optional unit type.
-fn distribute_attribute_options(objects: &mut [object::Object], attr_string: String) -> Option<()> {
+/// Returns a `MdModelsError::Parse` if an option is invalid.
+fn distribute_attribute_options(
+    objects: &mut [object::Object],
+    attr_string: String,
+) -> Result<(), MdModelsError> {
     if attr_string.contains(':') {
-        let (key, value) = process_option(&attr_string);
-        add_option_to_last_attribute(objects, key, value).expect("Failed to add option");
-        return None;
+        let object = &objects.last().unwrap().name;
+        let (key, value) = process_option(&attr_string, object)?;
+        return add_option_to_last_attribute(objects, key, value);
     }
 
     objects
@@ -349,7 +1328,7 @@ fn distribute_attribute_options(objects: &mut [object::Object], attr_string: Str
         .unwrap()
         .create_new_attribute(attr_string, false);
 
-    None
+    Ok(())
 }
 
 /// Processes an attribute option string.
@@ -357,74 +1336,104 @@ fn distribute_attribute_options(objects: &mut [object::Object], attr_string: Str
 /// # Arguments
 ///
 /// * `option` - A string containing the attribute option.
+/// * `object` - The name of the object the option belongs to.
 ///
 /// # Returns
 ///
 /// A tuple containing the key and value of the attribute option.
-fn process_option(option: &String) -> (String, String) {
-    let parts: Vec<&str> = option.split(':').collect();
-
-    assert!(
-        parts.len() > 1,
-        "Attribute {} does not have a valid option",
-        option
-    );
-
-    let key = parts[0].trim();
-    let value = parts[1..].join(":");
+///
+/// # Errors
+///
+/// Returns a `MdModelsError::Parse` if the option is not of the form `Key: value`.
+fn process_option(option: &str, object: &str) -> Result<(String, String), MdModelsError> {
+    match option.split_once(':') {
+        Some((key, value)) => Ok((key.trim().to_string(), value.trim().to_string())),
+        None => Err(MdModelsError::Parse {
+            object: Some(object.to_string()),
+            message: format!("Option '{}' is not of the form 'Key: value'", option.trim()),
+        }),
+    }
+}
 
-    (key.to_string(), value.trim().to_string())
+/// Processes the heading of an enumeration.
+///
+/// # Arguments
+///
+/// * `iterator` - An iterator over the events of the heading.
+///
+/// # Returns
+///
+/// An empty `Enumeration` named after the heading.
+///
+/// # Errors
+///
+/// Returns a `MdModelsError::Parse` if the heading is empty.
+fn process_enum_heading<'a>(
+    iterator: &mut impl Iterator<Item = Event<'a>>,
+) -> Result<Enumeration, MdModelsError> {
+    Ok(Enumeration {
+        name: unescape_heading_parens(&extract_name(iterator, None)?),
+        mappings: BTreeMap::new(),
+        docstring: "".to_string(),
+        open: false,
+        iris: BTreeMap::new(),
+        extends: None,
+        source: None,
+    })
 }
 
-/// Processes a single Markdown event for enumeration extraction.
+/// Processes an annotation line (`Key: value`) within an enumeration description.
 ///
 /// # Arguments
 ///
-/// * `iterator` - A mutable reference to the parser iterator.
-/// * `enums` - A mutable reference to the vector of enumerations.
-/// * `event` - The current Markdown event.
-pub fn process_enum_event(iterator: &mut Parser, enums: &mut Vec<Enumeration>, event: Event) {
-    match event {
-        Event::Start(Tag::Heading(3)) => {
-            let enum_name = extract_name(iterator);
-            let enum_obj = Enumeration {
-                name: enum_name,
-                mappings: BTreeMap::new(),
-                docstring: "".to_string(),
-            };
-            enums.push(enum_obj);
-        }
-        Event::Start(Tag::CodeBlock(pulldown_cmark::CodeBlockKind::Fenced(_))) => {
-            let event = iterator.next().unwrap();
-            if let Event::Text(text) = event {
-                let mappings = text.to_string();
-                let enum_obj = enums.last_mut().unwrap();
-                process_enum_mappings(enum_obj, mappings);
-            }
+/// * `enum_obj` - A mutable reference to the enumeration being described.
+/// * `text` - The text of the description line.
+fn process_enum_annotation(enum_obj: &mut Enumeration, text: &str) {
+    if let Some((key, value)) = text.split_once(':') {
+        if key.trim().eq_ignore_ascii_case("open") {
+            enum_obj.open = value.trim().eq_ignore_ascii_case("true");
+        } else if key.trim().eq_ignore_ascii_case("extends") {
+            enum_obj.extends = Some(value.trim().to_string());
+        } else if key.trim().eq_ignore_ascii_case("@source") && !value.trim().is_empty() {
+            enum_obj.source = Some(value.trim().to_string());
         }
-        _ => {}
     }
 }
 
+/// Separators between the key and value of an enumeration mapping.
+const ENUM_SEPARATORS: [&str; 3] = ["=", "->", ":"];
+
 /// Processes enumeration mappings from a code block.
 ///
+/// Keys and values are separated by `=`, `:` or `->`. If a line contains
+/// several separators, it is split at the first one. Empty lines and
+/// comments starting with `#` or `//` are skipped.
+///
 /// # Arguments
 ///
 /// * `enum_obj` - A mutable reference to the enumeration object.
 /// * `mappings` - A string containing the mappings.
 fn process_enum_mappings(enum_obj: &mut Enumeration, mappings: String) {
-    let lines = mappings.split('\n');
-    for line in lines {
-        let parts: Vec<&str> = line.split('=').collect();
-        if parts.len() != 2 {
-            // Skip empty lines or lines that do not contain a mapping
+    for line in mappings.lines().map(str::trim) {
+        if line.starts_with('#') || line.starts_with("//") {
             continue;
         }
 
+        // Split at the first occurring separator
+        let split = ENUM_SEPARATORS
+            .iter()
+            .filter_map(|sep| line.find(sep).map(|pos| (pos, sep.len())))
+            .min_by_key(|(pos, _)| *pos);
+
+        let Some((pos, len)) = split else {
+            // Skip empty lines or lines that do not contain a mapping
+            continue;
+        };
+
         // Extract key and value, insert into enum object
-        let key = parts[0].trim().replace('"', "");
-        let value = parts[1].trim().replace('"', "");
-        enum_obj.mappings.insert(key.to_string(), value.to_string());
+        let key = line[..pos].trim().replace('"', "");
+        let value = line[pos + len..].trim().replace('"', "");
+        enum_obj.mappings.insert(key, value);
     }
 }
 
@@ -434,16 +1443,10 @@ fn process_enum_mappings(enum_obj: &mut Enumeration, mappings: String) {
 ///
 /// * `model` - A mutable reference to the data model.
 ///
-///
-/// # Panics
-///
-/// Panics if an object has a parent that does not exist.
-///
 /// # Errors
 ///
-/// An error is logged if an object has a parent that does not exist.
-///
-fn add_parent_types(model: &mut DataModel) -> Result<(), Box<dyn Error>> {
+/// Returns a `MdModelsError::Parse` if an object has a parent that does not exist.
+fn add_parent_types(model: &mut DataModel) -> Result<(), MdModelsError> {
     // Filter and clone the objects without a parent
     let parents: Vec<Object> = model
         .objects
@@ -483,7 +1486,10 @@ fn add_parent_types(model: &mut DataModel) -> Result<(), Box<dyn Error>> {
                     parent_name.red().bold(),
                 );
 
-                return Err("Object has a parent that does not exist".into());
+                return Err(MdModelsError::Parse {
+                    object: Some(object.name.clone()),
+                    message: format!("Parent '{}' does not exist", parent_name),
+                });
             }
         }
     }
@@ -495,31 +1501,1025 @@ fn add_parent_types(model: &mut DataModel) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn add_internal_types(model: &mut DataModel) {
-    // Get all datatypes within the model
-    let mut all_types = vec![];
-    for object in &model.objects {
-        for attr in &object.attributes {
-            all_types.extend(attr.dtypes.clone());
-        }
-    }
-
-    let object_names = model
+/// Adds an implicit reference attribute to the parents of nested objects.
+///
+/// A nested object `ParentChild` is referenced by the attribute `child`
+/// of `Parent`, unless `Parent` already has an attribute of this type.
+///
+/// # Arguments
+///
+/// * `model` - A mutable reference to the data model.
+fn add_nested_references(model: &mut DataModel) {
+    let nested = model
         .objects
         .iter()
-        .map(|obj| obj.name.clone())
-        .collect::<Vec<String>>();
+        .filter_map(|o| {
+            let parent = o.nested_in.as_ref()?;
+            let child = o.name.strip_prefix(parent.as_str())?;
+            Some((o.name.clone(), parent.clone(), to_snake_case(child)))
+        })
+        .collect::<Vec<(String, String, String)>>();
 
-    for (name, content) in MD_MODEL_TYPES.iter() {
-        if object_names.contains(&name.to_string()) {
+    for (name, parent, attr_name) in nested {
+        let parent = match model.objects.iter_mut().find(|o| o.name == parent) {
+            Some(parent) => parent,
+            None => continue,
+        };
+
+        if parent.attributes.iter().any(|a| a.dtypes.contains(&name)) {
             continue;
         }
 
-        if all_types.contains(&name.to_string()) {
-            model.merge(
-                &serde_json::from_str::<DataModel>(content)
-                    .expect("Failed to parse internal data type"),
-            )
-        }
+        let mut attribute = attribute::Attribute::new(attr_name, false);
+        attribute.dtypes.push(name);
+        parent.add_attribute(attribute);
+    }
+}
+
+/// Converts a type name (e.g. `PostalAddress`) to snake case (`postal_address`).
+///
+/// Digits stay attached to their word, e.g. `Test2` becomes `test2`.
+///
+/// # Arguments
+///
+/// * `name` - The name to convert.
+pub(crate) fn to_snake_case(name: &str) -> String {
+    name.from_case(Case::Pascal)
+        .without_boundaries(&Boundary::digits())
+        .to_case(Case::Snake)
+}
+
+/// Adds the conditional requirements given in the frontmatter to the objects.
+///
+/// # Arguments
+///
+/// * `model` - A mutable reference to the data model.
+fn add_conditions(model: &mut DataModel) {
+    let conditions = match model.config.as_ref().and_then(|c| c.conditions.as_ref()) {
+        Some(conditions) => conditions.clone(),
+        None => return,
+    };
+
+    for object in model.objects.iter_mut() {
+        if let Some(conditions) = conditions.get(&object.name) {
+            object.conditions = conditions.clone();
+        }
+    }
+}
+
+/// Resolves the `Self` type of attributes to the name of their enclosing object.
+///
+/// This is resolved before inheritance, such that an inherited `Self` refers
+/// to the object defining the attribute.
+///
+/// # Arguments
+///
+/// * `model` - A mutable reference to the data model.
+fn resolve_self_types(model: &mut DataModel) {
+    for object in model.objects.iter_mut() {
+        for attribute in object.attributes.iter_mut() {
+            for dtype in attribute.dtypes.iter_mut().filter(|t| *t == SELF_TYPE) {
+                *dtype = object.name.clone();
+            }
+        }
+    }
+}
+
+/// Sets the XML representation of attributes without an `XML` option.
+///
+/// Single primitive values follow the `xml-default` policy of the frontmatter,
+/// all other attributes are serialized as elements.
+///
+/// # Arguments
+///
+/// * `model` - A mutable reference to the data model.
+fn add_default_xml_types(model: &mut DataModel) {
+    let policy = model
+        .config
+        .as_ref()
+        .and_then(|c| c.xml_default)
+        .unwrap_or_default();
+
+    let primitives = PrimitiveTypes::new();
+
+    for attribute in model
+        .objects
+        .iter_mut()
+        .flat_map(|o| o.attributes.iter_mut())
+    {
+        if attribute.xml.is_some() {
+            continue;
+        }
+
+        let is_single_primitive = !attribute.is_array
+            && attribute.map_key.is_none()
+            && primitives
+                .filter_non_primitives(&attribute.dtypes)
+                .is_empty();
+
+        let name = match (policy, is_single_primitive) {
+            (XmlDefault::Attribute, true) => format!("@{}", attribute.name),
+            _ => attribute.name.clone(),
+        };
+
+        attribute.set_xml(XMLType::from_str(&name).expect("Invalid XML type"));
+    }
+}
+
+/// Resolves attribute types that refer to a named pattern of the frontmatter.
+///
+/// Each named pattern acts as a string primitive carrying a regular
+/// expression. Hence, the type is replaced by `string` and the pattern is
+/// added as an option, unless the attribute defines its own pattern.
+///
+/// # Arguments
+///
+/// * `model` - A mutable reference to the data model.
+///
+/// # Errors
+///
+/// Returns a `MdModelsError::Frontmatter` if a registered pattern is not a valid
+/// regular expression.
+fn resolve_pattern_types(model: &mut DataModel) -> Result<(), MdModelsError> {
+    let patterns = match model.config.as_ref().and_then(|c| c.patterns.as_ref()) {
+        Some(patterns) => patterns.clone(),
+        None => return Ok(()),
+    };
+
+    for (name, pattern) in patterns.iter() {
+        if let Err(e) = Regex::new(pattern) {
+            error!(
+                "[{}] {}: Pattern {} is not a valid regular expression: {}",
+                "Global".bold(),
+                "PatternError".bold(),
+                name.red().bold(),
+                e,
+            );
+
+            return Err(MdModelsError::Frontmatter {
+                message: format!("Pattern '{}' is not a valid regular expression", name),
+            });
+        }
+    }
+
+    for object in model.objects.iter_mut() {
+        for attribute in object.attributes.iter_mut() {
+            let mut pattern = None;
+            for dtype in attribute.dtypes.iter_mut() {
+                if let Some(regex) = patterns.get(dtype.as_str()) {
+                    *dtype = "string".to_string();
+                    pattern = Some(regex.clone());
+                }
+            }
+
+            let has_pattern = attribute.options.iter().any(|o| o.key == "pattern");
+            if let (Some(pattern), false) = (pattern, has_pattern) {
+                attribute
+                    .options
+                    .push(attribute::AttrOption::new("pattern".to_string(), pattern));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Derives the terms of attributes without a term from their object's term.
+///
+/// Only applies if `infer-terms` is enabled in the frontmatter. An attribute
+/// without a term of an object with the term `prefix:Name` receives the term
+/// `prefix:attributeName`, given that the prefix is defined in the `prefixes`
+/// or `nsmap` of the frontmatter.
+///
+/// # Arguments
+///
+/// * `model` - A mutable reference to the data model.
+fn infer_attribute_terms(model: &mut DataModel) {
+    let config = match &model.config {
+        Some(config) if config.infer_terms == Some(true) => config,
+        _ => return,
+    };
+
+    let is_known_prefix = |prefix: &str| {
+        [&config.prefixes, &config.nsmap]
+            .iter()
+            .any(|map| map.as_ref().is_some_and(|map| map.contains_key(prefix)))
+    };
+
+    for object in model.objects.iter_mut() {
+        let prefix = match object.term.as_ref().and_then(|term| term.split_once(':')) {
+            Some((prefix, _)) if is_known_prefix(prefix) => prefix.to_string(),
+            _ => continue,
+        };
+
+        for attribute in object.attributes.iter_mut() {
+            if attribute.term.is_none() {
+                attribute.term = Some(format!("{}:{}", prefix, attribute.name));
+            }
+        }
+    }
+}
+
+/// Converts constants of string-typed attributes to strings.
+///
+/// A constant such as `Const: 1` is parsed as an integer, but has to be
+/// kept as a string if the attribute is of type `string`.
+///
+/// # Arguments
+///
+/// * `model` - A mutable reference to the data model.
+fn resolve_string_constants(model: &mut DataModel) {
+    for object in model.objects.iter_mut() {
+        for attribute in object.attributes.iter_mut() {
+            if attribute.dtypes.first().map(String::as_str) != Some("string") {
+                continue;
+            }
+
+            if let Some(constant) = &attribute.constant {
+                attribute.constant = Some(attribute::DataType::String(format!(
+                    "\"{}\"",
+                    constant.raw_value()
+                )));
+            }
+        }
+    }
+}
+
+/// Merges the mappings of extended enumerations into their extensions.
+///
+/// Mappings of the extension override those of the extended enumeration on
+/// key collision. Extensions of unknown or cyclic enumerations are left as
+/// they are and reported by the validator.
+///
+/// # Arguments
+///
+/// * `model` - A mutable reference to the data model.
+fn resolve_enum_extensions(model: &mut DataModel) {
+    let enums = model.enums.clone();
+
+    for enumeration in model.enums.iter_mut() {
+        let mut chain = vec![];
+        let mut base = enumeration.extends.as_ref();
+
+        while let Some(name) = base {
+            match enums.iter().find(|e| e.name == *name) {
+                Some(e) if e.name != enumeration.name && !chain.contains(&e) => chain.push(e),
+                _ => break,
+            }
+            base = chain.last().and_then(|e| e.extends.as_ref());
+        }
+
+        // Skip unknown and cyclic extensions
+        if base.is_some() {
+            continue;
+        }
+
+        let mut mappings = BTreeMap::new();
+        for extended in chain.iter().rev() {
+            mappings.extend(extended.mappings.clone());
+        }
+
+        mappings.append(&mut enumeration.mappings);
+        enumeration.mappings = mappings;
+    }
+}
+
+/// Loads the mappings of enumerations referencing a vocabulary file via `@source`.
+///
+/// The format is inferred from the file extension. CSV and TSV files contain
+/// a key and a value per row, optionally preceded by a `key,value` header.
+/// JSON files contain an object mapping keys to values. Mappings given in the
+/// markdown take precedence over the loaded ones.
+///
+/// # Arguments
+///
+/// * `model` - A mutable reference to the data model.
+/// * `read` - Reads the content of a source, given relative to the model.
+///
+/// # Errors
+///
+/// Returns the error of `read` if a file cannot be read and a
+/// `MdModelsError::Parse` if its format is unsupported or its content malformed.
+pub(crate) fn load_enum_sources(
+    model: &mut DataModel,
+    read: impl Fn(&str) -> Result<String, MdModelsError>,
+) -> Result<(), MdModelsError> {
+    let mut loaded = false;
+
+    for enumeration in model.enums.iter_mut() {
+        let Some(source) = &enumeration.source else {
+            continue;
+        };
+
+        let content = read(source)?;
+        let mut mappings = parse_vocabulary(&content, Path::new(source)).map_err(|message| {
+            MdModelsError::Parse {
+                object: Some(enumeration.name.clone()),
+                message: format!("Could not load '{}': {}", source, message),
+            }
+        })?;
+
+        mappings.append(&mut enumeration.mappings);
+        enumeration.mappings = mappings;
+        loaded = true;
+    }
+
+    // Apply the extensions and IRIs to the loaded mappings
+    if loaded {
+        resolve_enum_extensions(model);
+        resolve_enum_iris(model);
+    }
+
+    Ok(())
+}
+
+/// Parses the mappings of a vocabulary file, inferring the format from its extension.
+///
+/// # Arguments
+///
+/// * `content` - The content of the file.
+/// * `path` - The path of the file.
+fn parse_vocabulary(content: &str, path: &Path) -> Result<BTreeMap<String, String>, String> {
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+
+    match extension.to_lowercase().as_str() {
+        "csv" => parse_delimited_vocabulary(content, b','),
+        "tsv" => parse_delimited_vocabulary(content, b'\t'),
+        "json" => serde_json::from_str(content)
+            .map_err(|e| format!("Expected an object mapping keys to string values ({})", e)),
+        _ => Err("Unsupported format, use a CSV, TSV or JSON file".to_string()),
+    }
+}
+
+/// Parses the rows of a delimited vocabulary file into mappings.
+///
+/// Fields may be quoted, such that values can contain the delimiter.
+///
+/// # Arguments
+///
+/// * `content` - The content of the file.
+/// * `delimiter` - The byte separating key and value.
+fn parse_delimited_vocabulary(
+    content: &str,
+    delimiter: u8,
+) -> Result<BTreeMap<String, String>, String> {
+    let mut mappings = BTreeMap::new();
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+
+    for (index, record) in reader.records().enumerate() {
+        let record = record.map_err(|e| e.to_string())?;
+        let line = record.position().map_or(index as u64 + 1, |p| p.line());
+        let fields = record.iter().collect::<Vec<&str>>();
+
+        match fields.as_slice() {
+            [] | [""] => {}
+            [key, value]
+                if index == 0
+                    && key.eq_ignore_ascii_case("key")
+                    && value.eq_ignore_ascii_case("value") => {}
+            [key, value] if !key.is_empty() => {
+                mappings.insert(key.to_string(), value.to_string());
+            }
+            _ => return Err(format!("Line {} must contain a key and a value", line)),
+        }
+    }
+
+    Ok(mappings)
+}
+
+/// Resolves the defaults of enumeration-typed attributes to enumeration keys.
+///
+/// A default such as `Default: ACTIVE` on an attribute of an enumeration type
+/// refers to the key `ACTIVE` of the enumeration. Hence, the value is stored
+/// as the plain key, which generators turn into the respective enum member.
+///
+/// # Arguments
+///
+/// * `model` - A mutable reference to the data model.
+fn resolve_enum_defaults(model: &mut DataModel) {
+    let enum_names = model
+        .enums
+        .iter()
+        .map(|e| e.name.clone())
+        .collect::<HashSet<String>>();
+
+    for object in model.objects.iter_mut() {
+        for attribute in object.attributes.iter_mut() {
+            let is_enum = attribute
+                .dtypes
+                .first()
+                .is_some_and(|dtype| enum_names.contains(dtype));
+
+            if !is_enum {
+                continue;
+            }
+
+            attribute.default = attribute.default.as_ref().map(enum_key);
+        }
+    }
+}
+
+/// Converts a default to a plain enumeration key, or a list thereof.
+fn enum_key(default: &attribute::DataType) -> attribute::DataType {
+    match default {
+        attribute::DataType::List(items) => {
+            attribute::DataType::List(items.iter().map(enum_key).collect())
+        }
+        _ => attribute::DataType::String(default.raw_value()),
+    }
+}
+
+/// Expands enumeration values given as CURIEs or IRIs.
+///
+/// CURIEs are resolved against the prefixes of the frontmatter, such that
+/// semantic generators can use the expanded IRIs of the values.
+///
+/// # Arguments
+///
+/// * `model` - A mutable reference to the data model.
+fn resolve_enum_iris(model: &mut DataModel) {
+    let prefixes = model
+        .config
+        .as_ref()
+        .and_then(|c| c.prefixes.clone())
+        .unwrap_or_default();
+
+    for enumeration in model.enums.iter_mut() {
+        enumeration.resolve_iris(&prefixes);
+    }
+}
+
+fn add_internal_types(model: &mut DataModel) {
+    // Get all datatypes within the model
+    let mut all_types = vec![];
+    for object in &model.objects {
+        for attr in &object.attributes {
+            all_types.extend(attr.dtypes.clone());
+        }
+    }
+
+    let object_names = model
+        .objects
+        .iter()
+        .map(|obj| obj.name.clone())
+        .collect::<Vec<String>>();
+
+    for (name, content) in MD_MODEL_TYPES.iter() {
+        if object_names.contains(&name.to_string()) {
+            continue;
+        }
+
+        if all_types.contains(&name.to_string()) {
+            model.merge(
+                &serde_json::from_str::<DataModel>(content)
+                    .expect("Failed to parse internal data type"),
+            )
+        }
+    }
+}
+
+/// Expands the built-in `quantity` type into a reusable `Quantity` object.
+///
+/// Attributes typed as `quantity` are retyped to `Quantity`. Unless the model
+/// defines its own `Quantity` object, one with a required `value` and `unit`
+/// is added, extended by the `quantity-fields` of the frontmatter.
+///
+/// # Arguments
+///
+/// * `model` - The data model to expand the quantity type in.
+fn add_quantity_type(model: &mut DataModel) {
+    let mut used = false;
+    for attr in model
+        .objects
+        .iter_mut()
+        .flat_map(|o| o.attributes.iter_mut())
+    {
+        for dtype in attr.dtypes.iter_mut() {
+            if dtype == attribute::QUANTITY_TYPE {
+                *dtype = attribute::QUANTITY_OBJECT.to_string();
+                used = true;
+            }
+        }
+    }
+
+    if !used
+        || model
+            .objects
+            .iter()
+            .any(|o| o.name == attribute::QUANTITY_OBJECT)
+    {
+        return;
+    }
+
+    let mut quantity = Object::new(attribute::QUANTITY_OBJECT.to_string(), None);
+    quantity.set_docstring("A numeric value paired with its unit.".to_string());
+    quantity.builtin = true;
+
+    let extra_fields = model
+        .config
+        .as_ref()
+        .and_then(|c| c.quantity_fields.clone())
+        .unwrap_or_default();
+
+    let fields = [("value", "float", true), ("unit", "string", true)]
+        .into_iter()
+        .map(|(name, dtype, required)| (name.to_string(), dtype.to_string(), required))
+        .chain(
+            extra_fields
+                .into_iter()
+                .map(|(name, dtype)| (name, dtype, false)),
+        );
+
+    for (name, dtype, required) in fields {
+        let mut attr = attribute::Attribute::new(name, required);
+        attr.dtypes.push(dtype);
+        quantity.add_attribute(attr);
+    }
+
+    model.objects.push(quantity);
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// Helper function to parse an inline markdown model.
+    fn parse_objects(content: &str) -> Vec<Object> {
+        parse_markdown(content)
+            .expect("Could not parse markdown")
+            .objects
+    }
+
+    #[test]
+    fn test_extract_object_term_last_group() {
+        // Act
+        let term = extract_object_term("Sample (draft) (schema:Sample)");
+
+        // Assert
+        assert_eq!(term, Some("schema:Sample".to_string()));
+    }
+
+    #[test]
+    fn test_extract_object_term_requires_curie() {
+        // Act
+        let trailing = extract_object_term("Sample (schema:Sample) draft");
+        let no_curie = extract_object_term("Sample (draft)");
+
+        // Assert
+        assert_eq!(trailing, None);
+        assert_eq!(no_curie, None);
+    }
+
+    #[test]
+    fn test_ignored_heading_groups() {
+        // Act
+        let ignored =
+            ignored_heading_groups("Sample (draft) (schema:Sample)", Some("schema:Sample"));
+        let no_term = ignored_heading_groups("Sample (draft)", None);
+
+        // Assert
+        assert_eq!(ignored, vec!["draft".to_string()]);
+        assert_eq!(no_term, vec!["draft".to_string()]);
+    }
+
+    #[test]
+    fn test_escaped_parens_in_fenced_code() {
+        // Arrange
+        let content = "### A\\(b\\)\n\n```\n# x \\(y\\)\n```\n";
+
+        // Act
+        let escaped = escape_heading_parens(Cow::Borrowed(content));
+
+        // Assert
+        assert!(!escaped.starts_with("### A\\("));
+        assert!(escaped.contains("# x \\(y\\)"));
+    }
+
+    #[test]
+    fn test_fenced_ranges() {
+        // Arrange
+        let content = "a\n```\nb\n```\nc\n~~~\nd";
+
+        // Act
+        let fences = fenced_ranges(content);
+
+        // Assert
+        assert_eq!(fences, vec![2..12, 14..content.len()]);
+        assert!(!is_fenced(&fences, 0));
+        assert!(is_fenced(&fences, content.find('b').unwrap()));
+        assert!(!is_fenced(&fences, content.find('c').unwrap()));
+        assert!(is_fenced(&fences, content.find('d').unwrap()));
+    }
+
+    #[test]
+    fn test_annotation_keys_in_prose() {
+        // Arrange
+        let content = "### Sample\n\nSummary: a sample taken from a vessel.\nIt is stored in the lab.\n\nSee *Table: results* for values.\n\nTable: samples\n\n- name\n  - Type: string\n";
+
+        // Act
+        let objects = parse_objects(content);
+
+        // Assert
+        assert_eq!(objects[0].summary, None);
+        assert_eq!(objects[0].table, Some("samples".to_string()));
+        assert_eq!(
+            objects[0].docstring,
+            "Summary: a sample taken from a vessel.It is stored in the lab.See Table: results for values."
+        );
+    }
+
+    #[test]
+    fn test_heading_with_escaped_parens() {
+        // Arrange
+        let content = "### Sample\\(v2\\) (schema:Sample)\n\n- name\n  - Type: string\n";
+
+        // Act
+        let objects = parse_objects(content);
+
+        // Assert
+        assert_eq!(objects[0].name, "SampleV2");
+        assert_eq!(objects[0].display_name, Some("Sample(v2)".to_string()));
+        assert_eq!(objects[0].term, Some("schema:Sample".to_string()));
+    }
+
+    #[test]
+    fn test_heading_with_escaped_parens_only() {
+        // Arrange
+        let content = "### Sample\\(schema:Sample\\)\n\n- name\n  - Type: string\n";
+
+        // Act
+        let objects = parse_objects(content);
+
+        // Assert
+        assert_eq!(objects[0].name, "SampleSchemaSample");
+        assert_eq!(
+            objects[0].display_name,
+            Some("Sample(schema:Sample)".to_string())
+        );
+        assert_eq!(objects[0].term, None);
+    }
+
+    #[test]
+    fn test_heading_with_two_groups() {
+        // Arrange
+        let content = "### Sample (draft) (schema:Sample)\n\n- name\n  - Type: string\n";
+
+        // Act
+        let objects = parse_objects(content);
+
+        // Assert
+        assert_eq!(objects[0].name, "Sample");
+        assert_eq!(objects[0].term, Some("schema:Sample".to_string()));
+    }
+
+    #[test]
+    fn test_heading_with_multiple_words() {
+        // Arrange
+        let content =
+            "### Plate Reader (allotrope:PlateReader)\n\n- name\n  - Type: string\n\n### well-plate\n\n- rows\n  - Type: integer\n";
+
+        // Act
+        let objects = parse_objects(content);
+
+        // Assert
+        assert_eq!(objects[0].name, "PlateReader");
+        assert_eq!(objects[0].display_name, Some("Plate Reader".to_string()));
+        assert_eq!(objects[0].term, Some("allotrope:PlateReader".to_string()));
+        assert_eq!(objects[1].name, "WellPlate");
+        assert_eq!(objects[1].display_name, Some("well-plate".to_string()));
+    }
+
+    #[test]
+    fn test_heading_with_single_word_has_no_display_name() {
+        // Arrange
+        let content = "### Sample (draft)\n\n- name\n  - Type: string\n";
+
+        // Act
+        let objects = parse_objects(content);
+
+        // Assert
+        assert_eq!(objects[0].name, "Sample");
+        assert_eq!(objects[0].display_name, None);
+    }
+
+    #[test]
+    fn test_heading_with_parent_and_term() {
+        // Arrange
+        let content = "### Base\n\n- id\n  - Type: string\n\n### Sample [Base] (schema:Sample)\n\n- name\n  - Type: string\n";
+
+        // Act
+        let objects = parse_objects(content);
+
+        // Assert
+        let sample = objects.iter().find(|o| o.name == "Sample").unwrap();
+        assert_eq!(sample.parent, Some("Base".to_string()));
+        assert_eq!(sample.term, Some("schema:Sample".to_string()));
+    }
+
+    #[test]
+    fn test_infer_terms_enabled() {
+        // Arrange
+        let content = "---\ninfer-terms: true\nprefixes:\n  schema: http://schema.org/\n---\n\n### Person (schema:Person)\n\n- name\n  - Type: string\n- email\n  - Type: string\n  - Term: foaf:mbox\n";
+
+        // Act
+        let objects = parse_objects(content);
+
+        // Assert
+        let person = &objects[0];
+        assert_eq!(person.attributes[0].term, Some("schema:name".to_string()));
+        assert_eq!(person.attributes[1].term, Some("foaf:mbox".to_string()));
+    }
+
+    #[test]
+    fn test_infer_terms_unknown_prefix() {
+        // Arrange
+        let content = "---\ninfer-terms: true\n---\n\n### Person (schema:Person)\n\n- name\n  - Type: string\n";
+
+        // Act
+        let objects = parse_objects(content);
+
+        // Assert
+        assert_eq!(objects[0].attributes[0].term, None);
+    }
+
+    #[test]
+    fn test_infer_terms_disabled() {
+        // Arrange
+        let content = "---\nprefixes:\n  schema: http://schema.org/\n---\n\n### Person (schema:Person)\n\n- name\n  - Type: string\n";
+
+        // Act
+        let objects = parse_objects(content);
+
+        // Assert
+        assert_eq!(objects[0].attributes[0].term, None);
+    }
+
+    #[test]
+    fn test_clean_content_borrows_plain_markdown() {
+        // Act
+        let plain = clean_content("### Sample\n\n- name\n  - Type: string\n");
+        let html = clean_content("### Sample\n\n- <b>name</b>\n  - Type: [Link](#link)\n");
+
+        // Assert
+        assert!(matches!(plain.text, Cow::Borrowed(_)));
+        assert_eq!(html.text, "### Sample\n\n- name\n  - Type: Link\n");
+    }
+
+    #[test]
+    fn test_clean_content_maps_offsets() {
+        // Arrange
+        let content =
+            "### Sample\n\n<!--\n  A comment\n-->\n\n- <b>name</b>\n  - Type: [string](#string)\n";
+
+        // Act
+        let cleaned = clean_content(content);
+        let spans = Parser::new(&cleaned.text)
+            .into_offset_iter()
+            .filter_map(|(event, range)| match event {
+                Event::Text(text) => Some((text.to_string(), range)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Assert
+        let offset = |text: &str| {
+            let (_, range) = spans.iter().find(|(t, _)| t == text).unwrap();
+            cleaned.original_offset(range.start)
+        };
+
+        assert_eq!(offset("name"), content.find("name</b>").unwrap());
+        assert_eq!(offset("Type: string"), content.find("Type: [").unwrap());
+        assert_eq!(cleaned.position(cleaned.text.find("name").unwrap()), (7, 6));
+        assert_eq!(cleaned.position(cleaned.text.len()), (9, 1));
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        // Arrange
+        let content = "### Sample\n\n<!--\n  A comment\n-->\n\n- <b>name</b>\n  - Type: [string](#string)\n  - Inline: maybe\n";
+
+        // Act
+        let result = parse_markdown_unvalidated(content);
+
+        // Assert
+        let message = match result {
+            Err(MdModelsError::Parse { message, .. }) => message,
+            _ => String::new(),
+        };
+        assert!(message.ends_with("(line 9, column 3)"), "{}", message);
+    }
+
+    #[test]
+    fn test_option_without_separator() {
+        // Act
+        let option = process_option("Type string", "Sample");
+        let model = parse_markdown_unvalidated("### Sample\n\n- name\n  - Type string\n");
+
+        // Assert
+        assert!(matches!(
+            option,
+            Err(MdModelsError::Parse { object: Some(object), .. }) if object == "Sample"
+        ));
+        assert!(model.is_ok());
+    }
+
+    #[test]
+    fn test_item_without_name() {
+        // Act
+        let result = parse_markdown_unvalidated("### Sample\n\n- name\n  - Type: string\n-\n");
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(MdModelsError::Parse { object: Some(object), .. }) if object == "Sample"
+        ));
+    }
+
+    #[test]
+    fn test_split_documents() {
+        // Arrange
+        let content = "---\nprefix: a\n---\n\n### A\n\nHeading\n---\n\n```\n\n---\n```\n\n---\n---\nprefix: b\n---\n### B\n\n---\n";
+
+        // Act
+        let documents = split_documents(content);
+
+        // Assert
+        assert_eq!(
+            documents,
+            vec![
+                "---\nprefix: a\n---\n\n### A\n\nHeading\n---\n\n```\n\n---\n```\n\n",
+                "---\nprefix: b\n---\n### B\n\n",
+            ]
+        );
+    }
+
+    /// Helper function to parse enumeration mappings into a new enumeration.
+    fn parse_mappings(mappings: &str) -> BTreeMap<String, String> {
+        let mut enum_obj = Enumeration {
+            name: "Sample".to_string(),
+            mappings: BTreeMap::new(),
+            docstring: "".to_string(),
+            open: false,
+            iris: BTreeMap::new(),
+            extends: None,
+            source: None,
+        };
+        process_enum_mappings(&mut enum_obj, mappings.to_string());
+        enum_obj.mappings
+    }
+
+    #[test]
+    fn test_enum_mapping_separators() {
+        // Arrange
+        let expected = BTreeMap::from([
+            (
+                "ECO".to_string(),
+                "https://www.evidenceontology.org/term/".to_string(),
+            ),
+            (
+                "GO".to_string(),
+                "https://amigo.geneontology.org/amigo/term/".to_string(),
+            ),
+        ]);
+
+        // Act
+        let equals = parse_mappings(
+            "ECO = https://www.evidenceontology.org/term/\nGO = https://amigo.geneontology.org/amigo/term/",
+        );
+        let colon = parse_mappings(
+            "ECO: https://www.evidenceontology.org/term/\nGO: https://amigo.geneontology.org/amigo/term/",
+        );
+        let arrow = parse_mappings(
+            "ECO -> https://www.evidenceontology.org/term/\nGO -> https://amigo.geneontology.org/amigo/term/",
+        );
+        let quoted = parse_mappings(
+            "\"ECO\" : \"https://www.evidenceontology.org/term/\"\n\"GO\"->\"https://amigo.geneontology.org/amigo/term/\"",
+        );
+
+        // Assert
+        assert_eq!(equals, expected);
+        assert_eq!(colon, expected);
+        assert_eq!(arrow, expected);
+        assert_eq!(quoted, expected);
+    }
+
+    #[test]
+    fn test_object_with_example_block() {
+        // Act
+        let model = parse_markdown_unvalidated(
+            "### Sample\n\nExample:\n\n```json\n{\n  \"name\": \"x\"\n}\n```\n\n- name\n  - Type: string\n",
+        )
+        .expect("Could not parse markdown");
+
+        // Assert
+        assert_eq!(model.objects.len(), 1);
+        assert!(model.enums.is_empty());
+    }
+
+    #[test]
+    fn test_enum_mapping_first_separator() {
+        // Act
+        let mappings = parse_mappings("A -> x = y\nB = x: y\nC: x -> y");
+
+        // Assert
+        assert_eq!(mappings["A"], "x = y");
+        assert_eq!(mappings["B"], "x: y");
+        assert_eq!(mappings["C"], "x -> y");
+    }
+
+    #[test]
+    fn test_enum_mapping_skips_comments() {
+        // Act
+        let mappings =
+            parse_mappings("# KEY = comment\n\n// OTHER: comment\nVALUE = value\nno separator");
+
+        // Assert
+        assert_eq!(
+            mappings,
+            BTreeMap::from([("VALUE".to_string(), "value".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_vocabulary_formats() {
+        // Act
+        let tsv = parse_vocabulary("KEY\tVALUE\nA\t\"a\"\n", Path::new("v.tsv"));
+        let json = parse_vocabulary(r#"{"A": "a"}"#, Path::new("v.json"));
+        let malformed = parse_vocabulary("A,a\nB\n", Path::new("v.csv"));
+        let quoted = parse_vocabulary("A,\"a, b\"\n\"B\",\"say \"\"b\"\"\"\n", Path::new("v.csv"));
+        let unsupported = parse_vocabulary("A,a", Path::new("v.txt"));
+
+        // Assert
+        let expected = BTreeMap::from([("A".to_string(), "a".to_string())]);
+        assert_eq!(tsv, Ok(expected.clone()));
+        assert_eq!(json, Ok(expected));
+        assert_eq!(
+            malformed,
+            Err("Line 2 must contain a key and a value".to_string())
+        );
+        assert_eq!(
+            quoted,
+            Ok(BTreeMap::from([
+                ("A".to_string(), "a, b".to_string()),
+                ("B".to_string(), "say \"b\"".to_string()),
+            ]))
+        );
+        assert!(unsupported.is_err());
+    }
+
+    #[test]
+    fn test_resolve_type_kinds() {
+        // Act
+        let objects = parse_objects(
+            "### Sample\n\n- name\n  - Type: string\n- origin\n  - Type: Location\n- status\n  - Type: Status\n- mixed\n  - Type: Location, Status, float\n\n### Location\n\n- city\n  - Type: string\n\n## Enumerations\n\n### Status\n\n```\nOPEN = open\n```\n",
+        );
+
+        // Assert
+        let kinds = objects[0]
+            .attributes
+            .iter()
+            .map(|a| a.kinds.clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            kinds,
+            vec![
+                vec![attribute::TypeKind::Primitive],
+                vec![attribute::TypeKind::Object],
+                vec![attribute::TypeKind::Enum],
+                vec![
+                    attribute::TypeKind::Object,
+                    attribute::TypeKind::Enum,
+                    attribute::TypeKind::Primitive,
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_type_kinds_undefined() {
+        // Act
+        let model = parse_markdown_unvalidated("### Sample\n\n- origin\n  - Type: Missing\n")
+            .expect("Could not parse markdown");
+
+        // Assert
+        assert_eq!(
+            model.objects[0].attributes[0].kinds,
+            vec![attribute::TypeKind::Undefined]
+        );
+    }
+
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("PostalAddress"), "postal_address");
+        assert_eq!(to_snake_case("Test2"), "test2");
+        assert_eq!(to_snake_case("HTTPServer"), "http_server");
     }
 }
@@ -0,0 +1,146 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use crate::error::MdModelsError;
+
+/// Marker emphasizing the names of required attributes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RequiredMarker {
+    /// Underscores, e.g. `__name__`.
+    #[default]
+    Underscore,
+    /// Asterisks, e.g. `**name**`.
+    Asterisk,
+}
+
+/// Style of the markdown written by the markdown template.
+///
+/// The default style reproduces the canonical style of MD-Models, i.e. an
+/// indent of two spaces, required attributes marked by underscores and
+/// enumeration fences without a language tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownStyle {
+    /// Number of spaces the options of an attribute are indented by.
+    pub indent: usize,
+    /// Marker emphasizing the names of required attributes.
+    pub required: RequiredMarker,
+    /// Language tag of the code fences of enumerations, e.g. `python`.
+    pub fence: Option<String>,
+}
+
+impl Default for MarkdownStyle {
+    fn default() -> Self {
+        MarkdownStyle {
+            indent: 2,
+            required: RequiredMarker::default(),
+            fence: None,
+        }
+    }
+}
+
+impl MarkdownStyle {
+    /// Creates a style taking the indent width from an `.editorconfig` file.
+    ///
+    /// The `indent_size` of the last section matching markdown files, e.g.
+    /// `[*]` or `[*.md]`, is used. All other settings keep their defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the `.editorconfig` file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the file cannot be read.
+    pub fn from_editorconfig(path: &Path) -> Result<Self, MdModelsError> {
+        let content = fs::read_to_string(path).map_err(|e| MdModelsError::io(path, e))?;
+        let mut style = MarkdownStyle::default();
+        let mut matches = false;
+
+        for line in content.lines().map(str::trim) {
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                matches = section_matches_markdown(section);
+            } else if let Some((key, value)) = line.split_once('=') {
+                if matches && key.trim() == "indent_size" {
+                    if let Ok(indent) = value.trim().parse() {
+                        style.indent = indent;
+                    }
+                }
+            }
+        }
+
+        Ok(style)
+    }
+
+    /// Converts the style into the configuration of the markdown template.
+    ///
+    /// # Returns
+    ///
+    /// The configuration, holding the `indent`, `required` and `fence` keys.
+    pub fn to_config(&self) -> HashMap<String, String> {
+        let required = match self.required {
+            RequiredMarker::Underscore => "underscore",
+            RequiredMarker::Asterisk => "asterisk",
+        };
+
+        let mut config = HashMap::from([
+            ("indent".to_string(), self.indent.to_string()),
+            ("required".to_string(), required.to_string()),
+        ]);
+
+        if let Some(fence) = &self.fence {
+            config.insert("fence".to_string(), fence.clone());
+        }
+
+        config
+    }
+}
+
+/// Checks if an `.editorconfig` section applies to markdown files.
+///
+/// # Arguments
+///
+/// * `section` - The glob of the section, e.g. `*.{md,txt}`.
+fn section_matches_markdown(section: &str) -> bool {
+    let extensions = match section.strip_prefix("*.") {
+        Some(extensions) => extensions,
+        None => return section == "*",
+    };
+
+    extensions
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .split(',')
+        .any(|extension| extension.trim() == "md")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_section_matches_markdown() {
+        assert!(section_matches_markdown("*"));
+        assert!(section_matches_markdown("*.md"));
+        assert!(section_matches_markdown("*.{md,txt}"));
+        assert!(!section_matches_markdown("*.rs"));
+        assert!(!section_matches_markdown("Makefile"));
+    }
+
+    #[test]
+    fn test_to_config() {
+        // Arrange
+        let style = MarkdownStyle {
+            indent: 4,
+            required: RequiredMarker::Asterisk,
+            fence: Some("python".to_string()),
+        };
+
+        // Act
+        let config = style.to_config();
+
+        // Assert
+        assert_eq!(config["indent"], "4");
+        assert_eq!(config["required"], "asterisk");
+        assert_eq!(config["fence"], "python");
+    }
+}
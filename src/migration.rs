@@ -0,0 +1,417 @@
+use crate::{
+    attribute::{Attribute, DataType, TypeKind},
+    datamodel::DataModel,
+    diff::{Change, ModelDiff},
+    exporters::{rust_field, rust_variant},
+    object::{Enumeration, Object},
+};
+
+/// Generates Rust conversion stubs from a previous to the current version of a model.
+///
+/// Both versions are expected to be generated by the `rust` template into the
+/// sibling modules `old` and `new`. For every object and enumeration present in
+/// both versions, a `From` implementation is generated. Attributes that cannot be
+/// converted automatically are marked with `todo!()`, removed attributes with a
+/// `TODO` comment.
+///
+/// # Arguments
+///
+/// * `old` - The previous version of the data model.
+/// * `new` - The current version of the data model.
+///
+/// # Returns
+///
+/// The Rust source code of the conversion stubs.
+pub fn rust_migration(old: &DataModel, new: &DataModel) -> String {
+    let diff = old.diff(new);
+    let mut out = String::from(
+        "//! This file contains conversions from the previous to the current version of the data model.\n\
+         //!\n\
+         //! WARNING: This is an auto-generated file.\n\
+         //! Review all TODO markers before use.\n\
+         \n\
+         use super::{new, old};\n",
+    );
+
+    for new_obj in &new.objects {
+        if let Some(old_obj) = old.objects.iter().find(|o| o.name == new_obj.name) {
+            out.push('\n');
+            out.push_str(&object_conversion(old_obj, new_obj, &diff));
+        }
+    }
+
+    for new_enum in &new.enums {
+        if let Some(old_enum) = old.enums.iter().find(|e| e.name == new_enum.name) {
+            out.push('\n');
+            out.push_str(&enum_conversion(old_enum, new_enum));
+        }
+    }
+
+    out
+}
+
+/// Generates the `From` implementation between two versions of an object.
+fn object_conversion(old: &Object, new: &Object, diff: &ModelDiff) -> String {
+    let name = &new.name;
+    let mut fields = vec![];
+
+    for attr in &new.attributes {
+        let field = rust_field(&attr.name);
+        let value = match old.attributes.iter().find(|a| a.name == attr.name) {
+            Some(old_attr) => convert_attribute(&new.name, old_attr, attr, diff),
+            None => added_attribute(attr),
+        };
+
+        fields.push(format!("            {}: {},\n", field, value));
+    }
+
+    for attr in &old.attributes {
+        if !new.attributes.iter().any(|a| a.name == attr.name) {
+            fields.push(format!(
+                "            // TODO: Property '{}' was removed\n",
+                attr.name
+            ));
+        }
+    }
+
+    format!(
+        "impl From<old::{name}> for new::{name} {{\n    \
+             #[allow(unused_variables, unreachable_code)]\n    \
+             fn from(value: old::{name}) -> Self {{\n        \
+                 Self {{\n{fields}        }}\n    \
+             }}\n\
+         }}\n",
+        name = name,
+        fields = fields.concat(),
+    )
+}
+
+/// Returns the expression converting an attribute present in both versions.
+fn convert_attribute(object: &str, old: &Attribute, new: &Attribute, diff: &ModelDiff) -> String {
+    let changed = diff.changes.iter().find(|change| match change {
+        Change::AttributeRetyped {
+            object: o,
+            attribute,
+            ..
+        }
+        | Change::MultiplicityChanged {
+            object: o,
+            attribute,
+            ..
+        }
+        | Change::SizeChanged {
+            object: o,
+            attribute,
+            ..
+        }
+        | Change::TupleChanged {
+            object: o,
+            attribute,
+            ..
+        }
+        | Change::MapKeyChanged {
+            object: o,
+            attribute,
+            ..
+        } => o == object && *attribute == new.name,
+        _ => false,
+    });
+
+    if let Some(change) = changed {
+        return format!("todo!(\"{}\")", change);
+    }
+
+    let source = format!("value.{}", rust_field(&new.name));
+    let value = convert_value(&source, object, new, is_optional(old));
+
+    match (is_optional(old), is_optional(new)) {
+        (true, false) => match &new.default {
+            Some(default) => format!("{}.unwrap_or({})", value, default_value(new, default)),
+            None => format!(
+                "{}.unwrap_or_else(|| todo!(\"Property '{}' is now required\"))",
+                value, new.name
+            ),
+        },
+        (false, true) => format!("Some({})", value),
+        _ => value,
+    }
+}
+
+/// Returns the expression for an attribute that only exists in the new version.
+fn added_attribute(attr: &Attribute) -> String {
+    match (container(attr), &attr.default) {
+        (Container::Map, _) => "std::collections::HashMap::new()".to_string(),
+        (_, Some(default)) => default_value(attr, default),
+        (Container::Vec, None) => "Vec::new()".to_string(),
+        (Container::Option, None) => "None".to_string(),
+        (Container::Plain, None) => format!("todo!(\"Property '{}' was added\")", attr.name),
+    }
+}
+
+/// Returns the expression converting a value to the type of the new version.
+///
+/// Values of objects and enumerations are converted using their `From`
+/// implementations, primitives are copied. Self-references are boxed by the
+/// `rust` template and thus need to be unboxed first.
+fn convert_value(source: &str, object: &str, attr: &Attribute, optional: bool) -> String {
+    if !is_reference(attr) {
+        return source.to_string();
+    }
+
+    let boxed = attr.dtypes[0] == object;
+    let sized = attr.size.is_some();
+    match container(attr) {
+        Container::Map if attr.is_array => {
+            return format!(
+            "{}.into_iter().map(|(k, v)| (k, v.into_iter().map(Into::into).collect())).collect()",
+            source
+        )
+        }
+        Container::Map => {
+            return format!(
+                "{}.into_iter().map(|(k, v)| (k, v.into())).collect()",
+                source
+            )
+        }
+        Container::Vec => return format!("{}.into_iter().map(Into::into).collect()", source),
+        _ => {}
+    }
+
+    if sized && optional {
+        format!("{}.map(|v| v.map(Into::into))", source)
+    } else if sized {
+        format!("{}.map(Into::into)", source)
+    } else if boxed && optional {
+        format!("{}.map(|v| Box::new((*v).into()))", source)
+    } else if boxed {
+        format!("Box::new((*{}).into())", source)
+    } else if optional {
+        format!("{}.map(Into::into)", source)
+    } else {
+        format!("{}.into()", source)
+    }
+}
+
+/// Returns the Rust literal of the default value of an attribute.
+fn default_value(attr: &Attribute, default: &DataType) -> String {
+    let dtype = attr.dtypes.first().map(String::as_str).unwrap_or_default();
+    match default {
+        DataType::List(items) => {
+            let items = items
+                .iter()
+                .map(|item| default_value(attr, item))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            // Fixed-size arrays are plain Rust arrays
+            match attr.size {
+                Some(_) => format!("[{}]", items),
+                None => format!("vec![{}]", items),
+            }
+        }
+        DataType::String(_) if !matches!(dtype, "string" | "date" | "datetime") => {
+            format!("new::{}::{}", dtype, rust_variant(&default.raw_value()))
+        }
+        DataType::String(_) => format!("{:?}.to_string()", default.raw_value()),
+        DataType::Integer(i) if matches!(dtype, "float" | "number") => format!("{}.0", i),
+        DataType::Float(f) if f.fract() == 0.0 => format!("{:.1}", f),
+        _ => default.raw_value(),
+    }
+}
+
+/// The container the `rust` template wraps the value of an attribute in.
+#[derive(Debug, PartialEq)]
+enum Container {
+    /// A `HashMap` of an attribute with a map key.
+    Map,
+    /// A `Vec` of an array without a fixed size.
+    Vec,
+    /// An `Option` of an attribute that may be missing.
+    Option,
+    /// The plain value of a required or defaulted attribute, or a fixed-size array.
+    Plain,
+}
+
+/// Returns the container of an attribute, following `get_type` of the `rust` template.
+fn container(attr: &Attribute) -> Container {
+    if attr.map_key.is_some() {
+        Container::Map
+    } else if attr.is_array && attr.size.is_none() {
+        Container::Vec
+    } else if attr.required || attr.default.is_some() {
+        Container::Plain
+    } else {
+        Container::Option
+    }
+}
+
+/// Checks whether an attribute is represented as an `Option` by the `rust` template.
+fn is_optional(attr: &Attribute) -> bool {
+    container(attr) == Container::Option
+}
+
+/// Checks whether an attribute refers to a single object or enumeration of the model.
+fn is_reference(attr: &Attribute) -> bool {
+    matches!(attr.kinds.as_slice(), [TypeKind::Object | TypeKind::Enum])
+}
+
+/// Generates the `From` implementation between two versions of an enumeration.
+///
+/// Open enumerations carry unknown values in an `Other` variant. Values removed
+/// from an open enumeration are kept as `Other`, whereas unknown values of an
+/// enumeration that was closed cannot be converted automatically.
+fn enum_conversion(old: &Enumeration, new: &Enumeration) -> String {
+    let mut arms = old
+        .mappings
+        .iter()
+        .map(
+            |(key, value)| match (new.mappings.contains_key(key), new.open) {
+                (true, _) => format!(
+                    "            old::{name}::{variant} => Self::{variant},\n",
+                    name = old.name,
+                    variant = rust_variant(key)
+                ),
+                (false, true) => format!(
+                    "            old::{}::{} => Self::Other({:?}.to_string()),\n",
+                    old.name,
+                    rust_variant(key),
+                    value
+                ),
+                (false, false) => format!(
+                    "            old::{}::{} => todo!(\"Value '{}' was removed\"),\n",
+                    old.name,
+                    rust_variant(key),
+                    key
+                ),
+            },
+        )
+        .collect::<String>();
+
+    match (old.open, new.open) {
+        (true, true) => arms.push_str(&format!(
+            "            old::{}::Other(value) => Self::Other(value),\n",
+            old.name
+        )),
+        (true, false) => arms.push_str(&format!(
+            "            old::{}::Other(_) => todo!(\"Enumeration '{}' is no longer open\"),\n",
+            old.name, new.name
+        )),
+        _ => {}
+    }
+
+    format!(
+        "impl From<old::{name}> for new::{name} {{\n    \
+             fn from(value: old::{name}) -> Self {{\n        \
+                 match value {{\n{arms}        }}\n    \
+             }}\n\
+         }}\n",
+        name = new.name,
+        arms = arms,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Helper function to parse a model from a markdown string.
+    fn model(content: &str) -> DataModel {
+        DataModel::from_markdown_string(content).expect("Could not parse markdown")
+    }
+
+    #[test]
+    fn test_rename_stub() {
+        // Arrange
+        let old = model("### Sample\n\n- __id__\n  - Type: string\n- name\n  - Type: string\n");
+        let new =
+            model("### Sample\n\n- __id__\n  - Type: string\n- __title__\n  - Type: string\n");
+
+        // Act
+        let stub = rust_migration(&old, &new);
+
+        // Assert
+        assert!(stub.contains("impl From<old::Sample> for new::Sample {"));
+        assert!(stub.contains("            id: value.id,\n"));
+        assert!(stub.contains("            title: todo!(\"Property 'title' was added\"),\n"));
+        assert!(stub.contains("            // TODO: Property 'name' was removed\n"));
+    }
+
+    #[test]
+    fn test_retyped_and_required_stub() {
+        // Arrange
+        let old = model(
+            "### Sample\n\n- value\n  - Type: integer\n- count\n  - Type: integer\n- unit\n  - Type: Unit\n\n## Enumerations\n\n### Unit\n\n```\nLITRE = l\nGRAM = g\n```\n",
+        );
+        let new = model(
+            "### Sample\n\n- value\n  - Type: string\n- __count__\n  - Type: integer\n  - Default: 1\n- unit\n  - Type: Unit\n\n## Enumerations\n\n### Unit\n\n```\nLITRE = l\n```\n",
+        );
+
+        // Act
+        let stub = rust_migration(&old, &new);
+
+        // Assert
+        assert!(stub.contains("            value: todo!("));
+        assert!(stub.contains("            count: value.count.unwrap_or(1),\n"));
+        assert!(stub.contains("            unit: value.unit.map(Into::into),\n"));
+        assert!(stub.contains("            old::Unit::Litre => Self::Litre,\n"));
+        assert!(
+            stub.contains("            old::Unit::Gram => todo!(\"Value 'GRAM' was removed\"),\n")
+        );
+    }
+
+    #[test]
+    fn test_open_enum_stub() {
+        // Arrange
+        let old = model(
+            "### Sample\n\n- unit\n  - Type: Unit\n- kind\n  - Type: Kind\n\n## Enumerations\n\n### Unit\n\nOpen: true\n\n```\nLITRE = l\nGRAM = g\n```\n\n### Kind\n\nOpen: true\n\n```\nA = a\n```\n",
+        );
+        let new = model(
+            "### Sample\n\n- unit\n  - Type: Unit\n- kind\n  - Type: Kind\n\n## Enumerations\n\n### Unit\n\nOpen: true\n\n```\nLITRE = l\n```\n\n### Kind\n\n```\nA = a\n```\n",
+        );
+
+        // Act
+        let stub = rust_migration(&old, &new);
+
+        // Assert
+        assert!(stub.contains("            old::Unit::Gram => Self::Other(\"g\".to_string()),\n"));
+        assert!(stub.contains("            old::Unit::Other(value) => Self::Other(value),\n"));
+        assert!(stub.contains(
+            "            old::Kind::Other(_) => todo!(\"Enumeration 'Kind' is no longer open\"),\n"
+        ));
+    }
+
+    #[test]
+    fn test_added_container_stubs() {
+        // Arrange
+        let old = model("### Sample\n\n- __id__\n  - Type: string\n");
+        let new = model(
+            "### Sample\n\n- __id__\n  - Type: string\n- scores\n  - Type: map<string, float>\n- position\n  - Type: float[3]\n- tags\n  - Type: string[]\n  - Default: [C:\\data, b]\n- label\n  - Type: string\n  - Default: a \"b\" c\n",
+        );
+
+        // Act
+        let stub = rust_migration(&old, &new);
+
+        // Assert
+        assert!(stub.contains("            scores: std::collections::HashMap::new(),\n"));
+        assert!(stub.contains("            position: None,\n"));
+        assert!(stub
+            .contains("            tags: vec![\"C:\\\\data\".to_string(), \"b\".to_string()],\n"));
+        assert!(stub.contains("            label: \"a \\\"b\\\" c\".to_string(),\n"));
+    }
+
+    #[test]
+    fn test_converted_container_stubs() {
+        // Arrange
+        let content = "### Sample\n\n- units\n  - Type: map<string, Unit>\n- corners\n  - Type: Unit[2]\n\n## Enumerations\n\n### Unit\n\n```\nLITRE = l\n```\n";
+        let (old, new) = (model(content), model(content));
+
+        // Act
+        let stub = rust_migration(&old, &new);
+
+        // Assert
+        assert!(stub.contains(
+            "            units: value.units.into_iter().map(|(k, v)| (k, v.into())).collect(),\n"
+        ));
+        assert!(stub.contains("            corners: value.corners.map(|v| v.map(Into::into)),\n"));
+    }
+}
@@ -0,0 +1,638 @@
+use serde_json::{json, Map, Value};
+
+use crate::{
+    attribute::{Attribute, DataType},
+    datamodel::DataModel,
+    error::MdModelsError,
+    exporters::Templates,
+    markdown::frontmatter::TimestampFormat,
+    object::Object,
+    primitives::{integer_range, is_integer},
+};
+
+/// Depth up to which referenced objects are generated.
+///
+/// Deeper optional references are omitted and arrays are left empty, such that
+/// recursive models terminate. Required references are still generated.
+const MAX_DEPTH: usize = 3;
+
+/// Maximum number of items of generated arrays and maps.
+const MAX_ITEMS: u64 = 3;
+
+/// Range of generated dates, given as days since 1970-01-01 (2000-01-01 to 2030-12-31).
+const DATE_RANGE: (i64, i64) = (10957, 22279);
+
+const FIRST_NAMES: [&str; 12] = [
+    "Ada",
+    "Alan",
+    "Barbara",
+    "Claude",
+    "Donald",
+    "Edsger",
+    "Frances",
+    "Grace",
+    "John",
+    "Katherine",
+    "Linus",
+    "Margaret",
+];
+
+const LAST_NAMES: [&str; 12] = [
+    "Allen", "Backus", "Dijkstra", "Hamilton", "Hopper", "Johnson", "Knuth", "Liskov", "Lovelace",
+    "Shannon", "Torvalds", "Turing",
+];
+
+const WORDS: [&str; 16] = [
+    "alpha", "buffer", "catalyst", "delta", "enzyme", "flask", "gamma", "helix", "isotope",
+    "kinetics", "lambda", "molecule", "nucleus", "omega", "protein", "sample",
+];
+
+const CITIES: [&str; 8] = [
+    "Amsterdam",
+    "Berlin",
+    "Boston",
+    "Kyoto",
+    "Lisbon",
+    "Stuttgart",
+    "Toronto",
+    "Zurich",
+];
+
+const DOMAINS: [&str; 4] = ["example.com", "example.org", "example.net", "test.org"];
+
+/// Characters of base64 encoded binary data.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Pseudo-random number generator based on SplitMix64.
+///
+/// Only integer arithmetic is used, such that a seed yields the same
+/// sequence on every platform.
+struct Rng(u64);
+
+impl Rng {
+    /// Returns the next pseudo-random number.
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number within the inclusive range `[min, max]`.
+    fn range(&mut self, min: i64, max: i64) -> i64 {
+        let span = max.abs_diff(min).saturating_add(1);
+        min.wrapping_add((self.next_u64() % span) as i64)
+    }
+
+    /// Returns a float within `[min, max)`, rounded to two decimals.
+    fn float(&mut self, min: f64, max: f64) -> f64 {
+        let fraction = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        ((min + fraction * (max - min)) * 100.0).round() / 100.0
+    }
+
+    /// Returns whether an event with the probability `numerator / denominator` occurs.
+    fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.next_u64() % denominator < numerator
+    }
+
+    /// Picks an element of a non-empty slice.
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[(self.next_u64() % items.len() as u64) as usize]
+    }
+}
+
+/// Generates pseudo-random instances of the objects of a data model.
+struct MockGenerator<'a> {
+    model: &'a DataModel,
+    rng: Rng,
+}
+
+/// Generates reproducible mock instances of an object.
+///
+/// Values are drawn from pools of names, words and cities, numbers respect
+/// the `Minimum` and `Maximum` options and the range of fixed-width integers,
+/// enumerations take one of their values and optional references are
+/// generated up to a bounded depth. Abstract objects are replaced by one of
+/// their concrete subtypes. The same seed always yields the same instances.
+///
+/// # Arguments
+///
+/// * `name` - The name of the object to generate.
+/// * `model` - The data model containing the objects and enums.
+/// * `count` - The number of instances to generate.
+/// * `seed` - The seed of the pseudo-random number generator.
+///
+/// # Errors
+///
+/// Returns a `MdModelsError::Generation` if the object does not exist, is
+/// abstract without concrete subtypes or has a cycle of required references.
+pub fn to_mock_json(
+    name: &str,
+    model: &DataModel,
+    count: usize,
+    seed: u64,
+) -> Result<String, MdModelsError> {
+    let object = model
+        .objects
+        .iter()
+        .find(|o| o.name == name)
+        .ok_or_else(|| {
+            MdModelsError::generation(
+                Templates::MockJson,
+                format!("Object '{}' not found in the model", name),
+            )
+        })?;
+
+    if object.is_abstract && model.concrete_subtypes(name).is_empty() {
+        return Err(MdModelsError::generation(
+            Templates::MockJson,
+            format!("Object '{}' is abstract and has no concrete subtypes", name),
+        ));
+    }
+
+    if let Some(cycle) = required_cycle(object, model, &mut vec![]) {
+        return Err(MdModelsError::generation(
+            Templates::MockJson,
+            format!(
+                "Object '{}' has a cycle of required references ({}) that cannot be terminated",
+                name, cycle
+            ),
+        ));
+    }
+
+    let mut generator = MockGenerator {
+        model,
+        rng: Rng(seed),
+    };
+
+    let mut instances = Vec::with_capacity(count);
+    for _ in 0..count {
+        if let Some(object) = generator.concrete(object) {
+            instances.push(generator.object(object, 0));
+        }
+    }
+
+    serde_json::to_string_pretty(&instances)
+        .map_err(|e| MdModelsError::generation(Templates::MockJson, e.to_string()))
+}
+
+impl<'a> MockGenerator<'a> {
+    /// Generates an instance of an object.
+    fn object(&mut self, object: &Object, depth: usize) -> Value {
+        let mut instance = Map::new();
+
+        for attr in &object.attributes {
+            if !attr.required && !self.rng.chance(3, 4) {
+                continue;
+            }
+
+            if let Some(value) = self.attribute(object, attr, depth) {
+                instance.insert(attr.name.clone(), value);
+            }
+        }
+
+        Value::Object(instance)
+    }
+
+    /// Generates the value of an attribute, if any.
+    fn attribute(&mut self, object: &Object, attr: &Attribute, depth: usize) -> Option<Value> {
+        if let Some(constant) = &attr.constant {
+            return Some(constant_value(constant));
+        }
+
+        // Beyond the maximum depth, prefer types that do not reference objects
+        let mut dtypes = attr.dtypes.iter().collect::<Vec<&String>>();
+        if depth >= MAX_DEPTH && dtypes.iter().any(|d| self.find_object(d).is_none()) {
+            dtypes.retain(|d| self.find_object(d).is_none());
+        }
+
+        let dtype = match dtypes.as_slice() {
+            [] => return None,
+            dtypes => self.rng.pick(dtypes).to_string(),
+        };
+
+        // Stop at the maximum depth to terminate recursive references
+        if depth >= MAX_DEPTH && self.find_object(&dtype).is_some() {
+            match (&attr.map_key, attr.is_array) {
+                (Some(_), _) => return Some(json!({})),
+                (None, true) if attr.size.is_none() => return Some(json!([])),
+                (None, true) if !attr.required => return None,
+                (None, true) => {}
+                (None, false) if !attr.required => return None,
+                (None, false) => {}
+            }
+        }
+
+        // Tuples hold one item per data type, fixed-size arrays exactly `size` items
+        let value = |generator: &mut Self| match (attr.tuple, attr.is_array) {
+            (true, _) => Value::Array(
+                attr.dtypes
+                    .iter()
+                    .map(|dtype| generator.value(object, attr, dtype, depth))
+                    .collect(),
+            ),
+            (false, true) => {
+                let items = match attr.size {
+                    Some(size) => size,
+                    None => generator.rng.range(1, MAX_ITEMS as i64) as usize,
+                };
+                Value::Array(
+                    (0..items)
+                        .map(|_| generator.value(object, attr, &dtype, depth))
+                        .collect(),
+                )
+            }
+            (false, false) => generator.value(object, attr, &dtype, depth),
+        };
+
+        match attr.map_key {
+            Some(_) => {
+                let items = self.rng.range(1, MAX_ITEMS as i64);
+                let map = (1..=items)
+                    .map(|i| (format!("key_{}", i), value(self)))
+                    .collect::<Map<String, Value>>();
+                Some(Value::Object(map))
+            }
+            None => Some(value(self)),
+        }
+    }
+
+    /// Generates a single value of a data type.
+    fn value(&mut self, object: &Object, attr: &Attribute, dtype: &str, depth: usize) -> Value {
+        if let Some(referenced) = self.find_object(dtype) {
+            return match self.concrete(referenced) {
+                Some(referenced) => self.object(referenced, depth + 1),
+                None => Value::Null,
+            };
+        }
+
+        if let Some(enumeration) = self.model.enums.iter().find(|e| e.name == dtype) {
+            let values = enumeration.mappings.values().collect::<Vec<_>>();
+            return match values.is_empty() {
+                true => Value::Null,
+                false => json!(self.rng.pick(&values)),
+            };
+        }
+
+        match dtype {
+            dtype if is_integer(dtype) => {
+                let (lower, upper) = integer_range(dtype).unwrap_or((i64::MIN, u64::MAX));
+                let upper = i64::try_from(upper).unwrap_or(i64::MAX);
+                let min = option_number(attr, "minimum")
+                    .map_or(0, |v| v.ceil() as i64)
+                    .clamp(lower, upper);
+                let max = option_number(attr, "maximum")
+                    .map_or(min.max(0).saturating_add(100), |v| v as i64)
+                    .clamp(min, upper);
+                json!(self.rng.range(min, max))
+            }
+            "float" | "number" => {
+                let min = option_number(attr, "minimum").unwrap_or(0.0);
+                let max = option_number(attr, "maximum").unwrap_or(min.max(0.0) + 100.0);
+                json!(self.rng.float(min, max.max(min)))
+            }
+            "boolean" | "bool" => json!(self.rng.chance(1, 2)),
+            "date" => json!(self.date()),
+            "bytes" => json!(self.base64()),
+            "datetime" => json!(self.datetime()),
+            "timestamp" => match self.timestamps() {
+                TimestampFormat::Integer => {
+                    json!(self
+                        .rng
+                        .range(DATE_RANGE.0 * 86400, DATE_RANGE.1 * 86400 + 86399))
+                }
+                TimestampFormat::String => json!(self.datetime()),
+            },
+            "duration" => {
+                let minutes = self.rng.range(1, 600);
+                json!(format!("PT{}H{}M", minutes / 60, minutes % 60))
+            }
+            _ => json!(self.string(object, attr)),
+        }
+    }
+
+    /// Generates a string fitting the name of an attribute.
+    fn string(&mut self, object: &Object, attr: &Attribute) -> String {
+        let name = attr.name.to_lowercase();
+
+        if attr.is_id {
+            return format!(
+                "{}-{}",
+                object.name.to_lowercase(),
+                self.rng.range(1000, 9999)
+            );
+        }
+
+        if name.contains("email") || name.contains("mail") {
+            let first = self.rng.pick(&FIRST_NAMES).to_lowercase();
+            let last = self.rng.pick(&LAST_NAMES).to_lowercase();
+            format!("{}.{}@{}", first, last, self.rng.pick(&DOMAINS))
+        } else if name.contains("url") || name.contains("uri") || name.contains("iri") {
+            format!(
+                "https://{}/{}",
+                self.rng.pick(&DOMAINS),
+                self.rng.pick(&WORDS)
+            )
+        } else if name.contains("city") || name.contains("location") || name.contains("place") {
+            self.rng.pick(&CITIES).to_string()
+        } else if name.contains("name") || name.contains("author") || name.contains("person") {
+            format!(
+                "{} {}",
+                self.rng.pick(&FIRST_NAMES),
+                self.rng.pick(&LAST_NAMES)
+            )
+        } else {
+            format!("{} {}", self.rng.pick(&WORDS), self.rng.pick(&WORDS))
+        }
+    }
+
+    /// Generates a base64 encoded string of twelve bytes.
+    fn base64(&mut self) -> String {
+        (0..16)
+            .map(|_| BASE64_ALPHABET[self.rng.range(0, 63) as usize] as char)
+            .collect()
+    }
+
+    /// Generates a date as `YYYY-MM-DD`.
+    fn date(&mut self) -> String {
+        let (year, month, day) = civil_from_days(self.rng.range(DATE_RANGE.0, DATE_RANGE.1));
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+
+    /// Generates a date and time as `YYYY-MM-DDThh:mm:ssZ`.
+    fn datetime(&mut self) -> String {
+        let date = self.date();
+        let seconds = self.rng.range(0, 86399);
+        format!(
+            "{}T{:02}:{:02}:{:02}Z",
+            date,
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    }
+
+    /// Returns the JSON representation of timestamps set by the frontmatter.
+    fn timestamps(&self) -> TimestampFormat {
+        self.model
+            .config
+            .as_ref()
+            .and_then(|c| c.timestamp_format)
+            .unwrap_or_default()
+    }
+
+    /// Resolves an abstract object to one of its concrete subtypes.
+    fn concrete(&mut self, object: &'a Object) -> Option<&'a Object> {
+        if !object.is_abstract {
+            return Some(object);
+        }
+
+        let subtypes = self.model.concrete_subtypes(&object.name);
+        match subtypes.is_empty() {
+            true => None,
+            false => Some(*self.rng.pick(&subtypes)),
+        }
+    }
+
+    /// Finds the object of a data type, if it references one.
+    fn find_object(&self, dtype: &str) -> Option<&'a Object> {
+        self.model.objects.iter().find(|o| o.name == dtype)
+    }
+}
+
+/// Finds a cycle of required references reachable from an object.
+///
+/// A required attribute referencing only objects needs another instance, so
+/// a cycle of such attributes cannot be terminated at any depth. Abstract
+/// objects are followed to each of their concrete subtypes.
+///
+/// # Arguments
+///
+/// * `object` - The object to start from.
+/// * `model` - The data model containing the objects.
+/// * `path` - The names of the objects visited so far.
+///
+/// # Returns
+///
+/// The cycle as `A -> B -> A`, if any.
+fn required_cycle<'a>(
+    object: &'a Object,
+    model: &'a DataModel,
+    path: &mut Vec<&'a str>,
+) -> Option<String> {
+    if let Some(start) = path.iter().position(|name| *name == object.name) {
+        let mut cycle = path[start..].to_vec();
+        cycle.push(&object.name);
+        return Some(cycle.join(" -> "));
+    }
+
+    path.push(&object.name);
+
+    let find = |dtype: &String| model.objects.iter().find(|o| o.name == *dtype);
+    let targets = object
+        .attributes
+        .iter()
+        .filter(|attr| attr.required && !attr.is_array && attr.map_key.is_none())
+        .filter(|attr| attr.constant.is_none() && attr.dtypes.iter().all(|d| find(d).is_some()))
+        .flat_map(|attr| attr.dtypes.iter().filter_map(find))
+        .flat_map(|target| match target.is_abstract {
+            true => model.concrete_subtypes(&target.name),
+            false => vec![target],
+        })
+        .collect::<Vec<&Object>>();
+
+    for target in targets {
+        if let Some(cycle) = required_cycle(target, model, path) {
+            return Some(cycle);
+        }
+    }
+
+    path.pop();
+    None
+}
+
+/// Converts the value of a `Const` option to JSON.
+pub(crate) fn constant_value(constant: &DataType) -> Value {
+    match constant {
+        DataType::Boolean(b) => json!(b),
+        DataType::Integer(i) => json!(i),
+        DataType::Float(f) => json!(f),
+        DataType::String(_) => json!(constant.raw_value()),
+        DataType::List(items) => Value::Array(items.iter().map(constant_value).collect()),
+    }
+}
+
+/// Returns a numeric option of an attribute, e.g. `minimum`.
+fn option_number(attr: &Attribute, key: &str) -> Option<f64> {
+    attr.options
+        .iter()
+        .find(|o| o.key() == key)
+        .and_then(|o| o.value().parse::<f64>().ok())
+}
+
+/// Converts days since 1970-01-01 to a date of the proleptic Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Helper function to generate mock instances of the first object of an inline model.
+    fn mock(content: &str, count: usize, seed: u64) -> Value {
+        let model = DataModel::from_markdown_string(content).expect("Could not parse markdown");
+        let mock = to_mock_json(&model.objects[0].name, &model, count, seed)
+            .expect("Could not generate mock data");
+        serde_json::from_str(&mock).unwrap()
+    }
+
+    #[test]
+    fn test_mock_respects_bounds() {
+        // Act
+        let instances = mock(
+            "### Sample\n\n- __value__\n  - Type: integer\n  - Minimum: 5\n  - Maximum: 7\n- __created__\n  - Type: date\n- __status__\n  - Type: Status\n\n## Enumerations\n\n### Status\n\n```\nOPEN = open\nCLOSED = closed\n```\n",
+            20,
+            1,
+        );
+
+        // Assert
+        for instance in instances.as_array().unwrap() {
+            let value = instance["value"].as_i64().unwrap();
+            assert!((5..=7).contains(&value));
+
+            let year = instance["created"].as_str().unwrap()[..4]
+                .parse::<i64>()
+                .unwrap();
+            assert!((2000..=2030).contains(&year));
+
+            let status = instance["status"].as_str().unwrap();
+            assert!(["open", "closed"].contains(&status));
+        }
+    }
+
+    #[test]
+    fn test_mock_bounds_recursion() {
+        // Act
+        let instances = mock(
+            "### Node\n\n- __name__\n  - Type: string\n- parent\n  - Type: Node\n- __children__\n  - Type: Node[]\n",
+            1,
+            0,
+        );
+
+        // Assert
+        let mut node = &instances[0];
+        for _ in 0..MAX_DEPTH {
+            node = &node["children"][0];
+        }
+        assert!(node["name"].is_string());
+        assert!(node.get("parent").is_none());
+        assert_eq!(node["children"], json!([]));
+    }
+
+    #[test]
+    fn test_mock_deep_required_references() {
+        // Act
+        let instances = mock(
+            "### A\n\n- __b__\n  - Type: B\n\n### B\n\n- __c__\n  - Type: C\n\n### C\n\n- __d__\n  - Type: D\n\n### D\n\n- __e__\n  - Type: E\n\n### E\n\n- __name__\n  - Type: string\n",
+            1,
+            0,
+        );
+
+        // Assert
+        assert!(instances[0]["b"]["c"]["d"]["e"]["name"].is_string());
+    }
+
+    #[test]
+    fn test_mock_required_cycle() {
+        // Arrange
+        let model = DataModel::from_markdown_string(
+            "### Node\n\n- __name__\n  - Type: string\n- __parent__\n  - Type: Node\n",
+        )
+        .expect("Could not parse markdown");
+
+        // Act
+        let result = to_mock_json("Node", &model, 1, 0);
+
+        // Assert
+        let err = result.expect_err("A required cycle cannot be generated");
+        assert!(err.to_string().contains("(Node -> Node)"));
+    }
+
+    #[test]
+    fn test_mock_integer_ranges() {
+        // Act
+        let instances = mock(
+            "### Sample\n\n- __unsigned__\n  - Type: uint32\n  - Minimum: -5\n  - Maximum: 3\n- __signed__\n  - Type: int32\n  - Minimum: 2147483640\n  - Maximum: 9999999999\n",
+            20,
+            2,
+        );
+
+        // Assert
+        for instance in instances.as_array().unwrap() {
+            let unsigned = instance["unsigned"].as_i64().unwrap();
+            let signed = instance["signed"].as_i64().unwrap();
+            assert!((0..=3).contains(&unsigned));
+            assert!((2147483640..=i32::MAX as i64).contains(&signed));
+        }
+    }
+
+    #[test]
+    fn test_mock_fixed_arrays_and_tuples() {
+        // Act
+        let instances = mock(
+            "### Pixel\n\n- __rgb__\n  - Type: integer[3]\n- __position__\n  - Type: (float, string)\n",
+            10,
+            3,
+        );
+
+        // Assert
+        for instance in instances.as_array().unwrap() {
+            assert_eq!(instance["rgb"].as_array().unwrap().len(), 3);
+
+            let position = instance["position"].as_array().unwrap();
+            assert_eq!(position.len(), 2);
+            assert!(position[0].is_f64());
+            assert!(position[1].is_string());
+        }
+    }
+
+    #[test]
+    fn test_mock_abstract_object() {
+        // Act
+        let instances = mock(
+            "### Shape\n\nAbstract\n\n- __name__\n  - Type: string\n\n### Circle [Shape]\n\n- __radius__\n  - Type: float\n\n### Square [Shape]\n\n- __side__\n  - Type: float\n",
+            10,
+            3,
+        );
+
+        // Assert
+        for instance in instances.as_array().unwrap() {
+            let is_circle = instance.get("radius").is_some();
+            let is_square = instance.get("side").is_some();
+            assert!(is_circle != is_square);
+            assert!(instance.get("name").is_some());
+        }
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(DATE_RANGE.0), (2000, 1, 1));
+        assert_eq!(civil_from_days(DATE_RANGE.1), (2030, 12, 31));
+        assert_eq!(civil_from_days(11016), (2000, 2, 29));
+    }
+}
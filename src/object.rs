@@ -1,7 +1,15 @@
-use crate::attribute::Attribute;
+use crate::attribute::{generic_type, Attribute};
+use lazy_static::lazy_static;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+
+lazy_static! {
+    /// Matches a compact IRI (`prefix:reference`), which is not an absolute IRI.
+    static ref CURIE_PATTERN: Regex =
+        Regex::new(r"^([A-Za-z][A-Za-z0-9_.-]*):([^\s/][^\s]*)$").unwrap();
+}
 
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -9,14 +17,60 @@ use std::collections::BTreeMap;
 pub struct Object {
     /// Name of the object.
     pub name: String,
+    /// Name of the object as given in the heading, if it differs from the type identifier.
+    pub display_name: Option<String>,
     /// List of attributes associated with the object.
     pub attributes: Vec<Attribute>,
     /// Documentation string for the object.
     pub docstring: String,
+    /// One-line summary of the object.
+    pub summary: Option<String>,
     /// Optional term associated with the object.
     pub term: Option<String>,
     /// Parent object of the object.
     pub parent: Option<String>,
+    /// Object under whose heading the object is nested as an H4 heading.
+    pub nested_in: Option<String>,
+    /// Whether additional properties are disallowed, overriding the frontmatter.
+    pub closed: Option<bool>,
+    /// Whether an id is added to the object, overriding the frontmatter `id-field`.
+    pub id_field: Option<bool>,
+    /// Whether the object is a base that is only instantiated through its subtypes.
+    #[serde(
+        rename = "abstract",
+        default,
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub is_abstract: bool,
+    /// Conditional requirements of the object.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<Condition>,
+    /// Names of the attributes forming a composite primary key.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub primary_key: Vec<String>,
+    /// Name of the database table, overriding the name derived from the object name.
+    pub table: Option<String>,
+    /// Database indexes over attributes of the object.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub indexes: Vec<Index>,
+    /// Verbatim code per template, placed into the body of the generated object.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub inject: BTreeMap<String, String>,
+    /// Path of the markdown file the object was parsed from, if any.
+    #[serde(skip)]
+    pub source: Option<String>,
+    /// Type parameter of a generic object, given in the heading as `Name<T>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_param: Option<String>,
+    /// Targets the object is generated for, or excluded from if prefixed by `!`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<String>,
+    /// Classes the object is a `rdfs:subClassOf`, given as CURIEs or IRIs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subclass_of: Vec<String>,
+    /// Whether the object is added by a built-in type, e.g. `quantity`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub builtin: bool,
 }
 
 impl Object {
@@ -33,10 +87,26 @@ impl Object {
     pub fn new(name: String, term: Option<String>) -> Self {
         Object {
             name,
+            display_name: None,
             attributes: Vec::new(),
             docstring: String::new(),
+            summary: None,
             term,
             parent: None,
+            nested_in: None,
+            closed: None,
+            id_field: None,
+            is_abstract: false,
+            conditions: Vec::new(),
+            primary_key: Vec::new(),
+            table: None,
+            indexes: Vec::new(),
+            inject: BTreeMap::new(),
+            source: None,
+            type_param: None,
+            targets: Vec::new(),
+            subclass_of: Vec::new(),
+            builtin: false,
         }
     }
 
@@ -82,6 +152,19 @@ impl Object {
         self.attributes.push(attribute);
     }
 
+    /// Retrieves an attribute of the object by its name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A string slice representing the name of the attribute.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&Attribute>` - The attribute, or `None` if the object has no attribute of this name.
+    pub fn attribute(&self, name: &str) -> Option<&Attribute> {
+        self.attributes.iter().find(|a| a.name == name)
+    }
+
     /// Checks if the object has any attributes.
     ///
     /// # Returns
@@ -100,6 +183,19 @@ impl Object {
         self.name = name;
     }
 
+    /// Checks whether an id is added to the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `id_field` - The `id-field` setting of the frontmatter.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - The setting of the object, if given, otherwise the frontmatter setting.
+    pub fn has_id_field(&self, id_field: bool) -> bool {
+        self.id_field.unwrap_or(id_field)
+    }
+
     /// Checks if any attribute of the object has a term.
     ///
     /// # Returns
@@ -109,6 +205,42 @@ impl Object {
         self.attributes.iter().any(|attr| attr.has_term())
     }
 
+    /// Creates a concrete object from a generic object by substituting its type parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the concrete object.
+    /// * `arg` - The type substituted for the type parameter.
+    ///
+    /// # Returns
+    ///
+    /// * `Object` - The concrete object without a type parameter.
+    pub fn instantiate(&self, name: String, arg: &str) -> Object {
+        let mut object = self.clone();
+        let Some(param) = object.type_param.take() else {
+            return object;
+        };
+
+        object.name = name;
+        object.display_name = None;
+
+        for dtype in object
+            .attributes
+            .iter_mut()
+            .flat_map(|a| a.dtypes.iter_mut())
+        {
+            if *dtype == param {
+                *dtype = arg.to_string();
+            } else if let Some((generic, inner)) = generic_type(dtype) {
+                if inner == param {
+                    *dtype = format!("{}<{}>", generic, arg);
+                }
+            }
+        }
+
+        object
+    }
+
     /// Sorts the attributes of the object by their `required` field in descending order.
     pub fn sort_attrs_by_required(&mut self) {
         let mut top_elements: Vec<Attribute> = vec![];
@@ -127,6 +259,28 @@ impl Object {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+/// Represents a conditional requirement of an object.
+///
+/// If all fields in `when` equal the given values, the fields
+/// listed in `require` are required.
+pub struct Condition {
+    /// Fields and the values they have to be equal to.
+    pub when: BTreeMap<String, serde_json::Value>,
+    /// Fields that are required if the condition holds.
+    pub require: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+/// Represents a database index over one or more attributes of an object.
+pub struct Index {
+    /// Names of the indexed attributes, in the order of the index.
+    pub fields: Vec<String>,
+    /// Whether the combination of the fields has to be unique.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub unique: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 /// Represents an enumeration with a name and mappings.
 pub struct Enumeration {
@@ -136,6 +290,18 @@ pub struct Enumeration {
     pub mappings: BTreeMap<String, String>,
     /// Documentation string for the enumeration.
     pub docstring: String,
+    /// Indicates if values other than the mapped ones are accepted.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub open: bool,
+    /// Expanded IRIs of the values given as CURIEs, by key.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub iris: BTreeMap<String, String>,
+    /// Name of the enumeration whose mappings are extended.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+    /// Path of a CSV, TSV or JSON file the mappings are loaded from, relative to the model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
 }
 
 impl Enumeration {
@@ -145,10 +311,86 @@ impl Enumeration {
     ///
     /// * `bool` - `true` if the enumeration has values, `false` otherwise.
     pub fn has_values(&self) -> bool {
-        !self.mappings.is_empty()
+        !self.mappings.is_empty() || self.source.is_some()
+    }
+
+    /// Expands the values given as CURIEs into the `iris` of the enumeration.
+    ///
+    /// Absolute IRIs and plain values are kept as they are. CURIEs with an
+    /// unknown prefix are not expanded.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefixes` - The prefix map to resolve CURIEs against.
+    pub fn resolve_iris(&mut self, prefixes: &HashMap<String, String>) {
+        self.iris = self
+            .mappings
+            .iter()
+            .filter(|(_, value)| !value.contains("://"))
+            .filter_map(|(key, value)| Some((key.clone(), expand_iri(value, prefixes)?)))
+            .collect();
     }
 }
 
+/// Expands a CURIE or IRI into an absolute IRI.
+///
+/// # Arguments
+///
+/// * `value` - The CURIE or IRI to expand.
+/// * `prefixes` - The prefix map to resolve CURIEs against.
+///
+/// # Returns
+///
+/// The expanded IRI, if the value is an IRI or a CURIE with a known prefix.
+pub fn expand_iri(value: &str, prefixes: &HashMap<String, String>) -> Option<String> {
+    if value.contains("://") {
+        return Some(value.to_string());
+    }
+
+    let (prefix, reference) = split_curie(value)?;
+    let base = prefixes.get(prefix)?;
+    Some(format!("{}{}", base, reference))
+}
+
+/// Compacts an absolute IRI into a CURIE using the longest matching prefix.
+///
+/// IRIs without a matching prefix, or whose remainder is no valid CURIE
+/// reference, are returned unchanged.
+///
+/// # Arguments
+///
+/// * `iri` - The absolute IRI to compact.
+/// * `prefixes` - The prefix map to compact the IRI against.
+///
+/// # Returns
+///
+/// The CURIE, or the IRI if it cannot be compacted.
+pub fn compact_iri(iri: &str, prefixes: &HashMap<String, String>) -> String {
+    prefixes
+        .iter()
+        .filter_map(|(prefix, base)| Some((prefix, base, iri.strip_prefix(base.as_str())?)))
+        .filter(|(prefix, _, reference)| {
+            split_curie(&format!("{}:{}", prefix, reference)).is_some()
+        })
+        .max_by_key(|(_, base, _)| base.len())
+        .map(|(prefix, _, reference)| format!("{}:{}", prefix, reference))
+        .unwrap_or_else(|| iri.to_string())
+}
+
+/// Splits a CURIE into its prefix and reference.
+///
+/// # Arguments
+///
+/// * `value` - The value to split.
+///
+/// # Returns
+///
+/// The prefix and reference, if the value is a CURIE.
+pub fn split_curie(value: &str) -> Option<(&str, &str)> {
+    let captures = CURIE_PATTERN.captures(value)?;
+    Some((captures.get(1)?.as_str(), captures.get(2)?.as_str()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -188,6 +430,15 @@ mod tests {
         assert_eq!(last_attribute.name, "name");
     }
 
+    #[test]
+    fn test_attribute() {
+        let mut object = Object::new("Person".to_string(), None);
+        object.create_new_attribute("name".to_string(), true);
+        assert_eq!(object.attribute("name").map(|a| a.required), Some(true));
+        assert!(object.attribute("Name").is_none());
+        assert!(object.attribute("age").is_none());
+    }
+
     #[test]
     fn test_create_new_attribute() {
         let mut object = Object::new("Person".to_string(), None);
@@ -195,4 +446,64 @@ mod tests {
         assert_eq!(object.attributes.len(), 1);
         assert_eq!(object.attributes[0].name, "name");
     }
+
+    #[test]
+    fn test_split_curie() {
+        assert_eq!(
+            split_curie("obo:PATO_0000384"),
+            Some(("obo", "PATO_0000384"))
+        );
+        assert_eq!(split_curie("http://purl.obolibrary.org/obo/PATO"), None);
+        assert_eq!(split_curie("plain value"), None);
+        assert_eq!(split_curie("10:30"), None);
+    }
+
+    #[test]
+    fn test_compact_iri() {
+        // Arrange
+        let prefixes = HashMap::from([
+            (
+                "obo".to_string(),
+                "http://purl.obolibrary.org/obo/".to_string(),
+            ),
+            (
+                "go".to_string(),
+                "http://purl.obolibrary.org/obo/GO_".to_string(),
+            ),
+            ("schema".to_string(), "http://schema.org/".to_string()),
+        ]);
+
+        // Act & Assert
+        assert_eq!(
+            compact_iri("http://schema.org/name", &prefixes),
+            "schema:name"
+        );
+        assert_eq!(
+            compact_iri("http://purl.obolibrary.org/obo/GO_0008150", &prefixes),
+            "go:0008150"
+        );
+        assert_eq!(
+            compact_iri("http://xmlns.com/foaf/0.1/name", &prefixes),
+            "http://xmlns.com/foaf/0.1/name"
+        );
+        assert_eq!(
+            compact_iri("http://schema.org/", &prefixes),
+            "http://schema.org/"
+        );
+    }
+
+    #[test]
+    fn test_expand_and_compact_round_trip() {
+        // Arrange
+        let prefixes = HashMap::from([("schema".to_string(), "http://schema.org/".to_string())]);
+
+        // Act
+        let expanded = expand_iri("schema:name", &prefixes).unwrap();
+        let compacted = compact_iri(&expanded, &prefixes);
+
+        // Assert
+        assert_eq!(expanded, "http://schema.org/name");
+        assert_eq!(compacted, "schema:name");
+        assert_eq!(expand_iri(&compacted, &prefixes).unwrap(), expanded);
+    }
 }
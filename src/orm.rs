@@ -0,0 +1,478 @@
+use serde::Serialize;
+
+use crate::{
+    attribute::Attribute, datamodel::DataModel, markdown::parser::to_snake_case, object::Object,
+};
+
+/// Relational mapping of a data model, used by the ORM templates.
+///
+/// Every object is mapped to a table. Attributes referencing a single object
+/// become foreign keys (many-to-one), arrays of objects are mapped to a foreign
+/// key on the referenced table (one-to-many). If both objects reference each
+/// other via arrays, the relationship is mapped to an association table
+/// (many-to-many).
+#[derive(Debug, Serialize)]
+pub(crate) struct Relations {
+    /// Tables of the objects, in the order of the objects.
+    pub tables: Vec<Table>,
+    /// Association tables of many-to-many relationships.
+    pub associations: Vec<Association>,
+}
+
+/// Table of a single object.
+#[derive(Debug, Serialize)]
+pub(crate) struct Table {
+    /// Name of the mapped object.
+    pub object: String,
+    /// Name of the table.
+    pub name: String,
+    /// Columns of the primary key.
+    pub primary_key: Vec<Column>,
+    /// Whether the primary key is a generated `id` column.
+    pub surrogate: bool,
+    /// Foreign keys of the table.
+    pub foreign_keys: Vec<ForeignKey>,
+    /// Relationships of the mapped object.
+    pub relationships: Vec<Relationship>,
+}
+
+/// Column of a table.
+#[derive(Debug, Serialize, Clone)]
+pub(crate) struct Column {
+    /// Name of the column.
+    pub name: String,
+    /// Name of the mapped attribute.
+    pub key: String,
+    /// Data type of the column.
+    pub dtype: String,
+    /// Whether the column may be null.
+    pub nullable: bool,
+}
+
+/// Foreign key from one or more columns to the primary key of a table.
+#[derive(Debug, Serialize, Clone)]
+pub(crate) struct ForeignKey {
+    /// Columns holding the foreign key.
+    pub columns: Vec<Column>,
+    /// Name of the referenced table.
+    pub table: String,
+    /// Referenced primary key columns.
+    pub references: Vec<String>,
+}
+
+/// Kind of a relationship between two objects.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum RelationshipKind {
+    ManyToOne,
+    OneToMany,
+    ManyToMany,
+}
+
+/// Relationship accessor of a mapped object.
+#[derive(Debug, Serialize)]
+pub(crate) struct Relationship {
+    /// Name of the attribute.
+    pub name: String,
+    /// Name of the referenced object.
+    pub target: String,
+    /// Kind of the relationship.
+    pub kind: RelationshipKind,
+    /// Whether the relationship is required.
+    pub required: bool,
+    /// Qualified foreign key columns, e.g. `Object.column`.
+    pub foreign_keys: Vec<String>,
+    /// Association table of a many-to-many relationship.
+    pub secondary: Option<String>,
+    /// Attribute of the referenced object mapping the inverse relationship.
+    pub back_populates: Option<String>,
+    /// Qualified primary key columns of a self-referencing many-to-one relationship.
+    pub remote_side: Vec<String>,
+}
+
+/// Association table of a many-to-many relationship.
+#[derive(Debug, Serialize)]
+pub(crate) struct Association {
+    /// Name of the table.
+    pub name: String,
+    /// Foreign keys to both sides of the relationship.
+    pub foreign_keys: Vec<ForeignKey>,
+}
+
+impl Relations {
+    /// Derives the relational mapping of a data model.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - A reference to the data model.
+    pub fn new(model: &DataModel) -> Self {
+        let id_field = model.config.as_ref().is_none_or(|c| c.id_field);
+        let mut tables = model
+            .objects
+            .iter()
+            .map(|object| Table {
+                object: object.name.clone(),
+                name: table_name(object),
+                primary_key: primary_key(object),
+                surrogate: object.has_id_field(id_field)
+                    && object.primary_key.is_empty()
+                    && !object.attributes.iter().any(|a| a.is_id),
+                foreign_keys: vec![],
+                relationships: vec![],
+            })
+            .collect::<Vec<Table>>();
+
+        let mut associations = vec![];
+
+        for (index, object) in model.objects.iter().enumerate() {
+            for attr in &object.attributes {
+                let target = match reference(attr, model) {
+                    Some(target) => target,
+                    None => continue,
+                };
+
+                // Only attributes pointing back to this attribute are inverse relationships
+                let inverse = find_inverse(target, object)
+                    .filter(|_| find_inverse(object, target).is_some_and(|a| a.name == attr.name));
+
+                match (attr.is_array, inverse) {
+                    (false, inverse) => {
+                        let back_populates = inverse.filter(|i| i.is_array).map(|i| i.name.clone());
+
+                        let fk = foreign_key(&tables, target, &attr.name, !attr.required);
+                        let remote_side = match target.name == object.name {
+                            true => qualified_keys(&tables[index], &object.name),
+                            false => vec![],
+                        };
+                        tables[index].relationships.push(Relationship {
+                            name: attr.name.clone(),
+                            target: target.name.clone(),
+                            kind: RelationshipKind::ManyToOne,
+                            required: attr.required,
+                            foreign_keys: qualified(&object.name, &fk),
+                            secondary: None,
+                            back_populates,
+                            remote_side,
+                        });
+                        tables[index].foreign_keys.push(fk);
+                    }
+                    (true, Some(inverse)) if inverse.is_array => {
+                        let name = association_name(object, target);
+                        if !associations.iter().any(|a: &Association| a.name == name) {
+                            associations.push(Association {
+                                name: name.clone(),
+                                foreign_keys: vec![
+                                    association_key(&tables, object),
+                                    association_key(&tables, target),
+                                ],
+                            });
+                        }
+
+                        tables[index].relationships.push(Relationship {
+                            name: attr.name.clone(),
+                            target: target.name.clone(),
+                            kind: RelationshipKind::ManyToMany,
+                            required: false,
+                            foreign_keys: vec![],
+                            secondary: Some(name),
+                            back_populates: Some(inverse.name.clone()),
+                            remote_side: vec![],
+                        });
+                    }
+                    (true, Some(inverse)) => {
+                        // Reuse the foreign key of the inverse many-to-one relationship
+                        let fk = foreign_key(&tables, object, &inverse.name, !inverse.required);
+                        tables[index].relationships.push(Relationship {
+                            name: attr.name.clone(),
+                            target: target.name.clone(),
+                            kind: RelationshipKind::OneToMany,
+                            required: false,
+                            foreign_keys: qualified(&target.name, &fk),
+                            secondary: None,
+                            back_populates: Some(inverse.name.clone()),
+                            remote_side: vec![],
+                        });
+                    }
+                    (true, None) => {
+                        let prefix = backref_prefix(object, attr, target);
+                        let fk = foreign_key(&tables, object, &prefix, true);
+                        tables[index].relationships.push(Relationship {
+                            name: attr.name.clone(),
+                            target: target.name.clone(),
+                            kind: RelationshipKind::OneToMany,
+                            required: false,
+                            foreign_keys: qualified(&target.name, &fk),
+                            secondary: None,
+                            back_populates: None,
+                            remote_side: vec![],
+                        });
+
+                        let target_index = model
+                            .objects
+                            .iter()
+                            .position(|o| o.name == target.name)
+                            .unwrap();
+                        tables[target_index].foreign_keys.push(fk);
+                    }
+                }
+            }
+        }
+
+        Relations {
+            tables,
+            associations,
+        }
+    }
+}
+
+/// Returns the object referenced by an attribute, if it references a single object type.
+fn reference<'a>(attr: &Attribute, model: &'a DataModel) -> Option<&'a Object> {
+    match attr.dtypes.as_slice() {
+        [dtype] => model.objects.iter().find(|o| o.name == *dtype),
+        _ => None,
+    }
+}
+
+/// Returns the first attribute of an object referencing another object.
+///
+/// Self-references are not considered as inverse relationships.
+fn find_inverse<'a>(object: &'a Object, other: &Object) -> Option<&'a Attribute> {
+    if object.name == other.name {
+        return None;
+    }
+
+    object
+        .attributes
+        .iter()
+        .find(|a| a.dtypes == [other.name.clone()])
+}
+
+/// Returns the table name of an object.
+///
+/// The name is taken from the `Table` annotation or derived from the object name.
+pub(crate) fn table_name(object: &Object) -> String {
+    sql_table_name(&object.name, object.table.as_deref())
+}
+
+/// Returns the table name given by a `Table` annotation or derived from an object name.
+///
+/// Shared by the ORM and the templates, such that all SQL targets agree on the names.
+pub(crate) fn sql_table_name(name: &str, table: Option<&str>) -> String {
+    match table {
+        Some(table) => table.to_string(),
+        None => to_snake_case(name),
+    }
+}
+
+/// Returns the primary key columns of an object.
+///
+/// The primary key is taken from the `PrimaryKey` annotation, an `Identifier`
+/// attribute or, if neither is given, a generated integer `id` column.
+fn primary_key(object: &Object) -> Vec<Column> {
+    let column = |attr: &Attribute| Column {
+        name: attr.column.clone().unwrap_or_else(|| attr.name.clone()),
+        key: attr.name.clone(),
+        dtype: attr.dtypes[0].clone(),
+        nullable: false,
+    };
+
+    if !object.primary_key.is_empty() {
+        return object
+            .primary_key
+            .iter()
+            .filter_map(|name| object.attributes.iter().find(|a| a.name == *name))
+            .map(column)
+            .collect();
+    }
+
+    match object.attributes.iter().find(|a| a.is_id) {
+        Some(attr) => vec![column(attr)],
+        None => vec![Column {
+            name: "id".to_string(),
+            key: "id".to_string(),
+            dtype: "integer".to_string(),
+            nullable: false,
+        }],
+    }
+}
+
+/// Creates a foreign key to the primary key of the target object.
+///
+/// The columns are named after the given prefix and the primary key columns.
+fn foreign_key(tables: &[Table], target: &Object, prefix: &str, nullable: bool) -> ForeignKey {
+    let table = tables.iter().find(|t| t.object == target.name).unwrap();
+    ForeignKey {
+        columns: table
+            .primary_key
+            .iter()
+            .map(|pk| Column {
+                name: format!("{}_{}", prefix, pk.name),
+                key: format!("{}_{}", prefix, pk.name),
+                dtype: pk.dtype.clone(),
+                nullable,
+            })
+            .collect(),
+        table: table.name.clone(),
+        references: table.primary_key.iter().map(|pk| pk.name.clone()).collect(),
+    }
+}
+
+/// Creates the foreign key of an association table to one side of the relationship.
+fn association_key(tables: &[Table], object: &Object) -> ForeignKey {
+    foreign_key(tables, object, &table_name(object), false)
+}
+
+/// Returns the name of the association table between two objects.
+fn association_name(a: &Object, b: &Object) -> String {
+    let mut names = [table_name(a), table_name(b)];
+    names.sort();
+    names.join("_")
+}
+
+/// Returns the column prefix of a foreign key pointing back to the owner of an array.
+///
+/// The attribute name is included if the owner has several arrays of the same type.
+fn backref_prefix(owner: &Object, attr: &Attribute, target: &Object) -> String {
+    let arrays = owner
+        .attributes
+        .iter()
+        .filter(|a| a.is_array && a.dtypes == [target.name.clone()])
+        .count();
+
+    match arrays {
+        1 => table_name(owner),
+        _ => format!("{}_{}", table_name(owner), attr.name),
+    }
+}
+
+/// Qualifies the columns of a foreign key with the name of the object holding them.
+fn qualified(object: &str, fk: &ForeignKey) -> Vec<String> {
+    fk.columns
+        .iter()
+        .map(|c| format!("{}.{}", object, c.key))
+        .collect()
+}
+
+/// Qualifies the primary key attributes of a table with the name of its object.
+fn qualified_keys(table: &Table, object: &str) -> Vec<String> {
+    table
+        .primary_key
+        .iter()
+        .map(|c| format!("{}.{}", object, c.key))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Helper function to derive the relations of an inline markdown model.
+    fn relations(content: &str) -> Relations {
+        let model = DataModel::from_markdown_string(content).expect("Could not parse markdown");
+        Relations::new(&model)
+    }
+
+    #[test]
+    fn test_one_to_many() {
+        // Act
+        let relations = relations(
+            "### Author\n\n- __name__\n  - Type: Identifier\n- books\n  - Type: Book[]\n\n### Book\n\n- title\n  - Type: string\n",
+        );
+
+        // Assert
+        let author = &relations.tables[0];
+        assert_eq!(author.relationships[0].kind, RelationshipKind::OneToMany);
+        assert_eq!(
+            author.relationships[0].foreign_keys,
+            vec!["Book.author_name"]
+        );
+        assert!(author.foreign_keys.is_empty());
+
+        let book = &relations.tables[1];
+        assert!(book.surrogate);
+        assert_eq!(book.foreign_keys[0].table, "author");
+        assert_eq!(book.foreign_keys[0].references, vec!["name"]);
+        assert_eq!(book.foreign_keys[0].columns[0].dtype, "string");
+        assert!(relations.associations.is_empty());
+    }
+
+    #[test]
+    fn test_one_to_many_with_inverse() {
+        // Act
+        let relations = relations(
+            "### Author\n\n- books\n  - Type: Book[]\n\n### Book\n\n- __author__\n  - Type: Author\n",
+        );
+
+        // Assert
+        let books = &relations.tables[0].relationships[0];
+        assert_eq!(books.foreign_keys, vec!["Book.author_id"]);
+        assert_eq!(books.back_populates, Some("author".to_string()));
+
+        let book = &relations.tables[1];
+        assert_eq!(book.foreign_keys.len(), 1);
+        assert!(!book.foreign_keys[0].columns[0].nullable);
+        assert_eq!(book.relationships[0].kind, RelationshipKind::ManyToOne);
+        assert_eq!(
+            book.relationships[0].back_populates,
+            Some("books".to_string())
+        );
+    }
+
+    #[test]
+    fn test_many_to_many() {
+        // Act
+        let relations = relations(
+            "### Student\n\n- courses\n  - Type: Course[]\n\n### Course\n\n- students\n  - Type: Student[]\n",
+        );
+
+        // Assert
+        assert_eq!(relations.associations.len(), 1);
+        let association = &relations.associations[0];
+        assert_eq!(association.name, "course_student");
+        assert_eq!(association.foreign_keys[0].columns[0].name, "student_id");
+        assert_eq!(association.foreign_keys[1].columns[0].name, "course_id");
+
+        let courses = &relations.tables[0].relationships[0];
+        assert_eq!(courses.kind, RelationshipKind::ManyToMany);
+        assert_eq!(courses.secondary, Some("course_student".to_string()));
+        assert_eq!(courses.back_populates, Some("students".to_string()));
+        assert!(relations.tables.iter().all(|t| t.foreign_keys.is_empty()));
+    }
+
+    #[test]
+    fn test_composite_foreign_key() {
+        // Act
+        let relations = relations(
+            "### Grade\n\n- __enrollment__\n  - Type: Enrollment\n\n### Enrollment\n\nPrimaryKey: student, course\n\n- __student__\n  - Type: string\n- __course__\n  - Type: integer\n",
+        );
+
+        // Assert
+        let fk = &relations.tables[0].foreign_keys[0];
+        let columns = fk
+            .columns
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, vec!["enrollment_student", "enrollment_course"]);
+        assert_eq!(fk.references, vec!["student", "course"]);
+        assert!(!relations.tables[1].surrogate);
+    }
+
+    #[test]
+    fn test_table_and_column_overrides() {
+        // Act
+        let relations = relations(
+            "### Node\n\nTable: nodes\n\n- __key__\n  - Type: Identifier\n  - Column: node_key\n- parent\n  - Type: Node\n",
+        );
+
+        // Assert
+        let node = &relations.tables[0];
+        assert_eq!(node.name, "nodes");
+        assert_eq!(node.primary_key[0].name, "node_key");
+        assert_eq!(node.primary_key[0].key, "key");
+        assert_eq!(node.foreign_keys[0].table, "nodes");
+        assert_eq!(node.foreign_keys[0].references, vec!["node_key"]);
+        assert_eq!(node.relationships[0].remote_side, vec!["Node.key"]);
+    }
+}
@@ -1,11 +1,10 @@
-use crate::{datamodel::DataModel, exporters::Templates};
+use crate::{datamodel::DataModel, error::MdModelsError, exporters::Templates};
 use colored::Colorize;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
-    error::Error,
-    fs,
+    fs, io,
     path::{Path, PathBuf},
     str::FromStr,
 };
@@ -56,6 +55,11 @@ impl GenSpecs {
         }
 
         self.out = path.join(&self.out);
+
+        // Lock files are located relative to the pipeline as well
+        if let Some(lock) = self.config.get_mut("lock") {
+            *lock = path.join(&*lock).display().to_string();
+        }
     }
 }
 
@@ -85,9 +89,28 @@ impl From<bool> for MergeState {
 /// # Returns
 ///
 /// A Result indicating success or failure.
-pub fn process_pipeline(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
-    let content = std::fs::read_to_string(path)?;
-    let mut gen_template: GenTemplate = toml::from_str(content.as_str()).unwrap();
+pub fn process_pipeline(path: &PathBuf) -> Result<(), MdModelsError> {
+    process_pipeline_with(path, false)
+}
+
+/// Processes the pipeline like [`process_pipeline`], optionally without printing
+/// the paths of the written files.
+///
+/// # Arguments
+///
+/// * `path` - Path to the template file.
+/// * `quiet` - Whether to suppress the status messages.
+///
+/// # Returns
+///
+/// A Result indicating success or failure.
+pub fn process_pipeline_with(path: &PathBuf, quiet: bool) -> Result<(), MdModelsError> {
+    let content = std::fs::read_to_string(path).map_err(|e| MdModelsError::io(path, e))?;
+    let mut gen_template: GenTemplate =
+        toml::from_str(content.as_str()).map_err(|e| MdModelsError::Parse {
+            object: None,
+            message: format!("Invalid pipeline: {}", e),
+        })?;
 
     if let Some(parent) = path.parent() {
         gen_template.prepend_root(parent);
@@ -102,16 +125,44 @@ pub fn process_pipeline(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>
         match template {
             Templates::JsonSchema => {
                 let model = build_models(paths)?;
-                serialize_to_json_schema(model, specs.root, &specs.out, &merge_state)?;
+                serialize_to_json_schema(model, specs.root, &specs.out, &merge_state, quiet)?;
             }
             Templates::JsonSchemaAll => {
-                serialize_all_json_schemes(&specs.out, paths, &merge_state)?;
+                serialize_all_json_schemes(&specs.out, paths, &merge_state, quiet)?;
+            }
+            Templates::JsonSchemaBundle => {
+                let model = build_models(paths)?;
+                serialize_to_json_schema_bundle(model, &specs.out, &merge_state, quiet)?;
+            }
+            Templates::TableSchema => {
+                let model = build_models(paths)?;
+                let flatten = specs.config.get("flatten").is_some_and(|f| f == "true");
+                serialize_to_table_schema(
+                    model,
+                    specs.root,
+                    flatten,
+                    &specs.out,
+                    &merge_state,
+                    quiet,
+                )?;
+            }
+            Templates::MockJson => {
+                let model = build_models(paths)?;
+                serialize_to_mock_json(
+                    model,
+                    specs.root,
+                    &specs.config,
+                    &specs.out,
+                    &merge_state,
+                    quiet,
+                )?;
             }
             Templates::Shex => {
                 serialize_by_template(
                     &specs.out,
                     paths,
                     &merge_state,
+                    quiet,
                     &template,
                     Some(&specs.config),
                 )?;
@@ -121,6 +172,7 @@ pub fn process_pipeline(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>
                     &specs.out,
                     paths,
                     &merge_state,
+                    quiet,
                     &template,
                     Some(&specs.config),
                 )?;
@@ -130,6 +182,7 @@ pub fn process_pipeline(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>
                     &specs.out,
                     paths,
                     &merge_state,
+                    quiet,
                     &template,
                     Some(&specs.config),
                 )?;
@@ -139,6 +192,7 @@ pub fn process_pipeline(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>
                     &specs.out,
                     paths,
                     &merge_state,
+                    quiet,
                     &template,
                     Some(&specs.config),
                 )?;
@@ -148,6 +202,7 @@ pub fn process_pipeline(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>
                     &specs.out,
                     paths,
                     &merge_state,
+                    quiet,
                     &template,
                     Some(&specs.config),
                 )?;
@@ -157,6 +212,7 @@ pub fn process_pipeline(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>
                     &specs.out,
                     paths,
                     &merge_state,
+                    quiet,
                     &template,
                     Some(&specs.config),
                 )?;
@@ -166,6 +222,7 @@ pub fn process_pipeline(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>
                     &specs.out,
                     paths,
                     &merge_state,
+                    quiet,
                     &template,
                     Some(&specs.config),
                 )?;
@@ -175,15 +232,121 @@ pub fn process_pipeline(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>
                     &specs.out,
                     paths,
                     &merge_state,
+                    quiet,
+                    &template,
+                    Some(&specs.config),
+                )?;
+            }
+            Templates::Zod => {
+                serialize_by_template(
+                    &specs.out,
+                    paths,
+                    &merge_state,
+                    quiet,
+                    &template,
+                    Some(&specs.config),
+                )?;
+            }
+            Templates::Elixir => {
+                serialize_by_template(
+                    &specs.out,
+                    paths,
+                    &merge_state,
+                    quiet,
+                    &template,
+                    Some(&specs.config),
+                )?;
+            }
+            Templates::Rust => {
+                serialize_by_template(
+                    &specs.out,
+                    paths,
+                    &merge_state,
+                    quiet,
+                    &template,
+                    Some(&specs.config),
+                )?;
+            }
+            Templates::Sql => {
+                serialize_by_template(
+                    &specs.out,
+                    paths,
+                    &merge_state,
+                    quiet,
+                    &template,
+                    Some(&specs.config),
+                )?;
+            }
+            Templates::PythonSqlAlchemy => {
+                serialize_by_template(
+                    &specs.out,
+                    paths,
+                    &merge_state,
+                    quiet,
+                    &template,
+                    Some(&specs.config),
+                )?;
+            }
+            Templates::FSharp => {
+                serialize_by_template(
+                    &specs.out,
+                    paths,
+                    &merge_state,
+                    quiet,
+                    &template,
+                    Some(&specs.config),
+                )?;
+            }
+            Templates::Nim
+            | Templates::OCaml
+            | Templates::Haskell
+            | Templates::Capnp
+            | Templates::Dart => {
+                serialize_by_template(
+                    &specs.out,
+                    paths,
+                    &merge_state,
+                    quiet,
+                    &template,
+                    Some(&specs.config),
+                )?;
+            }
+            Templates::Docs => {
+                serialize_by_template(
+                    &specs.out,
+                    paths,
+                    &merge_state,
+                    quiet,
                     &template,
                     Some(&specs.config),
                 )?;
             }
-            Templates::Typescript => {
+            Templates::PlantUml => {
                 serialize_by_template(
                     &specs.out,
                     paths,
                     &merge_state,
+                    quiet,
+                    &template,
+                    Some(&specs.config),
+                )?;
+            }
+            Templates::Dot => {
+                serialize_by_template(
+                    &specs.out,
+                    paths,
+                    &merge_state,
+                    quiet,
+                    &template,
+                    Some(&specs.config),
+                )?;
+            }
+            Templates::Typescript | Templates::TypescriptClass | Templates::TypescriptAjv => {
+                serialize_by_template(
+                    &specs.out,
+                    paths,
+                    &merge_state,
+                    quiet,
                     &template,
                     Some(&specs.config),
                 )?;
@@ -200,13 +363,14 @@ pub fn process_pipeline(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>
                     &specs.out,
                     paths,
                     &merge_state,
+                    quiet,
                     &template,
                     Some(&specs.config),
                 )?;
             }
             Templates::Internal => {
                 let model = build_models(paths)?;
-                serialize_to_internal_schema(model, &specs.out, &merge_state)?;
+                serialize_to_internal_schema(model, &specs.out, &merge_state, quiet)?;
             }
         }
     }
@@ -223,7 +387,7 @@ pub fn process_pipeline(path: &PathBuf) -> Result<(), Box<dyn std::error::Error>
 /// # Returns
 ///
 /// A Result containing the DataModel or an error.
-fn build_models(paths: &[PathBuf]) -> Result<DataModel, Box<dyn Error>> {
+fn build_models(paths: &[PathBuf]) -> Result<DataModel, MdModelsError> {
     let first_path = paths.first().unwrap();
     path_exists(first_path)?;
 
@@ -251,9 +415,12 @@ fn build_models(paths: &[PathBuf]) -> Result<DataModel, Box<dyn Error>> {
 /// # Returns
 ///
 /// A Result indicating success or failure.
-fn path_exists(path: &PathBuf) -> Result<(), Box<dyn Error>> {
+fn path_exists(path: &PathBuf) -> Result<(), MdModelsError> {
     if !path.exists() {
-        return Err(format!("Path does not exist: {:?}", path).into());
+        return Err(MdModelsError::io(
+            path,
+            io::Error::new(io::ErrorKind::NotFound, "Path does not exist"),
+        ));
     }
     Ok(())
 }
@@ -274,22 +441,132 @@ fn serialize_to_json_schema(
     root: Option<String>,
     out: &PathBuf,
     merge_state: &MergeState,
-) -> Result<(), Box<dyn Error>> {
+    quiet: bool,
+) -> Result<(), MdModelsError> {
     if let MergeState::NoMerge = merge_state {
-        return Err(
-            "Per spec is not supported for single JSON schema generation at the moment.".into(),
-        );
+        return Err(MdModelsError::generation(
+            Templates::JsonSchema,
+            "Per spec is not supported for single JSON schema generation at the moment.",
+        ));
     }
 
     match root {
         Some(root) => {
-            let schema = model.json_schema(Some(root));
+            let schema = model.json_schema(Some(root))?;
             save_to_file(out, &schema)?;
-            print_render_msg(out, &Templates::JsonSchema);
+            print_render_msg(out, &Templates::JsonSchema, quiet);
             Ok(())
         }
-        None => Err("Root object has to be specified".into()),
+        None => Err(MdModelsError::generation(
+            Templates::JsonSchema,
+            "Root object has to be specified",
+        )),
+    }
+}
+
+/// Serializes all objects of the data model to a single JSON schema file.
+///
+/// # Arguments
+///
+/// * `model` - The DataModel to serialize.
+/// * `out` - The output path for the JSON schema file.
+///
+/// # Returns
+///
+/// A Result indicating success or failure.
+fn serialize_to_json_schema_bundle(
+    model: DataModel,
+    out: &PathBuf,
+    merge_state: &MergeState,
+    quiet: bool,
+) -> Result<(), MdModelsError> {
+    if let MergeState::NoMerge = merge_state {
+        return Err(MdModelsError::generation(
+            Templates::JsonSchemaBundle,
+            "Per spec is not supported for bundled JSON schema generation at the moment.",
+        ));
     }
+
+    let schema = model.json_schema_bundle()?;
+    save_to_file(out, &schema)?;
+    print_render_msg(out, &Templates::JsonSchemaBundle, quiet);
+    Ok(())
+}
+
+/// Serializes an object of the data model to a Frictionless Table Schema file.
+///
+/// # Arguments
+///
+/// * `model` - The DataModel to serialize.
+/// * `root` - The object to convert, defaults to the first object.
+/// * `flatten` - Whether nested objects and arrays are flattened.
+/// * `out` - The output path for the Table Schema file.
+///
+/// # Returns
+///
+/// A Result indicating success or failure.
+fn serialize_to_table_schema(
+    model: DataModel,
+    root: Option<String>,
+    flatten: bool,
+    out: &PathBuf,
+    merge_state: &MergeState,
+    quiet: bool,
+) -> Result<(), MdModelsError> {
+    if let MergeState::NoMerge = merge_state {
+        return Err(MdModelsError::generation(
+            Templates::TableSchema,
+            "Per spec is not supported for Table Schema generation at the moment.",
+        ));
+    }
+
+    let schema = model.table_schema(root, flatten)?;
+    save_to_file(out, &schema)?;
+    print_render_msg(out, &Templates::TableSchema, quiet);
+    Ok(())
+}
+
+/// Serializes reproducible mock instances of an object to a JSON file.
+///
+/// # Arguments
+///
+/// * `model` - The DataModel to generate the instances from.
+/// * `root` - The object to generate, defaults to the first object.
+/// * `config` - The configuration, optionally holding the `count` and `seed`.
+/// * `out` - The output path for the JSON file.
+///
+/// # Returns
+///
+/// A Result indicating success or failure.
+fn serialize_to_mock_json(
+    model: DataModel,
+    root: Option<String>,
+    config: &HashMap<String, String>,
+    out: &PathBuf,
+    merge_state: &MergeState,
+    quiet: bool,
+) -> Result<(), MdModelsError> {
+    if let MergeState::NoMerge = merge_state {
+        return Err(MdModelsError::generation(
+            Templates::MockJson,
+            "Per spec is not supported for mock data generation at the moment.",
+        ));
+    }
+
+    let parse = |key: &str, default: u64| match config.get(key) {
+        Some(value) => value.parse::<u64>().map_err(|_| {
+            MdModelsError::generation(
+                Templates::MockJson,
+                format!("Option '{}' must be a non-negative integer", key),
+            )
+        }),
+        None => Ok(default),
+    };
+
+    let mock = model.mock_json(root, parse("count", 1)? as usize, parse("seed", 0)?)?;
+    save_to_file(out, &mock)?;
+    print_render_msg(out, &Templates::MockJson, quiet);
+    Ok(())
 }
 
 /// Serializes the data model to the internal schema.
@@ -308,17 +585,19 @@ fn serialize_to_internal_schema(
     model: DataModel,
     out: &PathBuf,
     merge_state: &MergeState,
-) -> Result<(), Box<dyn Error>> {
+    quiet: bool,
+) -> Result<(), MdModelsError> {
     match merge_state {
         MergeState::Merge => {
             let schema = model.sdrdm_schema();
             save_to_file(out, &schema)?;
-            print_render_msg(out, &Templates::Internal);
+            print_render_msg(out, &Templates::Internal, quiet);
             Ok(())
         }
-        MergeState::NoMerge => {
-            Err("Per spec is not supported for internal schema generation at the moment.".into())
-        }
+        MergeState::NoMerge => Err(MdModelsError::generation(
+            Templates::Internal,
+            "Per spec is not supported for internal schema generation at the moment.",
+        )),
     }
 }
 
@@ -336,27 +615,31 @@ fn serialize_all_json_schemes(
     out: &PathBuf,
     specs: &[PathBuf],
     merge_state: &MergeState,
-) -> Result<(), Box<dyn Error>> {
+    quiet: bool,
+) -> Result<(), MdModelsError> {
     if out.is_file() {
-        return Err("Output path is a file".into());
+        return Err(MdModelsError::generation(
+            Templates::JsonSchemaAll,
+            "Output path is a file",
+        ));
     }
     if !out.exists() {
-        fs::create_dir_all(out)?;
+        fs::create_dir_all(out).map_err(|e| MdModelsError::io(out, e))?;
     }
 
     match merge_state {
         MergeState::Merge => {
             let model = build_models(specs)?;
-            model.json_schema_all(out.to_str().unwrap().to_string());
-            print_render_msg(out, &Templates::JsonSchemaAll);
+            model.json_schema_all(out.to_str().unwrap().to_string())?;
+            print_render_msg(out, &Templates::JsonSchemaAll, quiet);
             Ok(())
         }
         MergeState::NoMerge => {
             for spec in specs {
                 let model = DataModel::from_markdown(spec)?;
                 let path = out.join(get_file_name(spec));
-                model.json_schema_all(path.to_str().unwrap().to_string());
-                print_render_msg(&path, &Templates::JsonSchemaAll);
+                model.json_schema_all(path.to_str().unwrap().to_string())?;
+                print_render_msg(&path, &Templates::JsonSchemaAll, quiet);
             }
             Ok(())
         }
@@ -370,6 +653,7 @@ fn serialize_all_json_schemes(
 /// * `out` - The output path for the serialized data model.
 /// * `specs` - A slice of PathBuf representing the paths to read.
 /// * `merge_state` - The merge state.
+/// * `quiet` - Whether to suppress the status messages.
 /// * `template` - The template to use for serialization.
 ///
 /// # Returns
@@ -379,12 +663,13 @@ fn serialize_by_template(
     out: &PathBuf,
     specs: &[PathBuf],
     merge_state: &MergeState,
+    quiet: bool,
     template: &Templates,
     config: Option<&HashMap<String, String>>,
-) -> Result<(), Box<dyn Error>> {
+) -> Result<(), MdModelsError> {
     match merge_state {
         MergeState::Merge => {
-            print_render_msg(out, template);
+            print_render_msg(out, template, quiet);
 
             let mut model = build_models(specs)?;
             let content = model.convert_to(template, config)?;
@@ -393,19 +678,17 @@ fn serialize_by_template(
         }
         MergeState::NoMerge => {
             if !has_wildcard_fname(out) {
-                return Err("
-                    Output file name must contain a wildcard.
-                    For example, a valid wildcard is 'path/to/*.json'"
-                    .into());
+                return Err(MdModelsError::generation(
+                    template,
+                    "Output file name must contain a wildcard. For example, a valid wildcard is 'path/to/*.json'",
+                ));
             }
 
             for spec in specs {
-                if !spec.exists() {
-                    return Err(format!("Path does not exist: {:?}", spec).into());
-                }
+                path_exists(spec)?;
 
                 let path = replace_wildcard_fname(out, get_file_name(spec).as_str());
-                print_render_msg(&path, template);
+                print_render_msg(&path, template, quiet);
 
                 let mut model = DataModel::from_markdown(spec)?;
                 let content = model.convert_to(template, config)?;
@@ -478,17 +761,21 @@ fn get_file_name(path: &Path) -> String {
 /// # Returns
 ///
 /// A Result indicating success or failure.
-fn save_to_file(out: &PathBuf, content: &str) -> Result<(), Box<dyn Error>> {
+fn save_to_file(out: &PathBuf, content: &str) -> Result<(), MdModelsError> {
     let dir = out.parent().unwrap();
     if !dir.exists() {
-        fs::create_dir_all(dir)?;
+        fs::create_dir_all(dir).map_err(|e| MdModelsError::io(dir, e))?;
     }
 
-    fs::write(out, content.trim())?;
+    fs::write(out, content.trim()).map_err(|e| MdModelsError::io(out, e))?;
     Ok(())
 }
 
-fn print_render_msg(out: &Path, template: &Templates) {
+fn print_render_msg(out: &Path, template: &Templates, quiet: bool) {
+    if quiet {
+        return;
+    }
+
     println!(
         " [{}] Writing to '{}'",
         template.to_string().green().bold(),
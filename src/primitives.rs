@@ -1,5 +1,38 @@
 use std::collections::HashMap;
 
+/// Integer types of a fixed width, mapped to their inclusive minimum and maximum.
+pub(crate) const INTEGER_WIDTHS: [(&str, i64, u64); 4] = [
+    ("int32", i32::MIN as i64, i32::MAX as u64),
+    ("int64", i64::MIN, i64::MAX as u64),
+    ("uint32", 0, u32::MAX as u64),
+    ("uint64", 0, u64::MAX),
+];
+
+/// Returns the inclusive range of an integer type of a fixed width.
+///
+/// # Arguments
+///
+/// * `dtype` - The data type, e.g. `int32`.
+///
+/// # Returns
+///
+/// The minimum and maximum value, or `None` if the type has no fixed width.
+pub(crate) fn integer_range(dtype: &str) -> Option<(i64, u64)> {
+    INTEGER_WIDTHS
+        .iter()
+        .find(|(name, _, _)| *name == dtype)
+        .map(|(_, min, max)| (*min, *max))
+}
+
+/// Checks if the given data type is an integer, either of a fixed width or not.
+///
+/// # Arguments
+///
+/// * `dtype` - The data type to check.
+pub(crate) fn is_integer(dtype: &str) -> bool {
+    dtype == "integer" || integer_range(dtype).is_some()
+}
+
 /// A struct to manage primitive types and their corresponding JSON mappings.
 pub struct PrimitiveTypes {
     types: Vec<String>,
@@ -27,19 +60,33 @@ impl PrimitiveTypes {
         json_mappings.insert("date".to_string(), "string".to_string());
         json_mappings.insert("number".to_string(), "number".to_string());
         json_mappings.insert("identifier".to_string(), "string".to_string());
+        json_mappings.insert("bytes".to_string(), "string".to_string());
+        json_mappings.insert("timestamp".to_string(), "integer".to_string());
+        json_mappings.insert("duration".to_string(), "string".to_string());
+
+        for (name, _, _) in INTEGER_WIDTHS {
+            json_mappings.insert(name.to_string(), "integer".to_string());
+        }
+
+        let mut types = vec![
+            "string".to_string(),
+            "float".to_string(),
+            "integer".to_string(),
+            "boolean".to_string(),
+            "bool".to_string(),
+            "null".to_string(),
+            "number".to_string(),
+            "date".to_string(),
+            "identifier".to_string(),
+            "bytes".to_string(),
+            "timestamp".to_string(),
+            "duration".to_string(),
+            "any".to_string(),
+        ];
+        types.extend(INTEGER_WIDTHS.iter().map(|(name, _, _)| name.to_string()));
 
         PrimitiveTypes {
-            types: vec![
-                "string".to_string(),
-                "float".to_string(),
-                "integer".to_string(),
-                "boolean".to_string(),
-                "bool".to_string(),
-                "null".to_string(),
-                "number".to_string(),
-                "date".to_string(),
-                "identifier".to_string(),
-            ],
+            types,
             json_mappings,
         }
     }
@@ -93,7 +140,7 @@ impl PrimitiveTypes {
     /// # Returns
     ///
     /// A boolean value indicating whether the data type is a primitive type.
-    fn is_primitive(&self, dtype: &str) -> bool {
+    pub(crate) fn is_primitive(&self, dtype: &str) -> bool {
         self.types.contains(&dtype.to_string())
     }
 
@@ -109,7 +156,8 @@ impl PrimitiveTypes {
     ///
     /// # Panics
     ///
-    /// Panics if the data type is not a primitive type.
+    /// Panics if the data type is not a primitive type or is `any`, which has
+    /// no JSON type.
     pub fn dtype_to_json(&self, dtype: &String) -> String {
         if !self.json_mappings.contains_key(dtype) {
             panic!("The data type {} is not a primitive type", dtype)
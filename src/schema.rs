@@ -1,8 +1,12 @@
 use crate::attribute;
-use crate::attribute::AttrOption;
+use crate::attribute::{AttrOption, SCHEMA_KEYWORDS};
 use crate::datamodel::DataModel;
+use crate::markdown::frontmatter::TimestampFormat;
 use crate::object::{self, Enumeration};
-use crate::primitives::PrimitiveTypes;
+use crate::primitives::{integer_range, PrimitiveTypes};
+use colored::Colorize;
+use convert_case::{Case, Casing};
+use log::warn;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::HashSet;
@@ -11,6 +15,8 @@ use std::hash::Hash;
 
 static DEFINITIONS_KEY: &str = "definitions";
 static SCHEMA_VERSION: &str = "http://json-schema.org/draft-07/schema";
+static BUNDLE_DEFINITIONS_KEY: &str = "$defs";
+static BUNDLE_SCHEMA_VERSION: &str = "https://json-schema.org/draft/2020-12/schema";
 
 #[derive(PartialEq, Eq, Debug)]
 enum RefType {
@@ -40,6 +46,8 @@ impl Display for RefType {
 struct JSONSchema {
     #[serde(rename = "$schema")]
     schema: String,
+    #[serde(rename = "$id", skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
     #[serde(flatten)]
     definitions: serde_json::Value,
 }
@@ -49,23 +57,63 @@ struct JSONSchema {
 /// # Arguments
 /// * `name` - The name of the object to convert.
 /// * `model` - The data model containing the objects and enums.
+/// * `id_name` - Name the `$id` is built from, if a `$id` and anchors are embedded.
+/// * `provenance` - Whether to note the source of each object in a `$comment`.
 ///
 /// # Returns
 /// A JSON string representing the schema.
-pub fn to_json_schema(name: &String, model: &DataModel) -> String {
+pub fn to_json_schema(
+    name: &String,
+    model: &DataModel,
+    id_name: Option<&str>,
+    provenance: bool,
+) -> String {
+    // JSON schema has no generics, hence generic objects are made concrete
+    let concrete;
+    let model = match model.objects.iter().any(|o| o.type_param.is_some()) {
+        true => {
+            let mut model = model.clone();
+            model.monomorphize_generics();
+            concrete = model;
+            &concrete
+        }
+        false => model,
+    };
+
     let objects = &model.objects;
     let obj = objects.iter().find(|o| o.name == *name).unwrap();
-    let (mut schema, used_refs) = process_class(obj, model);
+    let subtypes = match obj.is_abstract {
+        true => model.concrete_subtypes(name),
+        false => vec![],
+    };
+
+    // Abstract roots are replaced by a choice of their concrete subtypes
+    let (mut schema, used_refs) = match subtypes.is_empty() {
+        true => process_class(obj, model),
+        false => abstract_root(obj, &subtypes, model),
+    };
+
+    if provenance {
+        schema["$comment"] = json!(provenance_comment(obj, model));
+    }
 
-    // Get unique used_refs
-    let used_refs = used_refs.into_iter().collect::<HashSet<RefType>>();
+    // Add the definitions of all used references, including nested ones
+    let mut pending = used_refs.into_iter().collect::<Vec<RefType>>();
+
+    while let Some(reference) = pending.pop() {
+        if schema[DEFINITIONS_KEY].get(reference.to_string()).is_some() {
+            continue;
+        }
 
-    for reference in used_refs {
         match reference {
             RefType::Object(name) => {
                 let sub_obj = objects.iter().find(|o| o.name == name).unwrap();
-                let (properties, _) = process_class(sub_obj, model);
+                let (mut properties, sub_refs) = process_class(sub_obj, model);
+                if provenance {
+                    properties["$comment"] = json!(provenance_comment(sub_obj, model));
+                }
                 schema[DEFINITIONS_KEY][name] = properties;
+                pending.extend(sub_refs);
             }
             RefType::Enum(name) => {
                 let sub_enum = model.enums.iter().find(|e| e.name == name).unwrap();
@@ -75,14 +123,264 @@ pub fn to_json_schema(name: &String, model: &DataModel) -> String {
         }
     }
 
+    let id = match id_name {
+        Some(id_name) => {
+            embed_anchors(&mut schema);
+            schema_id(id_name, model)
+        }
+        None => None,
+    };
+
     let schema = JSONSchema {
         schema: SCHEMA_VERSION.to_string(),
+        id,
         definitions: schema,
     };
 
     serde_json::to_string_pretty(&schema).unwrap()
 }
 
+/// Converts a data model to a single JSON schema holding all objects under `$defs`.
+///
+/// The bundle has no root, such that consumers refer to whichever definition
+/// they need, e.g. `model.json#/$defs/Address`. References between the
+/// definitions point into the `$defs` of the bundle itself.
+///
+/// The `$id` of the bundle is derived from the title of the model and the
+/// absolute `repo` URL of the frontmatter, and omitted if either is missing.
+///
+/// # Arguments
+/// * `model` - The data model containing the objects and enums.
+///
+/// # Returns
+/// A JSON string representing the schema.
+pub fn to_json_schema_bundle(model: &DataModel) -> String {
+    let mut model = model.clone();
+    model.monomorphize_generics();
+
+    let mut definitions = serde_json::Map::new();
+
+    for object in model.objects.iter().filter(|o| o.type_param.is_none()) {
+        let subtypes = match object.is_abstract {
+            true => model.concrete_subtypes(&object.name),
+            false => vec![],
+        };
+
+        let (properties, _) = match subtypes.is_empty() {
+            true => process_class(object, &model),
+            false => abstract_root(object, &subtypes, &model),
+        };
+
+        definitions.insert(object.name.clone(), properties);
+    }
+
+    for enumeration in model.enums.iter() {
+        definitions.insert(enumeration.name.clone(), process_enum(enumeration));
+    }
+
+    let mut schema = json!({ "$schema": BUNDLE_SCHEMA_VERSION });
+    // The bundle is identified by the title of the model, not by one of its objects
+    match model.name.as_deref() {
+        Some(title) => {
+            if let Some(id) = schema_id(title, &model) {
+                schema["$id"] = json!(id);
+            }
+        }
+        None => warn!(
+            "[{}] {}: The model has no title, the bundle has no $id.",
+            "Bundle".bold(),
+            "SchemaIdWarning".bold(),
+        ),
+    }
+    schema[BUNDLE_DEFINITIONS_KEY] = json!(definitions);
+
+    rebase_refs(&mut schema);
+    rebase_tuples(&mut schema);
+
+    serde_json::to_string_pretty(&schema).unwrap()
+}
+
+/// Rewrites all references to `definitions` into references to `$defs`.
+///
+/// # Arguments
+/// * `value` - The schema or subschema to rewrite.
+fn rebase_refs(value: &mut serde_json::Value) {
+    let prefix = format!("#/{}/", DEFINITIONS_KEY);
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match value.as_str().and_then(|r| r.strip_prefix(&prefix)) {
+                    Some(name) if key == "$ref" => {
+                        *value = json!(format!("#/{}/{}", BUNDLE_DEFINITIONS_KEY, name))
+                    }
+                    _ => rebase_refs(value),
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(rebase_refs),
+        _ => {}
+    }
+}
+
+/// Rewrites all tuples from `items` lists into `prefixItems` lists.
+///
+/// Since draft 2020-12, `items` only holds the schema of all items, and the
+/// schemas of the items by position are given by `prefixItems`. Further
+/// items are forbidden by `items` rather than `additionalItems`.
+///
+/// # Arguments
+/// * `value` - The schema or subschema to rewrite.
+fn rebase_tuples(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            if map.get("items").is_some_and(|items| items.is_array()) {
+                let items = map.remove("items").unwrap();
+                map.insert("prefixItems".to_string(), items);
+
+                if let Some(additional) = map.remove("additionalItems") {
+                    map.insert("items".to_string(), additional);
+                }
+            }
+
+            map.values_mut().for_each(rebase_tuples);
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(rebase_tuples),
+        _ => {}
+    }
+}
+
+/// Creates the root of a schema for an abstract object.
+///
+/// The root only validates against one of the concrete subtypes, such that
+/// the abstract object itself is not a valid instance.
+///
+/// # Arguments
+/// * `object` - The abstract object.
+/// * `subtypes` - The concrete subtypes of the object.
+/// * `model` - The data model containing the objects and enums.
+fn abstract_root(
+    object: &object::Object,
+    subtypes: &[&object::Object],
+    model: &DataModel,
+) -> (serde_json::Value, HashSet<RefType>) {
+    let mut refs = HashSet::new();
+    let mut members = vec![];
+
+    for subtype in subtypes {
+        let (_, subtype_refs) = process_class(subtype, model);
+        refs.extend(subtype_refs);
+        refs.insert(RefType::Object(subtype.name.clone()));
+        members.push(json!({ "$ref": ref_path(&subtype.name) }));
+    }
+
+    let mut schema = json!({
+        "title": object.summary.as_ref().unwrap_or(&object.name),
+        "oneOf": members,
+    });
+
+    if !object.docstring.is_empty() {
+        schema["description"] = json!(object.docstring);
+    }
+
+    (schema, refs)
+}
+
+/// Returns the `$id` of a schema, built from the repository URL and a name.
+///
+/// A `$id` has to be an absolute URI, hence none is returned if the model
+/// has no absolute repository URL.
+///
+/// # Arguments
+/// * `name` - The name identifying the schema, e.g. the model or object name.
+/// * `model` - The data model containing the objects and enums.
+fn schema_id(name: &str, model: &DataModel) -> Option<String> {
+    let repo = model.config.clone().unwrap_or_default().repo;
+    if !repo.contains("://") {
+        warn!(
+            "[{}] {}: No absolute repository URL given, the schema has no $id.",
+            name.bold(),
+            "SchemaIdWarning".bold(),
+        );
+        return None;
+    }
+
+    Some(format!(
+        "{}/{}.json",
+        repo.trim_end_matches('/'),
+        name.to_case(Case::Kebab)
+    ))
+}
+
+/// Returns the `$comment` noting where an object of a schema came from.
+///
+/// The comment has the stable format `source: <file>; object: <name>; version: <version>`,
+/// where the source and version are omitted if unknown.
+///
+/// # Arguments
+/// * `object` - The object of the definition.
+/// * `model` - The data model containing the object.
+fn provenance_comment(object: &object::Object, model: &DataModel) -> String {
+    let mut parts = vec![];
+
+    if let Some(source) = &object.source {
+        parts.push(format!("source: {}", source));
+    }
+
+    parts.push(format!("object: {}", object.name));
+
+    if let Some(version) = model.version() {
+        parts.push(format!("version: {}", version));
+    }
+
+    parts.join("; ")
+}
+
+/// Assigns a plain-name `$id` anchor to each definition of a schema.
+///
+/// Draft-07 expresses anchors as `$id` fragments, e.g. `#Address`. All
+/// references to the definitions are rewritten to use these anchors, such
+/// that external documents and the schema itself refer to definitions
+/// in the same way.
+///
+/// # Arguments
+/// * `schema` - The schema containing the definitions.
+fn embed_anchors(schema: &mut serde_json::Value) {
+    rewrite_refs(schema);
+
+    if let Some(definitions) = schema[DEFINITIONS_KEY].as_object_mut() {
+        for (name, definition) in definitions.iter_mut() {
+            let mut anchored = serde_json::Map::new();
+            anchored.insert("$id".to_string(), json!(format!("#{}", name)));
+
+            if let Some(properties) = definition.as_object() {
+                anchored.extend(properties.clone());
+            }
+
+            *definition = serde_json::Value::Object(anchored);
+        }
+    }
+}
+
+/// Rewrites all references to definitions into references to their anchors.
+///
+/// # Arguments
+/// * `value` - The schema or subschema to rewrite.
+fn rewrite_refs(value: &mut serde_json::Value) {
+    let prefix = format!("#/{}/", DEFINITIONS_KEY);
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match value.as_str().and_then(|r| r.strip_prefix(&prefix)) {
+                    Some(name) if key == "$ref" => *value = json!(format!("#{}", name)),
+                    _ => rewrite_refs(value),
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(rewrite_refs),
+        _ => {}
+    }
+}
+
 /// Processes a class object to generate its JSON schema and collect references.
 ///
 /// # Arguments
@@ -110,7 +408,7 @@ fn process_class(
     // Initialize the schema and references
     let mut all_refs = HashSet::new();
     let mut schema = json!({
-        "title": object.name,
+        "title": object.summary.as_ref().unwrap_or(&object.name),
         "type": "object",
         "properties": {},
     });
@@ -119,37 +417,254 @@ fn process_class(
         schema["description"] = json!(object.docstring);
     }
 
-    if object.term.is_some() {
-        schema["term"] = json!(object.term.as_ref().unwrap());
+    if let Some(ref term) = object.term {
+        schema["term"] = json!(term);
     }
 
+    let timestamps = model
+        .config
+        .as_ref()
+        .and_then(|c| c.timestamp_format)
+        .unwrap_or_default();
+
+    let mut inlined = vec![];
     for attribute in &object.attributes {
+        // Attributes that cannot be inlined are kept as regular properties
+        if let ([dtype], true, false) = (
+            attribute.dtypes.as_slice(),
+            attribute.inline,
+            attribute.is_array,
+        ) {
+            if object_names.contains(dtype) {
+                all_refs.insert(RefType::Object(dtype.clone()));
+                inlined.push(dtype.as_str());
+                continue;
+            }
+        }
+
+        if attribute.tuple {
+            for reference in attribute.dtypes.iter() {
+                if enum_names.contains(reference) {
+                    all_refs.insert(RefType::Enum(reference.clone()));
+                } else if object_names.contains(reference) {
+                    all_refs.insert(RefType::Object(reference.clone()));
+                }
+            }
+
+            process_tuple(&mut schema["properties"], attribute, timestamps);
+            continue;
+        }
+
         let (primitives, references) = extract_primitives_and_refs(&attribute.dtypes);
 
+        if attribute.dtypes.len() > 1 {
+            for reference in references.iter() {
+                if attribute::is_external_type(reference) {
+                    continue;
+                } else if enum_names.contains(reference) {
+                    all_refs.insert(RefType::Enum(reference.clone()));
+                } else if object_names.contains(reference) {
+                    all_refs.insert(RefType::Object(reference.clone()));
+                } else {
+                    warn_missing_reference(&object.name, attribute, reference);
+                }
+            }
+
+            let members = attribute
+                .dtypes
+                .iter()
+                .filter(|dtype| {
+                    primitives.contains(*dtype)
+                        || attribute::is_external_type(dtype)
+                        || enum_names.contains(*dtype)
+                        || object_names.contains(*dtype)
+                })
+                .cloned()
+                .collect::<Vec<String>>();
+
+            process_union(&mut schema["properties"], attribute, &members, timestamps);
+            continue;
+        }
+
         for primitive in primitives {
-            process_primitive(&mut schema["properties"], attribute, &primitive);
+            process_primitive(&mut schema["properties"], attribute, &primitive, timestamps);
         }
 
         for reference in references {
-            if enum_names.contains(&reference) {
+            if attribute::is_external_type(&reference) {
+                process_reference(&mut schema["properties"], attribute, &reference);
+            } else if enum_names.contains(&reference) {
                 all_refs.insert(RefType::Enum(reference.clone()));
-                process_enum_reference(
-                    &attribute.name,
-                    &mut schema["properties"],
-                    reference.as_str(),
-                );
+                process_enum_reference(attribute, &mut schema["properties"], reference.as_str());
+            } else if let Some(quantity) = builtin_quantity(&reference, model) {
+                let (quantity_schema, quantity_refs) = process_class(quantity, model);
+                all_refs.extend(quantity_refs);
+                process_quantity(&mut schema["properties"], attribute, quantity_schema);
             } else if object_names.contains(&reference) {
                 all_refs.insert(RefType::Object(reference.clone()));
                 process_reference(&mut schema["properties"], attribute, &reference);
             } else {
-                panic!("Reference {} not found in the markdown file", reference);
+                warn_missing_reference(&object.name, attribute, &reference);
+                schema["properties"][&attribute.name] = create_property(attribute);
             }
         }
     }
 
+    for attribute in object.attributes.iter().filter(|a| a.size.is_some()) {
+        if let Some(property) = schema["properties"].get_mut(&attribute.name) {
+            property["minItems"] = json!(attribute.size);
+            property["maxItems"] = json!(attribute.size);
+        }
+    }
+
+    // Only list defaults are written, which are given for arrays exclusively
+    for attribute in object.attributes.iter() {
+        if let (Some(default @ attribute::DataType::List(_)), Some(property)) = (
+            &attribute.default,
+            schema["properties"].get_mut(&attribute.name),
+        ) {
+            let enumeration = attribute
+                .dtypes
+                .first()
+                .and_then(|dtype| model.enums.iter().find(|e| e.name == *dtype));
+            property["default"] = default_value(default, enumeration);
+        }
+    }
+
+    for attribute in object.attributes.iter().filter(|a| a.map_key.is_some()) {
+        if let Some(property) = schema["properties"].get_mut(&attribute.name) {
+            wrap_map_values(property, attribute);
+        }
+    }
+
+    let closed_objects = model.config.as_ref().and_then(|c| c.closed_objects);
+    if object.closed.or(closed_objects).unwrap_or(false) {
+        schema["additionalProperties"] = json!(false);
+    }
+
+    if !object.conditions.is_empty() {
+        process_conditions(&mut schema, &object.conditions);
+    }
+
+    if !inlined.is_empty() {
+        process_inlined(&mut schema, &inlined, model);
+    }
+
     (schema, all_refs)
 }
 
+/// Logs a reference to a type that is not part of the model.
+///
+/// Only occurs for models that have not been validated. The affected
+/// property is left untyped.
+///
+/// # Arguments
+/// * `obj_name` - The name of the object containing the attribute.
+/// * `attribute` - The attribute with the missing reference.
+/// * `reference` - The name of the missing type.
+fn warn_missing_reference(obj_name: &str, attribute: &attribute::Attribute, reference: &str) {
+    warn!(
+        "[{}] {}: Type {} of property {} not found. The property is left untyped.",
+        obj_name.bold(),
+        "ReferenceWarning".bold(),
+        reference.red().bold(),
+        attribute.name.red().bold(),
+    );
+}
+
+/// Adds the conditional requirements of an object to its schema.
+///
+/// A single condition is added as `if`/`then`, multiple conditions are
+/// wrapped in an `allOf` list.
+///
+/// # Arguments
+/// * `schema` - The JSON schema of the object.
+/// * `conditions` - The conditions of the object.
+fn process_conditions(schema: &mut serde_json::Value, conditions: &[object::Condition]) {
+    let mut clauses = conditions
+        .iter()
+        .map(|condition| {
+            let properties = condition
+                .when
+                .iter()
+                .map(|(field, value)| (field.clone(), json!({ "const": value })))
+                .collect::<serde_json::Map<String, serde_json::Value>>();
+
+            json!({
+                "if": {
+                    "properties": properties,
+                    "required": condition.when.keys().collect::<Vec<&String>>(),
+                },
+                "then": {
+                    "required": condition.require,
+                },
+            })
+        })
+        .collect::<Vec<serde_json::Value>>();
+
+    if clauses.len() == 1 {
+        let clause = clauses.remove(0);
+        schema["if"] = clause["if"].clone();
+        schema["then"] = clause["then"].clone();
+    } else {
+        schema["allOf"] = json!(clauses);
+    }
+}
+
+/// Adds the inlined objects of an object to its schema.
+///
+/// Each inlined object is added as a reference to the `allOf` list, next to
+/// the clauses of multiple conditions. Since `additionalProperties` does not
+/// see the properties of the referenced schemas, closed objects permit the
+/// inlined properties explicitly.
+///
+/// # Arguments
+/// * `schema` - The schema of the object.
+/// * `inlined` - The names of the inlined objects.
+/// * `model` - The data model containing the objects and enums.
+fn process_inlined(schema: &mut serde_json::Value, inlined: &[&str], model: &DataModel) {
+    let mut all_of = inlined
+        .iter()
+        .map(|reference| json!({ "$ref": ref_path(reference) }))
+        .collect::<Vec<serde_json::Value>>();
+
+    if let Some(clauses) = schema["allOf"].as_array() {
+        all_of.extend(clauses.iter().cloned());
+    }
+
+    if schema["additionalProperties"] == json!(false) {
+        // Objects inlined by inlined objects add their properties as well
+        let mut pending = inlined.to_vec();
+        let mut visited = vec![];
+        while let Some(name) = pending.pop() {
+            if visited.contains(&name) {
+                continue;
+            }
+            visited.push(name);
+
+            let Some(child) = model.objects.iter().find(|o| o.name == name) else {
+                continue;
+            };
+
+            for attribute in &child.attributes {
+                match (attribute.inline, attribute.dtypes.as_slice()) {
+                    (true, [dtype]) if !attribute.is_array => pending.push(dtype),
+                    _ => schema["properties"][&attribute.name] = json!(true),
+                }
+            }
+        }
+    }
+
+    schema["allOf"] = json!(all_of);
+}
+
+/// Creates the schema of an enumeration.
+///
+/// Open enumerations accept values beyond their known ones, hence the known
+/// values are only one alternative next to any other string.
+///
+/// # Arguments
+/// * `enumeration` - The enumeration to process.
 fn process_enum(enumeration: &Enumeration) -> serde_json::Value {
     let values = enumeration
         .mappings
@@ -157,6 +672,17 @@ fn process_enum(enumeration: &Enumeration) -> serde_json::Value {
         .cloned()
         .collect::<Vec<String>>();
 
+    if enumeration.open {
+        return json!({
+            "title": enumeration.name,
+            "type": "string",
+            "anyOf": [
+                { "enum": values },
+                { "type": "string" },
+            ],
+        });
+    }
+
     json!({
         "title": enumeration.name,
         "type": "string",
@@ -179,32 +705,73 @@ fn extract_primitives_and_refs(dtypes: &Vec<String>) -> (Vec<String>, Vec<String
     (primitives, references)
 }
 
-/// Creates a JSON property with a capitalized title.
+/// Creates a JSON property titled by the summary or name of the attribute.
 ///
 /// # Arguments
-/// * `name` - The name of the property.
+/// * `attribute` - The attribute of the property.
 ///
 /// # Returns
 /// A JSON value representing the property.
-fn create_property(name: &String) -> serde_json::Value {
+fn create_property(attribute: &attribute::Attribute) -> serde_json::Value {
     json!({
-        "title": name,
+        "title": attribute.summary.as_ref().unwrap_or(&attribute.name),
     })
 }
 
+/// Turns a property into a map, whose values follow the schema of the property.
+///
+/// Annotations such as the title and description are kept on the property,
+/// whereas the type, references and options are moved to `additionalProperties`.
+/// References carry no annotations, hence the title and description are
+/// taken from the attribute.
+///
+/// # Arguments
+/// * `property` - The property JSON object.
+/// * `attribute` - The map attribute of the property.
+fn wrap_map_values(property: &mut serde_json::Value, attribute: &attribute::Attribute) {
+    const ANNOTATIONS: [&str; 5] = ["title", "description", "term", "x-unit", "x-aliases"];
+
+    let Some(map) = property.as_object_mut() else {
+        return;
+    };
+
+    let values = map
+        .keys()
+        .filter(|key| !ANNOTATIONS.contains(&key.as_str()))
+        .cloned()
+        .collect::<Vec<String>>()
+        .into_iter()
+        .filter_map(|key| map.remove(&key).map(|value| (key, value)))
+        .collect::<serde_json::Map<String, serde_json::Value>>();
+
+    let mut wrapped = create_property(attribute);
+    if !attribute.docstring.is_empty() {
+        wrapped["description"] = json!(attribute.docstring);
+    }
+    for (key, value) in std::mem::take(map) {
+        wrapped[key] = value;
+    }
+
+    wrapped["type"] = json!("object");
+    wrapped["additionalProperties"] = json!(values);
+    *property = wrapped;
+}
+
 /// Processes a primitive attribute and adds it to the properties.
 ///
 /// # Arguments
 /// * `properties` - The properties JSON object.
 /// * `attribute` - The attribute to process.
 /// * `primitive` - The primitive type of the attribute.
+/// * `timestamps` - The JSON representation of timestamps.
 fn process_primitive(
     properties: &mut serde_json::Value,
     attribute: &attribute::Attribute,
-    primitive: &String,
+    primitive: &str,
+    timestamps: TimestampFormat,
 ) {
     let name = &attribute.name;
-    properties[name] = create_property(name);
+    properties[name] = create_property(attribute);
 
     if !attribute.docstring.is_empty() {
         properties[name]["description"] = json!(attribute.docstring);
@@ -214,8 +781,48 @@ fn process_primitive(
         properties[name]["term"] = json!(term);
     }
 
-    set_primitive_dtype(properties, attribute, primitive);
+    if let Some(ref unit) = attribute.unit {
+        properties[name]["x-unit"] = json!(unit);
+    }
+
+    if !attribute.aliases.is_empty() {
+        properties[name]["x-aliases"] = json!(attribute.aliases);
+    }
+
+    set_primitive_dtype(properties, attribute, primitive, timestamps);
     set_options(&mut properties[name], &attribute.options);
+
+    if let Some(ref constant) = attribute.constant {
+        properties[name]["const"] = match constant {
+            attribute::DataType::String(_) => json!(constant.raw_value()),
+            _ => json!(constant),
+        };
+    }
+}
+
+/// Returns the JSON value of a default, mapping enumeration keys to their values.
+///
+/// # Arguments
+/// * `default` - The default of the attribute.
+/// * `enumeration` - The enumeration the attribute refers to, if any.
+fn default_value(
+    default: &attribute::DataType,
+    enumeration: Option<&Enumeration>,
+) -> serde_json::Value {
+    match default {
+        attribute::DataType::List(items) => items
+            .iter()
+            .map(|item| default_value(item, enumeration))
+            .collect(),
+        attribute::DataType::String(_) => {
+            let key = default.raw_value();
+            match enumeration.and_then(|e| e.mappings.get(&key)) {
+                Some(value) => json!(value),
+                None => json!(key),
+            }
+        }
+        _ => json!(default),
+    }
 }
 
 /// Sets the data type of a primitive attribute.
@@ -224,26 +831,61 @@ fn process_primitive(
 /// * `properties` - The properties JSON object.
 /// * `attribute` - The attribute to process.
 /// * `primitive` - The primitive type of the attribute.
+/// * `timestamps` - The JSON representation of timestamps.
 fn set_primitive_dtype(
     properties: &mut serde_json::Value,
     attribute: &attribute::Attribute,
-    primitive: &String,
+    primitive: &str,
+    timestamps: TimestampFormat,
 ) {
     let is_array = attribute.is_array;
     let name = &attribute.name;
-    let primitives = PrimitiveTypes::new();
-    let json_dtype = primitives.dtype_to_json(primitive);
+    let dtype = primitive_schema(primitive, timestamps);
 
     if is_array {
         properties[name]["type"] = json!("array");
-        properties[name]["items"] = json!({
-            "type": json_dtype
-        });
+        properties[name]["items"] = dtype;
 
         return;
     }
 
-    properties[name]["type"] = json!(json_dtype);
+    for (key, value) in dtype.as_object().unwrap() {
+        properties[name][key] = value.clone();
+    }
+}
+
+/// Returns the schema of a single value of a primitive type.
+///
+/// # Arguments
+/// * `primitive` - The primitive type.
+/// * `timestamps` - The JSON representation of timestamps.
+fn primitive_schema(primitive: &str, timestamps: TimestampFormat) -> serde_json::Value {
+    // Values of any type are accepted by the empty schema
+    if primitive == attribute::ANY_TYPE {
+        return json!({});
+    }
+
+    let primitives = PrimitiveTypes::new();
+    let mut dtype = json!({ "type": primitives.dtype_to_json(&primitive.to_string()) });
+
+    // Integers of a fixed width encode their range, unless narrowed by options
+    if let Some((minimum, maximum)) = integer_range(primitive) {
+        dtype["minimum"] = json!(minimum);
+        dtype["maximum"] = json!(maximum);
+    }
+
+    match primitive {
+        // Binary data is embedded as a base64 encoded string
+        "bytes" => dtype["contentEncoding"] = json!("base64"),
+        // Durations are ISO 8601 strings, e.g. `PT1H30M`
+        "duration" => dtype["format"] = json!("duration"),
+        "timestamp" if timestamps == TimestampFormat::String => {
+            dtype = json!({ "type": "string", "format": "date-time" })
+        }
+        _ => {}
+    }
+
+    dtype
 }
 
 /// Sets additional options for a JSON property.
@@ -253,17 +895,34 @@ fn set_primitive_dtype(
 /// * `options` - The list of attribute options.
 fn set_options(property: &mut serde_json::Value, options: &Vec<AttrOption>) {
     for option in options {
-        match is_numeric(&option.value) {
-            true => {
-                property[option.key()] = json!(option.value().parse::<f64>().unwrap());
-            }
-            false => {
-                property[option.key()] = json!(option.value());
-            }
+        let value = match is_numeric(&option.value) {
+            true => json!(option.value().parse::<f64>().unwrap()),
+            false => json!(option.value()),
+        };
+
+        // Keywords are written in JSON Schema case with typed values
+        match schema_keyword(option.key()) {
+            Some(keyword) if keyword == "examples" => property[keyword] = json!([value]),
+            Some(keyword) => match option.value().parse::<bool>() {
+                Ok(flag) => property[keyword] = json!(flag),
+                Err(_) => property[keyword] = value,
+            },
+            None => property[option.key()] = value,
         }
     }
 }
 
+/// Returns the JSON Schema keyword of an option key, e.g. `readOnly` for `readonly`.
+///
+/// # Arguments
+/// * `key` - The lowercase key of the option.
+fn schema_keyword(key: &str) -> Option<String> {
+    SCHEMA_KEYWORDS
+        .iter()
+        .find(|keyword| keyword.eq_ignore_ascii_case(key))
+        .map(|keyword| keyword[..1].to_lowercase() + &keyword[1..])
+}
+
 /// Checks if a value is numeric or a string.
 ///
 /// # Arguments
@@ -281,26 +940,216 @@ fn is_numeric(value: &str) -> bool {
 fn process_reference(
     properties: &mut serde_json::Value,
     attribute: &attribute::Attribute,
-    reference: &String,
+    reference: &str,
 ) {
     let name = &attribute.name;
     if let Some(ref term) = attribute.term {
         properties[name]["term"] = json!(term);
     }
 
+    if !attribute.aliases.is_empty() {
+        properties[name]["x-aliases"] = json!(attribute.aliases);
+    }
+
     set_ref_dtype(properties, attribute, reference);
     set_options(&mut properties[name], &attribute.options);
 }
 
+/// Returns the object of the built-in `quantity` type, if it is referenced.
+///
+/// An object named `Quantity` that is defined by the model itself is
+/// referenced like any other object rather than inlined.
+///
+/// # Arguments
+/// * `reference` - The referenced type.
+/// * `model` - The data model containing the objects and enums.
+fn builtin_quantity<'a>(reference: &str, model: &'a DataModel) -> Option<&'a object::Object> {
+    model
+        .objects
+        .iter()
+        .find(|o| o.builtin && o.name == reference && reference == attribute::QUANTITY_OBJECT)
+}
+
+/// Processes a quantity attribute and adds the `Quantity` object inline.
+///
+/// If the attribute has a unit, the `unit` property is constrained to it.
+///
+/// # Arguments
+/// * `properties` - The properties JSON object.
+/// * `attribute` - The attribute to process.
+/// * `quantity` - The JSON schema of the `Quantity` object.
+fn process_quantity(
+    properties: &mut serde_json::Value,
+    attribute: &attribute::Attribute,
+    mut quantity: serde_json::Value,
+) {
+    let name = &attribute.name;
+    properties[name] = create_property(attribute);
+
+    if !attribute.docstring.is_empty() {
+        properties[name]["description"] = json!(attribute.docstring);
+    }
+
+    if let Some(ref term) = attribute.term {
+        properties[name]["term"] = json!(term);
+    }
+
+    if !attribute.aliases.is_empty() {
+        properties[name]["x-aliases"] = json!(attribute.aliases);
+    }
+
+    if let Some(ref unit) = attribute.unit {
+        quantity["properties"]["unit"]["const"] = json!(unit);
+    }
+
+    let quantity = match quantity {
+        serde_json::Value::Object(mut map) => {
+            map.remove("title");
+            map.remove("description");
+            map
+        }
+        _ => unreachable!("Object schemas are always JSON objects"),
+    };
+
+    if attribute.is_array {
+        properties[name]["type"] = json!("array");
+        properties[name]["items"] = json!(quantity);
+    } else {
+        for (key, value) in quantity {
+            properties[name][key] = value;
+        }
+    }
+
+    set_options(&mut properties[name], &attribute.options);
+}
+
+/// Processes a union attribute and adds it to the properties.
+///
+/// Each member of the union is added to a `oneOf` list, either as the schema
+/// of a primitive or as a reference. If the attribute has a discriminator, an
+/// OpenAPI-style `discriminator` object is added.
+///
+/// # Arguments
+/// * `properties` - The properties JSON object.
+/// * `attribute` - The attribute to process.
+/// * `members` - The member types of the union.
+/// * `timestamps` - The JSON representation of timestamps.
+fn process_union(
+    properties: &mut serde_json::Value,
+    attribute: &attribute::Attribute,
+    members: &[String],
+    timestamps: TimestampFormat,
+) {
+    let name = &attribute.name;
+    properties[name] = create_property(attribute);
+
+    if !attribute.docstring.is_empty() {
+        properties[name]["description"] = json!(attribute.docstring);
+    }
+
+    if let Some(ref term) = attribute.term {
+        properties[name]["term"] = json!(term);
+    }
+
+    if !attribute.aliases.is_empty() {
+        properties[name]["x-aliases"] = json!(attribute.aliases);
+    }
+
+    let primitives = PrimitiveTypes::new();
+    let members = members
+        .iter()
+        .map(|member| match primitives.is_primitive(member) {
+            true => primitive_schema(member, timestamps),
+            false => json!({ "$ref": ref_path(member) }),
+        })
+        .collect::<Vec<serde_json::Value>>();
+
+    let mut union = json!({ "oneOf": members });
+    if let Some(ref discriminator) = attribute.discriminator {
+        union["discriminator"] = json!({ "propertyName": discriminator });
+    }
+
+    if attribute.is_array {
+        properties[name]["type"] = json!("array");
+        properties[name]["items"] = union;
+    } else {
+        for (key, value) in union.as_object().unwrap() {
+            properties[name][key] = value.clone();
+        }
+    }
+
+    set_options(&mut properties[name], &attribute.options);
+}
+
+/// Processes a tuple attribute and adds it to the properties.
+///
+/// Each item is typed by the data type at its position, and no further
+/// items are allowed. The number of items is constrained by the size of
+/// the attribute, which is added along with fixed-size arrays.
+///
+/// # Arguments
+/// * `properties` - The properties JSON object.
+/// * `attribute` - The attribute to process.
+/// * `timestamps` - The JSON representation of timestamps.
+fn process_tuple(
+    properties: &mut serde_json::Value,
+    attribute: &attribute::Attribute,
+    timestamps: TimestampFormat,
+) {
+    let name = &attribute.name;
+    properties[name] = create_property(attribute);
+
+    if !attribute.docstring.is_empty() {
+        properties[name]["description"] = json!(attribute.docstring);
+    }
+
+    if let Some(ref term) = attribute.term {
+        properties[name]["term"] = json!(term);
+    }
+
+    if !attribute.aliases.is_empty() {
+        properties[name]["x-aliases"] = json!(attribute.aliases);
+    }
+
+    let primitives = PrimitiveTypes::new();
+    let items = attribute
+        .dtypes
+        .iter()
+        .map(|dtype| match primitives.is_primitive(dtype) {
+            true => primitive_schema(dtype, timestamps),
+            false => json!({ "$ref": ref_path(dtype) }),
+        })
+        .collect::<Vec<serde_json::Value>>();
+
+    properties[name]["type"] = json!("array");
+    properties[name]["items"] = json!(items);
+    properties[name]["additionalItems"] = json!(false);
+
+    set_options(&mut properties[name], &attribute.options);
+}
+
 /// Processes an enum reference attribute and adds it to the properties.
 ///
 /// # Arguments
-/// * `name` - The name of the attribute.
+/// * `attribute` - The attribute to process.
 /// * `properties` - The properties JSON object.
 /// * `enumeration` - The enumeration object.
-fn process_enum_reference(name: &String, properties: &mut serde_json::Value, reference: &str) {
-    properties[name] = create_property(name);
+fn process_enum_reference(
+    attribute: &attribute::Attribute,
+    properties: &mut serde_json::Value,
+    reference: &str,
+) {
+    let name = &attribute.name;
+    properties[name] = create_property(attribute);
     let def_path = format!("#/{}/{}", DEFINITIONS_KEY, reference);
+
+    if attribute.is_array {
+        properties[name]["type"] = json!("array");
+        properties[name]["items"] = json!({ "$ref": def_path });
+
+        return;
+    }
+
     properties[name]["$ref"] = json!(def_path);
 }
 
@@ -313,10 +1162,10 @@ fn process_enum_reference(name: &String, properties: &mut serde_json::Value, ref
 fn set_ref_dtype(
     properties: &mut serde_json::Value,
     attribute: &attribute::Attribute,
-    reference: &String,
+    reference: &str,
 ) {
     let name = &attribute.name;
-    let def_path = format!("#/{}/{}", DEFINITIONS_KEY, reference);
+    let def_path = ref_path(reference);
     if attribute.is_array {
         properties[name]["type"] = json!("array");
         properties[name]["items"] = json!({
@@ -328,3 +1177,18 @@ fn set_ref_dtype(
 
     properties[name]["$ref"] = json!(def_path);
 }
+
+/// Returns the `$ref` path of a reference.
+///
+/// External references are given as URLs and used verbatim, whereas
+/// references to types of the model point to the definitions.
+///
+/// # Arguments
+/// * `reference` - The name or URL of the referenced type.
+fn ref_path(reference: &str) -> String {
+    if attribute::is_external_type(reference) {
+        reference.to_string()
+    } else {
+        format!("#/{}/{}", DEFINITIONS_KEY, reference)
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{datamodel::DataModel, object::Object, validation::collect_warnings};
+
+/// Metrics summarizing the size and complexity of a data model.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ModelStats {
+    /// Number of objects.
+    pub objects: usize,
+    /// Number of attributes across all objects.
+    pub attributes: usize,
+    /// Number of enumerations.
+    pub enums: usize,
+    /// Number of required attributes.
+    pub required: usize,
+    /// Number of optional attributes.
+    pub optional: usize,
+    /// Number of attributes holding multiple values.
+    pub arrays: usize,
+    /// Number of attributes referencing other objects.
+    pub references: usize,
+    /// Length of the longest chain of object references.
+    pub max_depth: usize,
+    /// Warnings of the model, which do not invalidate it.
+    pub warnings: Vec<String>,
+}
+
+impl ModelStats {
+    /// Computes the metrics of a data model.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The data model to summarize.
+    ///
+    /// # Returns
+    ///
+    /// A `ModelStats` containing the metrics of the model.
+    pub fn new(model: &DataModel) -> Self {
+        let attributes = model.objects.iter().flat_map(|o| &o.attributes);
+        let required = attributes.clone().filter(|a| a.required).count();
+        let total = attributes.clone().count();
+
+        let references = attributes
+            .clone()
+            .filter(|a| a.dtypes.iter().any(|t| find_object(model, t).is_some()))
+            .count();
+
+        let max_depth = model
+            .objects
+            .iter()
+            .map(|o| reference_depth(o, model, &mut vec![o.name.as_str()]))
+            .max()
+            .unwrap_or(0);
+
+        ModelStats {
+            objects: model.objects.len(),
+            attributes: total,
+            enums: model.enums.len(),
+            required,
+            optional: total - required,
+            arrays: attributes.filter(|a| a.is_array).count(),
+            references,
+            max_depth,
+            warnings: collect_warnings(model),
+        }
+    }
+}
+
+impl Display for ModelStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Objects:    {}", self.objects)?;
+        writeln!(
+            f,
+            "Attributes: {} ({} required, {} optional)",
+            self.attributes, self.required, self.optional
+        )?;
+        writeln!(f, "Enums:      {}", self.enums)?;
+        writeln!(f, "Arrays:     {}", self.arrays)?;
+        writeln!(f, "References: {}", self.references)?;
+        writeln!(f, "Max depth:  {}", self.max_depth)?;
+        write!(f, "Warnings:   {}", self.warnings.len())?;
+
+        for warning in &self.warnings {
+            write!(f, "\n  {}", warning)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Finds an object of the model by its name.
+fn find_object<'a>(model: &'a DataModel, name: &str) -> Option<&'a Object> {
+    model.objects.iter().find(|o| o.name == name)
+}
+
+/// Computes the length of the longest chain of references starting at an object.
+///
+/// Objects already part of the current chain are not followed again, so that
+/// recursive models terminate.
+///
+/// # Arguments
+///
+/// * `object` - The object to start from.
+/// * `model` - The data model containing the objects.
+/// * `path` - Names of the objects of the current chain.
+fn reference_depth<'a>(object: &'a Object, model: &'a DataModel, path: &mut Vec<&'a str>) -> usize {
+    let mut depth = 0;
+
+    for dtype in object.attributes.iter().flat_map(|a| &a.dtypes) {
+        let Some(target) = find_object(model, dtype) else {
+            continue;
+        };
+
+        if path.contains(&target.name.as_str()) {
+            continue;
+        }
+
+        path.push(&target.name);
+        depth = depth.max(1 + reference_depth(target, model, path));
+        path.pop();
+    }
+
+    depth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_model_stats() {
+        // Arrange
+        let model = DataModel::from_markdown_string(
+            "### A\n\n- __id__\n  - Type: string\n- b\n  - Type: B[]\n\n### B\n\n- c\n  - Type: C\n- a\n  - Type: A\n\n### C\n\n- value\n  - Type: float\n  - Unit: mL\n- kind\n  - Type: Kind\n\n## Enumerations\n\n### Kind\n\n```\nX = x\n```\n",
+        )
+        .expect("Could not parse markdown");
+
+        // Act
+        let stats = model.stats();
+
+        // Assert
+        assert_eq!(
+            stats,
+            ModelStats {
+                objects: 3,
+                attributes: 6,
+                enums: 1,
+                required: 1,
+                optional: 5,
+                arrays: 1,
+                references: 3,
+                max_depth: 2,
+                warnings: vec![],
+            }
+        );
+    }
+}
@@ -0,0 +1,312 @@
+use serde::Serialize;
+use serde_json::json;
+use serde_with::skip_serializing_none;
+
+use crate::{
+    attribute::Attribute, datamodel::DataModel, error::MdModelsError, exporters::Templates,
+    markdown::frontmatter::TimestampFormat, object::Object,
+};
+
+/// Frictionless Table Schema descriptor of a single object.
+#[derive(Debug, Serialize)]
+struct TableSchema {
+    /// Fields of the table, one per column.
+    fields: Vec<Field>,
+    /// Names of the fields forming the primary key.
+    #[serde(rename = "primaryKey", skip_serializing_if = "Vec::is_empty")]
+    primary_key: Vec<String>,
+}
+
+/// Field of a Table Schema.
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+struct Field {
+    /// Name of the field, dotted for flattened nested objects.
+    name: String,
+    /// Frictionless type of the field.
+    #[serde(rename = "type")]
+    dtype: String,
+    /// Description of the field.
+    description: Option<String>,
+    /// Constraints of the field.
+    constraints: Option<Constraints>,
+}
+
+/// Constraints of a Table Schema field.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct Constraints {
+    required: Option<bool>,
+    unique: Option<bool>,
+    pattern: Option<String>,
+    minimum: Option<serde_json::Value>,
+    maximum: Option<serde_json::Value>,
+    min_length: Option<serde_json::Value>,
+    max_length: Option<serde_json::Value>,
+    #[serde(rename = "enum")]
+    values: Option<Vec<String>>,
+}
+
+/// Converts an object of a data model to a Frictionless Table Schema.
+///
+/// Each scalar attribute becomes a field of the table. Attributes referencing
+/// other objects are flattened into dotted field names and arrays are mapped
+/// to `array` fields, if `flatten` is enabled. Otherwise, both are rejected.
+///
+/// # Arguments
+///
+/// * `name` - The name of the object to convert.
+/// * `model` - The data model containing the objects and enums.
+/// * `flatten` - Whether to flatten nested objects and arrays.
+///
+/// # Errors
+///
+/// Returns a `MdModelsError::Generation` if the object does not exist or
+/// cannot be mapped to a flat table.
+pub fn to_table_schema(
+    name: &str,
+    model: &DataModel,
+    flatten: bool,
+) -> Result<String, MdModelsError> {
+    let object = model
+        .objects
+        .iter()
+        .find(|o| o.name == name)
+        .ok_or_else(|| {
+            MdModelsError::generation(
+                Templates::TableSchema,
+                format!("Object '{}' not found in the model", name),
+            )
+        })?;
+
+    let mut fields = vec![];
+    let mut stack = vec![object.name.clone()];
+    collect_fields(object, model, None, true, flatten, &mut stack, &mut fields)?;
+
+    let primary_key = match object.primary_key.is_empty() {
+        true => object
+            .attributes
+            .iter()
+            .find(|a| a.is_id && !a.is_array)
+            .map(|a| vec![a.name.clone()])
+            .unwrap_or_default(),
+        false => object.primary_key.clone(),
+    };
+
+    let schema = TableSchema {
+        fields,
+        primary_key,
+    };
+
+    Ok(serde_json::to_string_pretty(&schema).expect("Could not serialize Table Schema"))
+}
+
+/// Collects the fields of an object, descending into referenced objects.
+///
+/// # Arguments
+///
+/// * `object` - The object whose attributes are collected.
+/// * `model` - The data model containing the objects and enums.
+/// * `prefix` - Dotted name of the attribute referencing the object, if nested.
+/// * `required` - Whether the object itself is required.
+/// * `flatten` - Whether to flatten nested objects and arrays.
+/// * `stack` - Names of the objects currently being flattened.
+/// * `fields` - The collected fields.
+fn collect_fields(
+    object: &Object,
+    model: &DataModel,
+    prefix: Option<&str>,
+    required: bool,
+    flatten: bool,
+    stack: &mut Vec<String>,
+    fields: &mut Vec<Field>,
+) -> Result<(), MdModelsError> {
+    for attr in &object.attributes {
+        let name = match prefix {
+            Some(prefix) => format!("{}.{}", prefix, attr.name),
+            None => attr.name.clone(),
+        };
+
+        let reference = match attr.dtypes.as_slice() {
+            [dtype] => model.objects.iter().find(|o| o.name == *dtype),
+            _ => None,
+        };
+
+        if !flatten && (attr.is_array || reference.is_some()) {
+            return Err(MdModelsError::generation(
+                Templates::TableSchema,
+                format!(
+                    "Attribute '{}' of object '{}' is not a scalar. Enable flattening to map nested objects and arrays",
+                    attr.name, object.name
+                ),
+            ));
+        }
+
+        match reference {
+            Some(target) if !attr.is_array => {
+                if stack.contains(&target.name) {
+                    return Err(MdModelsError::generation(
+                        Templates::TableSchema,
+                        format!(
+                            "Attribute '{}' of object '{}' recursively references object '{}'",
+                            attr.name, object.name, target.name
+                        ),
+                    ));
+                }
+
+                stack.push(target.name.clone());
+                let required = required && attr.required;
+                collect_fields(target, model, Some(&name), required, flatten, stack, fields)?;
+                stack.pop();
+            }
+            _ => fields.push(field(attr, model, name, required, prefix.is_none())),
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates the field of a scalar or array attribute.
+///
+/// # Arguments
+///
+/// * `attr` - The attribute to convert.
+/// * `model` - The data model containing the enums.
+/// * `name` - The name of the field.
+/// * `required` - Whether the object holding the attribute is required.
+/// * `root` - Whether the attribute belongs to the root object.
+fn field(attr: &Attribute, model: &DataModel, name: String, required: bool, root: bool) -> Field {
+    let enumeration = match attr.dtypes.as_slice() {
+        [dtype] => model.enums.iter().find(|e| e.name == *dtype),
+        _ => None,
+    };
+
+    let dtype = match (attr.is_array, attr.dtypes.as_slice(), enumeration) {
+        (true, _, _) => "array",
+        (false, _, Some(_)) => "string",
+        (false, [dtype], None) if dtype == "timestamp" => {
+            match model.config.as_ref().and_then(|c| c.timestamp_format) {
+                Some(TimestampFormat::String) => "datetime",
+                _ => "integer",
+            }
+        }
+        (false, [dtype], None) => frictionless_type(dtype),
+        _ => "any",
+    };
+
+    let mut constraints = Constraints {
+        required: (required && attr.required).then_some(true),
+        unique: (root && attr.is_id && !attr.is_array).then_some(true),
+        values: enumeration
+            .filter(|_| !attr.is_array)
+            .map(|e| e.mappings.values().cloned().collect()),
+        ..Default::default()
+    };
+
+    if !attr.is_array {
+        for option in &attr.options {
+            let value = Some(option_value(option.value()));
+            match option.key() {
+                "pattern" => constraints.pattern = Some(option.value().to_string()),
+                "minimum" => constraints.minimum = value,
+                "maximum" => constraints.maximum = value,
+                "minlength" => constraints.min_length = value,
+                "maxlength" => constraints.max_length = value,
+                _ => {}
+            }
+        }
+    }
+
+    Field {
+        name,
+        dtype: dtype.to_string(),
+        description: Some(attr.docstring.clone()).filter(|d| !d.is_empty()),
+        constraints: Some(constraints).filter(|c| *c != Constraints::default()),
+    }
+}
+
+/// Maps an MD-Models type to a Frictionless field type.
+fn frictionless_type(dtype: &str) -> &'static str {
+    match dtype {
+        "string" | "bytes" => "string",
+        "integer" | "int32" | "int64" | "uint32" | "uint64" => "integer",
+        "float" | "number" => "number",
+        "boolean" | "bool" => "boolean",
+        "date" => "date",
+        "datetime" => "datetime",
+        "duration" => "duration",
+        _ => "any",
+    }
+}
+
+/// Converts the value of an option to a JSON number, if it is numeric.
+fn option_value(value: &str) -> serde_json::Value {
+    if let Ok(value) = value.parse::<i64>() {
+        return json!(value);
+    }
+
+    match value.parse::<f64>() {
+        Ok(value) => json!(value),
+        Err(_) => json!(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Helper function to convert the first object of an inline markdown model.
+    fn table_schema(content: &str, flatten: bool) -> Result<serde_json::Value, MdModelsError> {
+        let model = DataModel::from_markdown_string(content).expect("Could not parse markdown");
+        let schema = to_table_schema(&model.objects[0].name, &model, flatten)?;
+        Ok(serde_json::from_str(&schema).unwrap())
+    }
+
+    #[test]
+    fn test_flatten_nested_object() {
+        // Act
+        let schema = table_schema(
+            "### Sample\n\n- __id__\n  - Type: Identifier\n- origin\n  - Type: Location\n- tags\n  - Type: string[]\n\n### Location\n\n- __city__\n  - Type: string\n- lat\n  - Type: float\n",
+            true,
+        )
+        .expect("Could not convert model");
+
+        // Assert
+        let names = schema["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| f["name"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["id", "origin.city", "origin.lat", "tags"]);
+        assert_eq!(schema["fields"][1]["constraints"], serde_json::Value::Null);
+        assert_eq!(schema["fields"][3]["type"], "array");
+        assert_eq!(schema["primaryKey"], json!(["id"]));
+    }
+
+    #[test]
+    fn test_reject_nested_object() {
+        // Act
+        let result = table_schema(
+            "### Sample\n\n- origin\n  - Type: Location\n\n### Location\n\n- city\n  - Type: string\n",
+            false,
+        );
+
+        // Assert
+        assert!(matches!(result, Err(MdModelsError::Generation { .. })));
+    }
+
+    #[test]
+    fn test_reject_recursive_object() {
+        // Act
+        let result = table_schema(
+            "### Node\n\n- name\n  - Type: string\n- parent\n  - Type: Node\n",
+            true,
+        );
+
+        // Assert
+        assert!(matches!(result, Err(MdModelsError::Generation { .. })));
+    }
+}
@@ -0,0 +1,165 @@
+/// Metric prefixes defined by UCUM.
+const PREFIXES: [&str; 20] = [
+    "Y", "Z", "E", "P", "T", "G", "M", "k", "h", "da", "d", "c", "m", "u", "n", "p", "f", "a", "z",
+    "y",
+];
+
+/// Unit atoms that may be combined with a metric prefix.
+const METRIC_ATOMS: [&str; 38] = [
+    "m", "s", "g", "rad", "K", "C", "cd", "mol", "sr", "Hz", "N", "Pa", "J", "W", "A", "V", "F",
+    "Ohm", "S", "Wb", "Cel", "T", "H", "lm", "lx", "Bq", "Gy", "Sv", "L", "l", "t", "bar", "eV",
+    "u", "Da", "eq", "osm", "kat",
+];
+
+/// Unit atoms that must not be combined with a metric prefix.
+const NON_METRIC_ATOMS: [&str; 17] = [
+    "min", "h", "d", "wk", "mo", "a", "deg", "'", "''", "%", "[ppth]", "[ppm]", "[ppb]", "[iU]",
+    "[IU]", "[pH]", "[degF]",
+];
+
+/// Checks whether a unit expression conforms to the UCUM case-sensitive syntax.
+///
+/// Supports multiplication (`.`), division (`/`), integer exponents,
+/// parentheses, annotations (`{...}`) and numeric factors.
+///
+/// # Arguments
+///
+/// * `unit` - The unit expression to check.
+///
+/// # Returns
+///
+/// `true` if the unit is valid, `false` otherwise.
+pub(crate) fn is_valid_ucum(unit: &str) -> bool {
+    let unit = unit.trim();
+    if unit.is_empty() {
+        return false;
+    }
+
+    let term = unit.strip_prefix('/').unwrap_or(unit);
+    is_valid_term(term)
+}
+
+/// Validates a term consisting of components joined by `.` or `/`.
+fn is_valid_term(term: &str) -> bool {
+    match split_term(term) {
+        Some(components) => components.iter().all(|c| is_valid_component(c)),
+        None => false,
+    }
+}
+
+/// Splits a term at top-level operators, ignoring those within parentheses,
+/// brackets or annotations.
+fn split_term(term: &str) -> Option<Vec<&str>> {
+    let mut components = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+
+    for (i, c) in term.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            '.' | '/' if depth == 0 => {
+                components.push(&term[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+
+        if depth < 0 {
+            return None;
+        }
+    }
+
+    if depth != 0 {
+        return None;
+    }
+
+    components.push(&term[start..]);
+    Some(components)
+}
+
+/// Validates a single component of a term.
+fn is_valid_component(component: &str) -> bool {
+    if component.is_empty() {
+        return false;
+    }
+
+    // Strip a trailing annotation, which may also stand alone
+    let component = match component.find('{') {
+        Some(0) => return component.ends_with('}'),
+        Some(i) if component.ends_with('}') => &component[..i],
+        Some(_) => return false,
+        None => component,
+    };
+
+    if component.chars().all(|c| c.is_ascii_digit()) {
+        return true;
+    }
+
+    let component = strip_exponent(component);
+    if let Some(inner) = component
+        .strip_prefix('(')
+        .and_then(|c| c.strip_suffix(')'))
+    {
+        return is_valid_term(inner);
+    }
+
+    is_valid_simple_unit(component)
+}
+
+/// Removes a trailing (optionally signed) integer exponent.
+fn strip_exponent(component: &str) -> &str {
+    let trimmed = component.trim_end_matches(|c: char| c.is_ascii_digit());
+    if trimmed.len() == component.len() {
+        return component;
+    }
+
+    let trimmed = trimmed.strip_suffix(['+', '-']).unwrap_or(trimmed);
+
+    // Keep the digits if nothing but the exponent would remain
+    if trimmed.is_empty() {
+        component
+    } else {
+        trimmed
+    }
+}
+
+/// Checks whether a unit is a known atom, optionally with a metric prefix.
+fn is_valid_simple_unit(unit: &str) -> bool {
+    if METRIC_ATOMS.contains(&unit) || NON_METRIC_ATOMS.contains(&unit) {
+        return true;
+    }
+
+    PREFIXES.iter().any(|prefix| {
+        unit.strip_prefix(prefix)
+            .is_some_and(|atom| METRIC_ATOMS.contains(&atom))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_units() {
+        for unit in [
+            "mmol/L",
+            "m/s2",
+            "kg.m/s2",
+            "Cel",
+            "/min",
+            "mg{total}",
+            "%",
+            "(m/s)2",
+        ] {
+            assert!(is_valid_ucum(unit), "Expected {} to be valid", unit);
+        }
+    }
+
+    #[test]
+    fn test_invalid_units() {
+        for unit in ["", "mmol/", "furlong", "kmin", "m/(s", "mg{total"] {
+            assert!(!is_valid_ucum(unit), "Expected {} to be invalid", unit);
+        }
+    }
+}
@@ -1,25 +1,87 @@
-use std::{collections::HashSet, error::Error};
+use std::{collections::HashSet, fmt::Display};
 
 use crate::{
-    attribute::Attribute,
+    attribute::{
+        generic_type, is_external_type, Attribute, DataType, ANY_TYPE, KNOWN_OPTIONS,
+        QUANTITY_OBJECT, SCHEMA_KEYWORDS,
+    },
     datamodel::DataModel,
-    object::{Enumeration, Object},
+    error::MdModelsError,
+    exporters::{is_targeted, Templates},
+    markdown::frontmatter::NamingCase,
+    object::{split_curie, Enumeration, Object},
+    orm::table_name,
+    primitives::is_integer,
 };
 use colored::Colorize;
-use log::error;
+use convert_case::{Case, Casing};
+use log::{error, warn};
 
 // Basic types that are ignored in the validation process
-const BASIC_TYPES: [&str; 6] = ["string", "number", "integer", "boolean", "float", "date"];
+const BASIC_TYPES: [&str; 13] = [
+    "string",
+    "number",
+    "integer",
+    "boolean",
+    "float",
+    "date",
+    "int32",
+    "int64",
+    "uint32",
+    "uint64",
+    "bytes",
+    "timestamp",
+    "duration",
+];
+
+// Access modifiers of attributes
+const VISIBILITIES: [&str; 3] = ["public", "private", "internal"];
+
+// Numeric types that may carry a unit
+const NUMERIC_TYPES: [&str; 7] = [
+    "number", "integer", "float", "int32", "int64", "uint32", "uint64",
+];
+
+/// Label of violations that do not belong to a single definition.
+const GLOBAL: &str = "Global";
 
 /// Validator for checking the integrity of a data model.
 pub struct Validator {
     is_valid: bool,
+    invalid_definitions: Vec<String>,
+    strict: bool,
 }
 
 impl Validator {
     /// Creates a new instance of `Validator`.
     pub fn new() -> Self {
-        Self { is_valid: true }
+        Self {
+            is_valid: true,
+            invalid_definitions: Vec::new(),
+            strict: false,
+        }
+    }
+
+    /// Sets whether naming convention violations are errors instead of warnings.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - Whether to invalidate definitions violating the naming conventions.
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Marks the model as invalid due to a violation in the given definition.
+    ///
+    /// # Arguments
+    ///
+    /// * `definition` - The name of the definition, or `Global` for model-wide violations.
+    fn invalidate(&mut self, definition: &str) {
+        self.is_valid = false;
+
+        if !self.invalid_definitions.iter().any(|d| d == definition) {
+            self.invalid_definitions.push(definition.to_string());
+        }
     }
 
     /// Validates the provided `DataModel`.
@@ -27,17 +89,21 @@ impl Validator {
     /// # Arguments
     ///
     /// * `model` - A reference to the `DataModel` to be validated.
-    pub fn validate(&mut self, model: &DataModel) -> Result<(), Box<dyn Error>> {
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MdModelsError::Validation` listing the invalid definitions.
+    pub fn validate(&mut self, model: &DataModel) -> Result<(), MdModelsError> {
         // Check for duplicate object names
         let result_objs = check_duplicate_objects(&model.objects);
         if result_objs.is_err() {
-            self.is_valid = false;
+            self.invalidate(GLOBAL);
         }
 
         // Check for duplicate enum names
         let result_enums = check_duplicate_enums(&model.enums);
         if result_enums.is_err() {
-            self.is_valid = false;
+            self.invalidate(GLOBAL);
         }
 
         // Get the list of object types
@@ -61,19 +127,114 @@ impl Validator {
                 "[Global] {}: This model has no definitions.",
                 "ModelError".bold(),
             );
-            self.is_valid = false;
+            self.invalidate(GLOBAL);
+        }
+
+        // Check if the table names of the objects are unique
+        if check_table_collisions(&model.objects).is_err() {
+            self.invalidate(GLOBAL);
+        }
+
+        // Check if the CURIEs of enumeration values use known prefixes
+        for enumeration in &model.enums {
+            if validate_enum_curies(enumeration, model).is_err() {
+                self.invalidate(&enumeration.name);
+            }
+        }
+
+        // Check if the superclasses of objects are IRIs or CURIEs with known prefixes
+        for object in &model.objects {
+            if validate_subclass_curies(object, model).is_err() {
+                self.invalidate(&object.name);
+            }
+        }
+
+        // Check if the names follow the naming conventions of the frontmatter
+        for (definition, message) in naming_violations(model) {
+            if self.strict {
+                error!(
+                    "[{}] {}: {}",
+                    definition.bold(),
+                    "NamingError".bold(),
+                    message
+                );
+                self.invalidate(&definition);
+            } else {
+                warn!(
+                    "[{}] {}: {}",
+                    definition.bold(),
+                    "NamingWarning".bold(),
+                    message
+                );
+            }
+        }
+
+        // Check if the option keys of attributes are known
+        for (definition, message) in unknown_options(model) {
+            warn!(
+                "[{}] {}: {}",
+                definition.bold(),
+                "OptionWarning".bold(),
+                message
+            );
+        }
+
+        // Check if the targets of objects and attributes are consistent
+        for (definition, message) in target_violations(model) {
+            warn!(
+                "[{}] {}: {}",
+                definition.bold(),
+                "TargetWarning".bold(),
+                message
+            );
+        }
+
+        // Check if abstract objects can be instantiated through subtypes
+        for name in abstract_without_subtypes(model) {
+            warn!(
+                "[{}] {}: {}",
+                name.bold(),
+                "AbstractWarning".bold(),
+                ABSTRACT_WARNING
+            );
+        }
+
+        // Check if enumerations are used by any property
+        for name in unused_enums(model) {
+            warn!(
+                "[{}] {}: {}",
+                name.bold(),
+                "EnumWarning".bold(),
+                UNUSED_ENUM_WARNING
+            );
+        }
+
+        // Check if extended enumerations exist and are not cyclic
+        for enumeration in &model.enums {
+            if validate_enum_extension(enumeration, &model.enums).is_err() {
+                self.invalidate(&enumeration.name);
+            }
+        }
+
+        // Check if conditions refer to existing objects
+        if check_condition_objects(model).is_err() {
+            self.invalidate(GLOBAL);
         }
 
         // Validate the objects and enums
         for object in &model.objects {
-            let result = validate_object(object, &types);
+            let external_refs = model.config.as_ref().and_then(|c| c.external_refs.as_ref());
+            let result =
+                validate_object(object, &types, &model.objects, &model.enums, external_refs);
             if result.is_err() {
-                self.is_valid = false;
+                self.invalidate(&object.name);
             }
         }
 
         if !self.is_valid {
-            Err("Invalid Markdown Model".into())
+            Err(MdModelsError::Validation {
+                definitions: self.invalid_definitions.clone(),
+            })
         } else {
             Ok(())
         }
@@ -157,6 +318,182 @@ fn check_duplicate_enums(collection: &[Enumeration]) -> Result<(), ()> {
     valid
 }
 
+/// Checks that all objects with conditions in the frontmatter exist.
+///
+/// # Arguments
+///
+/// * `model` - A reference to the `DataModel` to be checked.
+fn check_condition_objects(model: &DataModel) -> Result<(), ()> {
+    let mut valid = Ok(());
+    let conditions = match model.config.as_ref().and_then(|c| c.conditions.as_ref()) {
+        Some(conditions) => conditions,
+        None => return valid,
+    };
+
+    for name in conditions.keys() {
+        if !model.objects.iter().any(|object| object.name == *name) {
+            error!(
+                "[{}] {}: Conditions are defined for object {}, which does not exist.",
+                "Global".bold(),
+                "ConditionError".bold(),
+                name.red().bold(),
+            );
+            valid = Err(());
+        }
+    }
+
+    valid
+}
+
+/// Checks that the table names of the objects are unique.
+///
+/// Table names are taken from the `Table` annotation or derived from the
+/// object name and are compared case-insensitively, as most databases do.
+///
+/// # Arguments
+///
+/// * `collection` - A slice of `Object` instances to be checked.
+fn check_table_collisions(collection: &[Object]) -> Result<(), ()> {
+    let mut valid = Ok(());
+    let mut seen: Vec<(&str, String)> = vec![];
+
+    for object in collection {
+        let table = table_name(object).to_lowercase();
+        let collision = seen
+            .iter()
+            .find(|(name, other)| *name != object.name && *other == table);
+
+        if let Some((other, _)) = collision {
+            error!(
+                "[{}] {}: Table {} of object {} collides with object {}.",
+                "Global".bold(),
+                "TableError".bold(),
+                table.red().bold(),
+                object.name.red().bold(),
+                other.red().bold(),
+            );
+            valid = Err(());
+        }
+
+        seen.push((object.name.as_str(), table));
+    }
+
+    valid
+}
+
+/// Checks that the enumeration values given as CURIEs use known prefixes.
+///
+/// # Arguments
+///
+/// * `enumeration` - A reference to the `Enumeration` to be checked.
+/// * `model` - A reference to the `DataModel` providing the prefixes.
+fn validate_enum_curies(enumeration: &Enumeration, model: &DataModel) -> Result<(), ()> {
+    let mut valid = Ok(());
+    let prefixes = model.config.as_ref().and_then(|c| c.prefixes.as_ref());
+
+    for (key, value) in &enumeration.mappings {
+        let prefix = match split_curie(value) {
+            Some((prefix, _)) => prefix,
+            None => continue,
+        };
+
+        if !prefixes.is_some_and(|p| p.contains_key(prefix)) {
+            error!(
+                "[{}] {}: Value {} of key {} uses prefix {}, which is not defined.",
+                enumeration.name.bold(),
+                "CurieError".bold(),
+                value.red().bold(),
+                key.red().bold(),
+                prefix.red().bold(),
+            );
+            valid = Err(());
+        }
+    }
+
+    valid
+}
+
+/// Checks that the superclasses of an object are IRIs or CURIEs with known prefixes.
+///
+/// # Arguments
+///
+/// * `object` - A reference to the `Object` to be checked.
+/// * `model` - A reference to the `DataModel` holding the prefixes.
+fn validate_subclass_curies(object: &Object, model: &DataModel) -> Result<(), ()> {
+    let mut valid = Ok(());
+    let prefixes = model.config.as_ref().and_then(|c| c.prefixes.as_ref());
+
+    for class in &object.subclass_of {
+        if class.contains("://") {
+            continue;
+        }
+
+        match split_curie(class) {
+            Some((prefix, _)) if prefixes.is_some_and(|p| p.contains_key(prefix)) => {}
+            Some((prefix, _)) => {
+                error!(
+                    "[{}] {}: Superclass {} uses prefix {}, which is not defined.",
+                    object.name.bold(),
+                    "CurieError".bold(),
+                    class.red().bold(),
+                    prefix.red().bold(),
+                );
+                valid = Err(());
+            }
+            None => {
+                error!(
+                    "[{}] {}: Superclass {} is neither a CURIE nor an IRI.",
+                    object.name.bold(),
+                    "CurieError".bold(),
+                    class.red().bold(),
+                );
+                valid = Err(());
+            }
+        }
+    }
+
+    valid
+}
+
+/// Checks that an enumeration extends an existing enumeration without a cycle.
+///
+/// # Arguments
+///
+/// * `enumeration` - A reference to the `Enumeration` to be checked.
+/// * `enums` - A slice of all enumerations within the model.
+fn validate_enum_extension(enumeration: &Enumeration, enums: &[Enumeration]) -> Result<(), ()> {
+    let mut chain = vec![enumeration.name.as_str()];
+    let mut base = enumeration.extends.as_deref();
+
+    while let Some(name) = base {
+        let Some(extended) = enums.iter().find(|e| e.name == name) else {
+            error!(
+                "[{}] {}: Extended enumeration {} not found.",
+                enumeration.name.bold(),
+                "EnumError".bold(),
+                name.red().bold(),
+            );
+            return Err(());
+        };
+
+        if chain.contains(&name) {
+            chain.push(name);
+            error!(
+                "[{}] {}: Cyclic extension {}.",
+                enumeration.name.bold(),
+                "EnumError".bold(),
+                chain.join(" -> ").red().bold(),
+            );
+            return Err(());
+        }
+
+        chain.push(name);
+        base = extended.extends.as_deref();
+    }
+
+    Ok(())
+}
+
 /// Returns a list of unique elements from a slice.
 fn unique_elements<T: std::cmp::Eq + std::hash::Hash + Clone>(input: &[T]) -> Vec<T> {
     let mut set = HashSet::new();
@@ -174,7 +511,15 @@ fn unique_elements<T: std::cmp::Eq + std::hash::Hash + Clone>(input: &[T]) -> Ve
 ///
 /// * `object` - A reference to the `Object` to be validated.
 /// * `types` - A slice of type names that are valid within the model.
-fn validate_object(object: &Object, types: &[&str]) -> Result<(), ()> {
+/// * `objects` - A slice of all objects within the model.
+/// * `enums` - A slice of all enumerations within the model.
+fn validate_object(
+    object: &Object,
+    types: &[&str],
+    objects: &[Object],
+    enums: &[Enumeration],
+    external_refs: Option<&Vec<String>>,
+) -> Result<(), ()> {
     let mut valid = Ok(());
 
     // Check if the object has fields
@@ -215,9 +560,91 @@ fn validate_object(object: &Object, types: &[&str]) -> Result<(), ()> {
         valid = Err(());
     }
 
+    // Check if the aliases collide with other attributes or aliases
+    if check_alias_collisions(object).is_err() {
+        valid = Err(());
+    }
+
+    // Check if the column overrides collide with other columns
+    if check_column_collisions(object).is_err() {
+        valid = Err(());
+    }
+
+    // Check if the conditions refer to existing attributes
+    if validate_conditions(object).is_err() {
+        valid = Err(());
+    }
+
+    // Check if the primary key consists of existing and required attributes
+    if validate_primary_key(object).is_err() {
+        valid = Err(());
+    }
+
+    // Check if the indexes consist of existing single-column attributes
+    if validate_indexes(object, objects).is_err() {
+        valid = Err(());
+    }
+
+    // Check if inlined objects exist and do not collide with other attributes
+    if validate_inline(object, objects).is_err() {
+        valid = Err(());
+    }
+
+    // The type parameter of a generic object is a valid type within it
+    let types = types
+        .iter()
+        .copied()
+        .chain(object.type_param.as_deref())
+        .collect::<Vec<&str>>();
+
     // Validate the attributes of the object
     object.attributes.iter().for_each(|attribute| {
-        let result = validate_attribute(attribute, types, &object.name);
+        let result = validate_attribute(attribute, &types, &object.name);
+        if result.is_err() {
+            valid = Err(());
+        }
+
+        let result = validate_generic_types(attribute, &types, objects, &object.name);
+        if result.is_err() {
+            valid = Err(());
+        }
+
+        let result = validate_discriminator(attribute, objects, &object.name);
+        if result.is_err() {
+            valid = Err(());
+        }
+
+        let result = validate_unit(attribute, &object.name);
+        if result.is_err() {
+            valid = Err(());
+        }
+
+        let result = validate_default_shape(attribute, &object.name);
+        if result.is_err() {
+            valid = Err(());
+        }
+
+        let result = validate_enum_default(attribute, enums, &object.name);
+        if result.is_err() {
+            valid = Err(());
+        }
+
+        let result = validate_constant(attribute, &object.name);
+        if result.is_err() {
+            valid = Err(());
+        }
+
+        let result = validate_external_refs(attribute, external_refs, &object.name);
+        if result.is_err() {
+            valid = Err(());
+        }
+
+        let result = validate_visibility(attribute, &object.name);
+        if result.is_err() {
+            valid = Err(());
+        }
+
+        let result = validate_size(attribute, &object.name);
         if result.is_err() {
             valid = Err(());
         }
@@ -226,38 +653,1259 @@ fn validate_object(object: &Object, types: &[&str]) -> Result<(), ()> {
     valid
 }
 
-/// Validates a single attribute within an object.
+/// Checks that the aliases of an object's attributes are unique.
+///
+/// An alias must neither match the name of another attribute nor an
+/// alias of any other attribute in the same object. Within an attribute,
+/// aliases must differ from each other and from the name of the attribute.
 ///
 /// # Arguments
 ///
-/// * `attribute` - A reference to the `Attribute` to be validated.
-/// * `types` - A slice of type names that are valid within the model.
-/// * `obj_name` - The name of the object that contains the attribute.
-fn validate_attribute(attribute: &Attribute, types: &[&str], obj_name: &str) -> Result<(), ()> {
-    // Check if the types given in the attributes
-    // are part of the model
+/// * `object` - A reference to the `Object` to be checked.
+fn check_alias_collisions(object: &Object) -> Result<(), ()> {
     let mut valid = Ok(());
+    let mut seen: Vec<(&str, &str)> = vec![];
 
-    if attribute.dtypes.is_empty() {
-        error!(
-            "[{}] {}: Property {} has no type specified.",
-            obj_name.bold(),
-            "TypeError".bold(),
-            attribute.name.red().bold()
-        );
-        return Err(());
+    for attribute in &object.attributes {
+        for (index, alias) in attribute.aliases.iter().enumerate() {
+            if *alias == attribute.name || attribute.aliases[..index].contains(alias) {
+                error!(
+                    "[{}] {}: Alias {} of property {} is given more than once.",
+                    object.name.bold(),
+                    "AliasError".bold(),
+                    alias.red().bold(),
+                    attribute.name.red().bold(),
+                );
+
+                valid = Err(());
+                continue;
+            }
+
+            let collision = object
+                .attributes
+                .iter()
+                .find(|other| other.name != attribute.name && other.name == *alias)
+                .map(|other| other.name.as_str())
+                .or_else(|| {
+                    seen.iter()
+                        .find(|(name, seen_alias)| *name != attribute.name && seen_alias == alias)
+                        .map(|(name, _)| *name)
+                });
+
+            if let Some(other) = collision {
+                error!(
+                    "[{}] {}: Alias {} of property {} collides with property {}.",
+                    object.name.bold(),
+                    "AliasError".bold(),
+                    alias.red().bold(),
+                    attribute.name.red().bold(),
+                    other.red().bold(),
+                );
+
+                valid = Err(());
+            }
+
+            seen.push((attribute.name.as_str(), alias.as_str()));
+        }
     }
 
-    for dtype in &attribute.dtypes {
-        if !types.contains(&dtype.as_str()) && !BASIC_TYPES.contains(&dtype.as_str()) {
+    valid
+}
+
+/// Checks that the column names of an object's attributes are unique.
+///
+/// A `Column` override must neither match the name of another attribute
+/// nor the column override of any other attribute in the same object.
+///
+/// # Arguments
+///
+/// * `object` - A reference to the `Object` to be checked.
+fn check_column_collisions(object: &Object) -> Result<(), ()> {
+    let mut valid = Ok(());
+
+    for attribute in &object.attributes {
+        let column = match &attribute.column {
+            Some(column) => column,
+            None => continue,
+        };
+
+        let collision = object.attributes.iter().find(|other| {
+            other.name != attribute.name
+                && other.column.as_ref().unwrap_or(&other.name).to_lowercase()
+                    == column.to_lowercase()
+        });
+
+        if let Some(other) = collision {
             error!(
-                "[{}] {}: Type {} of property {} not found. Either define the type or use a base type.",
-                obj_name.bold(),
-                "TypeError".bold(),
-                dtype.red().bold(),
+                "[{}] {}: Column {} of property {} collides with property {}.",
+                object.name.bold(),
+                "ColumnError".bold(),
+                column.red().bold(),
                 attribute.name.red().bold(),
+                other.name.red().bold(),
             );
+            valid = Err(());
+        }
+    }
+
+    valid
+}
+
+/// Validates the conditional requirements of an object.
+///
+/// All fields used in a condition have to be attributes of the object.
+///
+/// # Arguments
+///
+/// * `object` - A reference to the `Object` to be validated.
+fn validate_conditions(object: &Object) -> Result<(), ()> {
+    let mut valid = Ok(());
 
+    for condition in &object.conditions {
+        let fields = condition.when.keys().chain(condition.require.iter());
+        for field in fields {
+            if !object.attributes.iter().any(|a| a.name == *field) {
+                error!(
+                    "[{}] {}: Condition refers to property {}, which does not exist.",
+                    object.name.bold(),
+                    "ConditionError".bold(),
+                    field.red().bold(),
+                );
+                valid = Err(());
+            }
+        }
+    }
+
+    valid
+}
+
+/// Validates the composite primary key of an object.
+///
+/// All fields of the primary key have to be required attributes of the object.
+///
+/// # Arguments
+///
+/// * `object` - A reference to the `Object` to be validated.
+fn validate_primary_key(object: &Object) -> Result<(), ()> {
+    let mut valid = Ok(());
+
+    for field in &object.primary_key {
+        match object.attributes.iter().find(|a| a.name == *field) {
+            Some(attribute) if attribute.required => {}
+            Some(_) => {
+                error!(
+                    "[{}] {}: Primary key property {} is not required.",
+                    object.name.bold(),
+                    "PrimaryKeyError".bold(),
+                    field.red().bold(),
+                );
+                valid = Err(());
+            }
+            None => {
+                error!(
+                    "[{}] {}: Primary key refers to property {}, which does not exist.",
+                    object.name.bold(),
+                    "PrimaryKeyError".bold(),
+                    field.red().bold(),
+                );
+                valid = Err(());
+            }
+        }
+    }
+
+    valid
+}
+
+/// Validates the indexes of an object.
+///
+/// All fields of an index have to be attributes of the object that map to
+/// a single column. Arrays and maps are stored as JSON and references to
+/// other objects become foreign key columns, so neither can be indexed.
+///
+/// # Arguments
+///
+/// * `object` - A reference to the `Object` to be validated.
+/// * `objects` - A slice of all objects within the model.
+fn validate_indexes(object: &Object, objects: &[Object]) -> Result<(), ()> {
+    let mut valid = Ok(());
+
+    for field in object.indexes.iter().flat_map(|index| &index.fields) {
+        let Some(attribute) = object.attributes.iter().find(|a| a.name == *field) else {
+            error!(
+                "[{}] {}: Index refers to property {}, which does not exist.",
+                object.name.bold(),
+                "IndexError".bold(),
+                field.red().bold(),
+            );
+            valid = Err(());
+            continue;
+        };
+
+        let reason = if attribute.is_array || attribute.map_key.is_some() {
+            Some("is a collection")
+        } else if attribute
+            .dtypes
+            .iter()
+            .any(|dtype| objects.iter().any(|o| o.name == *dtype))
+        {
+            Some("references another object")
+        } else {
+            None
+        };
+
+        if let Some(reason) = reason {
+            error!(
+                "[{}] {}: Index property {} {} and cannot be indexed.",
+                object.name.bold(),
+                "IndexError".bold(),
+                field.red().bold(),
+                reason,
+            );
+            valid = Err(());
+        }
+    }
+
+    valid
+}
+
+/// Validates the inlined attributes of an object.
+///
+/// An inlined attribute has to refer to a single object, whose attributes
+/// must neither collide with the other attributes of the parent nor with
+/// the attributes of other inlined objects.
+///
+/// # Arguments
+///
+/// * `object` - A reference to the `Object` to be validated.
+/// * `objects` - A slice of all objects within the model.
+fn validate_inline(object: &Object, objects: &[Object]) -> Result<(), ()> {
+    let mut valid = Ok(());
+    let mut seen: Vec<(&str, &str)> = object
+        .attributes
+        .iter()
+        .filter(|a| !a.inline)
+        .map(|a| (a.name.as_str(), object.name.as_str()))
+        .collect();
+
+    for attribute in object.attributes.iter().filter(|a| a.inline) {
+        let child = match inlined_object(attribute, objects) {
+            Some(child) => child,
+            None => {
+                error!(
+                    "[{}] {}: Property {} is inlined, but does not refer to a single object.",
+                    object.name.bold(),
+                    "InlineError".bold(),
+                    attribute.name.red().bold(),
+                );
+                valid = Err(());
+                continue;
+            }
+        };
+
+        let inherited = match inlined_attributes(child, objects, &mut vec![&object.name]) {
+            Ok(inherited) => inherited,
+            Err(cycle) => {
+                error!(
+                    "[{}] {}: Property {} recursively inlines {}.",
+                    object.name.bold(),
+                    "InlineError".bold(),
+                    attribute.name.red().bold(),
+                    cycle.bold(),
+                );
+                valid = Err(());
+                continue;
+            }
+        };
+
+        for (name, owner) in inherited {
+            if let Some((_, other)) = seen.iter().find(|(seen, _)| *seen == name) {
+                error!(
+                    "[{}] {}: Property {} of inlined object {} collides with a property of {}.",
+                    object.name.bold(),
+                    "InlineError".bold(),
+                    name.red().bold(),
+                    owner.bold(),
+                    other.bold(),
+                );
+                valid = Err(());
+            } else {
+                seen.push((name, owner));
+            }
+        }
+    }
+
+    valid
+}
+
+/// Returns the object an inlined attribute refers to.
+///
+/// Only attributes of a single, non-array object type can be inlined.
+///
+/// # Arguments
+///
+/// * `attribute` - The inlined attribute.
+/// * `objects` - A slice of all objects within the model.
+fn inlined_object<'a>(attribute: &Attribute, objects: &'a [Object]) -> Option<&'a Object> {
+    match (attribute.dtypes.as_slice(), attribute.is_array) {
+        ([dtype], false) => objects.iter().find(|o| o.name == *dtype),
+        _ => None,
+    }
+}
+
+/// Collects the attributes an inlined object adds to its parent.
+///
+/// Objects inlined by the object itself are flattened as well, such that
+/// collisions are found at any depth. Each attribute is paired with the
+/// name of the object declaring it.
+///
+/// # Arguments
+///
+/// * `object` - The inlined object.
+/// * `objects` - A slice of all objects within the model.
+/// * `path` - The names of the objects inlining this object.
+///
+/// # Returns
+///
+/// The attributes, or the name of the object that is inlined recursively.
+fn inlined_attributes<'a>(
+    object: &'a Object,
+    objects: &'a [Object],
+    path: &mut Vec<&'a str>,
+) -> Result<Vec<(&'a str, &'a str)>, &'a str> {
+    if path.contains(&object.name.as_str()) {
+        return Err(&object.name);
+    }
+
+    path.push(&object.name);
+    let mut attributes = vec![];
+    for attribute in &object.attributes {
+        match attribute.inline {
+            true => {
+                // Invalid inlined attributes are reported for their own object
+                if let Some(child) = inlined_object(attribute, objects) {
+                    attributes.extend(inlined_attributes(child, objects, path)?);
+                }
+            }
+            false => attributes.push((attribute.name.as_str(), object.name.as_str())),
+        }
+    }
+    path.pop();
+
+    Ok(attributes)
+}
+
+/// Validates a single attribute within an object.
+///
+/// # Arguments
+///
+/// * `attribute` - A reference to the `Attribute` to be validated.
+/// * `types` - A slice of type names that are valid within the model.
+/// * `obj_name` - The name of the object that contains the attribute.
+fn validate_attribute(attribute: &Attribute, types: &[&str], obj_name: &str) -> Result<(), ()> {
+    // Check if the types given in the attributes
+    // are part of the model
+    let mut valid = Ok(());
+
+    if attribute.dtypes.is_empty() {
+        error!(
+            "[{}] {}: Property {} has no type specified.",
+            obj_name.bold(),
+            "TypeError".bold(),
+            attribute.name.red().bold()
+        );
+        return Err(());
+    }
+
+    for dtype in &attribute.dtypes {
+        if is_external_type(dtype) || generic_type(dtype).is_some() {
+            continue;
+        }
+
+        if dtype.starts_with("map<") || dtype.starts_with("dict[") {
+            error!(
+                "[{}] {}: Map type {} of property {} is not supported. Maps can neither be nested nor be array items, wrap the inner map in an object instead.",
+                obj_name.bold(),
+                "TypeError".bold(),
+                dtype.red().bold(),
+                attribute.name.red().bold(),
+            );
+
+            valid = Err(());
+            continue;
+        }
+
+        if let Some((namespace, _)) = dtype.split_once(':') {
+            error!(
+                "[{}] {}: Namespace {} of type {} of property {} could not be resolved. Import the model in the frontmatter.",
+                obj_name.bold(),
+                "NamespaceError".bold(),
+                namespace.red().bold(),
+                dtype.red().bold(),
+                attribute.name.red().bold(),
+            );
+
+            valid = Err(());
+        } else if !types.contains(&dtype.as_str())
+            && !BASIC_TYPES.contains(&dtype.as_str())
+            && dtype != ANY_TYPE
+        {
+            error!(
+                "[{}] {}: Type {} of property {} not found. Either define the type or use a base type.",
+                obj_name.bold(),
+                "TypeError".bold(),
+                dtype.red().bold(),
+                attribute.name.red().bold(),
+            );
+
+            valid = Err(());
+        }
+    }
+
+    if let Some(key) = &attribute.map_key {
+        if !BASIC_TYPES.contains(&key.as_str()) {
+            error!(
+                "[{}] {}: Key type {} of map property {} is not a primitive. Use one of {}.",
+                obj_name.bold(),
+                "TypeError".bold(),
+                key.red().bold(),
+                attribute.name.red().bold(),
+                BASIC_TYPES.join(", "),
+            );
+
+            valid = Err(());
+        }
+    }
+
+    valid
+}
+
+/// Validates the generic types used by an attribute.
+///
+/// A generic type `Name<Arg>` has to refer to an object declaring a type
+/// parameter, and its argument has to be a known type. Generic objects in
+/// turn cannot be used without a type argument.
+///
+/// # Arguments
+///
+/// * `attribute` - A reference to the `Attribute` to be validated.
+/// * `types` - A slice of the known type names.
+/// * `objects` - A slice of all objects within the model.
+/// * `obj_name` - The name of the object that contains the attribute.
+fn validate_generic_types(
+    attribute: &Attribute,
+    types: &[&str],
+    objects: &[Object],
+    obj_name: &str,
+) -> Result<(), ()> {
+    let mut valid = Ok(());
+    let is_generic = |name: &str| {
+        objects
+            .iter()
+            .any(|o| o.name == name && o.type_param.is_some())
+    };
+
+    for dtype in &attribute.dtypes {
+        let Some((name, arg)) = generic_type(dtype) else {
+            if is_generic(dtype) {
+                error!(
+                    "[{}] {}: Generic type {} of property {} requires a type argument, e.g. {}<string>.",
+                    obj_name.bold(),
+                    "GenericError".bold(),
+                    dtype.red().bold(),
+                    attribute.name.red().bold(),
+                    dtype,
+                );
+
+                valid = Err(());
+            }
+
+            continue;
+        };
+
+        if !is_generic(name) {
+            error!(
+                "[{}] {}: Type {} of property {} is not a generic object. Declare a type parameter in its heading, e.g. {}<T>.",
+                obj_name.bold(),
+                "GenericError".bold(),
+                name.red().bold(),
+                attribute.name.red().bold(),
+                name,
+            );
+
+            valid = Err(());
+        }
+
+        if !types.contains(&arg) && !BASIC_TYPES.contains(&arg) && arg != ANY_TYPE {
+            error!(
+                "[{}] {}: Type argument {} of property {} not found. Either define the type or use a base type.",
+                obj_name.bold(),
+                "TypeError".bold(),
+                arg.red().bold(),
+                attribute.name.red().bold(),
+            );
+
+            valid = Err(());
+        }
+    }
+
+    valid
+}
+
+/// Validates the discriminator of a union attribute.
+///
+/// The discriminator field has to be defined on every member object of the union
+/// with a constant value, which is distinct across the members. Hence, each value
+/// identifies exactly one member when parsing polymorphic data.
+///
+/// # Arguments
+///
+/// * `attribute` - A reference to the `Attribute` to be validated.
+/// * `objects` - A slice of all objects within the model.
+/// * `obj_name` - The name of the object that contains the attribute.
+fn validate_discriminator(
+    attribute: &Attribute,
+    objects: &[Object],
+    obj_name: &str,
+) -> Result<(), ()> {
+    let discriminator = match &attribute.discriminator {
+        Some(discriminator) => discriminator,
+        None => return Ok(()),
+    };
+
+    let mut valid = Ok(());
+    let mut tags: Vec<(String, &str)> = Vec::new();
+
+    for dtype in &attribute.dtypes {
+        let member = objects.iter().find(|object| object.name == *dtype);
+        let field = member.and_then(|m| m.attributes.iter().find(|a| a.name == *discriminator));
+
+        match (member, field) {
+            (Some(member), Some(field)) => match &field.constant {
+                Some(constant) => tags.push((constant.raw_value(), &member.name)),
+                None => {
+                    error!(
+                        "[{}] {}: Discriminator {} of property {} has no constant value in member {}. Add a Const option.",
+                        obj_name.bold(),
+                        "DiscriminatorError".bold(),
+                        discriminator.red().bold(),
+                        attribute.name.red().bold(),
+                        member.name.red().bold(),
+                    );
+
+                    valid = Err(());
+                }
+            },
+            (Some(member), None) => {
+                error!(
+                    "[{}] {}: Discriminator {} of property {} is not defined in member {}.",
+                    obj_name.bold(),
+                    "DiscriminatorError".bold(),
+                    discriminator.red().bold(),
+                    attribute.name.red().bold(),
+                    member.name.red().bold(),
+                );
+
+                valid = Err(());
+            }
+            (None, _) => {
+                error!(
+                    "[{}] {}: Member {} of discriminated property {} is not an object.",
+                    obj_name.bold(),
+                    "DiscriminatorError".bold(),
+                    dtype.red().bold(),
+                    attribute.name.red().bold(),
+                );
+
+                valid = Err(());
+            }
+        }
+    }
+
+    for (index, (tag, member)) in tags.iter().enumerate() {
+        if let Some((_, other)) = tags[..index].iter().find(|(other, _)| other == tag) {
+            error!(
+                "[{}] {}: Members {} and {} of property {} share the discriminator value {}.",
+                obj_name.bold(),
+                "DiscriminatorError".bold(),
+                other.red().bold(),
+                member.red().bold(),
+                attribute.name.red().bold(),
+                tag.red().bold(),
+            );
+
+            valid = Err(());
+        }
+    }
+
+    valid
+}
+
+/// A warning of a data model, which does not invalidate it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Name of the affected definition.
+    pub definition: String,
+    /// Kind of the warning, e.g. `NamingWarning`.
+    pub kind: String,
+    /// Message describing the warning.
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn new(definition: impl Into<String>, kind: &str, message: impl Into<String>) -> Self {
+        Self {
+            definition: definition.into(),
+            kind: kind.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}: {}", self.definition, self.kind, self.message)
+    }
+}
+
+/// Collects the warnings of a data model, which do not invalidate it.
+///
+/// # Arguments
+///
+/// * `model` - A reference to the `DataModel` to be checked.
+///
+/// # Returns
+///
+/// A list of the warnings in the order they are logged by the validator.
+pub fn collect_diagnostics(model: &DataModel) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for object in &model.objects {
+        for attribute in &object.attributes {
+            match &attribute.unit {
+                Some(unit) if !is_quantitative(attribute) => diagnostics.push(Diagnostic::new(
+                    &object.name,
+                    "UnitWarning",
+                    format!(
+                        "Property {} has unit {} but is not numeric.",
+                        attribute.name, unit
+                    ),
+                )),
+                _ => {}
+            }
+        }
+    }
+
+    for (definition, message) in naming_violations(model) {
+        diagnostics.push(Diagnostic::new(definition, "NamingWarning", message));
+    }
+
+    for (definition, message) in unknown_options(model) {
+        diagnostics.push(Diagnostic::new(definition, "OptionWarning", message));
+    }
+
+    for (definition, message) in target_violations(model) {
+        diagnostics.push(Diagnostic::new(definition, "TargetWarning", message));
+    }
+
+    for name in abstract_without_subtypes(model) {
+        diagnostics.push(Diagnostic::new(name, "AbstractWarning", ABSTRACT_WARNING));
+    }
+
+    for name in unused_enums(model) {
+        diagnostics.push(Diagnostic::new(name, "EnumWarning", UNUSED_ENUM_WARNING));
+    }
+
+    diagnostics
+}
+
+/// Collects the warnings of a data model, which do not invalidate it.
+///
+/// # Arguments
+///
+/// * `model` - A reference to the `DataModel` to be checked.
+///
+/// # Returns
+///
+/// A list of warning messages in the same format as the logged warnings.
+pub fn collect_warnings(model: &DataModel) -> Vec<String> {
+    collect_diagnostics(model)
+        .iter()
+        .map(Diagnostic::to_string)
+        .collect()
+}
+
+/// Collects the attribute options whose keys are neither known nor allowed by the frontmatter.
+///
+/// Unknown keys are most likely typos, which would otherwise silently drop
+/// e.g. a description. Hence, the closest known key is suggested.
+///
+/// # Arguments
+///
+/// * `model` - A reference to the `DataModel` to be checked.
+///
+/// # Returns
+///
+/// A list of the objects containing unknown options and messages suggesting a fix.
+fn unknown_options(model: &DataModel) -> Vec<(String, String)> {
+    let custom = model
+        .config
+        .as_ref()
+        .and_then(|c| c.custom_options.as_ref())
+        .map(|keys| keys.iter().map(|k| k.to_lowercase()).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let mut violations = Vec::new();
+
+    for object in &model.objects {
+        for attribute in &object.attributes {
+            for option in &attribute.options {
+                let key = option.key();
+                if custom.iter().any(|k| k == key)
+                    || KNOWN_OPTIONS
+                        .iter()
+                        .chain(SCHEMA_KEYWORDS.iter())
+                        .any(|k| k.eq_ignore_ascii_case(key))
+                {
+                    continue;
+                }
+
+                let suggestion = match closest_option(key) {
+                    Some(known) => format!("Did you mean {}?", known),
+                    None => "Add it to custom-options in the frontmatter if intended.".to_string(),
+                };
+
+                violations.push((
+                    object.name.clone(),
+                    format!(
+                        "Property {} has unknown option {}. {}",
+                        attribute.name, key, suggestion
+                    ),
+                ));
+            }
+        }
+    }
+
+    violations
+}
+
+/// Finds the known option key closest to a key, if it is within a small edit distance.
+///
+/// # Arguments
+///
+/// * `key` - The unknown option key.
+fn closest_option(key: &str) -> Option<&'static str> {
+    let max_distance = (key.chars().count() / 3).clamp(1, 3);
+
+    KNOWN_OPTIONS
+        .iter()
+        .chain(SCHEMA_KEYWORDS.iter())
+        .map(|known| (edit_distance(key, &known.to_lowercase()), *known))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, known)| known)
+}
+
+/// Computes the Levenshtein distance between two strings.
+///
+/// # Arguments
+///
+/// * `a` - The first string.
+/// * `b` - The second string.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<char>>();
+    let mut previous = (0..=b.len()).collect::<Vec<usize>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Collects targets naming unknown templates and attributes targeting excluded objects.
+///
+/// An attribute generated for a target its object is excluded from is never
+/// generated, which is most likely an oversight.
+///
+/// # Arguments
+///
+/// * `model` - A reference to the `DataModel` to be checked.
+///
+/// # Returns
+///
+/// A list of the objects containing inconsistent targets and messages describing them.
+fn target_violations(model: &DataModel) -> Vec<(String, String)> {
+    let mut violations = Vec::new();
+    let unknown = |target: &String| target.trim_start_matches('!').parse::<Templates>().is_err();
+
+    for object in &model.objects {
+        for target in object.targets.iter().filter(|t| unknown(t)) {
+            violations.push((
+                object.name.clone(),
+                format!("Object has unknown target {}.", target),
+            ));
+        }
+
+        for attribute in &object.attributes {
+            for target in attribute.targets.iter() {
+                let template = match target.trim_start_matches('!').parse::<Templates>() {
+                    Ok(template) => template,
+                    Err(_) => {
+                        violations.push((
+                            object.name.clone(),
+                            format!("Property {} has unknown target {}.", attribute.name, target),
+                        ));
+                        continue;
+                    }
+                };
+
+                if !target.starts_with('!') && !is_targeted(&object.targets, &template) {
+                    violations.push((
+                        object.name.clone(),
+                        format!(
+                            "Property {} targets {}, but its object is excluded from it.",
+                            attribute.name, target
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    violations
+}
+
+/// Message of the warning for abstract objects without subtypes.
+const ABSTRACT_WARNING: &str =
+    "Object is abstract but has no subtypes, hence it can never be instantiated.";
+
+/// Collects the names of abstract objects no other object inherits from.
+///
+/// # Arguments
+///
+/// * `model` - A reference to the `DataModel` to be checked.
+fn abstract_without_subtypes(model: &DataModel) -> Vec<&str> {
+    model
+        .objects
+        .iter()
+        .filter(|o| o.is_abstract)
+        .filter(|o| {
+            !model
+                .objects
+                .iter()
+                .any(|other| other.parent.as_deref() == Some(o.name.as_str()))
+        })
+        .map(|o| o.name.as_str())
+        .collect()
+}
+
+/// Message of the warning for enumerations no property refers to.
+const UNUSED_ENUM_WARNING: &str = "Enumeration is not used by any property.";
+
+/// Collects the names of enumerations that are neither used by a property nor extended.
+///
+/// # Arguments
+///
+/// * `model` - A reference to the `DataModel` to be checked.
+fn unused_enums(model: &DataModel) -> Vec<&str> {
+    let mut used = HashSet::new();
+
+    for attribute in model.objects.iter().flat_map(|o| &o.attributes) {
+        for dtype in attribute.dtypes.iter().chain(&attribute.map_key) {
+            match generic_type(dtype) {
+                Some((name, arg)) => used.extend([name, arg]),
+                None => {
+                    used.insert(dtype.as_str());
+                }
+            }
+        }
+    }
+
+    used.extend(model.enums.iter().filter_map(|e| e.extends.as_deref()));
+
+    model
+        .enums
+        .iter()
+        .map(|e| e.name.as_str())
+        .filter(|name| !used.contains(name))
+        .collect()
+}
+
+/// Collects the names violating the naming conventions of the frontmatter.
+///
+/// # Arguments
+///
+/// * `model` - A reference to the `DataModel` to be checked.
+///
+/// # Returns
+///
+/// A list of the violating definitions and messages suggesting a fix.
+fn naming_violations(model: &DataModel) -> Vec<(String, String)> {
+    let Some(naming) = model.config.as_ref().and_then(|c| c.naming.as_ref()) else {
+        return Vec::new();
+    };
+
+    let mut violations = Vec::new();
+
+    for object in &model.objects {
+        if let Some((case, fix)) = naming_fix(&object.name, naming.object_case) {
+            violations.push((
+                object.name.clone(),
+                format!(
+                    "Object name {} is not {}. Rename it to {}.",
+                    object.name, case, fix
+                ),
+            ));
+        }
+
+        for attribute in &object.attributes {
+            if let Some((case, fix)) = naming_fix(&attribute.name, naming.attribute_case) {
+                violations.push((
+                    object.name.clone(),
+                    format!(
+                        "Property {} is not {}. Rename it to {}.",
+                        attribute.name, case, fix
+                    ),
+                ));
+            }
+        }
+    }
+
+    for enumeration in &model.enums {
+        if let Some((case, fix)) = naming_fix(&enumeration.name, naming.enum_name_case) {
+            violations.push((
+                enumeration.name.clone(),
+                format!(
+                    "Enumeration name {} is not {}. Rename it to {}.",
+                    enumeration.name, case, fix
+                ),
+            ));
+        }
+
+        for key in enumeration.mappings.keys() {
+            if let Some((case, fix)) = naming_fix(key, naming.enum_value_case) {
+                violations.push((
+                    enumeration.name.clone(),
+                    format!("Key {} is not {}. Rename it to {}.", key, case, fix),
+                ));
+            }
+        }
+    }
+
+    violations
+}
+
+/// Returns the name converted to the given case, if it does not follow it.
+///
+/// # Arguments
+///
+/// * `name` - The name to be checked.
+/// * `case` - The required case, if any.
+fn naming_fix(name: &str, case: Option<NamingCase>) -> Option<(NamingCase, String)> {
+    let case = case?;
+    let target = match case {
+        NamingCase::Pascal => Case::Pascal,
+        NamingCase::Camel => Case::Camel,
+        NamingCase::Snake => Case::Snake,
+        NamingCase::ScreamingSnake => Case::ScreamingSnake,
+        NamingCase::Kebab => Case::Kebab,
+    };
+
+    let fix = name.to_case(target);
+
+    (fix != name).then_some((case, fix))
+}
+
+/// Checks whether all types of an attribute are numeric or quantities.
+///
+/// # Arguments
+///
+/// * `attribute` - A reference to the `Attribute` to be checked.
+fn is_quantitative(attribute: &Attribute) -> bool {
+    attribute
+        .dtypes
+        .iter()
+        .all(|dtype| NUMERIC_TYPES.contains(&dtype.as_str()) || dtype == QUANTITY_OBJECT)
+}
+
+/// Validates the access modifier of an attribute.
+///
+/// # Arguments
+///
+/// * `attribute` - A reference to the `Attribute` to be validated.
+/// * `obj_name` - The name of the object that contains the attribute.
+fn validate_visibility(attribute: &Attribute, obj_name: &str) -> Result<(), ()> {
+    match &attribute.visibility {
+        Some(visibility) if !VISIBILITIES.contains(&visibility.as_str()) => {
+            error!(
+                "[{}] {}: Property {} has unknown visibility {}. Use one of {}.",
+                obj_name.bold(),
+                "VisibilityError".bold(),
+                attribute.name.red().bold(),
+                visibility.red().bold(),
+                VISIBILITIES.join(", "),
+            );
+
+            Err(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Validates the size of a fixed-size array attribute.
+///
+/// # Arguments
+///
+/// * `attribute` - A reference to the `Attribute` to be validated.
+/// * `obj_name` - The name of the object that contains the attribute.
+fn validate_size(attribute: &Attribute, obj_name: &str) -> Result<(), ()> {
+    match attribute.size {
+        Some(0) => {
+            error!(
+                "[{}] {}: Property {} is a fixed-size array of size zero. Use a size of at least one.",
+                obj_name.bold(),
+                "SizeError".bold(),
+                attribute.name.red().bold(),
+            );
+
+            Err(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Validates the unit of a quantitative attribute.
+///
+/// A unit on an attribute that is neither numeric nor a quantity results in
+/// a warning. If the `ucum` feature is enabled, the unit has to be a valid
+/// UCUM expression.
+///
+/// # Arguments
+///
+/// * `attribute` - A reference to the `Attribute` to be validated.
+/// * `obj_name` - The name of the object that contains the attribute.
+fn validate_unit(attribute: &Attribute, obj_name: &str) -> Result<(), ()> {
+    let unit = match &attribute.unit {
+        Some(unit) => unit,
+        None => return Ok(()),
+    };
+
+    if !is_quantitative(attribute) {
+        warn!(
+            "[{}] {}: Property {} has unit {} but is not numeric.",
+            obj_name.bold(),
+            "UnitWarning".bold(),
+            attribute.name.red().bold(),
+            unit.red().bold(),
+        );
+    }
+
+    #[cfg(feature = "ucum")]
+    if !crate::ucum::is_valid_ucum(unit) {
+        error!(
+            "[{}] {}: Unit {} of property {} is not a valid UCUM expression.",
+            obj_name.bold(),
+            "UnitError".bold(),
+            unit.red().bold(),
+            attribute.name.red().bold(),
+        );
+
+        return Err(());
+    }
+
+    Ok(())
+}
+
+/// Validates the default of an enumeration-typed attribute.
+///
+/// The default, or each item of a list default, has to be a key of the enumeration.
+///
+/// # Arguments
+///
+/// * `attribute` - A reference to the `Attribute` to be validated.
+/// * `enums` - A slice of all enumerations within the model.
+/// * `obj_name` - The name of the object that contains the attribute.
+fn validate_enum_default(
+    attribute: &Attribute,
+    enums: &[Enumeration],
+    obj_name: &str,
+) -> Result<(), ()> {
+    let (default, enumeration) = match (&attribute.default, attribute.dtypes.first()) {
+        (Some(default), Some(dtype)) => match enums.iter().find(|e| e.name == *dtype) {
+            Some(enumeration) => (default, enumeration),
+            None => return Ok(()),
+        },
+        _ => return Ok(()),
+    };
+
+    let keys = match default {
+        DataType::List(items) => items.iter().map(DataType::raw_value).collect(),
+        _ => vec![default.raw_value()],
+    };
+
+    let default = match keys
+        .into_iter()
+        .find(|key| !enumeration.mappings.contains_key(key))
+    {
+        Some(key) => key,
+        None => return Ok(()),
+    };
+
+    error!(
+        "[{}] {}: Default {} of property {} is not a key of {}. Valid keys are: {}",
+        obj_name.bold(),
+        "DefaultError".bold(),
+        default.red().bold(),
+        attribute.name.red().bold(),
+        enumeration.name.bold(),
+        enumeration
+            .mappings
+            .keys()
+            .cloned()
+            .collect::<Vec<String>>()
+            .join(", "),
+    );
+
+    Err(())
+}
+
+/// Validates that the default of an attribute matches its shape.
+///
+/// Defaults of arrays have to be lists, such as `[a, b]` or the empty list
+/// `[]`, whereas defaults of single values must not be lists.
+///
+/// # Arguments
+///
+/// * `attribute` - A reference to the `Attribute` to be validated.
+/// * `obj_name` - The name of the object containing the attribute.
+fn validate_default_shape(attribute: &Attribute, obj_name: &str) -> Result<(), ()> {
+    let default = match &attribute.default {
+        Some(default) => default,
+        None => return Ok(()),
+    };
+
+    match (attribute.is_array, default.is_list()) {
+        (true, false) => error!(
+            "[{}] {}: Property {} is an array, but its default {} is not a list. Use a list such as [{}] instead.",
+            obj_name.bold(),
+            "DefaultError".bold(),
+            attribute.name.red().bold(),
+            default.raw_value().red().bold(),
+            default.raw_value(),
+        ),
+        (false, true) => error!(
+            "[{}] {}: Property {} is not an array, but its default {} is a list.",
+            obj_name.bold(),
+            "DefaultError".bold(),
+            attribute.name.red().bold(),
+            default.raw_value().red().bold(),
+        ),
+        _ => return Ok(()),
+    }
+
+    Err(())
+}
+
+/// Validates the constant value of an attribute.
+///
+/// Constants are only supported for single, primitive types and the value
+/// has to be compatible with the type of the attribute.
+///
+/// # Arguments
+///
+/// * `attribute` - A reference to the `Attribute` to be validated.
+/// * `obj_name` - The name of the object containing the attribute.
+fn validate_constant(attribute: &Attribute, obj_name: &str) -> Result<(), ()> {
+    let constant = match &attribute.constant {
+        Some(constant) => constant,
+        None => return Ok(()),
+    };
+
+    let dtype = match (attribute.dtypes.as_slice(), attribute.is_array) {
+        ([dtype], false) if BASIC_TYPES.contains(&dtype.as_str()) => dtype.as_str(),
+        _ => {
+            error!(
+                "[{}] {}: Property {} has a constant value, but is not a single primitive.",
+                obj_name.bold(),
+                "ConstError".bold(),
+                attribute.name.red().bold(),
+            );
+
+            return Err(());
+        }
+    };
+
+    let compatible = match (dtype, constant) {
+        (dtype, DataType::Integer(_)) if is_integer(dtype) => true,
+        _ => matches!(
+            (dtype, constant),
+            (
+                "float" | "number",
+                DataType::Integer(_) | DataType::Float(_)
+            ) | ("boolean", DataType::Boolean(_))
+                | ("string" | "date" | "duration", DataType::String(_))
+                | ("timestamp", DataType::Integer(_) | DataType::String(_))
+        ),
+    };
+
+    if !compatible {
+        error!(
+            "[{}] {}: Constant {} of property {} is not of type {}.",
+            obj_name.bold(),
+            "ConstError".bold(),
+            constant.raw_value().red().bold(),
+            attribute.name.red().bold(),
+            dtype.bold(),
+        );
+
+        return Err(());
+    }
+
+    Ok(())
+}
+
+/// Validates that external type references point to permitted hosts.
+///
+/// If the frontmatter defines no `external-refs` allowlist, all hosts are
+/// permitted.
+///
+/// # Arguments
+///
+/// * `attribute` - A reference to the `Attribute` to be validated.
+/// * `allowed` - The hosts that external references may point to.
+/// * `obj_name` - The name of the object containing the attribute.
+fn validate_external_refs(
+    attribute: &Attribute,
+    allowed: Option<&Vec<String>>,
+    obj_name: &str,
+) -> Result<(), ()> {
+    let allowed = match allowed {
+        Some(allowed) => allowed,
+        None => return Ok(()),
+    };
+
+    let mut valid = Ok(());
+    for dtype in attribute.dtypes.iter().filter(|d| is_external_type(d)) {
+        let host = dtype
+            .split("://")
+            .nth(1)
+            .and_then(|rest| rest.split(['/', '?', '#']).next())
+            .unwrap_or_default();
+
+        if !allowed.iter().any(|a| a == host) {
+            error!(
+                "[{}] {}: External type {} of property {} is not hosted at a permitted host. Permitted hosts are: {}",
+                obj_name.bold(),
+                "ExternalRefError".bold(),
+                dtype.red().bold(),
+                attribute.name.red().bold(),
+                allowed.join(", "),
+            );
             valid = Err(());
         }
     }
@@ -0,0 +1,56 @@
+// This file contains Dart classes for the data model.
+//
+// WARNING: This is an auto-generated file.
+// Do not edit directly - any changes will be overwritten.
+//
+// The serialization code is generated by `dart run build_runner build`.
+
+import 'package:json_annotation/json_annotation.dart';
+
+part 'model.g.dart';
+
+enum Ontology {
+  @JsonValue('https://www.evidenceontology.org/term/')
+  eco,
+  @JsonValue('https://amigo.geneontology.org/amigo/term/')
+  go,
+  @JsonValue('http://semanticscience.org/resource/')
+  sio,
+}
+
+@JsonSerializable()
+class Test {
+  /// The name of the test.
+  final String name;
+  final double? number;
+  final List<Test2> test2;
+  final Ontology? ontology;
+
+  const Test({
+    required this.name,
+    this.number,
+    this.test2 = const [],
+    this.ontology,
+  });
+
+  factory Test.fromJson(Map<String, dynamic> json) =>
+      _$TestFromJson(json);
+
+  Map<String, dynamic> toJson() => _$TestToJson(this);
+}
+
+@JsonSerializable()
+class Test2 {
+  final List<String> names;
+  final double? number;
+
+  const Test2({
+    this.names = const [],
+    this.number,
+  });
+
+  factory Test2.fromJson(Map<String, dynamic> json) =>
+      _$Test2FromJson(json);
+
+  Map<String, dynamic> toJson() => _$Test2ToJson(this);
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+# This file contains Elixir struct definitions for the data model.
+#
+# WARNING: This is an auto-generated file.
+# Do not edit directly - any changes will be overwritten.
+
+defmodule Ontology do
+  @mappings [
+    ECO: "https://www.evidenceontology.org/term/",
+    GO: "https://amigo.geneontology.org/amigo/term/",
+    SIO: "http://semanticscience.org/resource/"
+  ]
+
+  @type t :: :ECO | :GO | :SIO
+
+  @doc "Returns the keyword list mapping each member to its value."
+  def mappings, do: @mappings
+
+  @doc "Returns the values of all members."
+  def values, do: Keyword.values(@mappings)
+end
+
+defmodule Test do
+  @enforce_keys [:name]
+
+  defstruct name: nil,
+            number: 1.0,
+            test2: [],
+            ontology: nil
+
+  @type t :: %__MODULE__{
+          name: String.t(),
+          number: float() | nil,
+          test2: [Test2.t()],
+          ontology: Ontology.t() | nil
+        }
+end
+
+defmodule Test2 do
+  defstruct names: [],
+            number: nil
+
+  @type t :: %__MODULE__{
+          names: [String.t()],
+          number: float() | nil
+        }
+end
\ No newline at end of file
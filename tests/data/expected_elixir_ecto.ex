@@ -0,0 +1,58 @@
+# This file contains Elixir Ecto schema definitions for the data model.
+#
+# WARNING: This is an auto-generated file.
+# Do not edit directly - any changes will be overwritten.
+
+defmodule MyApp.Ontology do
+  @mappings [
+    ECO: "https://www.evidenceontology.org/term/",
+    GO: "https://amigo.geneontology.org/amigo/term/",
+    SIO: "http://semanticscience.org/resource/"
+  ]
+
+  @type t :: :ECO | :GO | :SIO
+
+  @doc "Returns the keyword list mapping each member to its value."
+  def mappings, do: @mappings
+
+  @doc "Returns the values of all members."
+  def values, do: Keyword.values(@mappings)
+end
+
+defmodule MyApp.Test do
+  use Ecto.Schema
+  import Ecto.Changeset
+
+  @primary_key false
+  embedded_schema do
+    field :name, :string
+    field :number, :float, default: 1.0
+    embeds_many :test2, MyApp.Test2
+    field :ontology, Ecto.Enum, values: MyApp.Ontology.mappings()
+  end
+
+  @doc "Casts and validates the given attributes."
+  def changeset(struct, attrs) do
+    struct
+    |> cast(attrs, [:name, :number, :ontology])
+    |> cast_embed(:test2)
+    |> validate_required([:name])
+  end
+end
+
+defmodule MyApp.Test2 do
+  use Ecto.Schema
+  import Ecto.Changeset
+
+  @primary_key false
+  embedded_schema do
+    field :names, {:array, :string}
+    field :number, :float
+  end
+
+  @doc "Casts and validates the given attributes."
+  def changeset(struct, attrs) do
+    struct
+    |> cast(attrs, [:names, :number])
+  end
+end
\ No newline at end of file
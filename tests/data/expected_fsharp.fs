@@ -0,0 +1,32 @@
+// This file contains F# record types for the data model.
+//
+// WARNING: This is an auto-generated file.
+// Do not edit directly - any changes will be overwritten.
+
+module Model
+
+[<RequireQualifiedAccess>]
+type Ontology =
+    | Eco
+    | Go
+    | Sio
+
+    member this.Value =
+        match this with
+        | Ontology.Eco -> "https://www.evidenceontology.org/term/"
+        | Ontology.Go -> "https://amigo.geneontology.org/amigo/term/"
+        | Ontology.Sio -> "http://semanticscience.org/resource/"
+
+type Test =
+    {
+        Name: string
+        Number: float option
+        Test2: Test2 list
+        Ontology: Ontology option
+    }
+
+and Test2 =
+    {
+        Names: string list
+        Number: float option
+    }
\ No newline at end of file
@@ -0,0 +1,41 @@
+// This file contains F# record types for the data model.
+//
+// WARNING: This is an auto-generated file.
+// Do not edit directly - any changes will be overwritten.
+
+module MyApp.Model
+
+open System.Text.Json.Serialization
+
+[<RequireQualifiedAccess>]
+type Ontology =
+    | Eco
+    | Go
+    | Sio
+
+    member this.Value =
+        match this with
+        | Ontology.Eco -> "https://www.evidenceontology.org/term/"
+        | Ontology.Go -> "https://amigo.geneontology.org/amigo/term/"
+        | Ontology.Sio -> "http://semanticscience.org/resource/"
+
+[<CLIMutable>]
+type Test =
+    {
+        [<JsonPropertyName("name")>]
+        Name: string
+        [<JsonPropertyName("number")>]
+        Number: float option
+        [<JsonPropertyName("test2")>]
+        Test2: Test2 list
+        [<JsonPropertyName("ontology")>]
+        Ontology: Ontology option
+    }
+
+and [<CLIMutable>] Test2 =
+    {
+        [<JsonPropertyName("names")>]
+        Names: string list
+        [<JsonPropertyName("number")>]
+        Number: float option
+    }
\ No newline at end of file
@@ -0,0 +1,82 @@
+-- This file contains Haskell data types for the data model.
+--
+-- WARNING: This is an auto-generated file.
+-- Do not edit directly - any changes will be overwritten.
+
+{-# LANGUAGE DeriveGeneric #-}
+{-# LANGUAGE OverloadedStrings #-}
+
+module Model where
+
+import Data.Aeson
+import Data.Maybe (fromMaybe)
+import Data.Text (Text)
+import GHC.Generics (Generic)
+
+-- | Options renaming the record fields, which are prefixed by their type to
+--   avoid collisions between types, to their names in JSON.
+jsonOptions :: [(String, String)] -> Options
+jsonOptions names =
+  defaultOptions
+    { fieldLabelModifier = \field -> fromMaybe field (lookup field names)
+    , omitNothingFields = True
+    }
+
+data Ontology
+  = OntologyEco
+  | OntologyGo
+  | OntologySio
+  deriving (Show, Eq, Generic)
+
+instance ToJSON Ontology where
+  toJSON OntologyEco = String "https://www.evidenceontology.org/term/"
+  toJSON OntologyGo = String "https://amigo.geneontology.org/amigo/term/"
+  toJSON OntologySio = String "http://semanticscience.org/resource/"
+
+instance FromJSON Ontology where
+  parseJSON = withText "Ontology" $ \value -> case value of
+    "https://www.evidenceontology.org/term/" -> pure OntologyEco
+    "https://amigo.geneontology.org/amigo/term/" -> pure OntologyGo
+    "http://semanticscience.org/resource/" -> pure OntologySio
+    _ -> fail "Unknown Ontology value"
+
+data Test = Test
+  { testName :: Text
+    -- ^ The name of the test.
+  , testNumber :: Maybe Double
+  , testTest2 :: Maybe [Test2]
+  , testOntology :: Maybe Ontology
+  }
+  deriving (Show, Eq, Generic)
+
+jsonFieldsTest :: [(String, String)]
+jsonFieldsTest =
+  [ ("testName", "name")
+  , ("testNumber", "number")
+  , ("testTest2", "test2")
+  , ("testOntology", "ontology")
+  ]
+
+instance ToJSON Test where
+  toJSON = genericToJSON (jsonOptions jsonFieldsTest)
+
+instance FromJSON Test where
+  parseJSON = genericParseJSON (jsonOptions jsonFieldsTest)
+
+data Test2 = Test2
+  { test2Names :: Maybe [Text]
+  , test2Number :: Maybe Double
+  }
+  deriving (Show, Eq, Generic)
+
+jsonFieldsTest2 :: [(String, String)]
+jsonFieldsTest2 =
+  [ ("test2Names", "names")
+  , ("test2Number", "number")
+  ]
+
+instance ToJSON Test2 where
+  toJSON = genericToJSON (jsonOptions jsonFieldsTest2)
+
+instance FromJSON Test2 where
+  parseJSON = genericParseJSON (jsonOptions jsonFieldsTest2)
\ No newline at end of file
@@ -0,0 +1,24 @@
+# This file contains Nim object types for the data model.
+#
+# WARNING: This is an auto-generated file.
+# Do not edit directly - any changes will be overwritten.
+
+import std/options
+import json_serialization
+
+type
+  Ontology* {.pure.} = enum
+    Eco = "https://www.evidenceontology.org/term/"
+    Go = "https://amigo.geneontology.org/amigo/term/"
+    Sio = "http://semanticscience.org/resource/"
+
+  Test* = object
+    name* {.serializedFieldName: "name".}: string
+      ## The name of the test.
+    number* {.serializedFieldName: "number".}: Option[float]
+    test2* {.serializedFieldName: "test2".}: seq[Test2]
+    ontology* {.serializedFieldName: "ontology".}: Option[Ontology]
+
+  Test2* = object
+    names* {.serializedFieldName: "names".}: seq[string]
+    number* {.serializedFieldName: "number".}: Option[float]
\ No newline at end of file
@@ -0,0 +1,35 @@
+(* This file contains OCaml types for the data model.
+
+   WARNING: This is an auto-generated file.
+   Do not edit directly - any changes will be overwritten. *)
+
+type ontology =
+  | Eco
+  | Go
+  | Sio
+
+let ontology_to_yojson : ontology -> Yojson.Safe.t = function
+  | Eco -> `String "https://www.evidenceontology.org/term/"
+  | Go -> `String "https://amigo.geneontology.org/amigo/term/"
+  | Sio -> `String "http://semanticscience.org/resource/"
+
+let ontology_of_yojson : Yojson.Safe.t -> (ontology, string) result = function
+  | `String "https://www.evidenceontology.org/term/" -> Ok Eco
+  | `String "https://amigo.geneontology.org/amigo/term/" -> Ok Go
+  | `String "http://semanticscience.org/resource/" -> Ok Sio
+  | _ -> Error "ontology"
+
+type test = {
+  (** The name of the test. *)
+  name : string;
+  number : float option [@default None];
+  test2 : test2 list [@default []];
+  ontology : ontology option [@default None];
+}
+[@@deriving yojson]
+
+and test2 = {
+  names : string list [@default []];
+  number : float option [@default None];
+}
+[@@deriving yojson]
\ No newline at end of file
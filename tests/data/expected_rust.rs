@@ -0,0 +1,40 @@
+//! This file contains Rust struct definitions with serde support.
+//!
+//! WARNING: This is an auto-generated file.
+//! Do not edit directly - any changes will be overwritten.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Test {
+    /// The name of the test.
+    pub name: String,
+    #[serde(default = "default_test_number")]
+    pub number: f64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub test2: Vec<Test2>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ontology: Option<Ontology>,
+}
+
+fn default_test_number() -> f64 {
+    1.0
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Test2 {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub names: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Ontology {
+    #[serde(rename = "https://www.evidenceontology.org/term/")]
+    Eco,
+    #[serde(rename = "https://amigo.geneontology.org/amigo/term/")]
+    Go,
+    #[serde(rename = "http://semanticscience.org/resource/")]
+    Sio,
+}
\ No newline at end of file
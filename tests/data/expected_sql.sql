@@ -0,0 +1,17 @@
+-- This file contains SQL table definitions for the data model.
+--
+-- WARNING: This is an auto-generated file.
+-- Do not edit directly - any changes will be overwritten.
+
+CREATE TABLE test (
+    name TEXT NOT NULL PRIMARY KEY,
+    number DOUBLE PRECISION DEFAULT 1.0,
+    test2 JSON,
+    ontology TEXT CHECK (ontology IN ('https://www.evidenceontology.org/term/', 'https://amigo.geneontology.org/amigo/term/', 'http://semanticscience.org/resource/'))
+);
+
+CREATE TABLE test2 (
+    id INTEGER PRIMARY KEY,
+    names JSON,
+    number DOUBLE PRECISION
+);
\ No newline at end of file
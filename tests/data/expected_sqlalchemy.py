@@ -0,0 +1,57 @@
+## This is a generated file. Do not modify it manually!
+
+from __future__ import annotations
+from datetime import date, datetime
+from enum import Enum
+from typing import Any, List, Optional
+
+from sqlalchemy import (
+    JSON,
+    Boolean,
+    Column,
+    Date,
+    DateTime,
+    Enum as SqlEnum,
+    Float,
+    ForeignKeyConstraint,
+    Index,
+    Integer,
+    String,
+    Table,
+    UniqueConstraint,
+)
+from sqlalchemy.orm import DeclarativeBase, Mapped, mapped_column, relationship
+
+
+class Base(DeclarativeBase):
+    pass
+
+
+class Ontology(Enum):
+    ECO = "https://www.evidenceontology.org/term/"
+    GO = "https://amigo.geneontology.org/amigo/term/"
+    SIO = "http://semanticscience.org/resource/"
+
+
+class Test(Base):
+
+    __tablename__ = "test"
+
+    name: Mapped[str] = mapped_column(primary_key=True)
+    number: Mapped[Optional[float]] = mapped_column(default=1.0)
+    ontology: Mapped[Optional[Ontology]] = mapped_column(SqlEnum(Ontology))
+
+    test2: Mapped[List[Test2]] = relationship(foreign_keys="[Test2.test_name]")
+
+
+class Test2(Base):
+
+    __tablename__ = "test2"
+    __table_args__ = (
+        ForeignKeyConstraint(["test_name"], ["test.name"]),
+    )
+
+    id: Mapped[int] = mapped_column(primary_key=True)
+    names: Mapped[Optional[Any]] = mapped_column(JSON)
+    number: Mapped[Optional[float]] = mapped_column()
+    test_name: Mapped[Optional[str]] = mapped_column()
\ No newline at end of file
@@ -0,0 +1,45 @@
+// This file contains ajv validators checking data against the JSON schema of the data model.
+//
+// WARNING: This is an auto-generated file.
+// Do not edit directly - any changes will be overwritten.
+//
+// The schema is generated by the `json-schema-bundle` target, the types by
+// the `typescript` target. Importing the schema requires `resolveJsonModule`.
+
+import Ajv2020, { ValidateFunction } from 'ajv/dist/2020';
+import schema from './schema.json';
+import type { Test, Test2 } from './model';
+
+const ajv = new Ajv2020({ strict: false });
+ajv.addSchema(schema);
+
+/**
+ * Compiles the validator of a definition of the schema.
+ */
+function compile<T>(name: string): ValidateFunction<T> {
+  return ajv.compile<T>({ $ref: `${schema.$id}#/$defs/${name}` });
+}
+
+/**
+ * Validator of Test, whose `errors` describe the last failed validation.
+ */
+export const TestValidator: ValidateFunction<Test> = compile<Test>('Test');
+
+/**
+ * Checks whether the data is a valid Test.
+ */
+export function validateTest(data: unknown): data is Test {
+  return TestValidator(data);
+}
+
+/**
+ * Validator of Test2, whose `errors` describe the last failed validation.
+ */
+export const Test2Validator: ValidateFunction<Test2> = compile<Test2>('Test2');
+
+/**
+ * Checks whether the data is a valid Test2.
+ */
+export function validateTest2(data: unknown): data is Test2 {
+  return Test2Validator(data);
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+// This file contains TypeScript classes with runtime validation.
+//
+// WARNING: This is an auto-generated file.
+// Do not edit directly - any changes will be overwritten.
+
+/**
+ * Thrown if the data passed to a constructor lacks a required field.
+ */
+export class ValidationError extends Error {
+  constructor(type: string, field: string) {
+    super(`${type}: missing required field '${field}'`);
+    this.name = "ValidationError";
+  }
+}
+
+export enum Ontology {
+  ECO = 'https://www.evidenceontology.org/term/',
+  GO = 'https://amigo.geneontology.org/amigo/term/',
+  SIO = 'http://semanticscience.org/resource/',
+}
+
+export class Test {
+  /** The name of the test. */
+  name: string;
+  number: number;
+  test2: Test2[];
+  ontology?: Ontology;
+
+  constructor(data: Record<string, any>) {
+    if (data["name"] == null) {
+      throw new ValidationError("Test", "name");
+    }
+    this.name = data["name"];
+    this.number = data["number"] ?? 1.0;
+    this.test2 = (data["test2"] ?? []).map((item: any) => item instanceof Test2 ? item : new Test2(item));
+    this.ontology = data["ontology"] ?? undefined;
+  }
+
+  static fromJSON(json: string | Record<string, any>): Test {
+    return new Test(typeof json === "string" ? JSON.parse(json) : json);
+  }
+
+  toJSON(): Record<string, unknown> {
+    return {
+      "name": this.name,
+      "number": this.number,
+      "test2": this.test2.map((item) => item.toJSON()),
+      "ontology": this.ontology,
+    };
+  }
+}
+
+export class Test2 {
+  names: string[];
+  number?: number;
+
+  constructor(data: Record<string, any>) {
+    this.names = data["names"] ?? [];
+    this.number = data["number"] ?? undefined;
+  }
+
+  static fromJSON(json: string | Record<string, any>): Test2 {
+    return new Test2(typeof json === "string" ? JSON.parse(json) : json);
+  }
+
+  toJSON(): Record<string, unknown> {
+    return {
+      "names": this.names,
+      "number": this.number,
+    };
+  }
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+import { z } from 'zod';
+
+// Enum definitions
+export const OntologySchema = z.enum([
+  'https://www.evidenceontology.org/term/',
+  'https://amigo.geneontology.org/amigo/term/',
+  'http://semanticscience.org/resource/',
+]);
+
+export type Ontology = z.infer<typeof OntologySchema>;
+
+// Schema definitions
+export const TestSchema = z.object({
+  name: z.string().describe("The name of the test."),
+  number: z.number().default(1.0),
+  test2: z.array(z.lazy(() => Test2Schema)).optional(),
+  ontology: OntologySchema.optional(),
+});
+
+export type Test = z.infer<typeof TestSchema>;
+
+export const Test2Schema = z.object({
+  names: z.array(z.string()).optional(),
+  number: z.number().min(0).optional(),
+});
+
+export type Test2 = z.infer<typeof Test2Schema>;
\ No newline at end of file
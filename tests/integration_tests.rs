@@ -2,9 +2,19 @@ extern crate mdmodels;
 
 #[cfg(test)]
 mod tests {
-    use mdmodels::{self, datamodel::DataModel};
+    use mdmodels::{
+        self,
+        datamodel::{DataModel, MarkdownStyle, RequiredMarker},
+        error::MdModelsError,
+        exporters::{render_jinja_template, render_jinja_units, Templates},
+        validation::Validator,
+    };
     use pretty_assertions::assert_eq;
-    use std::path::Path;
+    use std::{
+        collections::{BTreeMap, HashMap},
+        path::Path,
+        str::FromStr,
+    };
 
     #[test]
     fn test_parse() {
@@ -108,6 +118,53 @@ mod tests {
         DataModel::from_markdown(path).expect("Could not parse markdown");
     }
 
+    /// Removes the fields derived after parsing, e.g. the IRIs of enumerations.
+    fn strip_derived(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                map.remove("iris");
+                map.values_mut().for_each(strip_derived);
+            }
+            serde_json::Value::Array(values) => values.iter_mut().for_each(strip_derived),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_parser_parity() {
+        // Arrange
+        // Models as parsed by the two-pass parser, before parsing in a single pass
+        let expected: BTreeMap<String, serde_json::Value> = serde_json::from_str(
+            &std::fs::read_to_string("tests/data/expected_parser_parity.json").unwrap(),
+        )
+        .unwrap();
+
+        for (name, expected) in expected {
+            // Act
+            let path = Path::new("tests/data").join(&name);
+            let model = DataModel::from_markdown_unvalidated(&path).expect("Could not parse");
+            let mut parsed = serde_json::to_value(&model).unwrap();
+            strip_derived(&mut parsed);
+
+            // Assert
+            assert_eq!(parsed, expected, "Parsed model of {} differs", name);
+        }
+    }
+
+    #[test]
+    fn test_code_block_before_definitions() {
+        // Arrange
+        let content =
+            "# Model\n\n```python\nA = \"a\"\n```\n\n### Test\n\n- name\n  - Type: string\n";
+
+        // Act
+        let model = DataModel::from_markdown_string(content).expect("Could not parse markdown");
+
+        // Assert
+        assert_eq!(model.objects.len(), 1);
+        assert!(model.enums.is_empty());
+    }
+
     #[test]
     fn test_json_schema_known_obj() {
         // Arrange
@@ -115,7 +172,9 @@ mod tests {
         let model = DataModel::from_markdown(path).expect("Could not parse markdown");
 
         // Act
-        let schema = model.json_schema(Some("Test".to_string()));
+        let schema = model
+            .json_schema(Some("Test".to_string()))
+            .expect("Could not generate schema");
         let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
 
         // Assert
@@ -127,6 +186,228 @@ mod tests {
         assert_eq!(schema, expected_schema);
     }
 
+    #[test]
+    fn test_table_schema() {
+        // Arrange
+        let path = Path::new("tests/data/model_table_schema.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .table_schema(Some("Measurement".to_string()), false)
+            .expect("Could not generate Table Schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        let expected_schema =
+            std::fs::read_to_string("tests/data/expected_table_schema.json").unwrap();
+        let expected_schema: serde_json::Value = serde_json::from_str(&expected_schema).unwrap();
+
+        assert_eq!(schema, expected_schema);
+    }
+
+    #[test]
+    fn test_table_schema_nested_object() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let result = model.table_schema(Some("Test".to_string()), false);
+
+        // Assert
+        assert!(matches!(result, Err(MdModelsError::Generation { .. })));
+    }
+
+    #[test]
+    fn test_json_schema_summary() {
+        // Arrange
+        let path = Path::new("tests/data/model_summary.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model.json_schema(None).expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        assert_eq!(schema["title"], "A physical sample.");
+        assert_eq!(
+            schema["description"],
+            "A physical sample taken from a reaction vessel, which is stored and analyzed in the laboratory."
+        );
+        assert_eq!(schema["properties"]["name"]["title"], "Sample name");
+        assert_eq!(
+            schema["properties"]["name"]["description"],
+            "Unique name of the sample within the laboratory."
+        );
+        assert_eq!(schema["properties"]["volume"]["title"], "volume");
+        assert_eq!(
+            schema["properties"]["volume"]["description"],
+            "Volume of the sample in milliliters."
+        );
+    }
+
+    #[test]
+    fn test_rust_summary_docstring() {
+        // Arrange
+        let path = Path::new("tests/data/model_summary.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+        let rerendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert_eq!(rendered, rerendered);
+        assert_eq!(
+            model.objects[0].summary,
+            Some("A physical sample.".to_string())
+        );
+        assert!(rendered.contains("/// A physical sample.\n///\n/// A physical sample taken"));
+        assert!(rendered.contains(
+            "    /// Sample name\n    ///\n    /// Unique name of the sample within the laboratory.\n"
+        ));
+        assert!(rendered.contains("    /// Volume of the sample in milliliters.\n"));
+    }
+
+    #[test]
+    fn test_python_summary_docstring() {
+        // Arrange
+        let path = Path::new("tests/data/model_summary.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let pydantic = render_jinja_template(&Templates::PythonPydantic, &mut model, None)
+            .expect("Could not render template");
+        let dataclass = render_jinja_template(&Templates::PythonDataclass, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        let docstring = "    \"\"\"\n    A physical sample.\n\n    A physical sample taken";
+        assert!(pydantic.contains(&format!("class Sample(BaseModel):\n{}", docstring)));
+        assert!(dataclass.contains(&format!("class Sample:\n{}", docstring)));
+    }
+
+    #[test]
+    fn test_json_schema_with_ids() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema_with_ids(Some("Test".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        assert_eq!(schema["$id"], "https://www.github.com/my/repo/test.json");
+
+        let definitions = schema["definitions"].as_object().unwrap();
+        for (name, definition) in definitions {
+            assert_eq!(definition["$id"], format!("#{}", name));
+        }
+
+        let test2 = &schema["properties"]["test2"]["items"]["$ref"];
+        let ontology = &schema["properties"]["ontology"]["$ref"];
+        assert_eq!(test2, "#Test2");
+        assert_eq!(ontology, "#Ontology");
+        assert!(!schema.to_string().contains("#/definitions/"));
+    }
+
+    #[test]
+    fn test_json_schema_with_ids_relative_repo() {
+        // Arrange
+        let path = Path::new("tests/data/model_relative_repo.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema_with_ids(Some("Sample".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        assert!(schema.get("$id").is_none());
+        assert_eq!(schema["definitions"]["Origin"]["$id"], "#Origin");
+        assert_eq!(schema["properties"]["origin"]["$ref"], "#Origin");
+    }
+
+    #[test]
+    fn test_json_schemas_with_ids() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schemas = model.json_schemas(true);
+
+        // Assert
+        let ids = schemas
+            .iter()
+            .map(|(_, schema)| {
+                serde_json::from_str::<serde_json::Value>(schema).unwrap()["$id"].clone()
+            })
+            .collect::<Vec<serde_json::Value>>();
+        assert_eq!(
+            ids,
+            vec![
+                "https://www.github.com/my/repo/test.json",
+                "https://www.github.com/my/repo/test-2.json"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_schema_custom_quantity() {
+        // Arrange
+        let path = Path::new("tests/data/model_quantity_custom.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Order".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        assert!(!model.objects[1].builtin);
+        assert_eq!(
+            schema["properties"]["amount"]["$ref"],
+            "#/definitions/Quantity"
+        );
+        assert!(schema["definitions"]["Quantity"]["properties"]["pieces"].is_object());
+    }
+
+    #[test]
+    fn test_json_schema_quantity_inline() {
+        // Arrange
+        let path = Path::new("tests/data/model_quantity.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Measurement".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        let temperature = &schema["properties"]["temperature"];
+        assert_eq!(temperature["type"], "object");
+        assert_eq!(temperature["properties"]["value"]["type"], "number");
+        assert_eq!(temperature["properties"]["unit"]["const"], "K");
+        assert_eq!(temperature["properties"]["uncertainty"]["type"], "number");
+
+        let volumes = &schema["properties"]["volumes"];
+        assert_eq!(volumes["type"], "array");
+        assert_eq!(volumes["items"]["properties"]["unit"]["type"], "string");
+        assert!(volumes["items"]["properties"]["unit"]["const"].is_null());
+        assert!(schema.get("definitions").is_none());
+        assert!(model.objects[1].builtin);
+    }
+
     #[test]
     fn test_json_schema_unknown_obj() {
         // Arrange
@@ -134,7 +415,7 @@ mod tests {
         let model = DataModel::from_markdown(path).expect("Could not parse markdown");
 
         // Act
-        let schema = model.json_schema(None);
+        let schema = model.json_schema(None).expect("Could not generate schema");
         let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
 
         // Assert
@@ -147,24 +428,58 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
     fn test_json_schema_no_objects() {
         // Arrange
         let model = mdmodels::datamodel::DataModel::new(None, None);
 
         // Act
-        model.json_schema(Some("Test".to_string()));
+        let result = model.json_schema(None);
+
+        // Assert
+        assert!(matches!(result, Err(MdModelsError::Generation { .. })));
     }
 
     #[test]
-    #[should_panic]
     fn test_json_schema_no_object() {
         // Arrange
         let path = Path::new("tests/data/model.md");
         let model = DataModel::from_markdown(path).expect("Could not parse markdown");
 
         // Act
-        model.json_schema(Some("Test3".to_string()));
+        let result = model.json_schema(Some("Test3".to_string()));
+
+        // Assert
+        match result {
+            Err(MdModelsError::Generation { message, .. }) => {
+                assert_eq!(message, "Object 'Test3' not found in the model");
+            }
+            other => panic!("Expected a generation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_json_schema_bundle_no_objects() {
+        // Arrange
+        let model = mdmodels::datamodel::DataModel::new(None, None);
+
+        // Act
+        let result = model.json_schema_bundle();
+
+        // Assert
+        assert!(matches!(result, Err(MdModelsError::Generation { .. })));
+    }
+
+    #[test]
+    fn test_json_schema_all_no_objects() {
+        // Arrange
+        let model = mdmodels::datamodel::DataModel::new(None, None);
+
+        // Act
+        let result = model.json_schema_all("tests/intermediates/empty".to_string());
+
+        // Assert
+        assert!(matches!(result, Err(MdModelsError::Generation { .. })));
+        assert!(!Path::new("tests/intermediates/empty").exists());
     }
 
     #[test]
@@ -202,7 +517,9 @@ mod tests {
         let model = DataModel::from_markdown(path).expect("Could not parse markdown");
 
         // Act
-        model.json_schema_all("tests/intermediates/".to_string());
+        model
+            .json_schema_all("tests/intermediates/".to_string())
+            .expect("Could not write schemas");
 
         // Assert
         let filenames = vec!["Test.json", "Test2.json"];
@@ -210,7 +527,9 @@ mod tests {
             let obj_name = filename.replace(".json", "");
             let expected_schema =
                 std::fs::read_to_string(format!("tests/intermediates/{}", filename)).unwrap();
-            let schema = model.json_schema(Some(obj_name));
+            let schema = model
+                .json_schema(Some(obj_name))
+                .expect("Could not generate schema");
 
             assert_eq!(
                 serde_json::from_str::<serde_json::Value>(schema.as_str())
@@ -221,6 +540,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_json_schema_nested_definitions() {
+        // Arrange
+        let content = "### Root\n\n- middle\n  - Type: Middle\n\n### Middle\n\n- leaf\n  - Type: Leaf\n\n### Leaf\n\n- name\n  - Type: string\n";
+        let model = DataModel::from_markdown_string(content).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Root".to_string()))
+            .expect("Could not generate schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        let definitions = schema["definitions"].as_object().unwrap();
+        let mut names = definitions.keys().cloned().collect::<Vec<String>>();
+        names.sort();
+
+        assert_eq!(names, vec!["Leaf", "Middle"]);
+    }
+
+    #[test]
+    fn test_json_schema_enum_array() {
+        // Arrange
+        let content = "### Pixel\n\n- channels\n  - Type: Channel[]\n\n## Enumerations\n\n### Channel\n\n```\nRED = red\nGREEN = green\n```\n";
+        let model = DataModel::from_markdown_string(content).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Pixel".to_string()))
+            .expect("Could not generate schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        let channels = &schema["properties"]["channels"];
+        assert_eq!(channels["type"], "array");
+        assert_eq!(channels["items"]["$ref"], "#/definitions/Channel");
+        assert!(channels.get("$ref").is_none());
+    }
+
     #[test]
     fn test_model_merge() {
         // Arrange
@@ -288,4 +646,3517 @@ mod tests {
 
         assert_eq!(schema, expected_schema);
     }
+
+    #[test]
+    fn test_discriminated_union() {
+        // Arrange
+        let path = Path::new("tests/data/model_discriminator.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Zoo".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        let animals = &schema["properties"]["animals"];
+        assert_eq!(animals["type"], "array");
+        assert_eq!(
+            animals["items"]["oneOf"],
+            serde_json::json!([
+                { "$ref": "#/definitions/Cat" },
+                { "$ref": "#/definitions/Dog" }
+            ])
+        );
+        assert_eq!(
+            animals["items"]["discriminator"],
+            serde_json::json!({ "propertyName": "kind" })
+        );
+        assert!(schema["definitions"]["Cat"].is_object());
+        assert!(schema["definitions"]["Dog"].is_object());
+        assert_eq!(
+            schema["definitions"]["Cat"]["properties"]["kind"]["const"],
+            "cat"
+        );
+        assert_eq!(
+            schema["definitions"]["Dog"]["properties"]["kind"]["const"],
+            "dog"
+        );
+    }
+
+    #[test]
+    fn test_discriminated_union_rust() {
+        // Arrange
+        let path = Path::new("tests/data/model_discriminator.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("    pub animals: Vec<ZooAnimals>,"));
+        assert!(rendered.contains("    pub favourite: Option<ZooFavourite>,"));
+        assert!(rendered.contains(
+            "#[serde(tag = \"kind\")]\npub enum ZooAnimals {\n    #[serde(rename = \"cat\")]\n    Cat(Cat),\n    #[serde(rename = \"dog\")]\n    Dog(Dog),\n}"
+        ));
+        assert!(rendered.contains(
+            "    #[serde(default = \"default_cat_kind\", skip_serializing)]\n    pub kind: String,"
+        ));
+        assert!(rendered.contains("fn default_cat_kind() -> String {\n    \"cat\".to_string()\n}"));
+        assert!(!rendered.contains("serde_json::Value"));
+    }
+
+    #[test]
+    fn test_discriminated_union_pydantic() {
+        // Arrange
+        let path = Path::new("tests/data/model_discriminator.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::PythonPydantic, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains(
+            "    animals: list[Annotated[Union[Cat, Dog], Field(discriminator=\"kind\")]] = Field(default_factory=list)\n"
+        ));
+        assert!(rendered.contains(
+            "    favourite: Optional[Annotated[Union[Cat, Dog], Field(discriminator=\"kind\")]] = Field(default=None)\n"
+        ));
+    }
+
+    #[test]
+    fn test_discriminated_union_missing_field() {
+        // Arrange
+        let path = Path::new("tests/data/model_discriminator_invalid.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Validation { definitions }) => {
+                assert_eq!(definitions, vec!["Zoo".to_string()]);
+            }
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_union_with_primitives() {
+        // Arrange
+        let content = "### Zoo\n\n- keeper\n  - Type: string, integer, Cat\n\n### Cat\n\n- name\n  - Type: string\n";
+        let model = DataModel::from_markdown_string(content).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Zoo".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        assert_eq!(
+            schema["properties"]["keeper"]["oneOf"],
+            serde_json::json!([
+                { "type": "string" },
+                { "type": "integer" },
+                { "$ref": "#/definitions/Cat" }
+            ])
+        );
+        assert!(schema["definitions"]["Cat"].is_object());
+    }
+
+    #[test]
+    fn test_discriminated_union_duplicate_constant() {
+        // Arrange
+        let path = Path::new("tests/data/model_discriminator_duplicate.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Validation { definitions }) => {
+                assert_eq!(definitions, vec!["Zoo".to_string()]);
+            }
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_discriminated_union_missing_constant() {
+        // Arrange
+        let path = Path::new("tests/data/model_discriminator_no_const.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Validation { definitions }) => {
+                assert_eq!(definitions, vec!["Zoo".to_string()]);
+            }
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_dot_cyclic_references() {
+        // Arrange
+        let path = Path::new("tests/data/model_cyclic.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let config = HashMap::from([("root".to_string(), "Child".to_string())]);
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Dot, &mut model, Some(&config))
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains(r#""Parent" -> "Child" [label="children", arrowhead=crow];"#));
+        assert!(rendered.contains(r#""Child" -> "Parent" [label="parent"];"#));
+        assert!(
+            rendered.contains(r#""Child" [style="rounded,filled,bold", fillcolor="lightblue"];"#)
+        );
+        assert!(rendered.contains(r#""Parent";"#));
+    }
+
+    #[test]
+    fn test_plantuml_cyclic_references() {
+        // Arrange
+        let path = Path::new("tests/data/model_cyclic.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let config = HashMap::from([("root".to_string(), "Child".to_string())]);
+
+        // Act
+        let rendered = render_jinja_template(&Templates::PlantUml, &mut model, Some(&config))
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains(r#"Parent *-- "*" Child : children"#));
+        assert!(rendered.contains(r#"Child *-- "0..1" Parent : parent"#));
+        assert!(rendered.contains("class Child <<root>> {"));
+        assert!(rendered.contains("class Parent {"));
+    }
+
+    #[test]
+    fn test_unit_x_unit() {
+        // Arrange
+        let path = Path::new("tests/data/model_unit.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Measurement".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        let properties = &schema["properties"];
+        assert_eq!(properties["concentration"]["x-unit"], "mmol/L");
+        assert_eq!(properties["concentration"]["type"], "number");
+        assert_eq!(properties["volume"]["x-unit"], "mL");
+        assert!(properties["concentration"].get("unit").is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "ucum")]
+    #[should_panic]
+    fn test_unit_invalid_ucum() {
+        // Arrange
+        let path = Path::new("tests/data/model_unit_invalid.md");
+
+        // Act
+        DataModel::from_markdown(path).expect("Could not parse markdown");
+    }
+
+    #[test]
+    fn test_aliases() {
+        // Arrange
+        let path = Path::new("tests/data/model_alias.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Person".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        let rendered = render_jinja_template(&Templates::PythonPydantic, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert_eq!(
+            schema["properties"]["name"]["x-aliases"],
+            serde_json::json!(["full_name", "fullName"])
+        );
+        assert!(rendered.contains(
+            r#"name: str = Field(..., validation_alias=AliasChoices("name", "full_name", "fullName"))"#
+        ));
+        assert!(rendered.contains(
+            r#"age: Optional[int] = Field(default=None, validation_alias=AliasChoices("age", "years"))"#
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_aliases_collision() {
+        // Arrange
+        let path = Path::new("tests/data/model_alias_invalid.md");
+
+        // Act
+        DataModel::from_markdown(path).expect("Could not parse markdown");
+    }
+
+    #[test]
+    fn test_aliases_duplicate_within_attribute() {
+        // Arrange
+        let path = Path::new("tests/data/model_alias_duplicate.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        assert!(matches!(result, Err(MdModelsError::Validation { .. })));
+    }
+
+    #[test]
+    fn test_enum_default_by_name() {
+        // Arrange
+        let path = Path::new("tests/data/model_enum_default.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let pydantic = render_jinja_template(&Templates::PythonPydantic, &mut model.clone(), None)
+            .expect("Could not render template");
+        let elixir = render_jinja_template(&Templates::Elixir, &mut model.clone(), None)
+            .expect("Could not render template");
+        let zod = render_jinja_template(&Templates::Zod, &mut model.clone(), None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(pydantic.contains("status: Status = Status.ACTIVE"));
+        assert!(elixir.contains("status: :ACTIVE"));
+        assert!(zod.contains("status: StatusSchema.default('active')"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_enum_default_unknown_key() {
+        // Arrange
+        let path = Path::new("tests/data/model_enum_default_invalid.md");
+
+        // Act
+        DataModel::from_markdown(path).expect("Could not parse markdown");
+    }
+
+    #[test]
+    fn test_conditions_if_then() {
+        // Arrange
+        let path = Path::new("tests/data/model_conditions.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let sample = model
+            .json_schema(Some("Sample".to_string()))
+            .expect("Could not generate schema");
+        let sample: serde_json::Value = serde_json::from_str(&sample).unwrap();
+        let measurement = model
+            .json_schema(Some("Measurement".to_string()))
+            .expect("Could not generate schema");
+        let measurement: serde_json::Value = serde_json::from_str(&measurement).unwrap();
+
+        // Assert
+        assert_eq!(
+            sample["if"],
+            serde_json::json!({
+                "properties": { "state": { "const": "liquid" } },
+                "required": ["state"]
+            })
+        );
+        assert_eq!(
+            sample["then"],
+            serde_json::json!({ "required": ["volume"] })
+        );
+
+        let clauses = measurement["allOf"].as_array().unwrap();
+        assert_eq!(clauses.len(), 2);
+        assert_eq!(
+            clauses[1]["if"]["properties"]["calibrated"],
+            serde_json::json!({ "const": true })
+        );
+        assert_eq!(
+            clauses[1]["then"]["required"],
+            serde_json::json!(["volume", "calibration"])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_conditions_unknown_field() {
+        // Arrange
+        let path = Path::new("tests/data/model_conditions_invalid.md");
+
+        // Act
+        DataModel::from_markdown(path).expect("Could not parse markdown");
+    }
+
+    #[test]
+    fn test_pattern_registry() {
+        // Arrange
+        let path = Path::new("tests/data/model_patterns.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        let person = &model.objects[0];
+        let orcid = person
+            .attributes
+            .iter()
+            .find(|a| a.name == "orcid")
+            .unwrap();
+        assert_eq!(orcid.dtypes, vec!["string".to_string()]);
+        assert_eq!(orcid.options[0].key, "pattern");
+        assert_eq!(orcid.options[0].value, r"^\d{4}-\d{4}-\d{4}-\d{3}[\dX]$");
+
+        let custom = person
+            .attributes
+            .iter()
+            .find(|a| a.name == "custom")
+            .unwrap();
+        assert_eq!(custom.options.len(), 1);
+        assert_eq!(custom.options[0].value, "^0000-");
+    }
+
+    #[test]
+    fn test_pattern_registry_json_schema() {
+        // Arrange
+        let path = Path::new("tests/data/model_patterns.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Person".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        let properties = &schema["properties"];
+        assert_eq!(properties["orcid"]["type"], "string");
+        assert_eq!(
+            properties["orcid"]["pattern"],
+            r"^\d{4}-\d{4}-\d{4}-\d{3}[\dX]$"
+        );
+        assert_eq!(properties["birthday"]["pattern"], r"^\d{4}-\d{2}-\d{2}$");
+        assert_eq!(properties["aliases"]["type"], "array");
+    }
+
+    #[test]
+    fn test_pattern_registry_invalid_regex() {
+        // Arrange
+        let path = Path::new("tests/data/model_patterns_invalid.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_closed_objects() {
+        // Arrange
+        let path = Path::new("tests/data/model_closed.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Sample".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        assert_eq!(schema["additionalProperties"], serde_json::json!(false));
+        assert_eq!(schema["description"], "A sample measured in the lab.");
+        assert!(schema["definitions"]["Metadata"]
+            .get("additionalProperties")
+            .is_none());
+        assert_eq!(model.objects[1].docstring, "");
+    }
+
+    #[test]
+    fn test_closed_objects_disabled() {
+        // Arrange
+        let path = Path::new("tests/data/model_closed.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        model.config.as_mut().unwrap().closed_objects = Some(false);
+
+        // Act
+        let schema = model
+            .json_schema(Some("Sample".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        assert!(schema.get("additionalProperties").is_none());
+    }
+
+    #[test]
+    fn test_const_json_schema() {
+        // Arrange
+        let path = Path::new("tests/data/model_const.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Liquid".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        let properties = &schema["properties"];
+        assert_eq!(properties["kind"]["const"], serde_json::json!("liquid"));
+        assert_eq!(properties["version"]["const"], serde_json::json!(2));
+        assert_eq!(properties["verified"]["const"], serde_json::json!(true));
+        assert!(properties["volume"].get("const").is_none());
+        assert!(model.objects[0].attributes[0].required);
+    }
+
+    #[test]
+    fn test_const_typescript_literal() {
+        // Arrange
+        let path = Path::new("tests/data/model_const.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Typescript, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains(r#"kind: "liquid";"#));
+        assert!(rendered.contains("version: 2;"));
+        assert!(rendered.contains(r#"kind: D.literal("liquid"),"#));
+        assert!(rendered.contains("verified: D.literal(true),"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_const_incompatible_type() {
+        // Arrange
+        let path = Path::new("tests/data/model_const_invalid.md");
+
+        // Act
+        DataModel::from_markdown(path).expect("Could not parse markdown");
+    }
+
+    #[test]
+    fn test_external_type_ref() {
+        // Arrange
+        let path = Path::new("tests/data/model_external.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Measurement".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        let properties = &schema["properties"];
+        assert_eq!(
+            properties["sample"]["$ref"],
+            "https://purl.allotrope.org/json-schemas/adm/core/sample.schema.json"
+        );
+        assert_eq!(properties["devices"]["type"], "array");
+        assert_eq!(
+            properties["devices"]["items"]["$ref"],
+            "https://purl.allotrope.org/json-schemas/adm/core/device.schema.json"
+        );
+        assert!(schema.get("definitions").is_none());
+    }
+
+    #[test]
+    fn test_external_type_unsupported_template() {
+        // Arrange
+        let path = Path::new("tests/data/model_external.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let result = render_jinja_template(&Templates::PythonPydantic, &mut model, None);
+
+        // Assert
+        let err = result.expect_err("Expected an error for external types");
+        assert!(err.to_string().contains("does not support external type"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_external_type_host_not_allowed() {
+        // Arrange
+        let path = Path::new("tests/data/model_external_invalid.md");
+
+        // Act
+        DataModel::from_markdown(path).expect("Could not parse markdown");
+    }
+
+    #[test]
+    fn test_nested_objects() {
+        // Arrange
+        let path = Path::new("tests/data/model_nested.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        let names = model
+            .objects
+            .iter()
+            .map(|o| o.name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(names, vec!["Person", "PersonPostalAddress", "Organization"]);
+
+        let person = &model.objects[0];
+        assert_eq!(person.docstring, "A person with nested structures.");
+        let reference = person
+            .attributes
+            .iter()
+            .find(|a| a.name == "postal_address")
+            .expect("Missing implicit reference attribute");
+        assert_eq!(reference.dtypes, vec!["PersonPostalAddress".to_string()]);
+        assert!(!reference.required);
+
+        let address = &model.objects[1];
+        assert_eq!(address.attributes.len(), 2);
+        assert_eq!(address.nested_in, Some("Person".to_string()));
+    }
+
+    #[test]
+    fn test_rust_enum_serde_renames() {
+        // Arrange
+        let path = Path::new("tests/data/model_rust_enums.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains(
+            "#[serde(rename_all = \"snake_case\")]\npub enum Status {\n    Done,\n    InProgress,\n}"
+        ));
+        assert!(rendered.contains("    #[serde(rename = \"mmol/L\")]\n    MmolL,"));
+        assert!(rendered.contains("    Status::InProgress\n"));
+    }
+
+    #[test]
+    fn test_output_per_object_rust() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let units =
+            render_jinja_units(&Templates::Rust, &mut model, None).expect("Could not render units");
+        let units = units.into_iter().collect::<HashMap<String, String>>();
+
+        // Assert
+        let mut names = units.keys().cloned().collect::<Vec<String>>();
+        names.sort();
+        assert_eq!(names, vec!["mod.rs", "ontology.rs", "test.rs", "test2.rs"]);
+
+        let test = &units["test.rs"];
+        assert!(test.contains("use super::{Ontology, Test2};"));
+        assert!(test.contains("pub struct Test {"));
+        assert!(!test.contains("pub struct Test2 {"));
+        assert!(!units["test2.rs"].contains("use super::"));
+        assert!(units["ontology.rs"].contains("pub enum Ontology {"));
+
+        let index = &units["mod.rs"];
+        assert!(index.contains("mod test;\npub use test::Test;"));
+        assert!(index.contains("mod ontology;\npub use ontology::Ontology;"));
+    }
+
+    #[test]
+    fn test_output_per_object_zod() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let units =
+            render_jinja_units(&Templates::Zod, &mut model, None).expect("Could not render units");
+        let units = units.into_iter().collect::<HashMap<String, String>>();
+
+        // Assert
+        let test = &units["Test.ts"];
+        assert!(test.contains("import { OntologySchema } from './Ontology';"));
+        assert!(test.contains("import { Test2Schema } from './Test2';"));
+        assert!(test.contains("export const TestSchema = z.object({"));
+        assert!(!test.contains("export const Test2Schema"));
+        assert!(units["index.ts"].contains("export * from './Test2';"));
+    }
+
+    #[test]
+    fn test_output_per_object_unsupported() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let result = render_jinja_units(&Templates::Markdown, &mut model, None);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_inject_python_dataclass() {
+        // Arrange
+        let path = Path::new("tests/data/model_inject.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::PythonDataclass, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("from datetime import date, datetime\n\nimport re\n"));
+        assert!(rendered.contains(
+            "\n    def is_valid(self) -> bool:\n        return re.match(r\"^\\w+$\", self.name) is not None"
+        ));
+        assert!(!rendered.contains("ignored"));
+        assert_eq!(
+            model.objects[0].docstring,
+            "A person with an injected method."
+        );
+    }
+
+    #[test]
+    fn test_inject_other_targets() {
+        // Arrange
+        let path = Path::new("tests/data/model_inject.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::PythonPydantic, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(!rendered.contains("is_valid"));
+        assert!(!rendered.contains("import re"));
+        assert!(model.enums.is_empty());
+    }
+
+    #[test]
+    fn test_primary_key_ddl() {
+        // Arrange
+        let path = Path::new("tests/data/model_primary_key.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Sql, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert_eq!(
+            model.objects[0].primary_key,
+            vec!["student_id", "course_id"]
+        );
+        assert_eq!(
+            model.objects[0].docstring,
+            "A student enrolled in a course."
+        );
+        assert!(rendered.contains("    PRIMARY KEY (student_id, course_id)\n);"));
+        assert!(rendered.contains("    student_id TEXT NOT NULL,\n"));
+        assert!(!rendered.contains("id INTEGER PRIMARY KEY"));
+    }
+
+    #[test]
+    fn test_sql_quoted_defaults() {
+        // Arrange
+        let path = Path::new("tests/data/model_sql_defaults.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Sql, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("    text TEXT DEFAULT 'it''s here',\n"));
+        assert!(rendered.contains("DEFAULT 'o''pen' CHECK (status IN ('o''pen'))\n);"));
+        assert!(!rendered.contains(",\n);"));
+    }
+
+    #[test]
+    fn test_primary_key_invalid() {
+        // Arrange
+        let path = Path::new("tests/data/model_primary_key_invalid.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_table_names_ddl() {
+        // Arrange
+        let path = Path::new("tests/data/model_table_names.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Sql, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert_eq!(model.objects[0].table, Some("people".to_string()));
+        assert_eq!(
+            model.objects[0].docstring,
+            "A person registered in the database."
+        );
+        assert_eq!(
+            model.objects[0].attributes[0].column,
+            Some("full_name".to_string())
+        );
+        assert!(rendered.contains("CREATE TABLE people (\n"));
+        assert!(rendered.contains("CREATE TABLE postal_addresses (\n"));
+        assert!(rendered.contains("    full_name TEXT NOT NULL PRIMARY KEY,\n"));
+        assert!(rendered.contains("    age_years INTEGER,\n"));
+        assert!(!rendered.contains("CREATE TABLE Person"));
+    }
+
+    #[test]
+    fn test_table_names_sqlalchemy() {
+        // Arrange
+        let path = Path::new("tests/data/model_table_names.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::PythonSqlAlchemy, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("    __tablename__ = \"people\""));
+        assert!(rendered.contains("    __tablename__ = \"postal_addresses\""));
+        assert!(rendered
+            .contains("    name: Mapped[str] = mapped_column(\"full_name\", primary_key=True)"));
+        assert!(rendered.contains("    age: Mapped[Optional[int]] = mapped_column(\"age_years\")"));
+        assert!(rendered.contains(
+            "        ForeignKeyConstraint([\"people_full_name\"], [\"people.full_name\"]),"
+        ));
+    }
+
+    #[test]
+    fn test_default_table_names() {
+        // Arrange
+        let content = "### SampleRun\n\n- __name__\n  - Type: Identifier\n";
+        let mut model = DataModel::from_markdown_string(content).expect("Could not parse");
+
+        // Act
+        let sql = render_jinja_template(&Templates::Sql, &mut model, None)
+            .expect("Could not render template");
+        let sqlalchemy = render_jinja_template(&Templates::PythonSqlAlchemy, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(sql.contains("CREATE TABLE sample_run (\n"));
+        assert!(sqlalchemy.contains("    __tablename__ = \"sample_run\""));
+    }
+
+    #[test]
+    fn test_table_names_invalid() {
+        // Arrange
+        let path = Path::new("tests/data/model_table_names_invalid.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Validation { definitions }) => {
+                assert!(definitions.contains(&"Global".to_string()));
+                assert!(definitions.contains(&"Person".to_string()));
+            }
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_enum_curie_resolution() {
+        // Arrange
+        let path = Path::new("tests/data/model_enum_curies.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let shacl = render_jinja_template(&Templates::Shacl, &mut model, None)
+            .expect("Could not render template");
+        let shex = render_jinja_template(&Templates::Shex, &mut model, None)
+            .expect("Could not render template");
+        let pydantic = render_jinja_template(&Templates::PythonPydantic, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        let iris = &model.enums[0].iris;
+        assert_eq!(
+            iris.get("MALE"),
+            Some(&"http://purl.obolibrary.org/obo/PATO_0000384".to_string())
+        );
+        assert_eq!(iris.get("OTHER"), None);
+        assert_eq!(iris.get("UNKNOWN"), None);
+        assert!(shacl.contains(
+            "sh:in ( <http://purl.obolibrary.org/obo/PATO_0000383> <http://purl.obolibrary.org/obo/PATO_0000384> <http://purl.obolibrary.org/obo/PATO_0001340> \"unknown\" ) ;"
+        ));
+        assert!(
+            shex.contains("    obo:PATO_0000047 [ <http://purl.obolibrary.org/obo/PATO_0000383> ")
+        );
+        assert!(pydantic.contains("    MALE = \"obo:PATO_0000384\""));
+    }
+
+    #[test]
+    fn test_enum_curie_unknown_prefix() {
+        // Arrange
+        let path = Path::new("tests/data/model_enum_curies_invalid.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Validation { definitions }) => {
+                assert_eq!(definitions, vec!["Sex".to_string()]);
+            }
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_map_json_schema() {
+        // Arrange
+        let path = Path::new("tests/data/model_map.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Sample".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        assert_eq!(
+            schema["properties"]["concentrations"],
+            serde_json::json!({
+                "title": "concentrations",
+                "description": "Concentrations of the species in the sample.",
+                "type": "object",
+                "additionalProperties": {
+                    "type": "number",
+                    "minimum": 0.0,
+                },
+            })
+        );
+        assert_eq!(
+            schema["properties"]["measurements"]["title"],
+            "measurements"
+        );
+        assert_eq!(
+            schema["properties"]["measurements"]["additionalProperties"],
+            serde_json::json!({
+                "type": "array",
+                "items": { "$ref": "#/definitions/Measurement" },
+            })
+        );
+    }
+
+    #[test]
+    fn test_map_code_generation() {
+        // Arrange
+        let path = Path::new("tests/data/model_map.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let python = render_jinja_template(&Templates::PythonPydantic, &mut model.clone(), None)
+            .expect("Could not render template");
+        let rust = render_jinja_template(&Templates::Rust, &mut model.clone(), None)
+            .expect("Could not render template");
+        let typescript = render_jinja_template(&Templates::Typescript, &mut model.clone(), None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(python.contains("concentrations: dict[str, float] = Field(default_factory=dict"));
+        assert!(python.contains("measurements: dict[str, list[Measurement]]"));
+        assert!(rust.contains("use std::collections::HashMap;"));
+        assert!(rust.contains("pub concentrations: HashMap<String, f64>,"));
+        assert!(rust.contains("pub measurements: HashMap<String, Vec<Measurement>>,"));
+        assert!(typescript.contains("concentrations?: Record<string, number> | null;"));
+    }
+
+    #[test]
+    fn test_map_non_primitive_key() {
+        // Arrange
+        let path = Path::new("tests/data/model_map_invalid.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Validation { definitions }) => {
+                assert_eq!(definitions, vec!["Sample".to_string()]);
+            }
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_map_unsupported_nesting() {
+        // Arrange
+        let path = Path::new("tests/data/model_map_unsupported.md");
+
+        // Act
+        let model = DataModel::from_markdown_unvalidated(path).expect("Could not parse");
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        let sample = model.object("Sample").unwrap();
+        assert_eq!(
+            sample.attribute("nested").unwrap().dtypes,
+            vec!["map<string, map<string, float>>".to_string()]
+        );
+        assert_eq!(
+            sample.attribute("series").unwrap().dtypes,
+            vec!["map<string, float>[]".to_string()]
+        );
+        match result {
+            Err(MdModelsError::Validation { definitions }) => {
+                assert_eq!(definitions, vec!["Sample".to_string()]);
+            }
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_self_type_reference() {
+        // Arrange
+        let path = Path::new("tests/data/model_self.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+        let schema: serde_json::Value =
+            serde_json::from_str(&model.json_schema(None).expect("Could not generate schema"))
+                .expect("Could not parse schema");
+
+        // Assert
+        assert_eq!(
+            model.objects[0].attributes[1].dtypes,
+            vec!["Node".to_string()]
+        );
+        assert!(rendered.contains("    pub children: Vec<Node>,"));
+        assert_eq!(
+            schema["properties"]["children"]["items"]["$ref"],
+            "#/definitions/Node"
+        );
+        assert_eq!(
+            schema["definitions"]["Node"]["properties"]["children"]["items"]["$ref"],
+            "#/definitions/Node"
+        );
+    }
+
+    #[test]
+    fn test_enum_extension() {
+        // Arrange
+        let path = Path::new("tests/data/model_enum_extends.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        let detailed = model
+            .enums
+            .iter()
+            .find(|e| e.name == "DetailedStatus")
+            .unwrap();
+
+        assert_eq!(detailed.extends, Some("Status".to_string()));
+        assert_eq!(
+            detailed.mappings,
+            BTreeMap::from([
+                ("PENDING".to_string(), "pending".to_string()),
+                ("RETURNED".to_string(), "returned".to_string()),
+                ("SHIPPED".to_string(), "shipped_express".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_enum_extension_markdown_round_trip() {
+        // Arrange
+        let path = Path::new("tests/data/model_enum_extends.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let markdown = render_jinja_template(&Templates::Markdown, &mut model.clone(), None)
+            .expect("Could not render template");
+        let reparsed =
+            DataModel::from_markdown_string(&markdown).expect("Could not parse markdown");
+
+        // Assert
+        assert!(markdown.contains("### DetailedStatus\n\nExtends: Status\n"));
+        assert!(!markdown.contains("PENDING = pending\nRETURNED"));
+        assert_eq!(reparsed.enums, model.enums);
+    }
+
+    #[test]
+    fn test_enum_extension_cyclic() {
+        // Arrange
+        let path = Path::new("tests/data/model_enum_extends_cyclic.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Validation { definitions }) => {
+                assert_eq!(
+                    definitions,
+                    vec!["Status".to_string(), "DetailedStatus".to_string()]
+                );
+            }
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_naming_conventions_violation() {
+        // Arrange
+        let path = Path::new("tests/data/model_naming.md");
+        let model = DataModel::from_markdown(path).expect("Naming violations should only warn");
+
+        // Act
+        let mut validator = Validator::new();
+        validator.set_strict(true);
+        let result = validator.validate(&model);
+
+        // Assert
+        assert_eq!(
+            model.stats().warnings,
+            vec![
+                "[sampleItem] NamingWarning: Object name sampleItem is not PascalCase. Rename it to SampleItem.".to_string(),
+                "[sampleItem] NamingWarning: Property dateCreated is not snake_case. Rename it to date_created.".to_string(),
+                "[ItemStatus] NamingWarning: Key retired is not SCREAMING_SNAKE_CASE. Rename it to RETIRED.".to_string(),
+            ]
+        );
+
+        match result {
+            Err(MdModelsError::Validation { definitions }) => {
+                assert_eq!(
+                    definitions,
+                    vec!["sampleItem".to_string(), "ItemStatus".to_string()]
+                );
+            }
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_naming_conventions_compliant() {
+        // Arrange
+        let path = Path::new("tests/data/model_naming_compliant.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let mut validator = Validator::new();
+        validator.set_strict(true);
+        let result = validator.validate(&model);
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(model.stats().warnings.is_empty());
+    }
+
+    #[test]
+    fn test_any_type() {
+        // Arrange
+        let path = Path::new("tests/data/model_any.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema: serde_json::Value =
+            serde_json::from_str(&model.json_schema(None).expect("Could not generate schema"))
+                .expect("Could not parse schema");
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert_eq!(
+            schema["properties"]["payload"],
+            serde_json::json!({
+                "title": "payload",
+                "description": "Free-form payload of the event.",
+            })
+        );
+        assert_eq!(
+            schema["properties"]["extras"],
+            serde_json::json!({
+                "title": "extras",
+                "type": "array",
+                "items": {},
+            })
+        );
+        assert!(rendered.contains("    pub payload: Option<serde_json::Value>,"));
+        assert!(rendered.contains("    pub extras: Vec<serde_json::Value>,"));
+    }
+
+    /// Returns the XML names of the attributes of the first object and whether they are XML attributes.
+    fn xml_kinds(model: &DataModel) -> Vec<(String, bool)> {
+        model.objects[0]
+            .attributes
+            .iter()
+            .map(|a| {
+                let xml = serde_json::to_value(&a.xml).unwrap();
+                (
+                    xml["name"].as_str().unwrap().to_string(),
+                    xml["is_attr"] == true,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_xml_default_attribute() {
+        // Arrange
+        let path = Path::new("tests/data/model_xml_default.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        assert_eq!(
+            xml_kinds(&model),
+            vec![
+                ("id".to_string(), true),
+                ("name".to_string(), true),
+                ("note".to_string(), false),
+                ("code".to_string(), true),
+                ("tags".to_string(), false),
+                ("part".to_string(), false),
+                ("parts".to_string(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_xml_default_element() {
+        // Arrange
+        let content = std::fs::read_to_string("tests/data/model_xml_default.md")
+            .expect("Could not read markdown")
+            .replace("xml-default: attribute", "xml-default: element");
+
+        // Act
+        let model = DataModel::from_markdown_string(&content).expect("Could not parse markdown");
+
+        // Assert
+        assert_eq!(
+            xml_kinds(&model),
+            vec![
+                ("id".to_string(), false),
+                ("name".to_string(), false),
+                ("note".to_string(), false),
+                ("code".to_string(), true),
+                ("tags".to_string(), false),
+                ("part".to_string(), false),
+                ("parts".to_string(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_xml_wrap_arrays() {
+        // Arrange
+        let content = std::fs::read_to_string("tests/data/model_xml_default.md")
+            .expect("Could not read markdown");
+        let mut wrapped = DataModel::from_markdown_string(
+            &content.replace("xml-default: attribute", "xml-wrap-arrays: true"),
+        )
+        .expect("Could not parse markdown");
+        let mut unwrapped = DataModel::from_markdown_string(
+            &content.replace("xml-default: attribute", "xml-wrap-arrays: false"),
+        )
+        .expect("Could not parse markdown");
+        let mut default =
+            DataModel::from_markdown_string(&content).expect("Could not parse markdown");
+
+        // Act
+        let wrapped = render_jinja_template(&Templates::XmlSchema, &mut wrapped, None)
+            .expect("Could not render template");
+        let unwrapped = render_jinja_template(&Templates::XmlSchema, &mut unwrapped, None)
+            .expect("Could not render template");
+        let default = render_jinja_template(&Templates::XmlSchema, &mut default, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(wrapped.contains(
+            "<xs:element name=\"tags\">\n                <xs:complexType>\n                    <xs:sequence>\n                        <xs:element name=\"string\" type=\"xs:string\" maxOccurs=\"unbounded\"/>"
+        ));
+        assert!(unwrapped
+            .contains("<xs:element name=\"tags\" type=\"xs:string\"  maxOccurs=\"unbounded\"/>"));
+        assert!(unwrapped
+            .contains("<xs:element name=\"parts\" type=\"PartType\" maxOccurs=\"unbounded\"/>"));
+        assert!(default
+            .contains("<xs:element name=\"tags\" type=\"xs:string\"  maxOccurs=\"unbounded\"/>"));
+        assert!(default.contains("<xs:element name=\"parts\">"));
+    }
+
+    #[test]
+    fn test_rust_xml_names() {
+        // Arrange
+        let path = Path::new("tests/data/model_xml_rust.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let config = HashMap::from([("xml".to_string(), "true".to_string())]);
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, Some(&config))
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("    #[serde(rename = \"@id\")]\n    pub id: String,"));
+        assert!(rendered.contains(
+            "    #[serde(rename = \"ex:name\", default, skip_serializing_if = \"Option::is_none\")]\n    pub sample_name: Option<String>,"
+        ));
+        assert!(rendered.contains(
+            "    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n    pub value: Option<f64>,"
+        ));
+    }
+
+    #[test]
+    fn test_visibility_rust() {
+        // Arrange
+        let path = Path::new("tests/data/model_visibility.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("    pub id: String,"));
+        assert!(rendered.contains("    password_hash: Option<String>,"));
+        assert!(!rendered.contains("pub password_hash"));
+        assert!(rendered.contains("    pub(crate) audit_log: Vec<String>,"));
+    }
+
+    #[test]
+    fn test_visibility_nim() {
+        // Arrange
+        let path = Path::new("tests/data/model_visibility.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Nim, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("    id*: string"));
+        assert!(rendered.contains("    passwordHash: Option[string]"));
+        assert!(rendered.contains("    auditLog: seq[string]"));
+    }
+
+    #[test]
+    fn test_visibility_python() {
+        // Arrange
+        let path = Path::new("tests/data/model_visibility.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let dataclass = render_jinja_template(&Templates::PythonDataclass, &mut model, None)
+            .expect("Could not render template");
+        let pydantic = render_jinja_template(&Templates::PythonPydantic, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(dataclass.contains("    id: str"));
+        assert!(dataclass.contains(
+            "    _password_hash: Optional[str] = field(default=None, metadata=config(exclude=lambda x: x is None, field_name=\"password_hash\"))"
+        ));
+        assert!(dataclass.contains(
+            "    _audit_log: List[str] = field(default_factory=list, metadata=config(field_name=\"audit_log\"))"
+        ));
+        assert!(pydantic.contains("    id: str\n"));
+        assert!(
+            pydantic.contains("    password_hash: Optional[str] = Field(default=None, repr=False)")
+        );
+        assert!(
+            pydantic.contains("    audit_log: list[str] = Field(default_factory=list, repr=False)")
+        );
+    }
+
+    #[test]
+    fn test_visibility_typescript() {
+        // Arrange
+        let path = Path::new("tests/data/model_visibility.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let interface = render_jinja_template(&Templates::Typescript, &mut model, None)
+            .expect("Could not render template");
+        let class = render_jinja_template(&Templates::TypescriptClass, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(interface.contains("  id: string;"));
+        assert!(interface.contains("  /** @internal */\n  password_hash?: string | null;"));
+        assert!(interface.contains("  /** @internal */\n  audit_log?: string[] | null;"));
+        assert!(class.contains("  private password_hash?: string;"));
+        assert!(class.contains("  /** @internal */\n  audit_log: string[];"));
+    }
+
+    #[test]
+    fn test_visibility_fsharp() {
+        // Arrange
+        let path = Path::new("tests/data/model_visibility.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::FSharp, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("open System.ComponentModel"));
+        assert!(rendered.contains("        Id: string\n"));
+        assert!(rendered.contains(
+            "        [<EditorBrowsable(EditorBrowsableState.Never)>]\n        PasswordHash: string option"
+        ));
+        assert!(!rendered.contains("[<EditorBrowsable(EditorBrowsableState.Never)>]\n        Id:"));
+    }
+
+    #[test]
+    fn test_visibility_invalid() {
+        // Arrange
+        let path = Path::new("tests/data/model_visibility_invalid.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Validation { definitions }) => {
+                assert_eq!(definitions, vec!["Account".to_string()]);
+            }
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_qualified_import_reference() {
+        // Arrange
+        let path = Path::new("tests/data/model_imports.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        let reaction = model.objects.iter().find(|o| o.name == "Reaction").unwrap();
+        let reactants = reaction
+            .attributes
+            .iter()
+            .find(|a| a.name == "reactants")
+            .unwrap();
+        let name = reaction
+            .attributes
+            .iter()
+            .find(|a| a.name == "name")
+            .unwrap();
+
+        assert_eq!(reactants.dtypes, vec!["Chemical".to_string()]);
+        assert_eq!(name.term, Some("chem:name".to_string()));
+        assert!(model.objects.iter().any(|o| o.name == "Chemical"));
+        assert!(model.enums.iter().any(|e| e.name == "State"));
+    }
+
+    #[test]
+    fn test_json_schema_provenance_of_import() {
+        // Arrange
+        let path = Path::new("tests/data/model_imports.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema_with_provenance(Some("Reaction".to_string()), false)
+            .expect("Could not generate schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        assert_eq!(
+            schema["$comment"],
+            "source: tests/data/model_imports.md; object: Reaction; version: 1.0.0"
+        );
+        assert_eq!(
+            schema["definitions"]["Chemical"]["$comment"],
+            "source: tests/data/model_imports_chemicals.md; object: Chemical; version: 1.0.0"
+        );
+    }
+
+    #[test]
+    fn test_unresolved_import_namespace() {
+        // Arrange
+        let path = Path::new("tests/data/model_imports_invalid.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Validation { definitions }) => {
+                assert_eq!(definitions, vec!["Reaction".to_string()]);
+            }
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_multi_document_file() {
+        // Arrange
+        let path = Path::new("tests/data/model_multi.md");
+
+        // Act
+        let models = DataModel::from_markdown_multi(path).expect("Could not parse markdown");
+
+        // Assert
+        assert_eq!(models.len(), 2);
+        assert_eq!(models[0].name, Some("Laboratory".to_string()));
+        assert_eq!(models[0].config.as_ref().unwrap().prefix, "lab");
+        assert_eq!(models[0].objects.len(), 1);
+        assert_eq!(models[0].objects[0].name, "Sample");
+        assert!(models[0].enums.is_empty());
+        assert_eq!(models[1].name, Some("Inventory".to_string()));
+        assert_eq!(models[1].config.as_ref().unwrap().prefix, "inv");
+        assert_eq!(models[1].objects[0].name, "Chemical");
+        assert_eq!(models[1].enums[0].name, "Hazard");
+    }
+
+    #[test]
+    fn test_multi_document_imports_and_sources() {
+        // Arrange
+        let path = Path::new("tests/data/model_multi_imports.md");
+
+        // Act
+        let models = DataModel::from_markdown_multi(path).expect("Could not parse markdown");
+
+        // Assert
+        assert_eq!(models.len(), 2);
+        assert_eq!(
+            models[0].objects[0].attribute("chemical").unwrap().dtypes,
+            vec!["Chemical".to_string()]
+        );
+        assert!(models[0].object("Chemical").is_some());
+        assert_eq!(
+            models[1].enums[0].mappings.get("SHIPPED"),
+            Some(&"shipped".to_string())
+        );
+        assert_eq!(
+            models[1].objects[0].source,
+            Some(path.display().to_string())
+        );
+    }
+
+    #[test]
+    fn test_byte_order_mark() {
+        // Arrange
+        let path = Path::new("tests/data/model_bom.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        assert_eq!(model.objects.len(), 1);
+        assert_eq!(model.objects[0].name, "Sample");
+        assert_eq!(model.objects[0].attributes[0].name, "name");
+        assert!(model.objects[0].attributes[0].required);
+    }
+
+    #[test]
+    fn test_non_utf8_file() {
+        // Arrange
+        let path = Path::new("tests/data/model_latin1.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(err @ MdModelsError::Io { .. }) => {
+                let message = err.to_string();
+                assert!(message.contains("model_latin1.md"));
+                assert!(message.contains("not valid UTF-8"));
+            }
+            other => panic!("Expected an I/O error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_inline_rust_flatten() {
+        // Arrange
+        let path = Path::new("tests/data/model_inline.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(model.objects[0].attributes[1].inline);
+        assert!(rendered.contains("    #[serde(flatten)]\n    pub address: Option<Address>,\n"));
+    }
+
+    #[test]
+    fn test_inline_json_schema_all_of() {
+        // Arrange
+        let path = Path::new("tests/data/model_inline.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Person".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value =
+            serde_json::from_str(&schema).expect("Could not parse schema");
+
+        // Assert
+        assert_eq!(
+            schema["allOf"],
+            serde_json::json!([{ "$ref": "#/definitions/Address" }])
+        );
+        assert!(schema["properties"].get("address").is_none());
+        assert!(schema["definitions"]["Address"]["properties"]["city"].is_object());
+    }
+
+    #[test]
+    fn test_inline_collision_invalid() {
+        // Arrange
+        let path = Path::new("tests/data/model_inline_invalid.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_inline_nested_collision_invalid() {
+        // Arrange
+        let path = Path::new("tests/data/model_inline_nested_invalid.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_inline_cycle_invalid() {
+        // Arrange
+        let path = Path::new("tests/data/model_inline_cycle.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_inline_json_schema_union() {
+        // Arrange
+        let path = Path::new("tests/data/model_inline_union.md");
+        let model = DataModel::from_markdown_unvalidated(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Person".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value =
+            serde_json::from_str(&schema).expect("Could not parse schema");
+
+        // Assert
+        assert!(schema.get("allOf").is_none());
+        assert!(schema["properties"]["contact"]["oneOf"].is_array());
+    }
+
+    #[test]
+    fn test_open_enum_rust() {
+        // Arrange
+        let path = Path::new("tests/data/model_open_enum.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(model.enums[0].open);
+        assert!(rendered.contains("    Shipped,\n    /// A value that is not known to this version of the model.\n    Other(String),\n}"));
+        assert!(rendered.contains("            Status::Other(value) => value,\n"));
+        assert!(rendered.contains(
+            "            \"shipped\" => Status::Shipped,\n            _ => Status::Other(value),\n"
+        ));
+    }
+
+    #[test]
+    fn test_docs_attribute_groups() {
+        // Arrange
+        let path = Path::new("tests/data/model_groups.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Docs, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        let attributes = &model.objects[0].attributes;
+        assert_eq!(attributes[0].group, Some("Identity".to_string()));
+        assert_eq!(attributes[1].group, None);
+        assert_eq!(
+            attributes
+                .iter()
+                .map(|a| a.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["id", "notes", "created", "name"]
+        );
+
+        let general = rendered
+            .find("#### General")
+            .expect("Missing default group");
+        let identity = rendered.find("#### Identity").expect("Missing group");
+        let metadata = rendered.find("#### Metadata").expect("Missing group");
+        assert!(general < identity && identity < metadata);
+        assert!(rendered.contains("#### Identity\n\n| Name | Type | Required | Description | Term |\n|------|------|----------|-------------|------|\n| id | `string` | Yes |  |  |\n| name | `string` | No |  |  |\n"));
+        assert_eq!(rendered.matches("#### ").count(), 3);
+    }
+
+    #[test]
+    fn test_quantity_rust() {
+        // Arrange
+        let path = Path::new("tests/data/model_quantity.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("pub struct Quantity {"));
+        assert!(rendered.contains("    pub temperature: Quantity,\n"));
+        assert!(rendered.contains("    pub volumes: Vec<Quantity>,\n"));
+        assert!(rendered.contains("    pub uncertainty: Option<f64>,\n"));
+    }
+
+    #[test]
+    fn test_open_enum_json_schema() {
+        // Arrange
+        let path = Path::new("tests/data/model_open_enum.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Order".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value =
+            serde_json::from_str(&schema).expect("Could not parse schema");
+
+        // Assert
+        let status = &schema["definitions"]["Status"];
+        assert!(status.get("enum").is_none());
+        assert_eq!(
+            status["anyOf"],
+            serde_json::json!([{ "enum": ["pending", "shipped"] }, { "type": "string" }])
+        );
+    }
+
+    #[test]
+    fn test_open_enum_typescript() {
+        // Arrange
+        let path = Path::new("tests/data/model_open_enum.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Typescript, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("  status?: Status | (string & {}) | null;"));
+        assert!(rendered.contains("  tags?: (Status | (string & {}))[] | null;"));
+        assert!(rendered.contains("  D.literal(Status.SHIPPED),\n  D.string,\n);"));
+    }
+
+    #[test]
+    fn test_sqlalchemy_one_to_many() {
+        // Arrange
+        let path = Path::new("tests/data/model_orm.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::PythonSqlAlchemy, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains(
+            "    books: Mapped[List[Book]] = relationship(foreign_keys=\"[Book.author_name]\")"
+        ));
+        assert!(rendered
+            .contains("        ForeignKeyConstraint([\"author_name\"], [\"author.name\"]),"));
+        assert!(rendered.contains("    author_name: Mapped[Optional[str]] = mapped_column()"));
+        assert!(rendered.contains(
+            "    publisher: Mapped[Optional[Publisher]] = relationship(foreign_keys=\"[Book.publisher_id]\")"
+        ));
+    }
+
+    #[test]
+    fn test_sqlalchemy_many_to_many() {
+        // Arrange
+        let path = Path::new("tests/data/model_orm.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::PythonSqlAlchemy, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("course_student = Table(\n    \"course_student\",\n"));
+        assert!(rendered.contains(
+            "    ForeignKeyConstraint([\"student_id\"], [\"student.id\"]),\n    ForeignKeyConstraint([\"course_code\"], [\"course.code\"]),"
+        ));
+        assert!(rendered.contains(
+            "    courses: Mapped[List[Course]] = relationship(secondary=course_student, back_populates=\"students\")"
+        ));
+        assert!(rendered.contains(
+            "    students: Mapped[List[Student]] = relationship(secondary=course_student, back_populates=\"courses\")"
+        ));
+    }
+
+    #[test]
+    fn test_error_io() {
+        // Arrange
+        let path = Path::new("tests/data/does_not_exist.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Io { path: err_path, .. }) => assert_eq!(err_path, path),
+            other => panic!("Expected an IO error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_error_frontmatter() {
+        // Arrange
+        let path = Path::new("tests/data/model_invalid_frontmatter.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        assert!(matches!(result, Err(MdModelsError::Frontmatter { .. })));
+    }
+
+    #[test]
+    fn test_error_parse() {
+        // Arrange
+        let path = Path::new("tests/data/model_invalid_option.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Parse { object, message }) => {
+                assert_eq!(object, Some("Person".to_string()));
+                assert!(message.contains("Inline"));
+            }
+            other => panic!("Expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_error_validation() {
+        // Arrange
+        let path = Path::new("tests/data/model_missing_types.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Validation { definitions }) => {
+                assert_eq!(definitions, vec!["Test".to_string()])
+            }
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_error_generation() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let units = render_jinja_units(&Templates::Sql, &mut model, None);
+        let template = Templates::from_str("unknown");
+
+        // Assert
+        assert!(matches!(
+            units,
+            Err(MdModelsError::Generation { ref target, .. }) if target == "sql"
+        ));
+        assert!(matches!(
+            template,
+            Err(MdModelsError::UnknownTemplate { ref name }) if name == "unknown"
+        ));
+    }
+
+    #[cfg(feature = "remote")]
+    mod remote {
+        use mdmodels::{datamodel::DataModel, error::MdModelsError};
+        use pretty_assertions::assert_eq;
+        use std::{
+            io::{Read, Write},
+            net::TcpListener,
+            path::Path,
+            thread,
+        };
+
+        /// Serves a single HTTP response on a local port and returns the URL of the model.
+        fn serve_once(status: &str, body: &str) -> String {
+            let listener = TcpListener::bind("127.0.0.1:0").expect("Could not bind listener");
+            let url = format!("http://{}/models/model.md", listener.local_addr().unwrap());
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+
+            thread::spawn(move || {
+                let (mut stream, _) = listener.accept().expect("Could not accept connection");
+                let mut request = [0; 1024];
+                let _ = stream.read(&mut request);
+                stream.write_all(response.as_bytes()).unwrap();
+            });
+
+            url
+        }
+
+        /// Serves the given files below `/models/` and returns the URL of the directory.
+        ///
+        /// Each file is served exactly once, in any order.
+        fn serve_files(files: &[&str]) -> String {
+            let listener = TcpListener::bind("127.0.0.1:0").expect("Could not bind listener");
+            let base = format!("http://{}/models/", listener.local_addr().unwrap());
+            let files = files
+                .iter()
+                .map(|name| {
+                    let content = std::fs::read_to_string(format!("tests/data/{}", name)).unwrap();
+                    (format!("/models/{}", name), content)
+                })
+                .collect::<Vec<_>>();
+
+            thread::spawn(move || {
+                for _ in 0..files.len() {
+                    let (mut stream, _) = listener.accept().expect("Could not accept connection");
+                    let mut request = [0; 1024];
+                    let read = stream.read(&mut request).unwrap_or(0);
+                    let request = String::from_utf8_lossy(&request[..read]);
+                    let target = request.split_whitespace().nth(1).unwrap_or("");
+
+                    let response = match files.iter().find(|(path, _)| path == target) {
+                        Some((_, body)) => format!(
+                            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                            body.len(),
+                            body
+                        ),
+                        None => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                            .to_string(),
+                    };
+                    stream.write_all(response.as_bytes()).unwrap();
+                }
+            });
+
+            base
+        }
+
+        #[test]
+        fn test_from_url() {
+            // Arrange
+            let content = std::fs::read_to_string("tests/data/model.md").unwrap();
+            let url = serve_once("200 OK", &content);
+
+            // Act
+            let model = DataModel::from_url(&url).expect("Could not fetch model");
+
+            // Assert
+            assert!(model
+                .objects
+                .iter()
+                .all(|o| o.source.as_deref() == Some(url.as_str())));
+
+            let mut expected = DataModel::from_markdown(Path::new("tests/data/model.md")).unwrap();
+            expected
+                .objects
+                .iter_mut()
+                .for_each(|o| o.source = Some(url.clone()));
+            assert_eq!(model, expected);
+        }
+
+        #[test]
+        fn test_from_url_imports() {
+            // Arrange
+            let base = serve_files(&["model_imports.md", "model_imports_chemicals.md"]);
+            let url = format!("{}model_imports.md", base);
+
+            // Act
+            let model = DataModel::from_url(&url).expect("Could not fetch model");
+
+            // Assert
+            let expected =
+                DataModel::from_markdown(Path::new("tests/data/model_imports.md")).unwrap();
+            let names = |model: &DataModel| {
+                model
+                    .objects
+                    .iter()
+                    .map(|o| (o.name.clone(), o.attributes.clone()))
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(names(&model), names(&expected));
+            assert_eq!(model.enums, expected.enums);
+
+            let imported = model.objects.iter().find(|o| o.name == "Chemical").unwrap();
+            assert_eq!(
+                imported.source,
+                Some(format!("{}model_imports_chemicals.md", base))
+            );
+        }
+
+        #[test]
+        fn test_error_fetch() {
+            // Arrange
+            let url = serve_once("404 Not Found", "");
+            let unreachable = {
+                let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+                format!("http://{}/model.md", listener.local_addr().unwrap())
+            };
+
+            // Act
+            let not_found = DataModel::from_url(&url);
+            let refused = DataModel::from_url(&unreachable);
+
+            // Assert
+            match not_found {
+                Err(MdModelsError::Fetch {
+                    url: err_url,
+                    message,
+                }) => {
+                    assert_eq!(err_url, url);
+                    assert_eq!(message, "Server responded with 404 Not Found");
+                }
+                other => panic!("Expected a fetch error, got {:?}", other),
+            }
+
+            assert!(matches!(refused, Err(MdModelsError::Fetch { .. })));
+        }
+    }
+
+    #[test]
+    fn test_generic_object_typescript() {
+        // Arrange
+        let path = Path::new("tests/data/model_generics.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Typescript, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert_eq!(model.objects[0].type_param, Some("T".to_string()));
+        assert!(rendered.contains("export interface Result<T> extends JsonLd {\n  value: T;"));
+        assert!(rendered.contains(
+            "export const ResultCodec = <T>(TCodec: D.Decoder<unknown, T>) => D.lazy(\"Result\""
+        ));
+        assert!(rendered.contains("  sample: Result<Sample>;"));
+        assert!(rendered.contains("  temperatures?: Result<number>[] | null;"));
+        assert!(rendered.contains("    sample: ResultCodec(SampleCodec),"));
+        assert!(rendered.contains("    temperatures: D.array(ResultCodec(D.number)),"));
+    }
+
+    #[test]
+    fn test_generic_object_monomorphized() {
+        // Arrange
+        let path = Path::new("tests/data/model_generics.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema: serde_json::Value = serde_json::from_str(
+            &model
+                .json_schema(Some("Experiment".to_string()))
+                .expect("Could not generate schema"),
+        )
+        .expect("Could not parse schema");
+        let rendered = render_jinja_template(&Templates::PythonDataclass, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert_eq!(
+            schema["definitions"]["ResultSample"]["properties"]["value"]["$ref"],
+            "#/definitions/Sample"
+        );
+        assert_eq!(
+            schema["definitions"]["ResultFloat"]["properties"]["value"]["type"],
+            "number"
+        );
+        assert!(rendered.contains("class ResultSample:"));
+        assert!(rendered.contains("    sample: ResultSample\n"));
+        assert!(!rendered.contains("class Result:"));
+    }
+
+    #[test]
+    fn test_generic_object_typescript_ajv() {
+        // Arrange
+        let path = Path::new("tests/data/model_generics.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::TypescriptAjv, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("import type { Experiment, Sample, Result } from './model';"));
+        assert!(rendered.contains(
+            "export const ResultSampleValidator: ValidateFunction<Result<Sample>> = compile<Result<Sample>>('ResultSample');"
+        ));
+        assert!(rendered.contains(
+            "export function validateResultFloat(data: unknown): data is Result<number> {"
+        ));
+        assert!(!rendered.contains("ValidateFunction<ResultSample>"));
+    }
+
+    #[test]
+    fn test_nested_objects_typescript_ajv() {
+        // Arrange
+        let path = Path::new("tests/data/model_nested.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::TypescriptAjv, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered
+            .contains("import type { Person, PersonPostalAddress, Organization } from './model';"));
+        assert!(rendered.contains("compile<PersonPostalAddress>('PersonPostalAddress');"));
+        assert!(!rendered.contains("Person.PostalAddress"));
+    }
+
+    #[test]
+    fn test_invalid_generic_types() {
+        // Arrange
+        let path = Path::new("tests/data/model_generics_invalid.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Validation { definitions }) => {
+                assert_eq!(definitions, vec!["Experiment".to_string()]);
+            }
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_frontmatter_accessors() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let plain = DataModel::from_markdown_string("### Test\n\n- name\n  - Type: string\n")
+            .expect("Could not parse markdown");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        let frontmatter = model.frontmatter().expect("Expected a frontmatter");
+        assert_eq!(frontmatter.prefix, "tst");
+        assert_eq!(
+            model.prefixes(),
+            Some(&HashMap::from([(
+                "schema".to_string(),
+                "http://schema.org/".to_string()
+            )]))
+        );
+        assert_eq!(model.repo(), Some("https://www.github.com/my/repo/"));
+        assert_eq!(model.version(), None);
+
+        assert!(plain.frontmatter().is_none());
+        assert_eq!(plain.repo(), None);
+    }
+
+    #[test]
+    fn test_id_field_opt_out() {
+        // Arrange
+        let path = Path::new("tests/data/model_id_field.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let markdown = render_jinja_template(&Templates::Markdown, &mut model.clone(), None)
+            .expect("Could not render template");
+        let rendered = render_jinja_template(&Templates::Sql, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert_eq!(model.objects[0].id_field, None);
+        assert_eq!(model.objects[1].id_field, Some(false));
+        assert_eq!(
+            model.objects[1].docstring,
+            "A value object embedded in a sample."
+        );
+        assert!(rendered.contains("CREATE TABLE sample (\n    id INTEGER PRIMARY KEY,"));
+        assert!(rendered.contains("CREATE TABLE position (\n    x "));
+        assert!(markdown.contains("Id: false"));
+    }
+
+    #[test]
+    fn test_id_field_opt_in() {
+        // Arrange
+        let path = Path::new("tests/data/model_id_field_opt_in.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Sql, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert_eq!(model.objects[0].id_field, Some(true));
+        assert!(rendered.contains("CREATE TABLE sample (\n    id INTEGER PRIMARY KEY,"));
+        assert!(rendered.contains("CREATE TABLE position (\n    x "));
+    }
+
+    #[test]
+    fn test_id_field_opt_out_sqlalchemy() {
+        // Arrange
+        let path = Path::new("tests/data/model_id_field.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let result = render_jinja_template(&Templates::PythonSqlAlchemy, &mut model, None);
+
+        // Assert
+        let error = result
+            .expect_err("Keyless object should be rejected")
+            .to_string();
+        assert!(error.contains("requires a primary key for object 'Position'"));
+    }
+
+    #[test]
+    fn test_ocaml_maps_and_shared_variants() {
+        // Arrange
+        let path = Path::new("tests/data/model_ocaml_maps.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let config = HashMap::from([("yojson".to_string(), "true".to_string())]);
+
+        // Act
+        let rendered = render_jinja_template(&Templates::OCaml, &mut model, Some(&config))
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("let assoc_of_yojson value_of_yojson = function"));
+        assert!(rendered.contains(
+            "counts : (string * int) list [@to_yojson assoc_to_yojson [%to_yojson: int]] [@of_yojson assoc_of_yojson [%of_yojson: int]] [@default []];"
+        ));
+        assert!(rendered.contains("tags : (string * tag) list"));
+        assert!(rendered
+            .contains("let other_of_yojson : Yojson.Safe.t -> (other, string) result = function"));
+    }
+
+    #[test]
+    fn test_enum_source_csv() {
+        // Arrange
+        let path = Path::new("tests/data/model_enum_source.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        let status = model.enums.iter().find(|e| e.name == "Status").unwrap();
+
+        assert_eq!(status.source, Some("vocab/status.csv".to_string()));
+        assert_eq!(
+            status.mappings,
+            BTreeMap::from([
+                ("DELIVERED".to_string(), "delivered_to_customer".to_string()),
+                ("PENDING".to_string(), "pending".to_string()),
+                ("SHIPPED".to_string(), "shipped".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_enum_source_missing_file() {
+        // Arrange
+        let path = Path::new("tests/data/model_enum_source_missing.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Io { path, .. }) => {
+                assert!(path.ends_with("vocab/missing.csv"));
+            }
+            other => panic!("Expected an IO error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_mock_json_deterministic() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let first = model
+            .mock_json(None, 5, 42)
+            .expect("Could not generate mock data");
+        let second = model
+            .mock_json(None, 5, 42)
+            .expect("Could not generate mock data");
+        let other = model
+            .mock_json(None, 5, 7)
+            .expect("Could not generate mock data");
+
+        // Assert
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+
+        let instances: serde_json::Value = serde_json::from_str(&first).unwrap();
+        assert_eq!(instances.as_array().unwrap().len(), 5);
+        assert_eq!(instances[0]["name"], "test-8291");
+    }
+
+    #[test]
+    fn test_mock_json_generics() {
+        // Arrange
+        let path = Path::new("tests/data/model_generics.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let mock = model
+            .mock_json(Some("Experiment".to_string()), 1, 42)
+            .expect("Could not generate mock data");
+
+        // Assert
+        let instances: serde_json::Value = serde_json::from_str(&mock).unwrap();
+        let sample = &instances[0]["sample"];
+        assert!(sample["value"]["name"].is_string());
+        assert!(sample["message"].is_string() || sample["message"].is_null());
+        for temperature in instances[0]["temperatures"].as_array().unwrap() {
+            assert!(temperature["value"].is_number());
+        }
+    }
+
+    #[test]
+    fn test_abstract_object_typescript_class() {
+        // Arrange
+        let path = Path::new("tests/data/model_abstract.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::TypescriptClass, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(model.objects[0].is_abstract);
+        assert!(rendered.contains("export abstract class Shape {"));
+        assert!(rendered.contains("export class Circle {"));
+        assert!(!rendered.contains("new Shape("));
+    }
+
+    #[test]
+    fn test_abstract_object_json_schema() {
+        // Arrange
+        let path = Path::new("tests/data/model_abstract.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Shape".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        assert_eq!(
+            schema["oneOf"],
+            serde_json::json!([
+                { "$ref": "#/definitions/Circle" },
+                { "$ref": "#/definitions/Square" },
+            ])
+        );
+        assert!(schema["definitions"]["Circle"].is_object());
+        assert!(schema["definitions"].get("Shape").is_none());
+    }
+
+    #[test]
+    fn test_abstract_object_mock_json() {
+        // Arrange
+        let path = Path::new("tests/data/model_abstract.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let mock = model
+            .mock_json(Some("Shape".to_string()), 10, 0)
+            .expect("Could not generate mock data");
+        let unused = DataModel::from_markdown(Path::new("tests/data/model_abstract_unused.md"))
+            .expect("Could not parse markdown");
+
+        // Assert
+        let instances: serde_json::Value = serde_json::from_str(&mock).unwrap();
+        for instance in instances.as_array().unwrap() {
+            assert!(instance.get("radius").is_some() || instance.get("side").is_some());
+        }
+        assert!(matches!(
+            unused.mock_json(None, 1, 0),
+            Err(MdModelsError::Generation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_abstract_object_without_subtypes() {
+        // Arrange
+        let path = Path::new("tests/data/model_abstract_unused.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Abstract objects should only warn");
+
+        // Assert
+        assert_eq!(
+            model.stats().warnings,
+            vec![
+                "[Shape] AbstractWarning: Object is abstract but has no subtypes, hence it can never be instantiated.".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_option_warning() {
+        // Arrange
+        let path = Path::new("tests/data/model_option_typo.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Unknown options should only warn");
+        let schema = model
+            .json_schema(Some("Sample".to_string()))
+            .expect("Could not generate schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        assert_eq!(schema["properties"]["code"]["readOnly"], true);
+        assert_eq!(
+            schema["properties"]["code"]["examples"],
+            serde_json::json!(["S-001"])
+        );
+        assert_eq!(
+            model.stats().warnings,
+            vec![
+                "[Sample] OptionWarning: Property name has unknown option descripton. Did you mean Description?".to_string(),
+                "[Sample] OptionWarning: Property volume has unknown option colour. Add it to custom-options in the frontmatter if intended.".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_imports_inlined_into_json_schema() {
+        // Arrange
+        let path = Path::new("tests/data/model_bundle.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let schema = model
+            .json_schema(Some("Measurement".to_string()))
+            .expect("Could not generate schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        let definitions = schema["definitions"].as_object().unwrap();
+        let mut names = definitions.keys().cloned().collect::<Vec<String>>();
+        names.sort();
+
+        assert_eq!(names, vec!["Device", "InstSample", "Sample"]);
+        assert_eq!(
+            schema["properties"]["sample"]["$ref"],
+            "#/definitions/Sample"
+        );
+        assert_eq!(
+            definitions["Device"]["properties"]["calibration"]["$ref"],
+            "#/definitions/InstSample"
+        );
+        assert!(definitions["InstSample"]["properties"]
+            .as_object()
+            .unwrap()
+            .contains_key("lot"));
+    }
+
+    #[test]
+    fn test_import_name_collision_keeps_namespace() {
+        // Arrange
+        let path = Path::new("tests/data/model_bundle.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        let sample = model.objects.iter().find(|o| o.name == "Sample").unwrap();
+        let inst_sample = model
+            .objects
+            .iter()
+            .find(|o| o.name == "InstSample")
+            .unwrap();
+        let device = model.objects.iter().find(|o| o.name == "Device").unwrap();
+
+        assert!(sample.attributes.iter().any(|a| a.name == "volume"));
+        assert!(inst_sample.attributes.iter().any(|a| a.name == "lot"));
+        assert!(device
+            .attributes
+            .iter()
+            .any(|a| a.dtypes == vec!["InstSample".to_string()]));
+    }
+
+    #[test]
+    fn test_integer_width_json_schema() {
+        // Arrange
+        let content = "### Counter\n\n- __count__\n  - Type: uint32\n- limited\n  - Type: int32\n  - Maximum: 10\n";
+        let model = DataModel::from_markdown_string(content).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Counter".to_string()))
+            .expect("Could not generate schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        let count = &schema["properties"]["count"];
+        let limited = &schema["properties"]["limited"];
+
+        assert_eq!(count["type"], "integer");
+        assert_eq!(count["minimum"], 0);
+        assert_eq!(count["maximum"], 4294967295u64);
+        assert_eq!(limited["minimum"], -2147483648i64);
+        assert_eq!(limited["maximum"], 10.0);
+    }
+
+    #[test]
+    fn test_markdown_styles() {
+        // Arrange
+        let content = "### Sample\n\n- __name__\n  - Type: string\n  - Term: schema:name\n- status\n  - Type: Status\n\n## Enumerations\n\n### Status\n\n```\nOPEN = open\n```\n";
+        let mut model = DataModel::from_markdown_string(content).expect("Could not parse markdown");
+        let team = MarkdownStyle {
+            indent: 4,
+            required: RequiredMarker::Asterisk,
+            fence: Some("python".to_string()),
+        };
+
+        // Act
+        let canonical = model
+            .to_markdown(&MarkdownStyle::default())
+            .expect("Could not render markdown");
+        let styled = model.to_markdown(&team).expect("Could not render markdown");
+
+        // Assert
+        assert!(canonical.contains("- __name__\n  - Type: string\n  - Term: schema:name"));
+        assert!(canonical.contains("- status\n  - Type: Status"));
+        assert!(canonical.contains("```\nOPEN = open"));
+        assert!(styled.contains("- **name**\n    - Type: string\n    - Term: schema:name"));
+        assert!(styled.contains("- status\n    - Type: Status"));
+        assert!(styled.contains("```python\nOPEN = open"));
+
+        let canonical = DataModel::from_markdown_string(&canonical).unwrap();
+        let styled = DataModel::from_markdown_string(&styled).unwrap();
+        assert_eq!(canonical.objects, styled.objects);
+        assert_eq!(canonical.enums, styled.enums);
+        assert!(styled.objects[0].attributes[0].required);
+    }
+
+    #[test]
+    fn test_markdown_style_from_editorconfig() {
+        // Arrange
+        let path = Path::new("tests/data/style.editorconfig");
+
+        // Act
+        let style = MarkdownStyle::from_editorconfig(path).expect("Could not read editorconfig");
+
+        // Assert
+        assert_eq!(style.indent, 4);
+        assert_eq!(style.required, RequiredMarker::Underscore);
+        assert_eq!(style.fence, None);
+    }
+
+    #[test]
+    fn test_targets_json_schema_excludes_sql_only() {
+        // Arrange
+        let path = Path::new("tests/data/model_targets.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Sample".to_string()))
+            .expect("Could not generate schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert!(properties.contains_key("name"));
+        assert!(!properties.contains_key("internal_note"));
+        assert!(!properties.contains_key("audit"));
+        assert!(schema.get("definitions").is_none());
+    }
+
+    #[test]
+    fn test_targets_sql_includes_sql_only() {
+        // Arrange
+        let path = Path::new("tests/data/model_targets.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Sql, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("CREATE TABLE audit_log ("));
+        assert!(rendered.contains("internal_note TEXT"));
+        assert!(!rendered.contains("payload"));
+    }
+
+    #[test]
+    fn test_targets_keep_rendered_model() {
+        // Arrange
+        let path = Path::new("tests/data/model_targets.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        render_jinja_template(&Templates::PythonPydantic, &mut model, None)
+            .expect("Could not render template");
+        let units =
+            render_jinja_units(&Templates::Rust, &mut model, None).expect("Could not render units");
+
+        // Assert
+        assert_eq!(model.objects.len(), 2);
+        assert_eq!(model.objects[0].attributes.len(), 3);
+        assert_eq!(model.objects[1].attributes.len(), 2);
+        assert!(!units.iter().any(|(name, _)| name == "audit_log.rs"));
+    }
+
+    #[test]
+    fn test_targets_attribute_of_excluded_object_warning() {
+        // Arrange
+        let path = Path::new("tests/data/model_targets.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        assert_eq!(
+            model.stats().warnings,
+            vec![
+                "[AuditLog] TargetWarning: Property payload targets json-schema, but its object is excluded from it."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bytes_json_schema() {
+        // Arrange
+        let content =
+            "### Spectrum\n\n- __data__\n  - Type: bytes\n  - Description: Raw detector counts.\n";
+        let model = DataModel::from_markdown_string(content).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Spectrum".to_string()))
+            .expect("Could not generate schema");
+
+        // Assert
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+        assert_eq!(
+            schema["properties"]["data"],
+            serde_json::json!({
+                "title": "data",
+                "type": "string",
+                "contentEncoding": "base64",
+                "description": "Raw detector counts.",
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_schema_bundle() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema_bundle()
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        assert_eq!(
+            schema["$schema"],
+            "https://json-schema.org/draft/2020-12/schema"
+        );
+        assert!(schema.get("$id").is_none());
+        assert!(schema.get("properties").is_none());
+
+        let defs = schema["$defs"].as_object().unwrap();
+        for name in model.objects.iter().map(|o| &o.name) {
+            assert!(defs.contains_key(name), "Missing definition {}", name);
+        }
+        for name in model.enums.iter().map(|e| &e.name) {
+            assert!(defs.contains_key(name), "Missing definition {}", name);
+        }
+
+        fn collect_refs(value: &serde_json::Value, refs: &mut Vec<String>) {
+            match value {
+                serde_json::Value::Object(map) => {
+                    for (key, value) in map {
+                        match value.as_str() {
+                            Some(reference) if key == "$ref" => refs.push(reference.to_string()),
+                            _ => collect_refs(value, refs),
+                        }
+                    }
+                }
+                serde_json::Value::Array(items) => items.iter().for_each(|v| collect_refs(v, refs)),
+                _ => {}
+            }
+        }
+
+        let mut refs = vec![];
+        collect_refs(&schema, &mut refs);
+        assert!(!refs.is_empty());
+        for reference in refs {
+            let name = reference
+                .strip_prefix("#/$defs/")
+                .unwrap_or_else(|| panic!("Unexpected reference {}", reference));
+            assert!(
+                defs.contains_key(name),
+                "Unresolved reference {}",
+                reference
+            );
+        }
+    }
+
+    #[test]
+    fn test_indexes_ddl() {
+        // Arrange
+        let path = Path::new("tests/data/model_indexes.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let sql = render_jinja_template(&Templates::Sql, &mut model.clone(), None)
+            .expect("Could not render template");
+        let sqlalchemy = render_jinja_template(&Templates::PythonSqlAlchemy, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert_eq!(model.objects[0].indexes.len(), 2);
+        assert!(model.objects[0].indexes[0].unique);
+        assert_eq!(
+            model.objects[0].indexes[1].fields,
+            vec!["last_name", "first_name"]
+        );
+        assert!(sql.contains("CREATE UNIQUE INDEX uq_person_email ON person (email);"));
+        assert!(sql.contains(
+            "CREATE INDEX ix_person_surname_first_name ON person (surname, first_name);"
+        ));
+        assert!(
+            sqlalchemy.contains("        UniqueConstraint(\"email\", name=\"uq_person_email\"),\n")
+        );
+        assert!(sqlalchemy.contains(
+            "        Index(\"ix_person_surname_first_name\", \"surname\", \"first_name\"),\n"
+        ));
+    }
+
+    #[test]
+    fn test_indexes_invalid() {
+        // Arrange
+        let path = Path::new("tests/data/model_indexes_invalid.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_indexes_reject_collections_and_references() {
+        // Arrange
+        let path = Path::new("tests/data/model_indexes_columns.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        match result {
+            Err(MdModelsError::Validation { definitions }) => {
+                assert_eq!(definitions, vec!["Post".to_string()]);
+            }
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_markdown_with_diagnostics() {
+        // Arrange
+        let path = Path::new("tests/data/model_unused_enum.md");
+
+        // Act
+        let (model, diagnostics) =
+            DataModel::from_markdown_with_diagnostics(path).expect("Could not parse markdown");
+
+        // Assert
+        assert_eq!(model.enums.len(), 2);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].definition, "Color");
+        assert_eq!(diagnostics[0].kind, "EnumWarning");
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "[Color] EnumWarning: Enumeration is not used by any property."
+        );
+    }
+
+    #[test]
+    fn test_markdown_without_diagnostics() {
+        // Arrange
+        let path = Path::new("tests/data/model.md");
+        let content = std::fs::read_to_string(path).expect("Could not read markdown");
+
+        // Act
+        let (_, diagnostics) = DataModel::from_markdown_string_with_diagnostics(&content)
+            .expect("Could not parse markdown");
+
+        // Assert
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_subclass_of_shacl() {
+        // Arrange
+        let path = Path::new("tests/data/model_subclass.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let shacl = render_jinja_template(&Templates::Shacl, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert_eq!(
+            model.objects[0].subclass_of,
+            vec!["obo:OBI_0000747", "http://www.w3.org/ns/prov#Entity"]
+        );
+        assert!(shacl.contains("@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> ."));
+        assert!(shacl.contains("md:Sample rdfs:subClassOf obo:OBI_0000747 ."));
+        assert!(shacl.contains("md:Sample rdfs:subClassOf <http://www.w3.org/ns/prov#Entity> ."));
+    }
+
+    #[test]
+    fn test_subclass_of_unknown_prefix() {
+        // Arrange
+        let path = Path::new("tests/data/model_subclass_invalid.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_instance() {
+        // Arrange
+        let path = Path::new("tests/data/model_instance.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let instance = serde_json::json!({
+            "name": "Kinetics",
+            "samples": [
+                { "id": "s1", "state": "solid", "volume": 1.5 },
+                { "id": "s2", "state": "liquid", "volume": "unknown" }
+            ],
+            "replicates": 3,
+            "tags": { "lab": "B12" }
+        });
+
+        // Act
+        let result = model.validate_instance(None, &instance);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_validate_instance_violations() {
+        // Arrange
+        let path = Path::new("tests/data/model_instance.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let instance = serde_json::json!({
+            "samples": [
+                { "id": "s1", "state": "gas" },
+                { "id": 2, "state": "solid", "volume": true }
+            ],
+            "temperature": "hot",
+            "replicates": 5_000_000_000u64,
+            "tags": { "lab": 12 }
+        });
+
+        // Act
+        let errors = model
+            .validate_instance(None, &instance)
+            .expect_err("Instance should be invalid");
+
+        // Assert
+        let errors = errors
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(
+            errors,
+            vec![
+                "/name: Missing required property",
+                "/samples/0/state: Expected a value of the enumeration State",
+                "/samples/1/id: Expected a value of type string",
+                "/samples/1/volume: Expected one of the types float, string",
+                "/temperature: Expected a value of type float",
+                "/replicates: Expected a value of type int32",
+                "/tags/lab: Expected a value of type string",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_instance_root() {
+        // Arrange
+        let path = Path::new("tests/data/model_instance.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let instance = serde_json::json!({
+            "id": "s1",
+            "state": "solid",
+            "checked": "yes",
+            "batch": 4
+        });
+
+        // Act
+        let errors = model
+            .validate_instance(Some("Sample".to_string()), &instance)
+            .expect_err("Instance should be invalid");
+
+        // Assert
+        let errors = errors
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(
+            errors,
+            vec![
+                "/checked: Expected a value of type boolean",
+                "/batch: Expected a value of type string",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_instance_unknown_root() {
+        // Arrange
+        let path = Path::new("tests/data/model_instance.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let instance = serde_json::json!({});
+
+        // Act
+        let errors = model
+            .validate_instance(Some("Missing".to_string()), &instance)
+            .expect_err("Instance should be invalid");
+
+        // Assert
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "Object 'Missing' not found in the model");
+    }
+
+    #[test]
+    fn test_validate_instance_not_an_array() {
+        // Arrange
+        let path = Path::new("tests/data/model_instance.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+        let instance = serde_json::json!({
+            "name": "Kinetics",
+            "samples": { "id": "s1", "state": "solid" }
+        });
+
+        // Act
+        let errors = model
+            .validate_instance(None, &instance)
+            .expect_err("Instance should be invalid");
+
+        // Assert
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].pointer, "/samples");
+        assert_eq!(errors[0].message, "Expected an array");
+    }
+
+    #[test]
+    fn test_python_union_array_add_methods() {
+        // Arrange
+        let path = Path::new("tests/data/model_union_array.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        for template in [
+            Templates::PythonPydantic,
+            Templates::PythonDataclass,
+            Templates::PythonSdrdm,
+        ] {
+            // Act
+            let rendered = render_jinja_template(&template, &mut model.clone(), None)
+                .expect("Could not render template");
+
+            // Assert
+            assert!(rendered.contains("def add_to_values_quantity("));
+            assert!(rendered.contains("def add_to_values_range("));
+            assert!(!rendered.contains("def add_to_values("));
+        }
+    }
+
+    #[test]
+    fn test_json_schema_union_array() {
+        // Arrange
+        let path = Path::new("tests/data/model_union_array.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Measurement".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        let values = &schema["properties"]["values"];
+        assert_eq!(values["type"], "array");
+        assert_eq!(
+            values["items"]["oneOf"],
+            serde_json::json!([
+                { "$ref": "#/definitions/Quantity" },
+                { "$ref": "#/definitions/Range" }
+            ])
+        );
+
+        let single = &schema["properties"]["single"];
+        assert!(single.get("type").is_none());
+        assert_eq!(single["oneOf"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_json_schema_fixed_arrays() {
+        // Arrange
+        let path = Path::new("tests/data/model_fixed_arrays.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Pixel".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        let rgb = &schema["properties"]["rgb"];
+        assert_eq!(rgb["type"], "array");
+        assert_eq!(rgb["items"]["type"], "number");
+        assert_eq!(rgb["minItems"], 3);
+        assert_eq!(rgb["maxItems"], 3);
+
+        let position = &schema["properties"]["position"];
+        assert_eq!(
+            position["items"],
+            serde_json::json!([{ "type": "number" }, { "type": "integer" }])
+        );
+        assert_eq!(position["additionalItems"], false);
+        assert_eq!(position["minItems"], 2);
+        assert_eq!(position["maxItems"], 2);
+
+        let channels = &schema["properties"]["channels"];
+        assert_eq!(channels["items"]["$ref"], "#/definitions/Channel");
+        assert_eq!(channels["maxItems"], 2);
+    }
+
+    #[test]
+    fn test_json_schema_bundle_id_from_title() {
+        // Arrange
+        let content = "---\nrepo: https://example.org/schemas/\n---\n\n# Lab Model\n\n### Sample\n\n- name\n  - Type: string\n";
+        let model = DataModel::from_markdown_string(content).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema_bundle()
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        assert_eq!(schema["$id"], "https://example.org/schemas/lab-model.json");
+    }
+
+    #[test]
+    fn test_json_schema_bundle_tuples() {
+        // Arrange
+        let path = Path::new("tests/data/model_fixed_arrays.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema_bundle()
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        let label = &schema["$defs"]["Pixel"]["properties"]["label"];
+        assert_eq!(
+            label["prefixItems"],
+            serde_json::json!([{ "type": "string" }, { "$ref": "#/$defs/Channel" }])
+        );
+        assert_eq!(label["items"], false);
+        assert!(label.get("additionalItems").is_none());
+    }
+
+    #[test]
+    fn test_fixed_arrays_rust() {
+        // Arrange
+        let path = Path::new("tests/data/model_fixed_arrays.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("    pub rgb: [f64; 3],"));
+        assert!(rendered.contains("    pub position: (f64, i64),"));
+        assert!(rendered.contains("    pub label: Option<(String, Channel)>,"));
+        assert!(rendered.contains("    pub channels: Option<[Channel; 2]>,"));
+    }
+
+    #[test]
+    fn test_fixed_arrays_python() {
+        // Arrange
+        let path = Path::new("tests/data/model_fixed_arrays.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let pydantic = render_jinja_template(&Templates::PythonPydantic, &mut model.clone(), None)
+            .expect("Could not render template");
+        let dataclass =
+            render_jinja_template(&Templates::PythonDataclass, &mut model.clone(), None)
+                .expect("Could not render template");
+
+        // Assert
+        assert!(pydantic.contains("    rgb: tuple[float, float, float]\n"));
+        assert!(pydantic.contains("    position: tuple[float, int]\n"));
+        assert!(
+            pydantic.contains("    label: Optional[tuple[str, Channel]] = Field(default=None)\n")
+        );
+        assert!(dataclass.contains("from typing import List, Optional, Tuple\n"));
+        assert!(dataclass.contains("    channels: Optional[Tuple[Channel, Channel]] = field("));
+    }
+
+    #[test]
+    fn test_fixed_arrays_typescript() {
+        // Arrange
+        let path = Path::new("tests/data/model_fixed_arrays.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let zod = render_jinja_template(&Templates::Zod, &mut model.clone(), None)
+            .expect("Could not render template");
+        let classes = render_jinja_template(&Templates::TypescriptClass, &mut model.clone(), None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(zod.contains("  rgb: z.array(z.number()).length(3),"));
+        assert!(zod.contains("  position: z.tuple([z.number(), z.number().int()]),"));
+        assert!(zod.contains("  label: z.tuple([z.string(), ChannelSchema]).optional(),"));
+        assert!(classes.contains("  rgb: [number, number, number];"));
+        assert!(classes.contains("  label?: [string, Channel];"));
+        assert!(classes.contains(r#"    this.label = data["label"] ?? undefined;"#));
+    }
+
+    #[test]
+    fn test_fixed_arrays_unsupported_template() {
+        // Arrange
+        let path = Path::new("tests/data/model_fixed_arrays.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let err = render_jinja_template(&Templates::Dart, &mut model, None)
+            .expect_err("Dart should reject fixed-size arrays");
+
+        // Assert
+        assert!(err
+            .to_string()
+            .contains("does not support the fixed-size array of property 'Pixel.rgb'"));
+    }
+
+    #[test]
+    fn test_fixed_arrays_invalid() {
+        // Arrange
+        let path = Path::new("tests/data/model_fixed_arrays_invalid.md");
+
+        // Act
+        let result = DataModel::from_markdown(path);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_coerce_zod() {
+        // Arrange
+        let path = Path::new("tests/data/model_coerce.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Zod, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains("  count: z.coerce.number().int(),"));
+        assert!(rendered.contains("  values: z.array(z.coerce.number()).optional(),"));
+        assert!(rendered.contains("  label: z.string().optional(),"));
+        assert!(rendered.contains(
+            r#"  valid: z.preprocess((value) => (value === "true" ? true : value === "false" ? false : value), z.boolean()).optional(),"#
+        ));
+        assert!(!rendered.contains("z.coerce.boolean()"));
+    }
+
+    #[test]
+    fn test_coerce_rust() {
+        // Arrange
+        let path = Path::new("tests/data/model_coerce.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains(
+            "    #[serde(deserialize_with = \"deserialize_coerced\")]\n    pub count: i64,"
+        ));
+        assert!(rendered.contains("deserialize_with = \"deserialize_coerced_option\")]\n    pub temperature: Option<f64>,"));
+        assert!(rendered.contains(
+            "deserialize_with = \"deserialize_coerced_vec\")]\n    pub values: Vec<f64>,"
+        ));
+        assert!(rendered.contains("    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n    pub label: Option<String>,"));
+        assert!(rendered.contains("fn deserialize_coerced<'de, D, T>"));
+    }
+
+    #[test]
+    fn test_default_required() {
+        // Arrange
+        let path = Path::new("tests/data/model_default_required.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        let required = model.objects[0]
+            .attributes
+            .iter()
+            .map(|a| (a.name.as_str(), a.required))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            required,
+            vec![
+                ("name", true),
+                ("notes", false),
+                ("comment", false),
+                ("volume", false)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_default_optional() {
+        // Arrange
+        let content = std::fs::read_to_string("tests/data/model_default_required.md")
+            .expect("Could not read markdown")
+            .replace("default-required: true", "default-required: false");
+
+        // Act
+        let model = DataModel::from_markdown_string(&content).expect("Could not parse markdown");
+
+        // Assert
+        let required = model.objects[0]
+            .attributes
+            .iter()
+            .map(|a| (a.name.as_str(), a.required))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            required,
+            vec![
+                ("name", false),
+                ("notes", true),
+                ("comment", false),
+                ("volume", false)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fenced_description() {
+        // Arrange
+        let path = Path::new("tests/data/model_fenced_description.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        let object = model.object("Measurement").expect("Object not found");
+        let expected = "The measured value, which is\n\n\
+            - **positive** for absorbances\n\
+            - `0.0` if the sample is blank\n\n\
+            See the [protocol](https://example.org) for details.";
+        assert_eq!(object.attribute("value").unwrap().docstring, expected);
+        assert_eq!(
+            object.attribute("unit").unwrap().docstring,
+            "The unit of the value."
+        );
+    }
+
+    #[test]
+    fn test_fenced_description_rust() {
+        // Arrange
+        let path = Path::new("tests/data/model_fenced_description.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rendered = render_jinja_template(&Templates::Rust, &mut model, None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rendered.contains(
+            "    /// The measured value, which is\n    ///\n    \
+             /// - **positive** for absorbances\n    \
+             /// - `0.0` if the sample is blank\n"
+        ));
+    }
+
+    #[test]
+    fn test_json_schema_duration() {
+        // Arrange
+        let path = Path::new("tests/data/model_temporal.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Event".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        let properties = &schema["properties"];
+        assert_eq!(properties["runtime"]["type"], serde_json::json!("string"));
+        assert_eq!(properties["runtime"]["format"], "duration");
+        assert_eq!(
+            properties["intervals"]["items"],
+            serde_json::json!({ "type": "string", "format": "duration" })
+        );
+        assert_eq!(properties["started"]["type"], "integer");
+        assert!(properties["started"].get("format").is_none());
+    }
+
+    #[test]
+    fn test_json_schema_timestamp_string() {
+        // Arrange
+        let content = std::fs::read_to_string("tests/data/model_temporal.md")
+            .expect("Could not read markdown")
+            .replace(
+                "prefix: \"tst\"",
+                "prefix: \"tst\"\ntimestamp-format: string",
+            );
+        let model = DataModel::from_markdown_string(&content).expect("Could not parse markdown");
+
+        // Act
+        let schema = model
+            .json_schema(Some("Event".to_string()))
+            .expect("Could not generate schema");
+        let schema: serde_json::Value = serde_json::from_str(&schema).unwrap();
+
+        // Assert
+        let started = &schema["properties"]["started"];
+        assert_eq!(started["type"], "string");
+        assert_eq!(started["format"], "date-time");
+    }
+
+    #[test]
+    fn test_array_default_list() {
+        // Arrange
+        let path = Path::new("tests/data/model_array_default.md");
+
+        // Act
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Assert
+        let defaults = model.objects[0]
+            .attributes
+            .iter()
+            .map(|a| serde_json::to_value(&a.default).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            defaults,
+            vec![
+                serde_json::Value::Null,
+                serde_json::json!([]),
+                serde_json::json!([1, 2])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_list_defaults_typescript() {
+        // Arrange
+        let path = Path::new("tests/data/model_list_defaults.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let zod = render_jinja_template(&Templates::Zod, &mut model.clone(), None)
+            .expect("Could not render template");
+        let class = render_jinja_template(&Templates::TypescriptClass, &mut model.clone(), None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(zod.contains("  tags: z.array(z.string()).default([\"a, b\", \"c\"]),"));
+        assert!(zod.contains("  values: z.array(z.number()).default([1, 2.5]),"));
+        assert!(zod.contains("  states: z.array(StateSchema).default(['open', 'closed']),"));
+        assert!(zod.contains("  empty: z.array(z.number().int()).default([]),"));
+        assert!(class.contains("    this.tags = data[\"tags\"] ?? [\"a, b\", \"c\"];"));
+        assert!(class.contains("    this.states = data[\"states\"] ?? [State.OPEN, State.CLOSED];"));
+    }
+
+    #[test]
+    fn test_list_defaults_python() {
+        // Arrange
+        let path = Path::new("tests/data/model_list_defaults.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let pydantic = render_jinja_template(&Templates::PythonPydantic, &mut model.clone(), None)
+            .expect("Could not render template");
+        let dataclass =
+            render_jinja_template(&Templates::PythonDataclass, &mut model.clone(), None)
+                .expect("Could not render template");
+
+        // Assert
+        assert!(pydantic.contains("    name: str = \"sample\"\n"));
+        assert!(pydantic
+            .contains("    tags: list[str] = Field(default_factory=lambda: [\"a, b\", \"c\"])\n"));
+        assert!(pydantic.contains(
+            "    states: list[State] = Field(default_factory=lambda: [State.OPEN, State.CLOSED])\n"
+        ));
+        assert!(pydantic.contains("    empty: list[int] = Field(default_factory=list)\n"));
+        assert!(dataclass
+            .contains("    values: List[float] = field(default_factory=lambda: [1, 2.5])\n"));
+    }
+
+    #[test]
+    fn test_list_defaults_rust_and_dart() {
+        // Arrange
+        let path = Path::new("tests/data/model_list_defaults.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let rust = render_jinja_template(&Templates::Rust, &mut model.clone(), None)
+            .expect("Could not render template");
+        let dart = render_jinja_template(&Templates::Dart, &mut model.clone(), None)
+            .expect("Could not render template");
+
+        // Assert
+        assert!(rust.contains(
+            "    #[serde(default = \"default_sample_tags\")]\n    pub tags: Vec<String>,"
+        ));
+        assert!(rust.contains(
+            "fn default_sample_tags() -> Vec<String> {\n    vec![\"a, b\".to_string(), \"c\".to_string()]\n}"
+        ));
+        assert!(rust.contains("    vec![1.0, 2.5]\n"));
+        assert!(rust.contains("    vec![State::Open, State::Closed]\n"));
+        assert!(rust.contains(
+            "    #[serde(default, skip_serializing_if = \"Vec::is_empty\")]\n    pub empty: Vec<i64>,"
+        ));
+        assert!(dart.contains("    this.tags = const ['a, b', 'c'],"));
+        assert!(dart.contains("    this.states = const [State.open, State.closed],"));
+    }
+
+    #[test]
+    fn test_list_defaults_json_schema() {
+        // Arrange
+        let path = Path::new("tests/data/model_list_defaults.md");
+        let model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let schema: serde_json::Value = serde_json::from_str(
+            &model
+                .json_schema(Some("Sample".to_string()))
+                .expect("Could not generate schema"),
+        )
+        .expect("Could not parse schema");
+
+        // Assert
+        let properties = &schema["properties"];
+        assert!(properties["name"].get("default").is_none());
+        assert_eq!(
+            properties["tags"]["default"],
+            serde_json::json!(["a, b", "c"])
+        );
+        assert_eq!(properties["values"]["default"], serde_json::json!([1, 2.5]));
+        assert_eq!(
+            properties["states"]["default"],
+            serde_json::json!(["open", "closed"])
+        );
+        assert_eq!(properties["empty"]["default"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_list_defaults_unsupported_template() {
+        // Arrange
+        let path = Path::new("tests/data/model_list_defaults.md");
+        let mut model = DataModel::from_markdown(path).expect("Could not parse markdown");
+
+        // Act
+        let err = render_jinja_template(&Templates::Sql, &mut model, None)
+            .expect_err("SQL should reject list defaults");
+
+        // Assert
+        assert!(err
+            .to_string()
+            .contains("does not support the list default of property 'Sample.tags'"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_array_default_scalar() {
+        // Arrange
+        let path = Path::new("tests/data/model_array_default_invalid.md");
+
+        // Act
+        DataModel::from_markdown(path).expect("Could not parse markdown");
+    }
 }